@@ -6,32 +6,149 @@ use std::{env, fmt, fs};
 
 use serde::{Deserialize, Serialize};
 
+use super::{Migration, Secret};
 use crate::cli::{arguments as args, subcommands as sub};
+use crate::fs::CsvDialect;
+use crate::models::{ColorMode, Colors, Column, Links, Lists, Plugins, Priority, Task, Urgency};
+use crate::plugin::Plugin;
+use crate::{debug, info, tr, warning, Hooks, Lang};
 
 /// Contains the configuration used while running `postit`.
 ///
-/// If the configuration file doesn't exist, it uses the default values defined
-/// in the [Default] trait implementation.
+/// The configuration is merged from several files (see [`Config::layers`]),
+/// where each file overrides the values of the previous ones. Values that
+/// aren't defined in any file use the defaults of the [Default] trait
+/// implementation.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
+    /// Version of the layout of the config file (see [`Migration`]).
+    pub version: u32,
     /// Defines where tasks are stored. It can be the path to a file or a database connection string (including protocol).
     pub persister: String,
+    /// Persisters that receive a copy of every change made to `persister` (see
+    /// [`Mirror`][crate::mirror::Mirror]). They can also be set by writing a
+    /// list in `persister`, where the first item is the persister.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<String>,
+    /// Command run when postit is invoked without arguments (e.g.: 'view').
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_command: Option<String>,
     /// If `true`, allows dropping tasks without them being checked.
     pub force_drop: bool,
+    /// Days that dropped tasks are kept in the trash (`0` drops them permanently).
+    pub trash_days: u32,
     /// If `true`, allows overwriting files if they already exist.
     pub force_copy: bool,
     /// If `true`, drops the old file after copying its contents to the new file.
     pub drop_after_copy: bool,
+    /// Seconds waited for other postit processes to finish changing a file before failing.
+    pub lock_timeout: u64,
+    /// If `true`, JSON files are written indented instead of minified (the '--compact' flag overrides it).
+    pub json_pretty: bool,
+    /// If `true`, destructive commands ask for confirmation when run in a terminal.
+    pub confirm: bool,
+    /// If `true`, commands fail when some of the passed ids don't match any task.
+    pub strict: bool,
+    /// If `true`, the 'view' command doesn't display checked tasks.
+    pub hide_checked: bool,
+    /// The 'view' command hides tasks with a lower priority than this one.
+    pub min_priority: Priority,
+    /// Priority of the tasks added without one.
+    pub default_priority: Priority,
+    /// Points given to unchecked tasks based on their priority, used by the 'next' command.
+    pub urgency: Urgency,
+    /// Columns displayed by 'view --table' (the '--columns' flag overrides it).
+    pub columns: Vec<Column>,
+    /// Template used to display tasks (see [`Task::render`]).
+    pub format: String,
+    /// Defines when the output is colored (the '--no-color' flag overrides it).
+    pub color: ColorMode,
+    /// Styles of tasks based on their priority and on wether they are checked.
+    pub colors: Colors,
+    /// Language of the errors, prompts and documentation headings (see [`Lang`]).
+    pub lang: Lang,
+    /// Shell commands executed after tasks are added or checked.
+    pub hooks: Hooks,
+    /// URL templates used to link the references of tasks, indexed by their prefix.
+    pub links: Links,
+    /// Persisters of the named task lists, indexed by their name.
+    pub lists: Lists,
+    /// Commands of the external persisters used by `plugin://` connections, indexed by their name.
+    pub plugins: Plugins,
+    /// Delimiter, quoting and header of the CSV files used as persisters.
+    pub csv: CsvDialect,
+    /// Database used when the connection string of a database persister doesn't specify one.
+    pub database: String,
+    /// Reference to the password of database persisters (see [`Secret`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+impl Config {
+    /// Config keys that can be overridden with a `POSTIT_<KEY>` env var (see
+    /// [`Config::env_overrides`]). The `password` can't, because `POSTIT_PASSWORD`
+    /// already holds the password itself.
+    pub const ENV_KEYS: [&str; 18] = [
+        "persister",
+        "default_command",
+        "force_drop",
+        "trash_days",
+        "force_copy",
+        "drop_after_copy",
+        "lock_timeout",
+        "json_pretty",
+        "confirm",
+        "strict",
+        "hide_checked",
+        "min_priority",
+        "default_priority",
+        "columns",
+        "format",
+        "color",
+        "lang",
+        "database",
+    ];
+
+    /// Keys that the project config file (see [`Config::layers`]) can't set,
+    /// because they run commands, choose the files that postit writes or hold
+    /// secrets.
+    pub const UNTRUSTED_KEYS: [&str; 6] =
+        ["hooks", "plugins", "password", "default_command", "persister", "mirrors"];
 }
 
 impl Default for Config {
     #[inline]
     fn default() -> Self {
         Self {
+            version: Migration::latest(),
             persister: String::from("tasks.csv"),
+            mirrors: Vec::new(),
+            default_command: None,
             force_drop: false,
+            trash_days: 30,
             force_copy: false,
             drop_after_copy: false,
+            lock_timeout: 5,
+            json_pretty: true,
+            confirm: true,
+            strict: false,
+            hide_checked: false,
+            min_priority: Priority::None,
+            default_priority: Priority::Med,
+            urgency: Urgency::default(),
+            columns: Column::defaults(),
+            format: String::from(Task::DEFAULT_FORMAT),
+            color: ColorMode::Auto,
+            colors: Colors::default(),
+            lang: Lang::default(),
+            hooks: Hooks::default(),
+            links: Links::default(),
+            lists: Lists::default(),
+            plugins: Plugins::default(),
+            csv: CsvDialect::default(),
+            database: String::from("test"),
+            password: None,
         }
     }
 }
@@ -40,9 +157,38 @@ impl fmt::Display for Config {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "persister: {}", self.persister)?;
+
+        if self.mirrors.is_empty() {
+            writeln!(f, "mirrors: none")?;
+        } else {
+            writeln!(f, "mirrors: {}", self.mirrors.join(", "))?;
+        }
+
+        writeln!(f, "default_command: {}", self.default_command.as_deref().unwrap_or("none"))?;
         writeln!(f, "force_drop: {}", self.force_drop)?;
+        writeln!(f, "trash_days: {}", self.trash_days)?;
         writeln!(f, "force_copy: {}", self.force_copy)?;
-        write!(f, "drop_after_copy: {}", self.drop_after_copy)
+        writeln!(f, "drop_after_copy: {}", self.drop_after_copy)?;
+        writeln!(f, "lock_timeout: {}", self.lock_timeout)?;
+        writeln!(f, "json_pretty: {}", self.json_pretty)?;
+        writeln!(f, "confirm: {}", self.confirm)?;
+        writeln!(f, "strict: {}", self.strict)?;
+        writeln!(f, "hide_checked: {}", self.hide_checked)?;
+        writeln!(f, "min_priority: {}", self.min_priority)?;
+        writeln!(f, "default_priority: {}", self.default_priority)?;
+        writeln!(f, "urgency: {}", self.urgency)?;
+        writeln!(f, "columns: {}", Column::join(&self.columns))?;
+        writeln!(f, "format: '{}'", self.format)?;
+        writeln!(f, "color: {}", self.color)?;
+        writeln!(f, "colors: {}", self.colors)?;
+        writeln!(f, "lang: {}", self.lang)?;
+        writeln!(f, "hooks: {}", self.hooks)?;
+        writeln!(f, "links: {}", self.links)?;
+        writeln!(f, "lists: {}", self.lists)?;
+        writeln!(f, "plugins: {}", self.plugins)?;
+        writeln!(f, "csv: {}", self.csv)?;
+        writeln!(f, "database: {}", self.database)?;
+        write!(f, "password: {}", self.password_reference())
     }
 }
 
@@ -61,10 +207,32 @@ impl Config {
             sub::Config::Remove => Self::remove(),
             sub::Config::List => Self::list(),
             sub::Config::Set(args) => Self::set(args),
+            sub::Config::Check => Self::report(&Self::check()?),
         }
     }
 
-    /// Creates the config file from the default values.
+    /// Prints the problems found by [`Config::check`], or that the config is
+    /// valid if there are none.
+    ///
+    /// # Errors
+    /// - There are problems, so scripts can detect them by the exit status.
+    #[inline]
+    pub fn report(problems: &[String]) -> super::Result<()> {
+        if problems.is_empty() {
+            println!("{}", tr!("The configuration is valid"));
+            return Ok(());
+        }
+
+        for problem in problems {
+            println!("- {problem}");
+        }
+
+        Err(super::Error::Invalid(problems.len()))
+    }
+
+    /// Creates the config file with its `version` and the default values
+    /// commented out, so the values of the system config file (see
+    /// [`Config::layers`]) still apply until a key is uncommented.
     ///
     /// # Errors
     /// - The path can't be obtained.
@@ -82,14 +250,26 @@ impl Config {
         }
 
         let mut file = fs::File::create(&path)?;
-        let toml = toml::to_string_pretty(&Self::default())?;
+        let mut defaults = Self::default().table()?;
+        defaults.remove("version");
+
+        let commented: Vec<String> = toml::to_string_pretty(&defaults)?
+            .lines()
+            .map(|line| if line.is_empty() { String::new() } else { format!("# {line}") })
+            .collect();
+
+        let toml = format!(
+            "version = {}\n\n# Default values; uncomment a key to change it\n{}\n",
+            Migration::latest(),
+            commented.join("\n")
+        );
 
         file.write_all(toml.as_bytes()).map_err(|e| {
-            eprintln!("Failed to write default config to file");
+            warning!("Failed to write default config to file");
             super::Error::Io(e)
         })?;
 
-        println!("Configuration file created at '{}'", path.display());
+        info!("Configuration file created at '{}'", path.display());
 
         Ok(())
     }
@@ -145,11 +325,11 @@ impl Config {
         }
 
         fs::remove_file(&path).map_err(|e| {
-            eprintln!("Config file couldn't be deleted.");
+            warning!("Config file couldn't be deleted.");
             super::Error::Io(e)
         })?;
 
-        println!("Config file removed from '{}'", path.parent().unwrap().display());
+        info!("Config file removed from '{}'", path.parent().unwrap().display());
 
         Ok(())
     }
@@ -186,44 +366,236 @@ impl Config {
     pub fn set(args: args::ConfigSet) -> super::Result<()> {
         Self::_check_path_exists()?;
 
-        if args.persister.is_none()
-            && args.force_drop.is_none()
-            && args.force_copy.is_none()
-            && args.drop_after_copy.is_none()
-        {
+        if args.is_empty() {
             return Err(super::Error::EmptySetArgs);
         }
 
-        let mut config = Self::load()?;
+        let keys = args.keys();
+        let mut config = Self::load_from(&[Self::path()?])?;
 
         if let Some(new) = args.persister {
-            println!("persister: {} -> {}", config.persister, new);
+            info!("persister: {} -> {}", config.persister, new);
             config.persister = new;
         }
 
+        if let Some(new) = args.default_command {
+            let old = config.default_command.as_deref().unwrap_or("none");
+            info!("default_command: {old} -> {new}");
+            config.default_command = (!new.is_empty()).then_some(new);
+        }
+
         if let Some(new) = args.force_drop {
-            println!("force_drop: {} -> {}", config.force_drop, new);
+            info!("force_drop: {} -> {}", config.force_drop, new);
             config.force_drop = new;
         }
 
+        if let Some(new) = args.trash_days {
+            info!("trash_days: {} -> {}", config.trash_days, new);
+            config.trash_days = new;
+        }
+
         if let Some(new) = args.force_copy {
-            println!("force_copy: {} -> {}", config.force_copy, new);
+            info!("force_copy: {} -> {}", config.force_copy, new);
             config.force_copy = new;
         }
 
         if let Some(new) = args.drop_after_copy {
-            println!("drop_after_copy: {} -> {}", config.drop_after_copy, new);
+            info!("drop_after_copy: {} -> {}", config.drop_after_copy, new);
             config.drop_after_copy = new;
         }
 
-        println!();
+        if let Some(new) = args.lock_timeout {
+            info!("lock_timeout: {} -> {}", config.lock_timeout, new);
+            config.lock_timeout = new;
+        }
+
+        if let Some(new) = args.json_pretty {
+            info!("json_pretty: {} -> {}", config.json_pretty, new);
+            config.json_pretty = new;
+        }
+
+        if let Some(new) = args.confirm {
+            info!("confirm: {} -> {}", config.confirm, new);
+            config.confirm = new;
+        }
+
+        if let Some(new) = args.strict {
+            info!("strict: {} -> {}", config.strict, new);
+            config.strict = new;
+        }
+
+        if let Some(new) = args.hide_checked {
+            info!("hide_checked: {} -> {}", config.hide_checked, new);
+            config.hide_checked = new;
+        }
+
+        if let Some(new) = args.min_priority {
+            info!("min_priority: {} -> {}", config.min_priority, new);
+            config.min_priority = new;
+        }
+
+        if let Some(new) = args.default_priority {
+            info!("default_priority: {} -> {}", config.default_priority, new);
+            config.default_priority = new;
+        }
+
+        if let Some(new) = args.columns {
+            info!("columns: {} -> {}", Column::join(&config.columns), Column::join(&new));
+            config.columns = new;
+        }
+
+        if let Some(new) = args.format {
+            info!("format: '{}' -> '{}'", config.format, new);
+            config.format = new;
+        }
+
+        if let Some(new) = args.color {
+            info!("color: {} -> {}", config.color, new);
+            config.color = new;
+        }
+
+        if let Some(new) = args.lang {
+            info!("lang: {} -> {}", config.lang, new);
+            config.lang = new;
+        }
+
+        if let Some(new) = args.database {
+            info!("database: {} -> {}", config.database, new);
+            config.database = new;
+        }
+
+        if let Some(new) = args.password {
+            let secret = Secret::parse(&new)?;
+
+            info!("password: {} -> {}", config.password_reference(), secret);
+            config.password = Some(new);
+        }
+
+        info!();
+
+        config.save_keys(&keys)
+    }
+}
+
+// Methods for managing the 'list' command
+impl Config {
+    /// Manages the named task lists using a `List` subcommand instance.
+    ///
+    /// # Errors
+    /// - Any error while doing operations on the configuration file.
+    #[inline]
+    pub fn manage_lists(subcommand: sub::List) -> super::Result<()> {
+        match subcommand {
+            sub::List::Create(args) => Self::create_list(args),
+            sub::List::Show => Self::show_lists(),
+            sub::List::Remove(args) => Self::remove_list(&args),
+        }
+    }
+
+    /// Adds a list to the user config file, which is created if it doesn't exist.
+    ///
+    /// # Errors
+    /// - The name of the list isn't valid or it already exists.
+    /// - The configuration can't be loaded or saved.
+    #[inline]
+    pub fn create_list(args: args::ListCreate) -> super::Result<()> {
+        if !Lists::is_name(&args.name) {
+            return Err(super::Error::InvalidListName(args.name));
+        }
+
+        let path = Self::path()?;
+        let mut config = Self::load_from(&[&path])?;
+
+        if config.lists.get(&args.name).is_some() {
+            return Err(super::Error::ListAlreadyExists(args.name));
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let persister = args
+            .persister
+            .unwrap_or_else(|| format!("{}.csv", args.name));
+
+        info!("Created the '{}' list (persister: {persister})", args.name);
+        config.lists.0.insert(args.name, persister);
 
         config.save()
     }
+
+    /// Displays the lists and their persisters.
+    ///
+    /// # Errors
+    /// - The configuration can't be loaded.
+    #[inline]
+    pub fn show_lists() -> super::Result<()> {
+        let config = Self::load()?;
+
+        if config.lists.0.is_empty() {
+            println!("There are no lists; create one with 'postit list create <NAME>'");
+            return Ok(());
+        }
+
+        for (name, persister) in &config.lists.0 {
+            println!("{name}: {persister}");
+        }
+
+        Ok(())
+    }
+
+    /// Removes a list from the user config file. The tasks stored in its
+    /// persister are kept.
+    ///
+    /// # Errors
+    /// - The list doesn't exist in the user config file.
+    /// - The configuration can't be loaded or saved.
+    #[inline]
+    pub fn remove_list(args: &args::ListRemove) -> super::Result<()> {
+        let mut config = Self::load_from(&[Self::path()?])?;
+
+        let Some(persister) = config.lists.0.remove(&args.name) else {
+            return Err(super::Error::UnknownList(args.name.clone()));
+        };
+
+        info!("Removed the '{}' list (its tasks are kept in {persister})", args.name);
+
+        config.save()
+    }
+
+    /// Returns the persister of the list named `name`.
+    ///
+    /// # Errors
+    /// - There is no list named `name`.
+    #[inline]
+    pub fn list_persister(&self, name: &str) -> super::Result<String> {
+        self.lists
+            .get(name)
+            .cloned()
+            .ok_or_else(|| super::Error::UnknownList(name.to_owned()))
+    }
+
+    /// Returns if the connection is the persister, a mirror or the persister
+    /// of a list of the config.
+    #[inline]
+    pub fn uses(&self, conn: &str) -> bool {
+        self.persister == conn
+            || self.mirrors.iter().any(|mirror| mirror == conn)
+            || self.lists.0.values().any(|persister| persister == conn)
+    }
 }
 
 // Utility methods to interact with the configuration
 impl Config {
+    /// Returns the password reference in a printable way, hiding plain passwords.
+    fn password_reference(&self) -> String {
+        match self.password.as_deref().map(Secret::parse) {
+            Some(Ok(secret)) => secret.to_string(),
+            Some(Err(_)) => String::from("invalid"),
+            None => String::from("none"),
+        }
+    }
+
     /// Returns the value of the `POSTIT_ROOT` environment variable, which must
     /// have a path structure.
     ///
@@ -349,53 +721,411 @@ impl Config {
         Ok(parent.join(path))
     }
 
-    /// Loads the config from a file or creates it if it doesn't exist.
+    /// Returns the path of the system-wide config file, which is used to set
+    /// defaults for every user:
+    /// - Unix: `/etc/postit/.postit.toml`
+    /// - Windows: `%ProgramData%\postit\.postit.toml`
+    #[inline]
+    pub fn system_path() -> PathBuf {
+        let root = if cfg!(windows) {
+            PathBuf::from(
+                env::var("ProgramData").unwrap_or_else(|_| String::from(r"C:\ProgramData")),
+            )
+        } else {
+            PathBuf::from("/etc")
+        };
+
+        root.join("postit").join(Self::config_file_name())
+    }
+
+    /// Returns the paths of the config files, from lowest to highest precedence:
+    /// 1. The system config file ([`Config::system_path`]).
+    /// 2. The user config file ([`Config::path`]).
+    /// 3. The project config file (`.postit.toml` in the current directory),
+    ///    which can't set the keys of [`Config::UNTRUSTED_KEYS`] nor use
+    ///    `plugin://` lists, because it may come from an untrusted repository.
     ///
     /// # Errors
-    /// - The config file can't be loaded.
-    /// - The config file can't be read.
+    /// - The path of the user config file can't be obtained.
+    #[inline]
+    pub fn layers() -> super::Result<Vec<PathBuf>> {
+        let mut layers = vec![Self::system_path(), Self::path()?];
+
+        if let Ok(dir) = env::current_dir() {
+            let local = dir.join(Self::config_file_name());
+
+            if !layers.contains(&local) {
+                layers.push(local);
+            }
+        }
+
+        Ok(layers)
+    }
+
+    /// Loads the config by merging every existing file of [`Config::layers`],
+    /// and then the values of the env vars of [`Config::ENV_KEYS`].
+    ///
+    /// # Errors
+    /// - The path of the user config file can't be obtained.
+    /// - A config file can't be read or parsed.
+    /// - An env var has an invalid value for its key.
     #[inline]
     pub fn load() -> super::Result<Self> {
-        let path = Self::path()?;
+        let mut table = Self::read(&Self::layers()?)?;
+        Self::merge(&mut table, Self::env_overrides());
 
-        if !path.exists() {
-            return Ok(Self::default());
+        Ok(toml::Value::Table(table).try_into()?)
+    }
+
+    /// Loads the config by merging the passed files in order, so the values of
+    /// a file override the ones of the previous files. Missing files are skipped.
+    ///
+    /// # Errors
+    /// - A config file can't be read or parsed.
+    #[inline]
+    pub fn load_from<T: AsRef<Path>>(paths: &[T]) -> super::Result<Self> {
+        Ok(toml::Value::Table(Self::read(paths)?).try_into()?)
+    }
+
+    /// Returns the values of the env vars that override config keys (e.g.:
+    /// `POSTIT_FORCE_DROP` overrides `force_drop`). Vars that aren't set or
+    /// aren't valid unicode are skipped.
+    ///
+    /// Lists (e.g.: `columns`) are separated by commas. Values that can't be
+    /// parsed as the type of their key are kept as strings, so loading the
+    /// config fails with the name of the key.
+    #[inline]
+    pub fn env_overrides() -> toml::Table {
+        Self::ENV_KEYS
+            .into_iter()
+            .filter_map(|key| {
+                let value = env::var(Self::env_var(key)).ok()?;
+                let value = match key {
+                    "force_drop" | "force_copy" | "drop_after_copy" | "json_pretty" | "confirm"
+                    | "strict" | "hide_checked" => value
+                        .parse()
+                        .map_or(toml::Value::String(value), toml::Value::Boolean),
+                    "trash_days" | "lock_timeout" => value
+                        .parse()
+                        .map_or(toml::Value::String(value), toml::Value::Integer),
+                    "columns" => toml::Value::Array(
+                        value
+                            .split(',')
+                            .map(|column| toml::Value::String(column.trim().to_owned()))
+                            .collect(),
+                    ),
+                    _ => toml::Value::String(value),
+                };
+
+                Some((key.to_owned(), value))
+            })
+            .collect()
+    }
+
+    /// Returns the name of the env var that overrides a config key.
+    #[inline]
+    pub fn env_var(key: &str) -> String {
+        format!("POSTIT_{}", key.to_uppercase())
+    }
+
+    /// Returns the keys that config files can have.
+    #[inline]
+    pub fn keys() -> Vec<String> {
+        let mut keys: Vec<String> = toml::Value::try_from(Self::default())
+            .ok()
+            .and_then(|value| {
+                value
+                    .as_table()
+                    .map(|table| table.keys().cloned().collect())
+            })
+            .unwrap_or_default();
+
+        keys.extend(["mirrors", "default_command", "password"].map(String::from));
+
+        keys
+    }
+
+    /// Checks the existing config files of [`Config::layers`] and the env vars
+    /// of [`Config::ENV_KEYS`], returning a description of every problem found:
+    /// invalid TOML, unknown keys, values of the wrong type, list names that
+    /// can't be used and invalid password references.
+    ///
+    /// # Errors
+    /// - The path of the user config file can't be obtained.
+    #[inline]
+    pub fn check() -> super::Result<Vec<String>> {
+        let keys = Self::keys();
+        let mut problems = Vec::new();
+
+        for path in Self::layers()?.iter().filter(|path| path.exists()) {
+            let file = path.display();
+
+            let mut table: toml::Table = match fs::read_to_string(path) {
+                Ok(content) => match toml::from_str(&content) {
+                    Ok(table) => table,
+                    Err(e) => {
+                        problems.push(format!("'{file}' isn't valid TOML: {}", e.message()));
+                        continue;
+                    }
+                },
+                Err(e) => {
+                    problems.push(format!("'{file}' can't be read: {e}"));
+                    continue;
+                }
+            };
+
+            if Migration::version_of(&table) > Migration::latest() {
+                problems.push(format!(
+                    "'{file}' was written by a newer version of postit (version {})",
+                    Migration::version_of(&table)
+                ));
+            }
+
+            Migration::run(&mut table);
+            Migration::rename(&mut table, "hide_done", "hide_checked");
+            Self::split_persister(&mut table);
+
+            if Self::is_project(path) {
+                for key in Self::remove_untrusted(&mut table) {
+                    problems.push(format!(
+                        "'{file}' sets '{key}', which is ignored in project config files"
+                    ));
+                }
+            }
+
+            for key in table.keys().filter(|key| !keys.contains(key)) {
+                problems.push(format!(
+                    "'{file}' has the unknown key '{key}' (see 'postit docs config')"
+                ));
+            }
+
+            if let Err(e) = toml::Value::Table(table).try_into::<Self>() {
+                problems.push(format!("'{file}' has an invalid value: {}", e.message()));
+            }
         }
 
-        let content = fs::read_to_string(path).map_err(|e| {
-            eprintln!("Failed to read config file");
-            super::Error::Io(e)
-        })?;
+        for (key, value) in Self::env_overrides() {
+            let table = toml::Table::from_iter([(key.clone(), value)]);
+
+            if let Err(e) = toml::Value::Table(table).try_into::<Self>() {
+                let var = Self::env_var(&key);
+                problems.push(format!("The '{var}' env var is invalid: {}", e.message()));
+            }
+        }
 
-        let config = toml::from_str(&content)?;
+        if let Ok(config) = Self::load() {
+            for name in config.lists.0.keys().filter(|name| !Lists::is_name(name)) {
+                problems.push(format!(
+                    "The list '{name}' can't be used, because its name has '.', '/', '\\' or ':'"
+                ));
+            }
 
-        Ok(config)
+            if let Some(Err(e)) = config.password.as_deref().map(Secret::parse) {
+                problems.push(format!("The 'password' is invalid: {e}"));
+            }
+        }
+
+        Ok(problems)
     }
 
-    /// Saves the config instance to a file.
+    /// Reads the passed config files and merges them in order into a table,
+    /// after applying their pending [`Migration`]s.
+    ///
+    /// # Errors
+    /// - A config file can't be read or parsed.
+    fn read<T: AsRef<Path>>(paths: &[T]) -> super::Result<toml::Table> {
+        let mut table = toml::Table::new();
+
+        for path in paths.iter().map(AsRef::as_ref).filter(|path| path.exists()) {
+            let content = fs::read_to_string(path).map_err(|e| {
+                warning!("Failed to read config file '{}'", path.display());
+                super::Error::Io(e)
+            })?;
+
+            let mut layer = toml::from_str(&content)?;
+
+            for migration in Migration::run(&mut layer) {
+                debug!("Migrated '{}': {}", path.display(), migration.description);
+            }
+
+            Migration::rename(&mut layer, "hide_done", "hide_checked");
+            Self::split_persister(&mut layer);
+
+            if Self::is_project(path) {
+                for key in Self::remove_untrusted(&mut layer) {
+                    warning!(
+                        "Ignoring '{key}' in '{}'; project config files can't run commands, choose where tasks are written or set passwords",
+                        path.display()
+                    );
+                }
+            }
+
+            Self::merge(&mut table, layer);
+
+            debug!("Loaded the config file '{}'", path.display());
+        }
+
+        Ok(table)
+    }
+
+    /// Returns `true` if the path is the project config file of
+    /// [`Config::layers`], which isn't the system nor the user config file.
+    fn is_project(path: &Path) -> bool {
+        env::current_dir().is_ok_and(|dir| dir.join(Self::config_file_name()) == path)
+            && path != Self::system_path()
+            && Self::path().is_ok_and(|user| user != path)
+    }
+
+    /// Removes the keys of a project config file that run commands, choose
+    /// the files that postit writes or hold secrets: the ones of
+    /// [`Config::UNTRUSTED_KEYS`] and the `plugin://` lists. Returns the
+    /// removed keys.
+    fn remove_untrusted(table: &mut toml::Table) -> Vec<String> {
+        let is_plugin = |value: &toml::Value| value.as_str().is_some_and(Plugin::is_plugin);
+        let mut removed = Vec::new();
+
+        for key in Self::UNTRUSTED_KEYS {
+            if table.remove(key).is_some() {
+                removed.push(key.to_owned());
+            }
+        }
+
+        if let Some(toml::Value::Table(lists)) = table.get_mut("lists") {
+            let names: Vec<String> = lists
+                .iter()
+                .filter(|&(_, persister)| is_plugin(persister))
+                .map(|(name, _)| name.clone())
+                .collect();
+
+            for name in names {
+                lists.remove(&name);
+                removed.push(format!("lists.{name}"));
+            }
+        }
+
+        removed
+    }
+
+    /// Splits a `persister` list into the persister (its first item) and the
+    /// `mirrors` (the rest of items), which replace the ones of the table.
+    fn split_persister(table: &mut toml::Table) {
+        if !table.get("persister").is_some_and(toml::Value::is_array) {
+            return;
+        }
+
+        let Some(toml::Value::Array(persisters)) = table.remove("persister") else {
+            return;
+        };
+
+        let mut persisters = persisters.into_iter();
+
+        if let Some(persister) = persisters.next() {
+            table.insert(String::from("persister"), persister);
+        }
+
+        table.insert(String::from("mirrors"), toml::Value::Array(persisters.collect()));
+    }
+
+    /// Merges the `layer` table into `base`, merging nested tables key by key.
+    fn merge(base: &mut toml::Table, layer: toml::Table) {
+        for (key, value) in layer {
+            match (base.get_mut(&key), value) {
+                (Some(toml::Value::Table(inner)), toml::Value::Table(nested)) => {
+                    Self::merge(inner, nested);
+                }
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Returns the config as a TOML table.
+    ///
+    /// # Errors
+    /// - The config can't be formatted to TOML.
+    fn table(&self) -> super::Result<toml::Table> {
+        Ok(toml::Value::try_from(self)?.try_into()?)
+    }
+
+    /// Updates the keys of the `file` table with the ones of `after` whose
+    /// value differs from the one in `before` (nested tables are compared key
+    /// by key), plus the `explicit` keys. Keys that `after` doesn't have are
+    /// removed.
+    fn update(file: &mut toml::Table, before: &toml::Table, after: toml::Table, explicit: &[&str]) {
+        file.retain(|key, _| after.contains_key(key) || !before.contains_key(key));
+
+        for (key, value) in after {
+            match (before.get(&key), value) {
+                (_, value) if explicit.contains(&key.as_str()) => {
+                    file.insert(key, value);
+                }
+                (Some(old), value) if *old == value => {}
+                (Some(toml::Value::Table(old)), toml::Value::Table(new)) => {
+                    let mut nested = match file.remove(&key) {
+                        Some(toml::Value::Table(nested)) => nested,
+                        _ => toml::Table::new(),
+                    };
+
+                    Self::update(&mut nested, old, new, &[]);
+
+                    if !nested.is_empty() {
+                        file.insert(key, toml::Value::Table(nested));
+                    }
+                }
+                (_, value) => {
+                    file.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Saves the config instance to the user config file, like
+    /// [`Config::save_keys`] without explicit keys.
     ///
     /// # Errors
     /// - The config path can't be obtained.
-    /// - The config file can't be created.
+    /// - The config file can't be read, created or saved.
     /// - The config can't be formatted to TOML.
-    /// - The config file can't be saved.
     #[inline]
     pub fn save(&self) -> super::Result<()> {
+        self.save_keys(&[])
+    }
+
+    /// Saves the config instance to the user config file.
+    ///
+    /// Only the keys that the file already sets, the ones whose value changed
+    /// and the `explicit` ones (e.g.: the keys passed to 'config set') are
+    /// written, so the rest keep falling back to the system config file and
+    /// the defaults (see [`Config::layers`]).
+    ///
+    /// # Errors
+    /// - The config path can't be obtained.
+    /// - The config file can't be read, created or saved.
+    /// - The config can't be formatted to TOML.
+    #[inline]
+    pub fn save_keys(&self, explicit: &[&str]) -> super::Result<()> {
         let path = Self::path()?;
 
+        let mut table = Self::read(&[&path])?;
+        let before = toml::Value::Table(table.clone())
+            .try_into::<Self>()?
+            .table()?;
+        Self::update(&mut table, &before, self.table()?, explicit);
+
         let mut file = fs::File::create(&path).map_err(|e| {
-            eprintln!("Failed to open the config file {}: {e}", path.display());
+            warning!("Failed to open the config file {}: {e}", path.display());
             super::Error::Io(e)
         })?;
 
-        let toml = toml::to_string_pretty(self)?;
+        let toml = toml::to_string_pretty(&table)?;
 
         file.write_all(toml.as_bytes()).map_err(|e| {
-            eprintln!("Failed to save config to file: {e}");
+            warning!("Failed to save config to file: {e}");
             super::Error::Io(e)
         })?;
 
-        println!("Configuration saved");
+        info!("Configuration saved");
 
         Ok(())
     }
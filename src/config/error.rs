@@ -5,6 +5,8 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use crate::tr;
+
 /// Convenience type for configuration related operations.
 pub type Result<T> = std::result::Result<T, self::Error>;
 
@@ -12,29 +14,59 @@ pub type Result<T> = std::result::Result<T, self::Error>;
 #[derive(Error, Debug)]
 pub enum Error {
     /// Used when the `POSTIT_ROOT` has a blank value.
-    #[error("The 'POSTIT_ROOT' environment variable is empty")]
+    #[error("{}", tr!("The 'POSTIT_ROOT' environment variable is empty"))]
     EmptyEnvVar,
 
     /// Used when the value of `POSTIT_ROOT` is not a valid path.
-    #[error("The value of 'POSTIT_ROOT' is not a valid path or is a relative path: {0}")]
+    #[error(
+        "{}",
+        tr!(
+            "The value of 'POSTIT_ROOT' is not a valid path or is a relative path: {path}",
+            path = .0.display()
+        )
+    )]
     InvalidPathEnvVar(PathBuf),
 
     /// Used when the `POSTIT_ROOT`
-    #[error("The value of 'POSTIT_ROOT' is not unicode: {0:?}")]
+    #[error("{}", tr!("The value of 'POSTIT_ROOT' is not unicode: {value}", value = format!("{:?}", .0)))]
     NotUnicode(OsString),
 
     /// Used when the configuration file doesn't exist when it was expected to.
-    #[error("The configuration file doesn't exist at '{0}'")]
+    #[error("{}", tr!("The configuration file doesn't exist at '{path}'", path = .0.display()))]
     FileDoesntExist(PathBuf),
 
     /// Used when the configuration file already exists when it wasn't expected to.
-    #[error("The configuration file already exists at '{0}'")]
+    #[error("{}", tr!("The configuration file already exists at '{path}'", path = .0.display()))]
     FileAlreadyExists(PathBuf),
 
     /// Used when the 'config set' command is used but no flags are passed.
-    #[error("You must provide arguments to set (e.g.: --persister tasks.json)")]
+    #[error("{}", tr!("You must provide arguments to set (e.g.: --persister tasks.json)"))]
     EmptySetArgs,
 
+    /// Used when there is no list with the passed name.
+    #[error(
+        "{}",
+        tr!("There is no list named '{name}'; create it with 'postit list create {name}'", name = .0)
+    )]
+    UnknownList(String),
+
+    /// Used when the passed name can't be the name of a list.
+    #[error("{}", tr!("'{name}' can't be the name of a list", name = .0))]
+    InvalidListName(String),
+
+    /// Used when a list with the passed name already exists.
+    #[error("{}", tr!("The '{name}' list already exists", name = .0))]
+    ListAlreadyExists(String),
+
+    /// Used when a keyring reference is malformed or the OS keyring can't be
+    /// read, with the reason.
+    #[error("{0}")]
+    Keyring(String),
+
+    /// Used when 'config check' finds problems in the configuration.
+    #[error("{}", tr!("The configuration has {count} problem(s)", count = .0))]
+    Invalid(usize),
+
     /// Used for I/O errors ([`std::io::Error`]).
     #[error("{0}")]
     Io(#[from] std::io::Error),
@@ -44,11 +76,11 @@ pub enum Error {
     Env(#[from] std::env::VarError),
 
     /// Used when there is an error serializing a TOML structure ([`toml::ser::Error`]).
-    #[error("Failed to serialize config to TOML: {0}")]
+    #[error("{}", tr!("Failed to serialize config to TOML: {reason}", reason = .0))]
     TOMLSerialize(#[from] toml::ser::Error),
 
     /// Used when there is an error deserializing a TOML structure ([`toml::de::Error`]).
-    #[error("Failed to deserialize TOML to config: {0}")]
+    #[error("{}", tr!("Failed to deserialize TOML to config: {reason}", reason = .0))]
     TOMLDeserialize(#[from] toml::de::Error),
 
     /// Any error that doesn't belong into the previous variants.
@@ -65,4 +97,28 @@ impl Error {
     {
         Self::Other(err.into())
     }
+
+    /// Returns the stable code of the error, which can be looked up with
+    /// 'postit docs errors'.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        match *self {
+            Self::EmptyEnvVar => "E101",
+            Self::InvalidPathEnvVar(_) => "E102",
+            Self::NotUnicode(_) => "E103",
+            Self::FileDoesntExist(_) => "E104",
+            Self::FileAlreadyExists(_) => "E105",
+            Self::EmptySetArgs => "E106",
+            Self::Io(_) => "E107",
+            Self::Env(_) => "E108",
+            Self::TOMLSerialize(_) => "E109",
+            Self::TOMLDeserialize(_) => "E110",
+            Self::UnknownList(_) => "E111",
+            Self::Invalid(_) => "E112",
+            Self::InvalidListName(_) => "E113",
+            Self::ListAlreadyExists(_) => "E114",
+            Self::Keyring(_) => "E115",
+            Self::Other(_) => "E199",
+        }
+    }
 }
@@ -0,0 +1,97 @@
+//! Contains the `Migration` struct, which describes the changes made to the
+//! layout of the config file since it was first written.
+//!
+//! Config files store the version of their layout in the `version` key, and
+//! the pending migrations are applied to every file when the config is
+//! loaded (see [`Config::load`][super::Config::load]), so old files keep
+//! working without being rewritten.
+
+use std::fmt;
+
+/// Change to the layout of the config file.
+#[derive(Clone, Copy)]
+pub struct Migration {
+    /// Version of the layout after applying the migration.
+    pub version: u32,
+    /// What the migration changes.
+    pub description: &'static str,
+    /// Function that changes the table of a config file.
+    apply: fn(&mut toml::Table),
+}
+
+impl fmt::Debug for Migration {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migration")
+            .field("version", &self.version)
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Migration {
+    /// Migrations in the order they are applied, one per version.
+    pub const ALL: [Self; 1] =
+        [Self::new(1, "'path' (0.1.x) was renamed to 'persister'", |table| {
+            Self::rename(table, "path", "persister");
+        })];
+
+    /// Constructor of the `Migration` struct.
+    #[inline]
+    #[must_use]
+    pub const fn new(version: u32, description: &'static str, apply: fn(&mut toml::Table)) -> Self {
+        Self { version, description, apply }
+    }
+
+    /// Returns the version of the config files written by this version of postit.
+    #[inline]
+    pub const fn latest() -> u32 {
+        Self::ALL[Self::ALL.len() - 1].version
+    }
+
+    /// Returns the version of the layout of a config file, which is `0` for
+    /// the files written before the layout was versioned.
+    #[inline]
+    pub fn version_of(table: &toml::Table) -> u32 {
+        table
+            .get("version")
+            .and_then(toml::Value::as_integer)
+            .and_then(|version| u32::try_from(version).ok())
+            .unwrap_or(0)
+    }
+
+    /// Returns the migrations that a config file with the `version` layout lacks.
+    #[inline]
+    pub fn pending(version: u32) -> Vec<Self> {
+        Self::ALL
+            .into_iter()
+            .filter(|migration| migration.version > version)
+            .collect()
+    }
+
+    /// Applies the pending migrations to the table of a config file and sets
+    /// its `version` to the latest one. Returns the applied migrations.
+    #[inline]
+    pub fn run(table: &mut toml::Table) -> Vec<Self> {
+        let pending = Self::pending(Self::version_of(table));
+
+        for migration in &pending {
+            (migration.apply)(table);
+        }
+
+        if !pending.is_empty() {
+            table.insert(String::from("version"), toml::Value::Integer(Self::latest().into()));
+        }
+
+        pending
+    }
+
+    /// Moves the value of the `from` key to the `to` key, unless the table
+    /// already has the `to` key.
+    #[inline]
+    pub fn rename(table: &mut toml::Table, from: &str, to: &str) {
+        if let Some(value) = table.remove(from) {
+            table.entry(to).or_insert(value);
+        }
+    }
+}
@@ -1,8 +1,12 @@
-//! Contains the configuration module, which includes the [`Config`] struct and
-//! an [`Error`] enum for error handling.
+//! Contains the configuration module, which includes the [`Config`] struct,
+//! the [`Migration`]s of its file and an [`Error`] enum for error handling.
 
 mod configuration;
 mod error;
+mod migration;
+mod secret;
 
 pub use configuration::Config;
 pub use error::{Error, Result};
+pub use migration::Migration;
+pub use secret::{Secret, PASSWORD_VAR};
@@ -0,0 +1,180 @@
+//! Contains the `Secret` enum, used to read the password of database
+//! persisters without having to store it in plain text.
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::{env, fmt, fs};
+
+/// Environment variable that, if present, contains the password used to
+/// connect to database persisters. It takes precedence over the `password`
+/// value of the config file.
+pub const PASSWORD_VAR: &str = "POSTIT_PASSWORD";
+
+/// Source of the password used to connect to a database persister.
+///
+/// It is parsed from a reference with one of the following formats:
+/// - `env:NAME`: the value of the `NAME` environment variable.
+/// - `file:PATH`: the first line of the file located at `PATH`.
+/// - `keyring:SERVICE/ACCOUNT`: the entry of the OS keyring (`secret-tool`
+///   on Linux, `security` on macOS).
+/// - Any other value is used as the password itself, which is stored in plain
+///   text, so a warning is shown when it is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Secret {
+    /// Reads the password from an environment variable.
+    Env(String),
+    /// Reads the password from the first line of a credentials file.
+    File(PathBuf),
+    /// Reads the password from the OS keyring.
+    Keyring {
+        /// Service the password belongs to.
+        service: String,
+        /// Account the password belongs to.
+        account: String,
+    },
+    /// Uses the value as the password, which is stored in plain text.
+    Plain(String),
+}
+
+impl fmt::Display for Secret {
+    /// Displays the reference of the secret, hiding plain passwords.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "env:{name}"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Keyring { service, account } => write!(f, "keyring:{service}/{account}"),
+            Self::Plain(_) => write!(f, "********"),
+        }
+    }
+}
+
+impl Secret {
+    /// Parses a password reference.
+    ///
+    /// # Errors
+    /// - The reference uses the `keyring:` prefix but has no account.
+    #[inline]
+    pub fn parse(reference: &str) -> super::Result<Self> {
+        if let Some(name) = reference.strip_prefix("env:") {
+            return Ok(Self::Env(name.to_owned()));
+        }
+
+        if let Some(path) = reference.strip_prefix("file:") {
+            return Ok(Self::File(PathBuf::from(path)));
+        }
+
+        if let Some(entry) = reference.strip_prefix("keyring:") {
+            let Some((service, account)) = entry.split_once('/') else {
+                let msg = format!("Keyring references must be 'keyring:SERVICE/ACCOUNT': {entry}");
+                return Err(super::Error::Keyring(msg));
+            };
+
+            return Ok(Self::Keyring {
+                service: service.to_owned(),
+                account: account.to_owned(),
+            });
+        }
+
+        Ok(Self::Plain(reference.to_owned()))
+    }
+
+    /// Reads the password from its source.
+    ///
+    /// # Errors
+    /// - The environment variable isn't present.
+    /// - The credentials file can't be read.
+    /// - The keyring entry can't be read or the platform has no supported keyring.
+    #[inline]
+    pub fn resolve(&self) -> super::Result<String> {
+        match self {
+            Self::Env(name) => Ok(env::var(name)?),
+            Self::File(path) => {
+                let content = fs::read_to_string(path)?;
+                Ok(content.lines().next().unwrap_or_default().to_owned())
+            }
+            Self::Keyring { service, account } => {
+                let mut command = if cfg!(target_os = "macos") {
+                    let mut cmd = Command::new("security");
+                    cmd.args(["find-generic-password", "-s", service, "-a", account, "-w"]);
+                    cmd
+                } else if cfg!(unix) {
+                    let mut cmd = Command::new("secret-tool");
+                    cmd.args(["lookup", "service", service, "username", account]);
+                    cmd
+                } else {
+                    let msg = "The OS keyring is only supported on Linux and macOS";
+                    return Err(super::Error::Keyring(msg.to_owned()));
+                };
+
+                let output = command.output()?;
+
+                if !output.status.success() {
+                    let msg = format!("Couldn't read '{service}/{account}' from the OS keyring");
+                    return Err(super::Error::Keyring(msg));
+                }
+
+                let password = String::from_utf8(output.stdout).map_err(super::Error::wrap)?;
+
+                Ok(password.trim_end_matches(['\r', '\n']).to_owned())
+            }
+            Self::Plain(password) => Ok(password.clone()),
+        }
+    }
+
+    /// Returns `true` if the connection string has a user but no password.
+    #[inline]
+    pub fn needs_password(conn: &str) -> bool {
+        Self::inject(conn, "").is_some()
+    }
+
+    /// Returns the connection string with the password added after the user,
+    /// percent-encoding the characters that aren't allowed in it.
+    ///
+    /// Returns `None` if the connection string has no user or already has a password.
+    #[inline]
+    pub fn inject(conn: &str, password: &str) -> Option<String> {
+        let (scheme, rest) = conn.split_once("://")?;
+        let end = rest.find(['/', '?']).unwrap_or(rest.len());
+        let (authority, path) = rest.split_at(end);
+        let (user, host) = authority.rsplit_once('@')?;
+
+        if user.is_empty() || user.contains(':') {
+            return None;
+        }
+
+        let encoded: String = password
+            .bytes()
+            .map(|b| {
+                if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                    char::from(b).to_string()
+                } else {
+                    format!("%{b:02X}")
+                }
+            })
+            .collect();
+
+        Some(format!("{scheme}://{user}:{encoded}@{host}{path}"))
+    }
+
+    /// Returns the connection string with its password replaced by `***`, so
+    /// it can be printed. Connection strings without a password are returned
+    /// as they are.
+    #[inline]
+    pub fn redact(conn: &str) -> String {
+        let Some((scheme, rest)) = conn.split_once("://") else {
+            return conn.to_owned();
+        };
+
+        let end = rest.find(['/', '?']).unwrap_or(rest.len());
+        let (authority, path) = rest.split_at(end);
+
+        authority
+            .rsplit_once('@')
+            .and_then(|(credentials, host)| Some((credentials.split_once(':')?.0, host)))
+            .map_or_else(
+                || conn.to_owned(),
+                |(user, host)| format!("{scheme}://{user}:***@{host}{path}"),
+            )
+    }
+}
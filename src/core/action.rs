@@ -17,6 +17,26 @@ pub enum Action {
     SetContent,
     /// Used to set the priority of tasks.
     SetPriority,
+    /// Used to set the references of tasks.
+    SetRefs,
+    /// Used to set the tags of tasks.
+    SetTags,
+    /// Used to set the notes of tasks.
+    SetNotes,
+    /// Used to set the estimate of tasks.
+    SetEstimate,
+    /// Used to snooze tasks.
+    Snooze,
+    /// Used to change the manual order of tasks.
+    Move,
+    /// Used to pin tasks.
+    Pin,
+    /// Used to unpin tasks.
+    Unpin,
+    /// Used to change the attachments of tasks.
+    Attach,
+    /// Used to set the due date of tasks.
+    SetDue,
 }
 
 impl fmt::Display for Action {
@@ -28,6 +48,16 @@ impl fmt::Display for Action {
             Self::Drop => write!(f, "drop"),
             Self::SetContent => write!(f, "set content"),
             Self::SetPriority => write!(f, "set priority"),
+            Self::SetRefs => write!(f, "set ref"),
+            Self::SetTags => write!(f, "set tags"),
+            Self::SetNotes => write!(f, "note"),
+            Self::SetEstimate => write!(f, "set estimate"),
+            Self::Snooze => write!(f, "snooze"),
+            Self::Move => write!(f, "move"),
+            Self::Pin => write!(f, "pin"),
+            Self::Unpin => write!(f, "unpin"),
+            Self::Attach => write!(f, "attach"),
+            Self::SetDue => write!(f, "set due"),
         }
     }
 }
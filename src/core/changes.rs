@@ -0,0 +1,39 @@
+//! Contains the `Changes` struct, which summarizes what a command changed, so
+//! the functions of [`Postit`][super::Postit] can return it instead of
+//! printing the tasks.
+
+use crate::models::Task;
+
+/// Tasks changed by a command.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    /// Changed tasks as they were saved, or as they were before being dropped
+    /// if the command dropped them.
+    pub tasks: Vec<Task>,
+}
+
+impl Changes {
+    /// Constructor of the `Changes` struct.
+    #[inline]
+    pub const fn new(tasks: Vec<Task>) -> Self {
+        Self { tasks }
+    }
+
+    /// Returns the identifiers of the changed tasks.
+    #[inline]
+    pub fn ids(&self) -> Vec<u32> {
+        self.tasks.iter().map(|task| task.id).collect()
+    }
+
+    /// Returns `true` if no task was changed.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Returns references to the changed tasks, as the hooks receive them.
+    #[inline]
+    pub fn refs(&self) -> Vec<&Task> {
+        self.tasks.iter().collect()
+    }
+}
@@ -1,14 +1,22 @@
 //! Argument parsing utilities with [clap].
 
+use std::env;
+use std::ffi::OsString;
+
 use arguments as args;
 use clap::{Parser, Subcommand};
 
 /// Contains the arguments struct used.
 pub mod arguments {
-    use clap::Args;
+    use std::path::PathBuf;
+
+    use chrono::{DateTime, NaiveDate, Utc};
+    use clap::{ArgAction, ArgGroup, Args};
 
     use super::subcommands as sub;
-    use crate::models::Priority;
+    use crate::fs::Format;
+    use crate::models::{Calendar, ColorMode, Column, GroupBy, Priority, SortBy, Task};
+    use crate::{ExportFormat, Lang, Output};
 
     /// Arguments of the 'docs' command.
     #[derive(Args, Debug)]
@@ -34,6 +42,255 @@ pub mod arguments {
         pub persister: Option<String>,
     }
 
+    /// Arguments of the 'sample' command.
+    #[derive(Args, Debug)]
+    pub struct Sample {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Number of tasks to generate (4 if only the seed is passed).
+        #[arg(long, short)]
+        pub count: Option<usize>,
+
+        /// Seed used to generate the tasks, so the same seed generates the same tasks.
+        #[arg(long, short)]
+        pub seed: Option<u64>,
+    }
+
+    /// Arguments of the 'view' command.
+    #[derive(Args, Debug)]
+    pub struct View {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Format used to display the tasks (plain, markdown, tsv, csv or json).
+        #[arg(long, short, value_enum, default_value_t)]
+        pub output: Output,
+
+        /// Displays the tasks as a table with aligned columns.
+        #[arg(long, conflicts_with = "output")]
+        pub table: bool,
+
+        /// Columns of the table, separated by commas (overrides the config value).
+        #[arg(long, value_enum, value_delimiter = ',', requires = "table")]
+        pub columns: Vec<Column>,
+
+        /// Template used to display the tasks (overrides the config value).
+        #[arg(long, value_name = "TEMPLATE", conflicts_with_all = ["output", "table"])]
+        pub format: Option<String>,
+
+        /// Prints the tasks directly even if they don't fit in the terminal.
+        #[arg(long)]
+        pub no_pager: bool,
+
+        /// Displays the tasks under a header for each group, with its number of tasks.
+        #[arg(long, value_enum, value_name = "CRITERIA", conflicts_with = "output")]
+        pub group_by: Option<GroupBy>,
+
+        /// Shows checked tasks even if the config hides them.
+        #[arg(long)]
+        pub show_checked: bool,
+
+        /// Hides checked tasks even if the config shows them (the opposite of '--show-checked').
+        #[arg(long, conflicts_with_all = ["show_checked", "checked"])]
+        pub hide_done: bool,
+
+        /// Shows the tasks that are snoozed.
+        #[arg(long)]
+        pub show_snoozed: bool,
+
+        /// Hides tasks with a lower priority (overrides the config value).
+        #[arg(long, value_enum)]
+        pub min_priority: Option<Priority>,
+
+        /// Only shows the tasks that have this tag (can be repeated).
+        #[arg(long = "tag", short, value_name = "TAG")]
+        pub tags: Vec<String>,
+
+        /// Only shows the tasks created since a date (YYYY-MM-DD or RFC 3339).
+        #[arg(long, value_name = "DATE", value_parser = Task::parse_date)]
+        pub created_since: Option<DateTime<Utc>>,
+
+        /// Only shows the tasks completed since a date (YYYY-MM-DD or RFC 3339).
+        #[arg(long, value_name = "DATE", value_parser = Task::parse_date)]
+        pub completed_since: Option<DateTime<Utc>>,
+
+        /// Only shows the tasks with this priority (overrides '--min-priority').
+        #[arg(long, value_enum, conflicts_with = "min_priority")]
+        pub priority: Option<Priority>,
+
+        /// Only shows checked tasks, even if the config hides them.
+        #[arg(long, conflicts_with = "unchecked")]
+        pub checked: bool,
+
+        /// Only shows unchecked tasks.
+        #[arg(long)]
+        pub unchecked: bool,
+
+        /// Only shows the tasks whose content contains a text (ignoring case).
+        #[arg(long, value_name = "TEXT")]
+        pub contains: Option<String>,
+
+        /// Sorts the tasks by a field (ids are not changed).
+        #[arg(long, value_enum, value_name = "FIELD")]
+        pub sort: Option<SortBy>,
+
+        /// Sorts the tasks in the opposite order.
+        #[arg(long, requires = "sort")]
+        pub reverse: bool,
+
+        /// Only shows the first N tasks.
+        #[arg(long, value_name = "N")]
+        pub limit: Option<usize>,
+    }
+
+    impl View {
+        /// Returns `true` if checked tasks must be hidden, based on the flags
+        /// and on the `hide_checked` value of the config.
+        #[inline]
+        pub const fn hides_checked(&self, config: bool) -> bool {
+            self.hide_done || (config && !self.show_checked && !self.checked)
+        }
+
+        /// Returns `true` if a task matches the filters of the flags (tags,
+        /// dates, priority, status and content). The checked, snoozed and
+        /// low priority tasks that are hidden by default aren't handled here.
+        #[inline]
+        pub fn matches(&self, task: &Task) -> bool {
+            let since = |date: Option<&DateTime<Utc>>, min: Option<&DateTime<Utc>>| {
+                min.is_none_or(|min| date.is_some_and(|date| date >= min))
+            };
+
+            let checked = (self.checked || self.unchecked).then_some(self.checked);
+
+            self.tags.iter().all(|tag| task.tags.contains(tag))
+                && since(task.created_at.as_ref(), self.created_since.as_ref())
+                && since(task.completed_at.as_ref(), self.completed_since.as_ref())
+                && self
+                    .priority
+                    .as_ref()
+                    .is_none_or(|priority| task.priority == *priority)
+                && checked.is_none_or(|checked| task.checked == checked)
+                && self
+                    .contains
+                    .as_ref()
+                    .is_none_or(|text| task.content.to_lowercase().contains(&text.to_lowercase()))
+        }
+    }
+
+    /// Arguments of the 'search' command.
+    #[derive(Args, Debug)]
+    pub struct Search {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Text that the content of the tasks must contain.
+        pub pattern: String,
+
+        /// Treats the pattern as a regular expression.
+        #[arg(long, short)]
+        pub regex: bool,
+
+        /// Ignores the case of letters when matching the pattern.
+        #[arg(long, short)]
+        pub ignore_case: bool,
+
+        /// Only shows checked tasks.
+        #[arg(long, conflicts_with = "unchecked")]
+        pub checked: bool,
+
+        /// Only shows unchecked tasks.
+        #[arg(long)]
+        pub unchecked: bool,
+
+        /// Only shows the tasks with this priority.
+        #[arg(long, value_enum)]
+        pub priority: Option<Priority>,
+
+        /// Format used to display the tasks (plain, markdown, tsv, csv or json).
+        #[arg(long, short, value_enum, default_value_t)]
+        pub output: Output,
+
+        /// Stops after finding N tasks.
+        #[arg(long, value_name = "N")]
+        pub limit: Option<usize>,
+    }
+
+    /// Arguments of the 'stats' command.
+    #[derive(Args, Debug)]
+    pub struct Stats {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Groups the counts by a criteria (priority, tag or status).
+        #[arg(long, value_enum)]
+        pub by: Option<GroupBy>,
+
+        /// Format used to display the counts (plain or json).
+        #[arg(long, short, default_value_t, value_parser = Output::summary())]
+        pub output: Output,
+    }
+
+    /// Arguments of the 'report' command.
+    #[derive(Args, Debug)]
+    pub struct Report {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Start of the period, as an amount of time before now (e.g.: 7d, 12h or 2w) or a date.
+        #[arg(long, short, default_value = "7d", value_name = "TIME", value_parser = crate::Report::parse_since)]
+        pub since: DateTime<Utc>,
+
+        /// Format used to display the report (plain or json).
+        #[arg(long, short, default_value_t, value_parser = Output::summary())]
+        pub output: Output,
+    }
+
+    /// Arguments of the 'next' command.
+    #[derive(Args, Debug)]
+    pub struct Next {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Number of tasks to show.
+        #[arg(default_value_t = 1)]
+        pub count: usize,
+
+        /// Format used to display the tasks (plain, markdown, tsv, csv or json).
+        #[arg(long, short, value_enum, default_value_t)]
+        pub output: Output,
+    }
+
+    /// Arguments of the 'agenda' command.
+    #[derive(Args, Debug)]
+    pub struct Agenda {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Only shows the tasks due in the next 7 days, including the overdue ones.
+        #[arg(long, short)]
+        pub week: bool,
+    }
+
+    /// Arguments of the 'cal' command.
+    #[derive(Args, Debug)]
+    pub struct Cal {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Month to display (YYYY-MM), the current one by default.
+        #[arg(value_parser = Calendar::parse_month)]
+        pub month: Option<NaiveDate>,
+    }
+
     /// Arguments of the 'add' command.
     #[derive(Args, Debug)]
     pub struct Add {
@@ -41,15 +298,42 @@ pub mod arguments {
         #[arg(long, short)]
         pub persister: Option<String>,
 
-        /// Priority of the task (none, low, med or high).
-        #[arg(value_enum)]
-        pub priority: Priority,
+        /// Priority (none, low, med or high) and content of the task. The priority can be
+        /// omitted to use the `default_priority` of the config, and both are asked if the
+        /// content is missing. A content of '-' is read from the standard input.
+        #[arg(value_name = "[PRIORITY] CONTENT", num_args = 0..=2)]
+        pub task: Vec<String>,
 
-        /// The content or description of a task.
-        pub content: String,
+        /// Tag of the task (can be repeated).
+        #[arg(long = "tag", short, value_name = "TAG")]
+        pub tags: Vec<String>,
+
+        /// Adds a task per line of the clipboard, where only the priority can be passed.
+        #[arg(long)]
+        pub clipboard: bool,
     }
 
-    /// Arguments of the 'check', 'uncheck', and 'drop' commands.
+    /// Arguments of the 'dup' command.
+    #[derive(Args, Debug)]
+    pub struct Dup {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifiers of the tasks that are copied, separated by commas.
+        #[arg(value_delimiter = ',', required = true)]
+        pub ids: Vec<u32>,
+
+        /// Priority of the copies, instead of the one of the original tasks.
+        #[arg(long, value_enum)]
+        pub priority: Option<Priority>,
+
+        /// Content of the copies, instead of the one of the original tasks.
+        #[arg(long)]
+        pub content: Option<String>,
+    }
+
+    /// Arguments of the 'check', 'uncheck', 'pin' and 'unpin' commands.
     #[derive(Args, Debug)]
     pub struct Edit {
         /// Used to read from and save tasks to.
@@ -61,6 +345,30 @@ pub mod arguments {
         pub ids: Vec<u32>,
     }
 
+    /// Arguments of the 'drop' command.
+    #[derive(Args, Debug)]
+    pub struct Drop {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifiers of tasks separated by commas. Without them, the filters pick from every task.
+        #[arg(value_delimiter = ',', required_unless_present_any = ["checked", "priority", "older_than"])]
+        pub ids: Vec<u32>,
+
+        /// Only drops checked tasks.
+        #[arg(long)]
+        pub checked: bool,
+
+        /// Only drops the tasks with this priority.
+        #[arg(long, value_enum)]
+        pub priority: Option<Priority>,
+
+        /// Only drops the tasks added before this moment, as an amount of time before now (e.g.: 30d) or a date.
+        #[arg(long, value_name = "TIME", value_parser = crate::Report::parse_since)]
+        pub older_than: Option<DateTime<Utc>>,
+    }
+
     /// Arguments of the 'set' command.
     #[derive(Args, Debug)]
     pub struct Set {
@@ -85,6 +393,34 @@ pub mod arguments {
         pub priority: Priority,
     }
 
+    /// Arguments of the 'set estimate' subcommand.
+    #[derive(Args, Debug)]
+    pub struct SetEstimate {
+        /// Identifiers of tasks separated by commas.
+        #[arg(value_delimiter = ',', required = true)]
+        pub ids: Vec<u32>,
+
+        /// Time that the tasks are expected to take (e.g.: 30m, 2h or 1h30m), or 'none'.
+        #[arg(value_parser = Task::parse_estimate)]
+        pub estimate: u32,
+    }
+
+    /// Arguments of the 'set due' subcommand.
+    #[derive(Args, Debug)]
+    pub struct SetDue {
+        /// Identifiers of tasks separated by commas.
+        #[arg(value_delimiter = ',', required = true)]
+        pub ids: Vec<u32>,
+
+        /// Due date, as an amount of time after now (e.g.: 3h, 2d or 1w) or a date.
+        #[arg(long, short, value_name = "TIME", value_parser = Task::parse_until, required_unless_present = "clear")]
+        pub on: Option<DateTime<Utc>>,
+
+        /// Removes the due date of the tasks.
+        #[arg(long, conflicts_with = "on")]
+        pub clear: bool,
+    }
+
     /// Arguments of the 'set content' subcommand.
     #[derive(Args, Debug)]
     pub struct SetContent {
@@ -96,6 +432,244 @@ pub mod arguments {
         pub content: String,
     }
 
+    /// Arguments of the 'set ref' subcommand.
+    #[derive(Args, Debug)]
+    pub struct SetRef {
+        /// Identifiers of tasks separated by commas.
+        #[arg(value_delimiter = ',', required = true, num_args = 1, action = ArgAction::Set)]
+        pub ids: Vec<u32>,
+
+        /// External references (e.g.: JIRA-123 or gh#42). Removes them if none are passed.
+        pub refs: Vec<String>,
+    }
+
+    /// Arguments of the 'set tags' subcommand.
+    #[derive(Args, Debug)]
+    pub struct SetTags {
+        /// Identifiers of tasks separated by commas.
+        #[arg(value_delimiter = ',', required = true, num_args = 1, action = ArgAction::Set)]
+        pub ids: Vec<u32>,
+
+        /// Tags of the tasks (e.g.: work or home). Removes them if none are passed.
+        pub tags: Vec<String>,
+    }
+
+    /// Arguments of the 'note' command.
+    #[derive(Args, Debug)]
+    pub struct Note {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task.
+        pub id: u32,
+
+        /// Text appended as a new line of the notes. If it isn't passed, the notes are shown.
+        #[arg(conflicts_with_all = ["edit", "clear"])]
+        pub text: Option<String>,
+
+        /// Opens the notes in the editor of the 'EDITOR' env var.
+        #[arg(long, short, conflicts_with = "clear")]
+        pub edit: bool,
+
+        /// Removes the notes of the task.
+        #[arg(long)]
+        pub clear: bool,
+    }
+
+    /// Arguments of the 'pomo' command.
+    #[derive(Args, Debug)]
+    pub struct Pomo {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task.
+        pub id: u32,
+
+        /// Length of the pomodoro.
+        #[arg(long, short, default_value_t = 25)]
+        pub minutes: u32,
+    }
+
+    /// Arguments of the 'attach' command.
+    #[derive(Args, Debug)]
+    pub struct Attach {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task.
+        pub id: u32,
+
+        /// File paths or URLs attached to the task.
+        #[arg(required = true)]
+        pub uris: Vec<String>,
+
+        /// Removes the passed attachments instead of adding them.
+        #[arg(long, short)]
+        pub remove: bool,
+    }
+
+    /// Arguments of the 'snooze' command.
+    #[derive(Args, Debug)]
+    pub struct Snooze {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifiers of tasks separated by commas.
+        #[arg(value_delimiter = ',', required = true)]
+        pub ids: Vec<u32>,
+
+        /// End of the snooze, as an amount of time after now (e.g.: 3h, 2d or 1w) or a date.
+        #[arg(long, short, value_name = "TIME", value_parser = Task::parse_until, required_unless_present = "wake")]
+        pub until: Option<DateTime<Utc>>,
+
+        /// Wakes the tasks up, showing them again.
+        #[arg(long, conflicts_with = "until")]
+        pub wake: bool,
+    }
+
+    /// Arguments of the 'show' command.
+    #[derive(Args, Debug)]
+    pub struct Show {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task.
+        pub id: u32,
+    }
+
+    /// Arguments of the 'move' command.
+    #[derive(Args, Debug)]
+    #[command(group = ArgGroup::new("place").required(true))]
+    pub struct Move {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task.
+        pub id: u32,
+
+        /// Moves the task right before this one.
+        #[arg(long, short, value_name = "ID", group = "place")]
+        pub before: Option<u32>,
+
+        /// Moves the task right after this one.
+        #[arg(long, short, value_name = "ID", group = "place")]
+        pub after: Option<u32>,
+
+        /// Moves the task to this position of the list, starting at 1.
+        #[arg(long, short, value_name = "POSITION", group = "place", value_parser = clap::value_parser!(u32).range(1..))]
+        pub to: Option<u32>,
+    }
+
+    /// Arguments of the 'open' command.
+    #[derive(Args, Debug)]
+    pub struct Open {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task whose attachment or references are opened.
+        pub id: u32,
+    }
+
+    /// Arguments of the 'edit' command.
+    #[derive(Args, Debug)]
+    pub struct EditTask {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifier of the task that is edited.
+        pub id: u32,
+    }
+
+    /// Arguments of the 'pick' command.
+    #[derive(Args, Debug)]
+    pub struct Pick {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Action applied to the picked tasks.
+        #[command(subcommand)]
+        pub subcommand: sub::Pick,
+    }
+
+    /// Arguments of the 'pick set priority' subcommand.
+    #[derive(Args, Debug)]
+    pub struct PickPriority {
+        /// Priority of the task (none, low, med or high).
+        #[arg(value_enum)]
+        pub priority: Priority,
+    }
+
+    /// Arguments of the 'pick set content' subcommand.
+    #[derive(Args, Debug)]
+    pub struct PickContent {
+        /// The content or description of a task.
+        pub content: String,
+    }
+
+    /// Arguments of the 'import' command.
+    #[derive(Args, Debug)]
+    pub struct Import {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Reads the tasks from the standard input.
+        #[arg(long, required = true)]
+        pub stdin: bool,
+
+        /// Format of the input (csv, json, jsonl or xml).
+        #[arg(long, short, value_enum)]
+        pub format: Format,
+    }
+
+    /// Arguments of the 'dump' command.
+    #[derive(Args, Debug)]
+    pub struct Archive {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Path of the archive (e.g.: postit.tar.gz). If it isn't passed, a backup is made.
+        pub path: Option<PathBuf>,
+    }
+
+    /// Arguments of the 'restore' command.
+    #[derive(Args, Debug)]
+    pub struct Restore {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Archive to restore, as a path or the name of a backup. If it isn't passed, the backups are listed.
+        #[arg(long, value_name = "BACKUP")]
+        pub from: Option<String>,
+    }
+
+    /// Arguments of the 'export' command.
+    #[derive(Args, Debug)]
+    pub struct Export {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Format of the exported file (json, csv, xml, markdown or ics).
+        #[arg(long, short, value_enum)]
+        pub format: ExportFormat,
+
+        /// Path of the exported file, which is overwritten if it exists.
+        #[arg(long, short)]
+        pub out: PathBuf,
+    }
+
     /// Arguments of the 'copy' command.
     #[derive(Args, Debug)]
     pub struct Copy {
@@ -104,6 +678,10 @@ pub mod arguments {
 
         /// Where the tasks will be copied to.
         pub right: String,
+
+        /// Appends the tasks after the existing tasks of 'right' instead of overwriting them.
+        #[arg(long)]
+        pub merge: bool,
     }
 
     /// Arguments of the 'config' command.
@@ -121,10 +699,18 @@ pub mod arguments {
         #[arg(long, value_name = "STRING")]
         pub persister: Option<String>,
 
+        /// Command run when postit is invoked without arguments (an empty string unsets it).
+        #[arg(long, value_name = "COMMAND")]
+        pub default_command: Option<String>,
+
         /// If 'true', allows dropping tasks without them being checked.
         #[arg(long, value_name = "BOOL")]
         pub force_drop: Option<bool>,
 
+        /// Days that dropped tasks are kept in the trash ('0' drops them permanently).
+        #[arg(long, value_name = "DAYS")]
+        pub trash_days: Option<u32>,
+
         /// If 'true', allows overwriting files if they already exist.
         #[arg(long, value_name = "BOOL")]
         pub force_copy: Option<bool>,
@@ -132,6 +718,172 @@ pub mod arguments {
         /// If 'true', drops the old file after copying its contents to the new file.
         #[arg(long, value_name = "BOOL")]
         pub drop_after_copy: Option<bool>,
+
+        /// Seconds waited for other postit processes to finish changing a file before failing.
+        #[arg(long, value_name = "SECONDS")]
+        pub lock_timeout: Option<u64>,
+
+        /// If 'false', JSON files are written minified instead of indented.
+        #[arg(long, value_name = "BOOL")]
+        pub json_pretty: Option<bool>,
+
+        /// If 'false', destructive commands don't ask for confirmation.
+        #[arg(long, value_name = "BOOL")]
+        pub confirm: Option<bool>,
+
+        /// If 'true', commands fail when some of the passed ids don't match any task.
+        #[arg(long, value_name = "BOOL")]
+        pub strict: Option<bool>,
+
+        /// If 'true', the 'view' command doesn't display checked tasks.
+        #[arg(long, value_name = "BOOL")]
+        pub hide_checked: Option<bool>,
+
+        /// The 'view' command hides tasks with a lower priority than this one.
+        #[arg(long, value_enum)]
+        pub min_priority: Option<Priority>,
+
+        /// Priority of the tasks added without one.
+        #[arg(long, value_enum)]
+        pub default_priority: Option<Priority>,
+
+        /// Columns displayed by 'view --table', separated by commas.
+        #[arg(long, value_enum, value_delimiter = ',')]
+        pub columns: Option<Vec<Column>>,
+
+        /// Template used to display tasks (e.g.: '{id} [{priority}] {content}').
+        #[arg(long, value_name = "TEMPLATE")]
+        pub format: Option<String>,
+
+        /// Defines when the output is colored (auto, always or never).
+        #[arg(long, value_enum)]
+        pub color: Option<ColorMode>,
+
+        /// Language of the errors, prompts and documentation headings (en or es).
+        #[arg(long, value_enum)]
+        pub lang: Option<Lang>,
+
+        /// Database used when the connection string of a database persister doesn't specify one.
+        #[arg(long, value_name = "STRING")]
+        pub database: Option<String>,
+
+        /// Reference to the password of database persisters, read from an env var, a file or the OS keyring.
+        #[arg(long, value_name = "STRING")]
+        pub password: Option<String>,
+    }
+
+    impl ConfigSet {
+        /// Returns `true` if no value was passed to the 'config set' command.
+        #[inline]
+        pub const fn is_empty(&self) -> bool {
+            self.persister.is_none()
+                && self.default_command.is_none()
+                && self.force_drop.is_none()
+                && self.trash_days.is_none()
+                && self.force_copy.is_none()
+                && self.drop_after_copy.is_none()
+                && self.lock_timeout.is_none()
+                && self.json_pretty.is_none()
+                && self.confirm.is_none()
+                && self.strict.is_none()
+                && self.hide_checked.is_none()
+                && self.min_priority.is_none()
+                && self.default_priority.is_none()
+                && self.columns.is_none()
+                && self.format.is_none()
+                && self.color.is_none()
+                && self.lang.is_none()
+                && self.database.is_none()
+                && self.password.is_none()
+        }
+
+        /// Returns the names of the keys that were passed to the 'config set'
+        /// command.
+        #[inline]
+        pub fn keys(&self) -> Vec<&'static str> {
+            [
+                ("persister", self.persister.is_some()),
+                ("default_command", self.default_command.is_some()),
+                ("force_drop", self.force_drop.is_some()),
+                ("trash_days", self.trash_days.is_some()),
+                ("force_copy", self.force_copy.is_some()),
+                ("drop_after_copy", self.drop_after_copy.is_some()),
+                ("lock_timeout", self.lock_timeout.is_some()),
+                ("json_pretty", self.json_pretty.is_some()),
+                ("confirm", self.confirm.is_some()),
+                ("strict", self.strict.is_some()),
+                ("hide_checked", self.hide_checked.is_some()),
+                ("min_priority", self.min_priority.is_some()),
+                ("default_priority", self.default_priority.is_some()),
+                ("columns", self.columns.is_some()),
+                ("format", self.format.is_some()),
+                ("color", self.color.is_some()),
+                ("lang", self.lang.is_some()),
+                ("database", self.database.is_some()),
+                ("password", self.password.is_some()),
+            ]
+            .into_iter()
+            .filter_map(|(key, is_set)| is_set.then_some(key))
+            .collect()
+        }
+    }
+
+    /// Arguments of the 'list' command.
+    #[derive(Args, Debug)]
+    pub struct List {
+        /// Subcommand the 'List' command will use.
+        #[command(subcommand)]
+        pub subcommand: sub::List,
+    }
+
+    /// Arguments for the 'list create' subcommand.
+    #[derive(Args, Clone, Debug, PartialEq, Eq)]
+    pub struct ListCreate {
+        /// Name of the list (can't contain '.', '/', '\\' or ':').
+        pub name: String,
+
+        /// Persister where the tasks of the list are stored ('NAME.csv' by default).
+        #[arg(long, short)]
+        pub persister: Option<String>,
+    }
+
+    /// Arguments for the 'list remove' subcommand.
+    #[derive(Args, Clone, Debug, PartialEq, Eq)]
+    pub struct ListRemove {
+        /// Name of the list.
+        pub name: String,
+    }
+
+    /// Arguments of the 'trash' command.
+    #[derive(Args, Debug)]
+    pub struct Trash {
+        /// Subcommand the 'Trash' command will use.
+        #[command(subcommand)]
+        pub subcommand: sub::Trash,
+    }
+
+    /// Arguments for the 'trash restore' subcommand.
+    #[derive(Args, Debug)]
+    pub struct TrashRestore {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Identifiers of the dropped tasks separated by commas.
+        #[arg(value_delimiter = ',', required = true)]
+        pub ids: Vec<u32>,
+    }
+
+    /// Arguments for the 'trash empty' subcommand.
+    #[derive(Args, Debug)]
+    pub struct TrashEmpty {
+        /// Used to read from and save tasks to.
+        #[arg(long, short)]
+        pub persister: Option<String>,
+
+        /// Empties the trash of every persister.
+        #[arg(long, conflicts_with = "persister")]
+        pub all: bool,
     }
 }
 
@@ -148,6 +900,37 @@ pub mod subcommands {
         Content(args::SetContent),
         /// Changes the 'priority' value.
         Priority(args::SetPriority),
+        /// Changes the 'refs' value.
+        Ref(args::SetRef),
+        /// Changes the 'tags' value.
+        Tags(args::SetTags),
+        /// Changes the 'estimate' value.
+        Estimate(args::SetEstimate),
+        /// Changes the 'due' value.
+        Due(args::SetDue),
+    }
+
+    /// Actions that can be applied to the tasks selected with 'pick'.
+    #[derive(Subcommand, Debug)]
+    pub enum Pick {
+        /// Checks the picked tasks.
+        Check,
+        /// Unchecks the picked tasks.
+        Uncheck,
+        /// Drops the picked tasks.
+        Drop,
+        /// Changes values inside of the picked tasks.
+        #[command(subcommand)]
+        Set(PickSet),
+    }
+
+    /// Subcommands for setting the value of picked tasks.
+    #[derive(Subcommand, Debug)]
+    pub enum PickSet {
+        /// Changes the 'content' value.
+        Content(args::PickContent),
+        /// Changes the 'priority' value.
+        Priority(args::PickPriority),
     }
 
     /// Subcommands for managing the config file.
@@ -168,6 +951,33 @@ pub mod subcommands {
         /// Deletes the config file
         #[command(alias = "rm")]
         Remove,
+        /// Validates the config files, the env vars and the persisters they use.
+        Check,
+    }
+
+    /// Subcommands for managing the named task lists.
+    #[derive(Subcommand, Debug)]
+    pub enum List {
+        /// Adds a list to the config file.
+        Create(args::ListCreate),
+        /// Displays the lists and their persisters.
+        #[command(alias = "ls")]
+        Show,
+        /// Removes a list from the config file (its tasks are kept).
+        #[command(alias = "rm")]
+        Remove(args::ListRemove),
+    }
+
+    /// Subcommands for managing the dropped tasks.
+    #[derive(Subcommand, Debug)]
+    pub enum Trash {
+        /// Displays the tasks dropped from a persister.
+        #[command(alias = "ls")]
+        List(args::Persister),
+        /// Adds dropped tasks back to their persister.
+        Restore(args::TrashRestore),
+        /// Deletes the dropped tasks permanently.
+        Empty(args::TrashEmpty),
     }
 
     /// Subcommands for the 'Flag' command
@@ -175,6 +985,16 @@ pub mod subcommands {
     pub enum Flag {
         /// Documentation of for the 'persister' flag
         Persister,
+        /// Documentation of for the 'quiet' flag
+        Quiet,
+        /// Documentation of for the 'verbose' flag
+        Verbose,
+        /// Documentation of for the 'no-color' flag
+        NoColor,
+        /// Documentation of for the 'compact' flag
+        Compact,
+        /// Documentation of for the 'yes' flag
+        Yes,
     }
 
     /// Subcommands for the 'Docs' command
@@ -182,26 +1002,84 @@ pub mod subcommands {
     pub enum Docs {
         /// Documentation of the 'config' command
         Config,
+        /// Documentation of the 'list' command
+        List,
         /// Documentation of the 'view' command
         View,
+        /// Documentation of the 'search' command
+        Search,
+        /// Documentation of the 'stats' command
+        Stats,
+        /// Documentation of the 'report' command
+        Report,
+        /// Documentation of the 'next' command
+        Next,
+        /// Documentation of the 'agenda' command
+        Agenda,
+        /// Documentation of the 'cal' command
+        Cal,
+        /// Documentation of the 'show' command
+        Show,
         /// Documentation of the 'add' command
         Add,
+        /// Documentation of the 'dup' command
+        Dup,
         /// Documentation of the 'set' command
         Set,
+        /// Documentation of the 'note' command
+        Note,
+        /// Documentation of the 'pomo' command
+        Pomo,
+        /// Documentation of the 'snooze' command
+        Snooze,
+        /// Documentation of the 'edit' command
+        Edit,
+        /// Documentation of the 'move' command
+        Move,
+        /// Documentation of the 'attach' command
+        Attach,
+        /// Documentation of the 'open' command
+        Open,
         /// Documentation of the 'check' command
         Check,
         /// Documentation of the 'uncheck' command
         Uncheck,
+        /// Documentation of the 'pin' command
+        Pin,
+        /// Documentation of the 'unpin' command
+        Unpin,
         /// Documentation of the 'drop' command
         Drop,
+        /// Documentation of the 'pick' command
+        Pick,
+        /// Documentation of the 'tidy' command
+        Tidy,
+        /// Documentation of the 'undo' command
+        Undo,
+        /// Documentation of the 'redo' command
+        Redo,
+        /// Documentation of the 'import' command
+        Import,
+        /// Documentation of the 'export' command
+        Export,
+        /// Documentation of the 'dump' command
+        Dump,
+        /// Documentation of the 'restore' command
+        Restore,
         /// Documentation of the 'copy' command
         Copy,
+        /// Documentation of the 'trash' command
+        Trash,
+        /// Documentation of the 'purge' command
+        Purge,
         /// Documentation of the 'clean' command
         Clean,
         /// Documentation of the 'remove' command
         Remove,
         /// Documentation of the 'sample' command
         Sample,
+        /// Documentation of the error codes
+        Errors,
     }
 }
 
@@ -212,18 +1090,82 @@ pub enum Command {
     #[command(alias = "conf")]
     Config(args::Config),
 
+    /// Manages the named task lists, each one stored in its own persister.
+    #[command(alias = "li")]
+    List(args::List),
+
     /// Shows a list of the current tasks.
     #[command(alias = "v")]
-    View(args::Persister),
+    View(args::View),
+
+    /// Shows the tasks whose content matches a text or a regular expression.
+    #[command(alias = "se")]
+    Search(args::Search),
+
+    /// Shows counts and completion percentages of the tasks.
+    #[command(alias = "st")]
+    Stats(args::Stats),
+
+    /// Summarizes the tasks added, completed and dropped over a period.
+    #[command(alias = "rp")]
+    Report(args::Report),
+
+    /// Shows the most urgent unchecked tasks.
+    #[command(alias = "n")]
+    Next(args::Next),
+
+    /// Shows the unchecked tasks grouped by their due date.
+    #[command(alias = "ag")]
+    Agenda(args::Agenda),
+
+    /// Shows a month calendar with the number of tasks due on each day.
+    #[command(alias = "ca")]
+    Cal(args::Cal),
+
+    /// Shows every field, the notes and the history of a task.
+    #[command(alias = "sh")]
+    Show(args::Show),
 
     /// Adds a new task to the list.
     #[command(alias = "a")]
     Add(args::Add),
 
+    /// Adds unchecked copies of tasks, with new identifiers.
+    #[command(alias = "dp")]
+    Dup(args::Dup),
+
     /// Changes values inside of tasks.
     #[command(alias = "s")]
     Set(args::Set),
 
+    /// Shows, appends to or edits the notes of a task.
+    #[command(alias = "nt")]
+    Note(args::Note),
+
+    /// Runs a pomodoro for a task and logs it in its notes.
+    #[command(alias = "po")]
+    Pomo(args::Pomo),
+
+    /// Hides tasks from 'view' until a moment.
+    #[command(alias = "sz")]
+    Snooze(args::Snooze),
+
+    /// Changes a task in the editor of the 'VISUAL' or 'EDITOR' env vars.
+    #[command(alias = "e")]
+    Edit(args::EditTask),
+
+    /// Changes the position of a task in the list.
+    #[command(alias = "mv")]
+    Move(args::Move),
+
+    /// Attaches file paths or URLs to a task.
+    #[command(alias = "at")]
+    Attach(args::Attach),
+
+    /// Opens the first attachment of a task, or the URLs of its references.
+    #[command(alias = "o")]
+    Open(args::Open),
+
     /// Marks a task as checked.
     #[command(alias = "c")]
     Check(args::Edit),
@@ -232,14 +1174,60 @@ pub enum Command {
     #[command(alias = "uc")]
     Uncheck(args::Edit),
 
+    /// Pins tasks to the top of the list.
+    #[command(alias = "pn")]
+    Pin(args::Edit),
+
+    /// Unpins tasks, so they go back to their place in the list.
+    #[command(alias = "upn")]
+    Unpin(args::Edit),
+
     /// Deletes a task from the list.
     #[command(alias = "d")]
-    Drop(args::Edit),
+    Drop(args::Drop),
+
+    /// Selects tasks with a fuzzy finder and applies an action to them.
+    #[command(alias = "pk")]
+    Pick(args::Pick),
+
+    /// Sorts tasks by priority, moves checked tasks to the bottom and renumbers ids.
+    #[command(alias = "td")]
+    Tidy(args::Persister),
+
+    /// Reverts the last change made to the tasks of a persister.
+    #[command(alias = "u")]
+    Undo(args::Persister),
+
+    /// Applies again the last change reverted with 'undo'.
+    #[command(alias = "r")]
+    Redo(args::Persister),
+
+    /// Adds the tasks read from another source, like a pipe.
+    #[command(alias = "im")]
+    Import(args::Import),
+
+    /// Writes the tasks to a standalone file (json, csv, xml, markdown or ics).
+    #[command(alias = "ex")]
+    Export(args::Export),
+
+    /// Bundles the config file, the tasks, the journal and the trash into a '.tar.gz' archive or a backup.
+    Dump(args::Archive),
+
+    /// Restores the config file, the tasks, the journal and the trash from an archive or a backup made with 'dump'.
+    Restore(args::Restore),
 
     /// Creates a copy of a file (can parse formats, like csv to json).
     #[command(alias = "cp")]
     Copy(args::Copy),
 
+    /// Lists, restores or empties the tasks dropped with 'drop'.
+    #[command(alias = "tr")]
+    Trash(args::Trash),
+
+    /// Drops the checked tasks and keeps the unchecked ones
+    #[command(alias = "pu")]
+    Purge(args::Persister),
+
     /// Cleans the tasks from a persister
     #[command(alias = "cl")]
     Clean(args::Persister),
@@ -250,7 +1238,7 @@ pub enum Command {
 
     /// Creates a sample of tasks. Useful to test postit's features.
     #[command(alias = "sa")]
-    Sample(args::Persister),
+    Sample(args::Sample),
 
     /// Provides documentation and use examples for commands
     #[command(alias = "man")]
@@ -265,7 +1253,63 @@ pub enum Command {
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None, next_line_help = false)]
 pub struct Cli {
+    /// Name of the task list used by the command instead of the default persister.
+    #[arg(long, global = true, value_name = "NAME")]
+    pub list: Option<String>,
+
+    /// Only prints the output of the command, without messages about what it did.
+    #[arg(long, short, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also prints details about how the command runs (e.g.: the persister used).
+    #[arg(long, short, global = true)]
+    pub verbose: bool,
+
+    /// Prints without colors or styles, overriding the `color` of the config.
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Writes JSON files minified, overriding the `json_pretty` of the config.
+    #[arg(long, global = true)]
+    pub compact: bool,
+
+    /// Runs destructive commands without asking for confirmation (restore: the newest backup if '--from' isn't passed).
+    #[arg(long, short, global = true)]
+    pub yes: bool,
+
     /// Command to execute
     #[command(subcommand)]
     pub command: Command,
 }
+
+impl Cli {
+    /// Parses the arguments of the program. If postit is invoked without
+    /// arguments, the command returned by `default` (e.g.: the
+    /// `default_command` of the config, see [`Postit::default_command`]) is
+    /// parsed instead, so the help is only printed if there is none.
+    ///
+    /// [`Postit::default_command`]: crate::Postit::default_command
+    #[inline]
+    pub fn parse_or_default<F>(default: F) -> Self
+    where
+        F: FnOnce() -> Option<String>,
+    {
+        let args: Vec<OsString> = env::args_os().collect();
+        let default = if args.len() == 1 { default() } else { None };
+
+        Self::parse_from(Self::with_default(args, default.as_deref()))
+    }
+
+    /// Returns the arguments to parse, which are the words of `default` after
+    /// the name of the program if `args` doesn't have any other argument.
+    #[inline]
+    pub fn with_default(args: Vec<OsString>, default: Option<&str>) -> Vec<OsString> {
+        match (args.as_slice(), default) {
+            ([program], Some(default)) => {
+                let words = default.split_whitespace().map(OsString::from);
+                std::iter::once(program.clone()).chain(words).collect()
+            }
+            _ => args,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Contains the `Dump` struct, which bundles the config file, the tasks of a
+//! persister, the journal and the trash into a single `.tar.gz` archive that
+//! can be moved to another machine or kept as a backup.
+
+use std::fs;
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::Local;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use super::{Journal, Trash};
+use crate::config::Config;
+use crate::models::Task;
+
+/// Name of the config file inside of the archive.
+const CONFIG: &str = ".postit.toml";
+
+/// Name of the tasks file inside of the archive.
+const TASKS: &str = "tasks.json";
+
+/// Name of the journal file inside of the archive.
+const JOURNAL: &str = "journal.json";
+
+/// Name of the trash file inside of the archive.
+const TRASH: &str = "trash.jsonl";
+
+/// Name of the directory, next to the config file, that stores the backups.
+const BACKUPS: &str = "backups";
+
+/// Extension of the archives.
+const EXTENSION: &str = ".tar.gz";
+
+/// Contents of a dump archive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dump {
+    /// Contents of the config file, if it existed when the dump was made.
+    pub config: Option<String>,
+    /// Contents of the journal file, if it existed when the dump was made.
+    pub journal: Option<String>,
+    /// Contents of the trash file, if it existed when the dump was made.
+    pub trash: Option<String>,
+    /// Tasks of the persister.
+    pub tasks: Vec<Task>,
+}
+
+impl Dump {
+    /// Constructor of the `Dump` struct, without a journal or a trash.
+    #[inline]
+    pub const fn new(config: Option<String>, tasks: Vec<Task>) -> Self {
+        Self {
+            config,
+            journal: None,
+            trash: None,
+            tasks,
+        }
+    }
+
+    /// Makes a dump of the passed tasks with the config, journal and trash
+    /// files that exist.
+    ///
+    /// # Errors
+    /// - The paths can't be obtained from the `POSTIT_ROOT` env var.
+    /// - Any of the files exists but can't be read.
+    #[inline]
+    pub fn collect(tasks: Vec<Task>) -> crate::Result<Self> {
+        Ok(Self {
+            config: Self::load(&Config::path()?)?,
+            journal: Self::load(&Journal::path()?)?,
+            trash: Self::load(&Trash::path()?)?,
+            tasks,
+        })
+    }
+
+    /// Replaces the journal and the trash files with the ones of the dump.
+    /// Files that aren't in the dump (e.g.: archives made by older versions)
+    /// are kept as they are.
+    ///
+    /// # Errors
+    /// - The paths can't be obtained from the `POSTIT_ROOT` env var.
+    /// - Any of the files can't be written.
+    #[inline]
+    pub fn unpack(&self) -> crate::Result<()> {
+        Self::store(&Journal::path()?, self.journal.as_deref())?;
+        Self::store(&Trash::path()?, self.trash.as_deref())
+    }
+
+    /// Writes the dump to a `.tar.gz` archive, overwriting it if it exists.
+    ///
+    /// # Errors
+    /// - The archive can't be created or written.
+    /// - The tasks can't be serialized.
+    #[inline]
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> crate::Result<()> {
+        let file = fs::File::create(path)?;
+        let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+        for (name, content) in
+            [(CONFIG, &self.config), (JOURNAL, &self.journal), (TRASH, &self.trash)]
+        {
+            if let Some(content) = content {
+                Self::append(&mut builder, name, content.as_bytes())?;
+            }
+        }
+
+        let tasks = serde_json::to_vec_pretty(&self.tasks).map_err(crate::Error::wrap)?;
+        Self::append(&mut builder, TASKS, &tasks)?;
+
+        builder.into_inner()?.finish()?;
+
+        Ok(())
+    }
+
+    /// Reads a dump from a `.tar.gz` archive. Unknown files are ignored.
+    ///
+    /// # Errors
+    /// - The archive can't be read or is not a valid `.tar.gz` file.
+    /// - The archive doesn't contain the tasks or they can't be deserialized.
+    #[inline]
+    pub fn read<P: AsRef<Path>>(path: P) -> crate::Result<Self> {
+        let file = fs::File::open(path)?;
+        let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+        let mut config = None;
+        let mut journal = None;
+        let mut trash = None;
+        let mut tasks = None;
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let name = entry.path()?.to_string_lossy().into_owned();
+
+            let mut content = String::new();
+            entry.read_to_string(&mut content)?;
+
+            if name == CONFIG {
+                config = Some(content);
+            } else if name == JOURNAL {
+                journal = Some(content);
+            } else if name == TRASH {
+                trash = Some(content);
+            } else if name == TASKS {
+                tasks = Some(serde_json::from_str(&content).map_err(crate::Error::wrap)?);
+            }
+        }
+
+        let Some(tasks) = tasks else {
+            let err = format!("The archive doesn't contain a '{TASKS}' file");
+            return Err(crate::Error::Archive(err));
+        };
+
+        Ok(Self { config, journal, trash, tasks })
+    }
+
+    /// Returns the directory where the backups are stored, which is next to
+    /// the config file.
+    ///
+    /// # Errors
+    /// - The path can't be obtained from the `POSTIT_ROOT` env var.
+    #[inline]
+    pub fn dir() -> crate::Result<PathBuf> {
+        Ok(Config::path_from_env()?.join(BACKUPS))
+    }
+
+    /// Returns the path of a new backup, named after the current local time
+    /// (e.g.: `postit-20250131-183000.tar.gz`). The backups directory is
+    /// created if it doesn't exist.
+    ///
+    /// # Errors
+    /// - The backups directory can't be created.
+    #[inline]
+    pub fn backup_path() -> crate::Result<PathBuf> {
+        let dir = Self::dir()?;
+        fs::create_dir_all(&dir)?;
+
+        let name = format!("postit-{}{EXTENSION}", Local::now().format("%Y%m%d-%H%M%S"));
+
+        Ok(dir.join(name))
+    }
+
+    /// Returns the archives of the backups directory, from the newest to the
+    /// oldest.
+    ///
+    /// # Errors
+    /// - The backups directory exists but can't be read.
+    #[inline]
+    pub fn backups() -> crate::Result<Vec<PathBuf>> {
+        let dir = Self::dir()?;
+
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut backups = Vec::new();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if path.is_file() && path.to_string_lossy().ends_with(EXTENSION) {
+                let modified = fs::metadata(&path)?
+                    .modified()
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                backups.push((modified, path));
+            }
+        }
+
+        backups.sort_by(|left, right| right.cmp(left));
+
+        Ok(backups.into_iter().map(|(_, path)| path).collect())
+    }
+
+    /// Returns the path of an archive, passed as a path or as the name of a
+    /// file in the backups directory.
+    ///
+    /// # Errors
+    /// - The archive doesn't exist.
+    #[inline]
+    pub fn find(name: &str) -> crate::Result<PathBuf> {
+        let path = PathBuf::from(name);
+
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let backup = Self::dir()?.join(name);
+
+        if backup.is_file() {
+            Ok(backup)
+        } else {
+            let err = format!("The archive '{name}' doesn't exist");
+            Err(crate::Error::Archive(err))
+        }
+    }
+
+    /// Returns the contents of a file, or `None` if it doesn't exist.
+    fn load(path: &Path) -> crate::Result<Option<String>> {
+        if path.exists() {
+            Ok(Some(fs::read_to_string(path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Writes the contents of a file, creating its directory if needed. If
+    /// there are no contents, the file is left as it is.
+    fn store(path: &Path, content: Option<&str>) -> crate::Result<()> {
+        let Some(content) = content else {
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        Ok(fs::write(path, content)?)
+    }
+
+    /// Appends a file to the archive.
+    fn append<W: std::io::Write>(
+        builder: &mut tar::Builder<W>,
+        name: &str,
+        data: &[u8],
+    ) -> crate::Result<()> {
+        let mut header = tar::Header::new_gnu();
+
+        header.set_size(u64::try_from(data.len()).map_err(crate::Error::wrap)?);
+        header.set_mode(0o644);
+        header.set_cksum();
+
+        builder.append_data(&mut header, name, data)?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::tr;
+
 /// Convenience type for database related operations.
 pub type Result<T> = std::result::Result<T, self::Error>;
 
@@ -24,6 +26,71 @@ pub enum Error {
     #[error("{0}")]
     Io(#[from] std::io::Error),
 
+    /// Used when some of the ids passed to a command don't match any task.
+    #[error("{}", match .0.as_slice() {
+        [id] => tr!("Task {id} doesn't exist", id = id),
+        ids => tr!(
+            "Tasks {ids} don't exist",
+            ids = ids.iter().map(ToString::to_string).collect::<Vec<String>>().join(", "),
+        ),
+    })]
+    TaskNotFound(Vec<u32>),
+
+    /// Used when a command needs tasks, but there are none to use, with the
+    /// action that couldn't be done (e.g.: `edit`).
+    #[error("There are no tasks to {0}")]
+    NoTasks(&'static str),
+
+    /// Used when the persister (or its table) doesn't exist yet.
+    #[error(
+        "{}",
+        tr!("'{persister}' has no tasks; add a task first to use this command", persister = .0)
+    )]
+    PersisterDoesntExist(String),
+
+    /// Used when the trash of a persister doesn't have any of the passed tasks.
+    #[error("{}", tr!("The trash of '{persister}' doesn't have the passed tasks", persister = .0))]
+    NotInTrash(String),
+
+    /// Used when a hook or the webhook of the config fails, with the reason.
+    #[error("{0}")]
+    Hook(String),
+
+    /// Used when a dump archive doesn't exist or doesn't have the tasks.
+    #[error("{0}")]
+    Archive(String),
+
+    /// Used when the passed priority isn't valid.
+    #[error("'{0}' is not a valid priority")]
+    InvalidPriority(String),
+
+    /// Used when the source of the content of the tasks (e.g.: `clipboard`)
+    /// doesn't have any text.
+    #[error("The {0} doesn't have any text")]
+    NoText(&'static str),
+
+    /// Used when the clipboard can't be read, with the reason.
+    #[error("{0}")]
+    Clipboard(String),
+
+    /// Used when the editor fails or the edited text isn't valid, so nothing
+    /// was changed.
+    #[error("{0}")]
+    Editor(String),
+
+    /// Used when the tasks changed outside of postit after a journal entry,
+    /// so it can't be undone or redone.
+    #[error("{0}")]
+    Journal(String),
+
+    /// Used when the tasks of a persister can't be copied to the other one.
+    #[error("{0}")]
+    Copy(String),
+
+    /// Used for errors returned by a persister plugin.
+    #[error("{}", tr!("The '{name}' plugin failed: {reason}", name = .0, reason = .1))]
+    Plugin(String, String),
+
     /// Any error that doesn't belong into the previous variants.
     #[error("{0}")]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -38,4 +105,30 @@ impl Error {
     {
         Self::Other(err.into())
     }
+
+    /// Returns the stable code of the error, which can be looked up with
+    /// 'postit docs errors'.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Config(e) => e.code(),
+            Self::Fs(e) => e.code(),
+            Self::Db(e) => e.code(),
+            Self::Io(_) => "E001",
+            Self::TaskNotFound(_) => "E003",
+            Self::Plugin(..) => "E004",
+            Self::NoTasks(_) => "E005",
+            Self::PersisterDoesntExist(_) => "E006",
+            Self::NotInTrash(_) => "E007",
+            Self::Hook(_) => "E008",
+            Self::Archive(_) => "E009",
+            Self::InvalidPriority(_) => "E010",
+            Self::NoText(_) => "E011",
+            Self::Clipboard(_) => "E012",
+            Self::Editor(_) => "E013",
+            Self::Journal(_) => "E014",
+            Self::Copy(_) => "E015",
+            Self::Other(_) => "E002",
+        }
+    }
 }
@@ -0,0 +1,72 @@
+//! Contains the `ExportFormat` enum, which defines the formats that tasks can
+//! be exported to with the 'export' command.
+
+use std::path::Path;
+use std::{fmt, fs};
+
+use chrono::Utc;
+use clap::ValueEnum;
+
+use crate::fs::{Csv, Json, Xml};
+use crate::models::{Links, Todo};
+use crate::traits::FilePersister as _;
+use crate::Ics;
+
+/// Formats available to export tasks.
+#[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// A JSON file, like the ones of a JSON persister.
+    Json,
+    /// A CSV file, like the ones of a CSV persister.
+    Csv,
+    /// An XML file, like the ones of an XML persister.
+    Xml,
+    /// A Markdown checklist, with the references linked.
+    Markdown,
+    /// An iCalendar file, with a `VTODO` component per task.
+    Ics,
+}
+
+impl fmt::Display for ExportFormat {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Json => write!(f, "json"),
+            Self::Csv => write!(f, "csv"),
+            Self::Xml => write!(f, "xml"),
+            Self::Markdown => write!(f, "markdown"),
+            Self::Ics => write!(f, "ics"),
+        }
+    }
+}
+
+impl ExportFormat {
+    /// Writes the tasks to a file in this format, overwriting it if it exists.
+    ///
+    /// File formats use the same serializers as their persisters, so the
+    /// exported file can be used as a persister too. JSON files are written
+    /// indented if `pretty` is `true`.
+    ///
+    /// # Errors
+    /// - The tasks can't be serialized or the file can't be written.
+    #[inline]
+    pub fn write<P: AsRef<Path>>(
+        &self,
+        todo: &Todo,
+        links: &Links,
+        path: P,
+        pretty: bool,
+    ) -> crate::Result<()> {
+        let path = path.as_ref();
+
+        match *self {
+            Self::Json => Json::with(path, pretty).write(todo)?,
+            Self::Csv => Csv::new(path).write(todo)?,
+            Self::Xml => Xml::new(path).write(todo)?,
+            Self::Markdown => fs::write(path, todo.as_markdown(links))?,
+            Self::Ics => fs::write(path, Ics::calendar(todo, &Utc::now()))?,
+        }
+
+        Ok(())
+    }
+}
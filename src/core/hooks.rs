@@ -0,0 +1,249 @@
+//! Contains the `Hooks` struct, which runs the shell commands defined in the
+//! `[hooks]` table of the config file after tasks are written, and sends the
+//! changes to its webhook.
+
+use std::fmt;
+use std::process::Command;
+#[cfg(feature = "webhooks")]
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::models::Task;
+
+/// Events that can trigger a hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hook {
+    /// A task was added.
+    Add,
+    /// A task was checked.
+    Check,
+    /// A change left a task overdue (see [`Hooks::overdue`]).
+    Overdue,
+    /// A pomodoro of a task finished.
+    Pomo,
+}
+
+impl fmt::Display for Hook {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Add => write!(f, "on_add"),
+            Self::Check => write!(f, "on_check"),
+            Self::Overdue => write!(f, "on_overdue"),
+            Self::Pomo => write!(f, "on_pomo"),
+        }
+    }
+}
+
+/// Shell commands executed after an event is saved to the persister.
+///
+/// Commands can use the `{id}`, `{content}` and `{priority}` placeholders,
+/// which are replaced by the values of the task quoted for the shell (see
+/// [`Hooks::render`]), so they must not be quoted again. The same values are
+/// also available as the `POSTIT_ID`, `POSTIT_CONTENT` and `POSTIT_PRIORITY`
+/// environment variables.
+///
+/// After any command that changes tasks, a JSON description of the change is
+/// also sent as a POST request to the `webhook_url`, if it is defined.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hooks {
+    /// Command executed after a task is added.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_add: Option<String>,
+    /// Command executed after a task is checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_check: Option<String>,
+    /// Command executed after a change leaves a task overdue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_overdue: Option<String>,
+    /// Command executed after a pomodoro of a task finishes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_pomo: Option<String>,
+    /// URL that receives the changes made by every command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+}
+
+impl fmt::Display for Hooks {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hooks: Vec<String> = [
+            (Hook::Add.to_string(), &self.on_add),
+            (Hook::Check.to_string(), &self.on_check),
+            (Hook::Overdue.to_string(), &self.on_overdue),
+            (Hook::Pomo.to_string(), &self.on_pomo),
+            (String::from("webhook_url"), &self.webhook_url),
+        ]
+        .into_iter()
+        .filter_map(|(hook, value)| value.as_ref().map(|value| format!("{hook}='{value}'")))
+        .collect();
+
+        if hooks.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", hooks.join(" "))
+        }
+    }
+}
+
+impl Hooks {
+    /// Returns the command defined for the passed hook.
+    #[inline]
+    pub fn command(&self, hook: Hook) -> Option<&str> {
+        match hook {
+            Hook::Add => self.on_add.as_deref(),
+            Hook::Check => self.on_check.as_deref(),
+            Hook::Overdue => self.on_overdue.as_deref(),
+            Hook::Pomo => self.on_pomo.as_deref(),
+        }
+    }
+
+    /// Replaces the placeholders of a command with the values of a task,
+    /// quoted for the shell that runs it (see [`Hooks::quote`]), so the
+    /// content of a task can't run other commands.
+    #[inline]
+    pub fn render(command: &str, task: &Task) -> String {
+        command
+            .replace("{id}", &Self::quote(&task.id.to_string()))
+            .replace("{content}", &Self::quote(&task.content))
+            .replace("{priority}", &Self::quote(task.priority.to_str()))
+    }
+
+    /// Quotes a value, so the shell that runs the hooks reads it as a single
+    /// word: between single quotes for `sh` and between double quotes for
+    /// `cmd` (on Windows).
+    #[inline]
+    pub fn quote(value: &str) -> String {
+        if cfg!(windows) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            format!("'{}'", value.replace('\'', r"'\''"))
+        }
+    }
+
+    /// Returns the tasks of `after` that are overdue and weren't overdue in
+    /// `before` (e.g.: their due date was changed to a past day or they were
+    /// unchecked), which are the ones that run the `on_overdue` hook.
+    ///
+    /// Tasks are matched by their content and due date, so renumbering them
+    /// doesn't run the hook again.
+    #[inline]
+    pub fn overdue<'tasks>(before: &[Task], after: &'tasks [Task]) -> Vec<&'tasks Task> {
+        after
+            .iter()
+            .filter(|task| task.is_overdue())
+            .filter(|task| {
+                !before.iter().any(|old| {
+                    old.is_overdue() && old.content == task.content && old.due == task.due
+                })
+            })
+            .collect()
+    }
+
+    /// Runs the command of the passed hook once per task, in order.
+    ///
+    /// Does nothing if the hook isn't defined.
+    ///
+    /// # Errors
+    /// - The shell can't be spawned.
+    /// - The command exits with a non-zero status.
+    #[inline]
+    pub fn run(&self, hook: Hook, tasks: &[&Task]) -> super::Result<()> {
+        let Some(command) = self.command(hook) else {
+            return Ok(());
+        };
+
+        for task in tasks {
+            let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+
+            let status = Command::new(shell)
+                .arg(flag)
+                .arg(Self::render(command, task))
+                .env("POSTIT_ID", task.id.to_string())
+                .env("POSTIT_CONTENT", &task.content)
+                .env("POSTIT_PRIORITY", task.priority.to_str())
+                .status()?;
+
+            if !status.success() {
+                let msg = format!("The '{hook}' hook failed for task {} ({status})", task.id);
+                return Err(super::Error::Hook(msg));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the JSON object sent to the webhook when a command changes the
+    /// tasks of a persister. It has the name of the `command`, the
+    /// `persister`, the `ids` of the tasks that changed and the state of those
+    /// tasks `before` and `after` the change.
+    #[inline]
+    pub fn payload(command: &str, persister: &str, before: &[Task], after: &[Task]) -> Value {
+        let changed = |tasks: &[Task], others: &[Task]| -> Vec<Task> {
+            tasks
+                .iter()
+                .filter(|task| !others.contains(task))
+                .cloned()
+                .collect()
+        };
+
+        let old = changed(before, after);
+        let new = changed(after, before);
+
+        let mut ids: Vec<u32> = old.iter().chain(&new).map(|task| task.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        json!({
+            "command": command,
+            "persister": persister,
+            "ids": ids,
+            "before": old,
+            "after": new,
+        })
+    }
+
+    /// Sends the change made by a command to the `webhook_url` as a POST
+    /// request with a JSON body (see [`Hooks::payload`]).
+    ///
+    /// Does nothing if the webhook isn't defined.
+    ///
+    /// # Errors
+    /// - The request fails or the server answers with an error status.
+    /// - postit was built without the `webhooks` feature.
+    #[inline]
+    pub fn post(
+        &self,
+        command: &str,
+        persister: &str,
+        before: &[Task],
+        after: &[Task],
+    ) -> super::Result<()> {
+        let Some(url) = &self.webhook_url else {
+            return Ok(());
+        };
+
+        let body = Self::payload(command, persister, before, after).to_string();
+
+        #[cfg(feature = "webhooks")]
+        {
+            ureq::post(url)
+                .timeout(Duration::from_secs(10))
+                .set("Content-Type", "application/json")
+                .send_string(&body)
+                .map_err(|e| super::Error::Hook(format!("The webhook '{url}' failed: {e}")))?;
+
+            Ok(())
+        }
+
+        #[cfg(not(feature = "webhooks"))]
+        {
+            let _ = body;
+            let msg = format!("Can't send changes to '{url}'; the 'webhooks' feature is disabled");
+            Err(super::Error::Hook(msg))
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Contains the `Ics` struct, which formats tasks as an iCalendar file with a
+//! `VTODO` component per task, so calendar apps can import or subscribe to them.
+
+use chrono::{DateTime, Utc};
+
+use crate::models::{Priority, Task, Todo};
+
+/// Maximum length of a line, in bytes, before it is folded.
+const LINE_LIMIT: usize = 75;
+
+/// Formatter of tasks as an iCalendar ([RFC 5545]) file.
+///
+/// [RFC 5545]: https://datatracker.ietf.org/doc/html/rfc5545
+#[derive(Debug)]
+pub struct Ics;
+
+impl Ics {
+    /// Formats the tasks as an iCalendar file, where `now` is used as the
+    /// timestamp of every `VTODO` component.
+    ///
+    /// Priorities are mapped to the iCalendar scale (`high` is 1, `med` is 5,
+    /// `low` is 9 and `none` is 0, which means undefined), and checked tasks
+    /// are marked as `COMPLETED`.
+    #[inline]
+    pub fn calendar(todo: &Todo, now: &DateTime<Utc>) -> String {
+        let mut lines = vec![
+            String::from("BEGIN:VCALENDAR"),
+            String::from("VERSION:2.0"),
+            String::from("PRODID:-//postit//postit//EN"),
+        ];
+
+        for task in &todo.tasks {
+            lines.extend(Self::vtodo(task, now));
+        }
+
+        lines.push(String::from("END:VCALENDAR"));
+
+        let folded: Vec<String> = lines.iter().map(|line| Self::fold(line)).collect();
+
+        folded.join("\r\n") + "\r\n"
+    }
+
+    /// Returns the lines of the `VTODO` component of a task.
+    ///
+    /// The `UID` is made of the id of the task and its creation date, if it
+    /// has one, so exporting the same tasks again updates them instead of
+    /// duplicating them.
+    #[inline]
+    pub fn vtodo(task: &Task, now: &DateTime<Utc>) -> Vec<String> {
+        let priority = match task.priority {
+            Priority::High => 1,
+            Priority::Med => 5,
+            Priority::Low => 9,
+            Priority::None => 0,
+        };
+
+        let status = if task.checked { "COMPLETED" } else { "NEEDS-ACTION" };
+
+        let uid = task.created_at.as_ref().map_or_else(
+            || task.id.to_string(),
+            |created| format!("{}-{}", task.id, Self::date(created)),
+        );
+
+        let mut lines = vec![
+            String::from("BEGIN:VTODO"),
+            format!("UID:{uid}@postit"),
+            format!("DTSTAMP:{}", Self::date(now)),
+            format!("SUMMARY:{}", Self::escape(&task.content)),
+            format!("PRIORITY:{priority}"),
+            format!("STATUS:{status}"),
+        ];
+
+        let dates = [
+            ("CREATED", task.created_at.as_ref()),
+            ("LAST-MODIFIED", task.updated_at.as_ref()),
+            ("COMPLETED", task.completed_at.as_ref().filter(|_| task.checked)),
+        ];
+
+        for (name, date) in dates {
+            if let Some(date) = date {
+                lines.push(format!("{name}:{}", Self::date(date)));
+            }
+        }
+
+        if !task.tags.is_empty() {
+            let tags: Vec<String> = task.tags.iter().map(|tag| Self::escape(tag)).collect();
+            lines.push(format!("CATEGORIES:{}", tags.join(",")));
+        }
+
+        if !task.notes.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", Self::escape(&task.notes)));
+        }
+
+        lines.push(String::from("END:VTODO"));
+
+        lines
+    }
+
+    /// Formats a timestamp with the UTC format of iCalendar (e.g.: `20250131T183000Z`).
+    #[inline]
+    pub fn date(date: &DateTime<Utc>) -> String {
+        date.format("%Y%m%dT%H%M%SZ").to_string()
+    }
+
+    /// Escapes the characters with a special meaning inside of a text value.
+    #[inline]
+    pub fn escape(text: &str) -> String {
+        text.replace('\\', "\\\\")
+            .replace(';', "\\;")
+            .replace(',', "\\,")
+            .replace("\r\n", "\\n")
+            .replace('\n', "\\n")
+    }
+
+    /// Splits a line longer than 75 bytes into several lines,
+    /// where every line after the first one starts with a space.
+    #[inline]
+    pub fn fold(line: &str) -> String {
+        let mut folded = String::new();
+        let mut len = 0;
+
+        for c in line.chars() {
+            if len + c.len_utf8() > LINE_LIMIT {
+                folded.push_str("\r\n ");
+                len = 1;
+            }
+
+            folded.push(c);
+            len += c.len_utf8();
+        }
+
+        folded
+    }
+}
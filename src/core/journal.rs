@@ -0,0 +1,222 @@
+//! Contains the `Journal` struct, which records the changes that commands make
+//! to the tasks of persisters, so they can be reverted with 'undo' and applied
+//! again with 'redo'.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, SubsecRound as _, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::models::Task;
+
+/// Name of the journal file, stored next to the config file.
+const FILE: &str = ".postit_journal.json";
+
+/// Maximum number of changes that can be undone.
+const LIMIT: usize = 50;
+
+/// Change made by a command to the tasks of a persister. Only the tasks that
+/// the command added, changed or removed are stored, so an entry stays small
+/// however many tasks the persister has.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Name of the command that made the change.
+    pub command: String,
+    /// Persister whose tasks were changed, as passed to the command.
+    pub persister: String,
+    /// Tasks that changed or were removed, as they were before the command.
+    pub before: Vec<Task>,
+    /// Tasks that changed or were added, as they are after the command.
+    pub after: Vec<Task>,
+    /// When the change was made, which is `None` for changes recorded before
+    /// entries were timestamped.
+    #[serde(default)]
+    pub recorded_at: Option<DateTime<Utc>>,
+}
+
+impl Entry {
+    /// Constructor of the `Entry` struct, which keeps only the tasks that are
+    /// different in `before` and `after`.
+    #[inline]
+    pub fn new<T, U>(command: T, persister: U, before: &[Task], after: &[Task]) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let changed = |tasks: &[Task], other: &[Task]| {
+            let other: BTreeMap<u32, &Task> = other.iter().map(|task| (task.id, task)).collect();
+
+            tasks
+                .iter()
+                .filter(|task| other.get(&task.id) != Some(task))
+                .cloned()
+                .collect()
+        };
+
+        Self {
+            command: command.into(),
+            persister: persister.into(),
+            before: changed(before, after),
+            after: changed(after, before),
+            recorded_at: None,
+        }
+    }
+
+    /// Sets the time of the change to the current one.
+    #[inline]
+    #[must_use]
+    pub fn stamped(mut self) -> Self {
+        self.recorded_at = Some(Utc::now().trunc_subsecs(0));
+        self
+    }
+
+    /// Replaces the tasks changed by an entry, as they are in `from`, with
+    /// the ones of `to` (e.g.: `before` and `after` to redo the change).
+    /// Tasks that are only in `to` are inserted before the first task with a
+    /// greater id, and the other tasks are kept as they are.
+    ///
+    /// Returns `None` if the changed tasks aren't as they are in `from`, which
+    /// means that they were changed by something that isn't in the journal.
+    #[inline]
+    pub fn replay(tasks: &[Task], from: &[Task], to: &[Task]) -> Option<Vec<Task>> {
+        let from: BTreeMap<u32, &Task> = from.iter().map(|task| (task.id, task)).collect();
+        let to: BTreeMap<u32, &Task> = to.iter().map(|task| (task.id, task)).collect();
+
+        let current: BTreeMap<u32, &Task> = tasks.iter().map(|task| (task.id, task)).collect();
+        let unchanged = from
+            .keys()
+            .chain(to.keys())
+            .all(|id| current.get(id) == from.get(id));
+
+        if !unchanged {
+            return None;
+        }
+
+        let mut result: Vec<Task> = tasks
+            .iter()
+            .filter_map(|task| match to.get(&task.id) {
+                Some(&new) => Some(new.clone()),
+                None if from.contains_key(&task.id) => None,
+                None => Some(task.clone()),
+            })
+            .collect();
+
+        for (id, &task) in to.iter().filter(|(id, _)| !current.contains_key(id)) {
+            let index = result
+                .iter()
+                .position(|other| other.id > *id)
+                .unwrap_or(result.len());
+            result.insert(index, task.clone());
+        }
+
+        Some(result)
+    }
+}
+
+/// Changes that can be undone and redone, from the oldest to the newest.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Journal {
+    /// Changes that can be undone.
+    pub undo: Vec<Entry>,
+    /// Changes that were undone and can be applied again.
+    pub redo: Vec<Entry>,
+}
+
+impl Journal {
+    /// Returns the path of the journal file, which is stored in the same
+    /// directory as the config file.
+    ///
+    /// # Errors
+    /// - The path can't be obtained from the `POSTIT_ROOT` env var.
+    #[inline]
+    pub fn path() -> crate::Result<PathBuf> {
+        Ok(Config::path_from_env()?.join(FILE))
+    }
+
+    /// Loads the journal file, or returns an empty journal if it doesn't exist.
+    ///
+    /// # Errors
+    /// - The journal file can't be read or parsed.
+    #[inline]
+    pub fn load() -> crate::Result<Self> {
+        let path = Self::path()?;
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+
+        serde_json::from_str(&content).map_err(crate::Error::wrap)
+    }
+
+    /// Saves the journal to its file, creating its directory if needed.
+    ///
+    /// # Errors
+    /// - The journal can't be serialized or written.
+    #[inline]
+    pub fn save(&self) -> crate::Result<()> {
+        let path = Self::path()?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string(self).map_err(crate::Error::wrap)?;
+
+        Ok(fs::write(path, content)?)
+    }
+
+    /// Records a new change. Changes undone in the same persister can't be
+    /// redone anymore, and only the newest 50 changes are kept.
+    #[inline]
+    pub fn record(&mut self, entry: Entry) {
+        self.redo.retain(|redo| redo.persister != entry.persister);
+        self.undo.push(entry);
+
+        if let Some(excess) = self.undo.len().checked_sub(LIMIT) {
+            self.undo.drain(..excess);
+        }
+    }
+
+    /// Returns the changes that can be undone in a persister which changed the
+    /// task with the passed id, from the oldest to the newest. Changes made by
+    /// 'tidy' are ignored, since it changes the ids of the tasks.
+    #[inline]
+    pub fn history(&self, persister: &str, id: u32) -> Vec<&Entry> {
+        let find = |tasks: &[Task]| tasks.iter().find(|task| task.id == id).cloned();
+
+        self.undo
+            .iter()
+            .filter(|entry| {
+                entry.persister == persister
+                    && entry.command != "tidy"
+                    && find(&entry.before) != find(&entry.after)
+            })
+            .collect()
+    }
+
+    /// Removes and returns the last change that can be undone in a persister.
+    #[inline]
+    pub fn pop_undo(&mut self, persister: &str) -> Option<Entry> {
+        let index = self
+            .undo
+            .iter()
+            .rposition(|entry| entry.persister == persister)?;
+        Some(self.undo.remove(index))
+    }
+
+    /// Removes and returns the last undone change of a persister.
+    #[inline]
+    pub fn pop_redo(&mut self, persister: &str) -> Option<Entry> {
+        let index = self
+            .redo
+            .iter()
+            .rposition(|entry| entry.persister == persister)?;
+        Some(self.redo.remove(index))
+    }
+}
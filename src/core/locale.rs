@@ -0,0 +1,215 @@
+//! Contains the `Lang` enum, which translates the messages shown to users.
+//!
+//! Messages are written in English in the code and looked up in the catalog
+//! of the language of the config (like gettext does), so a message without a
+//! translation is shown in English. Use the [`tr`] macro to translate them.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Language of the messages, set by [`Lang::install`].
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// Translations of the messages to Spanish, indexed by the English message.
+/// Placeholders between braces (e.g.: `{id}`) are kept as they are.
+const ES: [(&str, &str); 63] = [
+    // Prompts
+    ("Content", "Contenido"),
+    ("The content can't be empty", "El contenido no puede estar vacío"),
+    (
+        "Priority (high, med, low or none) [{default}]",
+        "Prioridad (high, med, low o none) [{default}]",
+    ),
+    ("'{answer}' is not a valid priority", "'{answer}' no es una prioridad válida"),
+    (
+        "Due date (e.g.: 2d, 1w or 2025-01-31, optional)",
+        "Fecha límite (p. ej.: 2d, 1w o 2025-01-31, opcional)",
+    ),
+    ("'{answer}' is in the past", "'{answer}' ya pasó"),
+    (
+        "'{answer}' is not an amount of time or a date",
+        "'{answer}' no es una cantidad de tiempo ni una fecha",
+    ),
+    ("Tags (separated by spaces, optional)", "Etiquetas (separadas por espacios, opcional)"),
+    ("{question} [y/N]", "{question} [s/N]"),
+    ("'{answer}' is not 'y' or 'n'", "'{answer}' no es 's' ni 'n'"),
+    (
+        "'{answer}' is not a number between 1 and {max}",
+        "'{answer}' no es un número entre 1 y {max}",
+    ),
+    // Errors
+    ("Task {id} doesn't exist", "La tarea {id} no existe"),
+    ("Tasks {ids} don't exist", "Las tareas {ids} no existen"),
+    ("The '{name}' plugin failed: {reason}", "El plugin '{name}' falló: {reason}"),
+    (
+        "'{persister}' has no tasks; add a task first to use this command",
+        "'{persister}' no tiene tareas; añade una tarea antes de usar este comando",
+    ),
+    (
+        "The trash of '{persister}' doesn't have the passed tasks",
+        "La papelera de '{persister}' no tiene las tareas indicadas",
+    ),
+    ("The persister can't be a directory", "El persister no puede ser un directorio"),
+    ("Unsupported file format; defaulting to CSV", "Formato de archivo no soportado; se usa CSV"),
+    ("The file '{path}' doesn't exist", "El archivo '{path}' no existe"),
+    ("The file '{path}' already exists", "El archivo '{path}' ya existe"),
+    ("'{path}' is read-only", "'{path}' es de solo lectura"),
+    ("Invalid XML at line {line}: {reason}", "XML no válido en la línea {line}: {reason}"),
+    (
+        "Invalid CSV task at line {line}: '{content}'",
+        "Tarea CSV no válida en la línea {line}: '{content}'",
+    ),
+    (
+        "The file '{path}' is being changed by another postit process; try again later",
+        "Otro proceso de postit está cambiando el archivo '{path}'; inténtalo más tarde",
+    ),
+    ("Unsupported database; defaulting to Sqlite", "Base de datos no soportada; se usa Sqlite"),
+    ("The provided connection string is incorrect", "La cadena de conexión es incorrecta"),
+    (
+        "The '{table}' table has no tasks; add a task first to use this command",
+        "La tabla '{table}' no tiene tareas; añade una tarea antes de usar este comando",
+    ),
+    (
+        "The 'POSTIT_ROOT' environment variable is empty",
+        "La variable de entorno 'POSTIT_ROOT' está vacía",
+    ),
+    (
+        "The value of 'POSTIT_ROOT' is not a valid path or is a relative path: {path}",
+        "El valor de 'POSTIT_ROOT' no es una ruta válida o es una ruta relativa: {path}",
+    ),
+    (
+        "The value of 'POSTIT_ROOT' is not unicode: {value}",
+        "El valor de 'POSTIT_ROOT' no es unicode: {value}",
+    ),
+    (
+        "The configuration file doesn't exist at '{path}'",
+        "El archivo de configuración no existe en '{path}'",
+    ),
+    (
+        "The configuration file already exists at '{path}'",
+        "El archivo de configuración ya existe en '{path}'",
+    ),
+    (
+        "You must provide arguments to set (e.g.: --persister tasks.json)",
+        "Debes indicar los valores que se cambian (p. ej.: --persister tasks.json)",
+    ),
+    (
+        "There is no list named '{name}'; create it with 'postit list create {name}'",
+        "No hay ninguna lista llamada '{name}'; créala con 'postit list create {name}'",
+    ),
+    ("'{name}' can't be the name of a list", "'{name}' no puede ser el nombre de una lista"),
+    ("The '{name}' list already exists", "La lista '{name}' ya existe"),
+    ("The configuration has {count} problem(s)", "La configuración tiene {count} problema(s)"),
+    ("The configuration is valid", "La configuración es válida"),
+    (
+        "Failed to serialize config to TOML: {reason}",
+        "No se pudo convertir la configuración a TOML: {reason}",
+    ),
+    (
+        "Failed to deserialize TOML to config: {reason}",
+        "No se pudo leer la configuración del TOML: {reason}",
+    ),
+    // Documentation
+    ("Usage: {usage}", "Uso: {usage}"),
+    ("Before:", "Antes:"),
+    ("After:", "Después:"),
+    ("Available subcommands", "Subcomandos disponibles"),
+    ("Config", "Configuración"),
+    ("Config files", "Archivos de configuración"),
+    ("Config values", "Valores de configuración"),
+    ("Configuration", "Configuración"),
+    ("Databases", "Bases de datos"),
+    ("Description", "Descripción"),
+    ("Examples", "Ejemplos"),
+    ("Files", "Archivos"),
+    ("How to use", "Cómo se usa"),
+    ("How to use (content)", "Cómo se usa (content)"),
+    ("How to use (due)", "Cómo se usa (due)"),
+    ("How to use (estimate)", "Cómo se usa (estimate)"),
+    ("How to use (priority)", "Cómo se usa (priority)"),
+    ("How to use (ref)", "Cómo se usa (ref)"),
+    ("How to use (tags)", "Cómo se usa (tags)"),
+    ("Merging", "Combinación"),
+    ("More info", "Más información"),
+    ("Sample", "Ejemplo"),
+    ("Special parameters", "Parámetros especiales"),
+];
+
+/// Language of the messages shown to users.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Lang {
+    /// English, in which the messages are written.
+    #[default]
+    En,
+    /// Spanish.
+    Es,
+}
+
+impl fmt::Display for Lang {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::En => write!(f, "en"),
+            Self::Es => write!(f, "es"),
+        }
+    }
+}
+
+impl Lang {
+    /// Sets the language of the messages for the rest of the process. It
+    /// does nothing if a language was already set.
+    #[inline]
+    pub fn install(self) {
+        let _ = LANG.set(self);
+    }
+
+    /// Returns the language set with [`Lang::install`] (the `lang` of the
+    /// config, once [`Postit`][crate::Postit] loads it) or English.
+    #[inline]
+    pub fn current() -> &'static Self {
+        LANG.get().unwrap_or(&Self::En)
+    }
+
+    /// Returns the translation of a message, or the message itself if the
+    /// language doesn't have a translation for it.
+    #[inline]
+    pub fn tr<'msg>(&self, msg: &'msg str) -> &'msg str {
+        let catalog: &[(&str, &str)] = match *self {
+            Self::En => &[],
+            Self::Es => &ES,
+        };
+
+        catalog
+            .iter()
+            .find(|&&(english, _)| english == msg)
+            .map_or(msg, |&(_, translation)| translation)
+    }
+
+    /// Replaces the `{name}` placeholders of a message with their values.
+    #[inline]
+    pub fn fill(msg: &str, values: &[(&str, String)]) -> String {
+        values
+            .iter()
+            .fold(msg.to_owned(), |msg, (name, value)| msg.replace(&format!("{{{name}}}"), value))
+    }
+}
+
+/// Translates a message to the language of the config (see [`Lang::tr`]),
+/// replacing its `{name}` placeholders with the passed values.
+macro_rules! tr {
+    ($msg:literal) => {
+        String::from($crate::Lang::current().tr($msg))
+    };
+    ($msg:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        // The placeholders are filled by `Lang::fill`, not by `format!`.
+        #[allow(clippy::literal_string_with_formatting_args)]
+        let msg = $crate::Lang::current().tr($msg);
+        $crate::Lang::fill(msg, &[$((stringify!($name), $value.to_string())),+])
+    }};
+}
+
+pub(crate) use tr;
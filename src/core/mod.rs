@@ -1,11 +1,41 @@
 //! This is where all the task related management happens.
 
 mod action;
+mod changes;
 pub mod cli;
+mod dump;
 mod error;
+mod export;
+mod hooks;
+mod ics;
+mod journal;
+mod locale;
+mod output;
+mod pager;
+mod picker;
 mod postit;
+mod prompt;
+mod report;
+mod trash;
+mod verbosity;
 
 pub use action::Action;
+pub use changes::Changes;
 pub use cli::{Cli, Command};
+pub use dump::Dump;
 pub use error::{Error, Result};
+pub use export::ExportFormat;
+pub use hooks::{Hook, Hooks};
+pub use ics::Ics;
+pub use journal::{Entry, Journal};
+pub(crate) use locale::tr;
+pub use locale::Lang;
+pub use output::Output;
+pub use pager::Pager;
+pub use picker::Picker;
 pub use postit::Postit;
+pub use prompt::{Draft, Prompt};
+pub use report::Report;
+pub use trash::{Trash, Trashed};
+pub use verbosity::Verbosity;
+pub(crate) use verbosity::{debug, info, warning};
@@ -0,0 +1,45 @@
+//! Contains the `Output` enum, which defines how tasks are printed.
+
+use std::fmt;
+
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::ValueEnum;
+
+/// Formats available to display tasks.
+#[derive(Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Output {
+    /// Colored tasks, one per line.
+    #[default]
+    Plain,
+    /// Markdown checklist (`- [ ]` and `- [x]` lines with priority badges).
+    Markdown,
+    /// Unstyled tab-separated fields (`id`, `content`, `priority`, `checked`).
+    Tsv,
+    /// Unstyled comma-separated fields, like the lines of a CSV persister.
+    Csv,
+    /// JSON array of tasks with every field, meant to be read by scripts.
+    Json,
+}
+
+impl fmt::Display for Output {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Plain => write!(f, "plain"),
+            Self::Markdown => write!(f, "markdown"),
+            Self::Tsv => write!(f, "tsv"),
+            Self::Csv => write!(f, "csv"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl Output {
+    /// Parses the formats available for commands that print a summary
+    /// instead of a list of tasks (`plain` and `json`).
+    #[inline]
+    pub fn summary() -> impl TypedValueParser<Value = Self> {
+        PossibleValuesParser::new(["plain", "json"])
+            .map(|value| Self::from_str(&value, true).unwrap_or_default())
+    }
+}
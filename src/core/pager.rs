@@ -0,0 +1,93 @@
+//! Contains the `Pager` struct, which prints long outputs through a pager so
+//! they can be scrolled instead of flooding the terminal.
+
+use std::env;
+use std::io::{self, IsTerminal as _, Write as _};
+use std::process::{Command, Stdio};
+
+use crate::debug;
+
+/// Pager used when the `PAGER` env var isn't set.
+const DEFAULT_PAGER: &str = if cfg!(windows) { "more" } else { "less -R" };
+
+/// Prints text through the command of the `PAGER` env var when it doesn't fit
+/// in the terminal.
+#[derive(Debug)]
+pub struct Pager {
+    /// Command used to run the pager, or `None` if paging is disabled.
+    command: Option<String>,
+}
+
+impl Pager {
+    /// Constructor of the `Pager` struct.
+    ///
+    /// Paging is disabled if `enabled` is `false` (the '--no-pager' flag), if
+    /// `stdout` isn't a terminal or if the `PAGER` env var is empty or `cat`.
+    #[inline]
+    pub fn new(enabled: bool) -> Self {
+        let command = env::var("PAGER").unwrap_or_else(|_| DEFAULT_PAGER.to_owned());
+        let usable = !command.trim().is_empty() && command.trim() != "cat";
+
+        Self {
+            command: (enabled && usable && io::stdout().is_terminal()).then_some(command),
+        }
+    }
+
+    /// Returns `true` if long texts are paged.
+    #[inline]
+    pub const fn is_enabled(&self) -> bool {
+        self.command.is_some()
+    }
+
+    /// Returns `true` if text with that number of lines is paged.
+    #[inline]
+    pub fn pages(&self, lines: usize) -> bool {
+        self.is_enabled()
+            && terminal_size::terminal_size().is_some_and(|(_, h)| lines >= usize::from(h.0))
+    }
+
+    /// Prints the text, through the pager if it doesn't fit in the terminal.
+    ///
+    /// If the pager can't be started, the text is printed as is.
+    ///
+    /// # Errors
+    /// - The text can't be written to the pager.
+    #[inline]
+    pub fn print(&self, text: &str) -> super::Result<()> {
+        let Some(command) = self
+            .command
+            .as_ref()
+            .filter(|_| self.pages(text.lines().count()))
+        else {
+            print!("{text}");
+            return Ok(());
+        };
+
+        let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+
+        let mut child = match Command::new(shell)
+            .args([flag, command])
+            .stdin(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                debug!("Couldn't start the pager '{command}': {err}");
+                print!("{text}");
+                return Ok(());
+            }
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // The pager closes its input if the user quits before the end
+            match stdin.write_all(text.as_bytes()) {
+                Err(err) if err.kind() == io::ErrorKind::BrokenPipe => (),
+                result => result?,
+            }
+        }
+
+        child.wait()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! Contains the `Picker` struct, an inline fuzzy finder used to select tasks
+//! without having to know their ids beforehand.
+
+use std::cmp::Reverse;
+use std::io::{BufRead, Write};
+
+use crate::models::Task;
+
+/// Inline fuzzy finder over a list of tasks.
+///
+/// The picker shows the tasks that match the current query and lets the user
+/// either refine the query or pick some of the listed tasks by their position.
+#[derive(Debug)]
+pub struct Picker<'t> {
+    /// Tasks that can be picked.
+    tasks: &'t [Task],
+}
+
+impl<'t> Picker<'t> {
+    /// Constructor of the `Picker` struct.
+    #[inline]
+    pub const fn new(tasks: &'t [Task]) -> Self {
+        Self { tasks }
+    }
+
+    /// Returns the score of `text` against a fuzzy `query`, or `None` if the
+    /// characters of the query don't appear in order inside the text.
+    ///
+    /// Consecutive characters and characters at the start of a word are
+    /// rewarded, so closer matches get higher scores. Whitespace in the query
+    /// is ignored and the comparison is case insensitive.
+    #[inline]
+    pub fn score(query: &str, text: &str) -> Option<usize> {
+        let text: Vec<char> = text.to_lowercase().chars().collect();
+
+        let mut score = 0;
+        let mut pos = 0;
+        let mut last = None;
+
+        for qc in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+            let offset = text.get(pos..)?.iter().position(|&tc| tc == qc)?;
+            let i = pos + offset;
+
+            score += 1;
+
+            if last.is_some_and(|l: usize| l + 1 == i) {
+                score += 4;
+            } else if i == 0 || !text[i - 1].is_alphanumeric() {
+                score += 2;
+            }
+
+            last = Some(i);
+            pos = i + 1;
+        }
+
+        Some(score)
+    }
+
+    /// Returns the tasks that match the query, sorted by score (best first).
+    #[inline]
+    pub fn matches(&self, query: &str) -> Vec<&'t Task> {
+        let mut scored: Vec<(usize, &Task)> = self
+            .tasks
+            .iter()
+            .filter_map(|task| {
+                let text = format!("{} {}", task.id, task.content);
+                Self::score(query, &text).map(|score| (score, task))
+            })
+            .collect();
+
+        scored.sort_by_key(|&(score, _)| Reverse(score));
+
+        scored.into_iter().map(|(_, task)| task).collect()
+    }
+
+    /// Runs the picker, reading the user's input from `input` and writing the
+    /// matches to `output`. Returns the ids of the picked tasks, which is
+    /// empty if the user quits (`q`) or the input ends.
+    ///
+    /// On each step, the input can be:
+    /// - A list of positions separated by commas (e.g.: `1,3`), which picks
+    ///   the tasks displayed at those positions.
+    /// - An empty line, which picks the task if there is only one match.
+    /// - Any other text, which is used as the new query.
+    ///
+    /// # Errors
+    /// - There are no tasks to pick.
+    /// - The input can't be read or the output can't be written.
+    #[inline]
+    pub fn run<R: BufRead, W: Write>(
+        &self,
+        mut input: R,
+        mut output: W,
+    ) -> crate::Result<Vec<u32>> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("pick"));
+        }
+
+        let mut query = String::new();
+
+        loop {
+            let matches = self.matches(&query);
+
+            if matches.is_empty() {
+                writeln!(output, "No tasks match '{query}'")?;
+            }
+
+            for (i, task) in matches.iter().enumerate() {
+                writeln!(output, "[{}] {task}", i + 1)?;
+            }
+
+            writeln!(output)?;
+            write!(output, "Filter, pick by position (e.g.: 1,3) or quit with 'q': ")?;
+            output.flush()?;
+
+            let mut line = String::new();
+
+            if input.read_line(&mut line)? == 0 {
+                return Ok(Vec::new());
+            }
+
+            let line = line.trim();
+
+            if line == "q" {
+                return Ok(Vec::new());
+            }
+
+            if line.is_empty() && matches.len() == 1 {
+                return Ok(vec![matches[0].id]);
+            }
+
+            let picked: Option<Vec<u32>> = line
+                .split(',')
+                .map(|n| {
+                    n.trim()
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| n.checked_sub(1))
+                })
+                .map(|i| i.and_then(|i| matches.get(i)).map(|task| task.id))
+                .collect();
+
+            match picked {
+                Some(ids) if !line.is_empty() => return Ok(ids),
+                _ => line.clone_into(&mut query),
+            }
+        }
+    }
+}
@@ -5,15 +5,33 @@
 
 #![allow(clippy::single_call_fn)]
 
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::io::{self, IsTerminal as _, Read as _};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{env, fs, process, thread};
+
+use chrono::{DateTime, Local, SubsecRound as _, Utc};
+use clap::ValueEnum as _;
+use colored::Colorize as _;
+use regex::RegexBuilder;
+use serde_json::Value;
+
 use crate::db::Orm;
-use crate::fs::File;
+use crate::fs::{CsvDialect, File};
+use crate::memory::Memory;
+use crate::mirror::Mirror;
+use crate::plugin::Plugin;
 use crate::traits::Persister;
 
 use super::cli::{arguments as args, subcommands as sub};
-use super::{Action, Cli, Command};
-use crate::config::Config;
+use super::{
+    debug, info, warning, Action, Changes, Cli, Command, Draft, Dump, Entry, Hook, Hooks, Journal,
+    Output, Pager, Picker, Prompt, Report, Trash, Verbosity,
+};
+use crate::config::{Config, Secret, PASSWORD_VAR};
 use crate::docs;
-use crate::models::{Task, Todo};
+use crate::models::{Calendar, ColorMode, Lists, Priority, Stats, Task, Todo};
 
 /// Entry point where all operations are executed.
 ///
@@ -21,16 +39,79 @@ use crate::models::{Task, Todo};
 ///
 /// The [`Todo`] instance is loaded using the desired [`FilePersister`][`super::traits::FilePersister`]
 /// instance, which is modified when the `Postit` finishes working.
+///
+/// The config file is loaded at most once, the first time a command needs
+/// one of its values (e.g.: to build a persister), and its values are passed
+/// to the persisters when they are built. Commands like `docs` or `flag` only
+/// read it for its language, and, like the commands that receive the
+/// `--persister` flag, work without a valid config file.
+///
+/// Other programs can use it as a library through [`Postit::new`] and the
+/// public functions that return [`Changes`] (e.g.: [`Postit::add_task`] or
+/// [`Postit::edit_tasks`]), which don't print the tasks or ask for
+/// confirmation. The warnings about tasks that didn't change are silenced
+/// with [`Verbosity::Quiet`].
 #[non_exhaustive]
-pub struct Postit;
+pub struct Postit {
+    /// Config loaded on first use.
+    config: OnceCell<Config>,
+    /// Password of database persisters, read on first use.
+    password: OnceCell<Option<String>>,
+    /// Name of the list passed with '--list', used instead of the persister of the config.
+    list: Option<String>,
+    /// If `true` ('--yes'), destructive commands don't ask for confirmation.
+    yes: bool,
+    /// If `true` ('--compact'), JSON files are written minified, whatever the config says.
+    compact: bool,
+}
 
 impl Postit {
+    /// Constructor of the `Postit` struct, used to call its functions from
+    /// other programs. If `list` is passed, its persister is used instead of
+    /// the one of the config, like with '--list'.
+    #[inline]
+    #[must_use]
+    pub const fn new(list: Option<String>) -> Self {
+        Self {
+            config: OnceCell::new(),
+            password: OnceCell::new(),
+            list,
+            yes: false,
+            compact: false,
+        }
+    }
+
     /// Runs `Postit` commands based on the commands and arguments provided.
     ///
     /// # Errors
     /// - Any error while doing operations on a persister.
     #[inline]
     pub fn run(cli: Cli) -> super::Result<()> {
+        Self::new(None).execute(cli)
+    }
+
+    /// Runs `Postit` commands like [`Postit::run`], but keeps the config if
+    /// it was already loaded (e.g.: by [`Postit::default_command`]).
+    ///
+    /// # Errors
+    /// - Any error while doing operations on a persister.
+    #[inline]
+    pub fn execute(self, cli: Cli) -> super::Result<()> {
+        Verbosity::from_flags(cli.quiet, cli.verbose).set();
+
+        if cli.no_color {
+            ColorMode::Never.install();
+        }
+
+        let postit = Self {
+            list: cli.list,
+            yes: cli.yes,
+            compact: cli.compact,
+            ..self
+        };
+
+        postit.prepare(&cli.command);
+
         match cli.command {
             Command::Docs(args) => {
                 Self::docs(&args);
@@ -40,23 +121,89 @@ impl Postit {
                 Self::flag(&args);
                 Ok(())
             }
-            Command::Config(args) => Self::config(args),
-            Command::View(args) => Self::view(args),
-            Command::Add(args) => Self::add(args),
-            Command::Set(args) => Self::set(args),
-            Command::Check(args) => Self::edit(args, &Action::Check),
-            Command::Uncheck(args) => Self::edit(args, &Action::Uncheck),
-            Command::Drop(args) => Self::edit(args, &Action::Drop),
-            Command::Sample(args) => Self::sample(args),
-            Command::Copy(args) => Self::copy(&args),
-            Command::Clean(args) => Self::clean(args),
-            Command::Remove(args) => Self::remove(args),
+            Command::Config(args) => Self::manage_config(args),
+            Command::List(args) => Self::manage_lists(args),
+            Command::View(args) => postit.view(&args),
+            Command::Search(args) => postit.search(args),
+            Command::Stats(args) => postit.stats(args),
+            Command::Report(args) => postit.report(args),
+            Command::Next(args) => postit.next(args),
+            Command::Agenda(args) => postit.agenda(args),
+            Command::Cal(args) => postit.cal(args),
+            Command::Show(args) => postit.show_task(args),
+            Command::Add(args) => {
+                postit.appended("add", args.persister.clone(), |postit| postit.add(args))
+            }
+            Command::Dup(args) => {
+                postit.journaled("dup", args.persister.clone(), |postit| postit.dup(args))
+            }
+            Command::Set(args) => {
+                postit.journaled("set", args.persister.clone(), |postit| postit.set(args))
+            }
+            Command::Note(args) => {
+                postit.journaled("note", args.persister.clone(), |postit| postit.note(args))
+            }
+            Command::Pomo(args) => postit.pomo(&args),
+            Command::Snooze(args) => {
+                postit.journaled("snooze", args.persister.clone(), |postit| postit.snooze(args))
+            }
+            Command::Edit(args) => {
+                postit.journaled("edit", args.persister.clone(), |postit| postit.edit_task(args))
+            }
+            Command::Move(args) => {
+                postit.journaled("move", args.persister.clone(), |postit| postit.move_task(args))
+            }
+            Command::Attach(args) => {
+                postit.journaled("attach", args.persister.clone(), |postit| postit.attach(args))
+            }
+            Command::Open(args) => postit.open(args),
+            Command::Check(args) => postit.journaled("check", args.persister.clone(), |postit| {
+                postit.edit(args, &Action::Check)
+            }),
+            Command::Uncheck(args) => {
+                postit.journaled("uncheck", args.persister.clone(), |postit| {
+                    postit.edit(args, &Action::Uncheck)
+                })
+            }
+            Command::Pin(args) => postit
+                .journaled("pin", args.persister.clone(), |postit| postit.edit(args, &Action::Pin)),
+            Command::Unpin(args) => postit.journaled("unpin", args.persister.clone(), |postit| {
+                postit.edit(args, &Action::Unpin)
+            }),
+            Command::Drop(args) => {
+                postit.journaled("drop", args.persister.clone(), |postit| postit.drop(args))
+            }
+            Command::Pick(args) => {
+                postit.journaled("pick", args.persister.clone(), |postit| postit.pick(args))
+            }
+            Command::Tidy(args) => {
+                postit.journaled("tidy", args.persister.clone(), |postit| postit.tidy(args))
+            }
+            Command::Undo(args) => postit.undo(args),
+            Command::Redo(args) => postit.redo(args),
+            Command::Import(args) => {
+                postit.journaled("import", args.persister.clone(), |postit| postit.import(args))
+            }
+            Command::Export(args) => postit.export(&args),
+            Command::Dump(args) => postit.dump(args),
+            Command::Restore(args) => postit.restore(args),
+            Command::Trash(args) => postit.trash(args),
+            Command::Sample(args) => postit.sample(args),
+            Command::Copy(args) => postit.copy(&args),
+            Command::Purge(args) => {
+                postit.journaled("purge", args.persister.clone(), |postit| postit.purge(args))
+            }
+            Command::Clean(args) => {
+                postit.journaled("clean", args.persister.clone(), |postit| postit.clean(args))
+            }
+            Command::Remove(args) => postit.remove(args),
         }
     }
 
     /// Builds a persister based on the passed value.
     ///
     /// If the value of `persister` is:
+    /// - `Some` with the name of a list: returns the persister of the list.
     /// - `Some`: returns itself.
     /// - `None`: returns the persister stored in the config file.
     ///
@@ -67,183 +214,2335 @@ impl Postit {
     where
         T: AsRef<str>,
     {
-        let path_or_conn = match persister {
-            Some(v) => v.as_ref().to_owned(),
-            None => Config::load()?.persister,
-        };
+        let postit = Self::new(None);
+        let conn = postit.conn(persister.map(|p| p.as_ref().to_owned()))?;
+
+        Self::from_conn(&conn, &postit.persister_config(), false)
+    }
 
-        let persister = if path_or_conn.contains("://") || Orm::is_sqlite(&path_or_conn) {
-            Orm::from(path_or_conn)?.boxed()
+    /// Builds a persister from a connection that was already resolved, with
+    /// the values of the config that persisters depend on. If `compact` is
+    /// `true`, JSON files are written minified.
+    ///
+    /// The CSV dialect of the config is only used by the files that the
+    /// config uses (see [`Config::uses`]), so other CSV files passed with
+    /// '--persister' are read with the default one.
+    ///
+    /// # Errors
+    /// - The persister can't be obtained.
+    fn from_conn(conn: &str, config: &Config, compact: bool) -> crate::Result<Box<dyn Persister>> {
+        let persister = if Memory::is_memory(conn) {
+            Memory::from(conn).boxed()
+        } else if Plugin::is_plugin(conn) {
+            Plugin::from(conn, &config.plugins)?.boxed()
+        } else if conn.contains("://") || Orm::is_sqlite(conn) {
+            Orm::with(conn, &config.database)?.boxed()
         } else {
-            File::from(path_or_conn)?.boxed()
+            let dialect =
+                if config.uses(conn) { config.csv.clone() } else { CsvDialect::default() };
+
+            File::with(conn, dialect, !compact && config.json_pretty)?.boxed()
         };
 
         Ok(persister)
     }
 
-    /// Shows use cases for every other command.
-    fn docs(args: &args::Docs) {
-        docs::Command::run(&args.subcommand);
+    /// Loads the tasks of several persisters at the same time, each one in its
+    /// own thread, so a slow persister (e.g.: a remote database) doesn't delay
+    /// the others. Persisters that don't exist have no tasks.
+    ///
+    /// The tasks are returned in the same order as `conns`.
+    ///
+    /// # Errors
+    /// - The password can't be read.
+    /// - Any persister can't be obtained or its tasks can't be read.
+    fn load_all<T>(&self, conns: &[T]) -> super::Result<Vec<Vec<Task>>>
+    where
+        T: AsRef<str>,
+    {
+        // The config and the passwords are loaded before the threads start,
+        // since they are loaded on first use and can't be shared
+        let config = self.persister_config();
+        let config = config.as_ref();
+        let compact = self.compact;
+        let conns = conns
+            .iter()
+            .map(|conn| self.with_password(self.conn(Some(conn.as_ref().to_owned()))?))
+            .collect::<super::Result<Vec<String>>>()?;
+
+        thread::scope(|scope| {
+            // Every thread is spawned before any of them is joined
+            let mut handles = Vec::with_capacity(conns.len());
+
+            for conn in &conns {
+                handles.push(scope.spawn(move || {
+                    let persister = Self::from_conn(conn, config, compact)?;
+
+                    if persister.exists()? {
+                        persister.tasks()
+                    } else {
+                        Ok(Vec::new())
+                    }
+                }));
+            }
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .map_err(|_| super::Error::wrap("A thread panicked while loading tasks"))?
+                })
+                .collect()
+        })
     }
 
-    /// Shows use cases for commonly used flags.
-    fn flag(args: &args::Flag) {
-        docs::Flag::run(&args.subcommand);
+    /// Returns the config, loading it from the config file on first use, and
+    /// installs its values (see [`Postit::install`]) when it is loaded.
+    fn config(&self) -> super::Result<&Config> {
+        if let Some(config) = self.config.get() {
+            return Ok(config);
+        }
+
+        let config = self.loaded()?;
+        Self::install(config);
+
+        Ok(config)
     }
 
-    /// Shows the list of current tasks.
-    fn view(args: args::Persister) -> super::Result<()> {
-        Self::get_persister(args.persister)?.view()
+    /// Returns the config, loading it from the config file on first use,
+    /// without installing its values.
+    ///
+    /// # Errors
+    /// - The config file can't be loaded.
+    fn loaded(&self) -> super::Result<&Config> {
+        if let Some(config) = self.config.get() {
+            return Ok(config);
+        }
+
+        let config = Config::load()?;
+
+        Ok(self.config.get_or_init(|| config))
     }
 
-    /// Adds a new task to the list.
-    fn add(args: args::Add) -> super::Result<()> {
-        let persister = Self::get_persister(args.persister)?;
+    /// Installs the values of the config that are used by the whole process:
+    /// the colors, the color mode, the language and the format of the tasks.
+    fn install(config: &Config) {
+        config.colors.clone().install();
+        config.color.clone().install();
+        config.lang.clone().install();
+        Task::install_format(config.format.clone());
+    }
 
-        if !persister.exists()? {
-            persister.create()?;
+    /// Installs the values of the config before running a command:
+    /// - If the config was already loaded (e.g.: by [`Postit::default_command`]),
+    ///   they weren't installed, so the flags, which are installed first,
+    ///   override them.
+    /// - If the command doesn't need the config (e.g.: `docs`), its messages
+    ///   are still translated to the language of the config, if it can be loaded.
+    fn prepare(&self, command: &Command) {
+        if let Some(config) = self.config.get() {
+            Self::install(config);
+        } else if matches!(
+            command,
+            Command::Docs(_) | Command::Flag(_) | Command::Config(_) | Command::List(_)
+        ) {
+            let _ = self.config();
         }
+    }
 
-        let mut todo = Todo::from(persister.as_ref())?;
+    /// Returns the config that persisters are built with, or the default one
+    /// if it can't be loaded, so connections passed with '--persister' don't
+    /// need a valid config file.
+    fn persister_config(&self) -> Cow<'_, Config> {
+        self.config()
+            .map_or_else(|_| Cow::Owned(Config::default()), Cow::Borrowed)
+    }
 
-        let id = todo.tasks.last().map_or(1, |last| last.id + 1);
+    /// Returns the `default_command` of the config, which is run if postit is
+    /// invoked without arguments (see [`Cli::parse_or_default`]), or `None`
+    /// if it isn't set or the config can't be loaded.
+    ///
+    /// The values of the config are installed by [`Postit::execute`], after
+    /// the flags of the command.
+    #[inline]
+    pub fn default_command(&self) -> Option<String> {
+        self.loaded().ok()?.default_command.clone()
+    }
 
-        let task = Task::new(id, args.content, args.priority, false);
+    /// Builds a persister based on the passed value, resolved like in
+    /// [`Postit::get_persister`].
+    ///
+    /// If the connection string has a user but no password, the password is
+    /// added from the `POSTIT_PASSWORD` env var or the `password` of the config.
+    ///
+    /// If it is the persister of the config and the config has `mirrors`, a
+    /// [`Mirror`] that also writes every change to them is returned.
+    ///
+    /// # Errors
+    /// - The persister can't be obtained.
+    /// - The password can't be read.
+    #[inline]
+    pub fn persister<T>(&self, persister: Option<T>) -> super::Result<Box<dyn Persister>>
+    where
+        T: AsRef<str>,
+    {
+        let from_config = persister.is_none() && self.list.is_none();
+        let conn = self.conn(persister.map(|p| p.as_ref().to_owned()))?;
 
-        todo.add(task);
-        persister.save(&todo)?;
+        debug!("Using the '{conn}' persister");
 
-        persister.view()
-    }
+        // Passed connections are only compared with the config if it was
+        // already loaded, so they don't need a valid config file
+        let mirrored = from_config
+            || self
+                .config
+                .get()
+                .is_some_and(|config| config.persister == conn);
 
-    /// Changes the values of a task depending on the `Set` variant.
-    fn set(args: args::Set) -> super::Result<()> {
-        let persister = Self::get_persister(args.persister)?;
+        let primary = self.build(conn)?;
 
-        if !persister.exists()? {
-            let msg = "The persister doesn't exist; add a task first to use this command";
-            return Err(super::Error::wrap(msg));
+        if !mirrored || self.config()?.mirrors.is_empty() {
+            return Ok(primary);
         }
 
-        let mut todo = Todo::from(persister.as_ref())?;
+        let mirrors = self
+            .config()?
+            .mirrors
+            .iter()
+            .map(|mirror| self.build(mirror))
+            .collect::<super::Result<_>>()?;
+
+        Ok(Mirror::new(primary, mirrors).boxed())
+    }
+
+    /// Builds a persister based on a connection, adding the password to it (see
+    /// [`Postit::with_password`]). Unlike [`Postit::persister`], mirrors are
+    /// never added to it.
+    ///
+    /// # Errors
+    /// - The persister can't be obtained.
+    /// - The password can't be read.
+    fn build<T: AsRef<str>>(&self, conn: T) -> super::Result<Box<dyn Persister>> {
+        let conn = self.conn(Some(conn.as_ref().to_owned()))?;
 
-        todo.set(&args.subcommand)?;
+        let conn = self.with_password(conn)?;
 
-        let (ids, action) = match args.subcommand {
-            sub::Set::Content(args) => (args.ids, Action::SetContent),
-            sub::Set::Priority(args) => (args.ids, Action::SetPriority),
-        };
+        Self::from_conn(&conn, &self.persister_config(), self.compact)
+    }
 
-        persister.edit(&todo, &ids, &action)?;
+    /// Returns the connection with the password added to it, if it has a user
+    /// but no password and a password is configured.
+    ///
+    /// # Errors
+    /// - The password can't be read.
+    fn with_password(&self, conn: String) -> super::Result<String> {
+        if !Secret::needs_password(&conn) {
+            return Ok(conn);
+        }
+
+        Ok(match self.password()? {
+            Some(password) => Secret::inject(&conn, &password).unwrap_or(conn),
+            None => conn,
+        })
+    }
 
-        persister.view()
+    /// Returns the connection of a persister, which is:
+    /// - The persister of a list, if the passed value is its name.
+    /// - The passed value.
+    /// - The persister of the list passed with '--list' if it is `None`.
+    /// - The persister of the config file if it is `None`.
+    ///
+    /// Unlike [`Postit::persister`], passwords are never added to it, so it
+    /// can be stored safely (e.g.: in the journal).
+    ///
+    /// # Errors
+    /// - The config file can't be loaded.
+    /// - The list passed with '--list' doesn't exist.
+    fn conn(&self, persister: Option<String>) -> super::Result<String> {
+        match (persister, &self.list) {
+            (Some(conn), _) if Lists::is_name(&conn) => Ok(self.config()?.lists.resolve(&conn)),
+            (Some(conn), _) => Ok(conn),
+            (None, Some(list)) => Ok(self.config()?.list_persister(list)?),
+            (None, None) => Ok(self.config()?.persister.clone()),
+        }
     }
 
-    /// Edits tasks based on the action passed.
-    fn edit(args: args::Edit, action: &Action) -> super::Result<()> {
-        let persister = Self::get_persister(args.persister)?;
+    /// Returns the tasks of a persister, or no tasks if it doesn't exist.
+    ///
+    /// # Errors
+    /// - The persister can't be obtained or its tasks can't be read.
+    fn snapshot(&self, conn: &str) -> super::Result<Vec<Task>> {
+        let persister = self.persister(Some(conn))?;
 
-        if !persister.exists()? {
-            let msg = "The persister doesn't exist; add a task first to use this command";
-            return Err(super::Error::wrap(msg));
+        if persister.exists()? {
+            persister.tasks()
+        } else {
+            Ok(Vec::new())
         }
+    }
 
-        let mut todo = Todo::from(persister.as_ref())?;
+    /// Runs a command that changes the tasks of a persister, records the change
+    /// in the journal, so it can be reverted with 'undo', and sends it to the
+    /// webhook of the config.
+    ///
+    /// # Errors
+    /// - The command fails.
+    /// - The tasks can't be read or the journal can't be saved.
+    fn journaled<F>(&self, command: &str, persister: Option<String>, run: F) -> super::Result<()>
+    where
+        F: FnOnce(&Self) -> super::Result<()>,
+    {
+        let conn = self.conn(persister)?;
+        let timeout = Duration::from_secs(self.config()?.lock_timeout);
 
-        let changed_ids = match action {
-            Action::Check => todo.check(&args.ids),
-            Action::Uncheck => todo.uncheck(&args.ids),
-            Action::Drop => todo.drop(&args.ids),
-            Action::SetContent | Action::SetPriority => unreachable!(),
-        }?;
+        // Held until the change is recorded, so other processes can't interleave theirs
+        let _lock = self.persister(Some(&conn))?.lock(timeout)?;
 
-        persister.edit(&todo, &changed_ids, action)?;
+        let before = self.snapshot(&conn)?;
+
+        run(self)?;
 
-        persister.view()
+        let after = self.snapshot(&conn)?;
+
+        if before != after {
+            self.webhook(command, &conn, &before, &after)?;
+            self.hook(Hook::Overdue, &Hooks::overdue(&before, &after))?;
+
+            let mut journal = Journal::load()?;
+            journal.record(Entry::new(command, conn, &before, &after).stamped());
+            journal.save()?;
+        }
+
+        Ok(())
     }
 
-    /// Copies the contents of a persister to another.
+    /// Runs a command that only adds tasks at the end of a persister, like
+    /// [`Postit::journaled`], but records the tasks returned by the command
+    /// instead of comparing the tasks before and after it, so appending to a
+    /// file doesn't read the whole file.
     ///
     /// # Errors
-    /// - Both persisters are the same.
-    /// - The left persister has no tasks.
-    /// - The right persister has tasks.    
-    fn copy(args: &args::Copy) -> super::Result<()> {
-        let config = Config::load()?;
+    /// - The command fails.
+    /// - The journal can't be saved.
+    fn appended<F>(&self, command: &str, persister: Option<String>, run: F) -> super::Result<()>
+    where
+        F: FnOnce(&Self) -> super::Result<Changes>,
+    {
+        let conn = self.conn(persister)?;
+        let timeout = Duration::from_secs(self.config()?.lock_timeout);
 
-        let (left_path, right_path) = match args.left.as_ref() {
-            "from" => (&args.right, &config.persister),
-            "to" => (&config.persister, &args.right),
-            _ => (&args.left, &args.right),
+        let _lock = self.persister(Some(&conn))?.lock(timeout)?;
+
+        let added = run(self)?.tasks;
+
+        if !added.is_empty() {
+            self.webhook(command, &conn, &[], &added)?;
+            self.hook(Hook::Overdue, &Hooks::overdue(&[], &added))?;
+
+            let mut journal = Journal::load()?;
+            journal.record(Entry::new(command, conn, &[], &added).stamped());
+            journal.save()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the password of database persisters, which is read from the
+    /// `POSTIT_PASSWORD` env var or the source referenced in the config on
+    /// first use. A warning is shown if the config has the password itself.
+    ///
+    /// # Errors
+    /// - The password reference is invalid or its source can't be read.
+    fn password(&self) -> super::Result<Option<String>> {
+        if let Some(password) = self.password.get() {
+            return Ok(password.clone());
+        }
+
+        let password = match (env::var(PASSWORD_VAR), &self.config()?.password) {
+            (Ok(password), _) => Some(password),
+            (Err(_), Some(reference)) => {
+                let secret = Secret::parse(reference)?;
+
+                if matches!(secret, Secret::Plain(_)) {
+                    warning!(
+                        "The 'password' of the config is stored in plain text; use an 'env:', \
+                         'file:' or 'keyring:' reference or the '{PASSWORD_VAR}' env var instead"
+                    );
+                }
+
+                Some(secret.resolve()?)
+            }
+            (Err(_), None) => None,
         };
 
-        if left_path == right_path {
-            let msg = "Both persisters are the same";
-            return Err(super::Error::wrap(msg));
+        Ok(self.password.get_or_init(|| password).clone())
+    }
+
+    /// Shows use cases for every other command.
+    fn docs(args: &args::Docs) {
+        docs::Command::run(&args.subcommand);
+    }
+
+    /// Shows use cases for commonly used flags.
+    fn flag(args: &args::Flag) {
+        docs::Flag::run(&args.subcommand);
+    }
+
+    /// Shows the list of current tasks.
+    fn view(&self, args: &args::View) -> super::Result<()> {
+        if let Some(format) = &args.format {
+            Task::install_format(format.clone());
         }
 
-        let left = Self::get_persister(Some(left_path))?;
+        let hide_checked = args.hides_checked(self.config()?.hide_checked);
+        let persister = self.persister(args.persister.clone())?;
 
-        if left.tasks()?.is_empty() {
-            let msg = format!("The persister '{}' has no tasks to copy", left.to_string());
-            return Err(super::Error::wrap(msg));
+        let min_priority = match &args.min_priority {
+            Some(priority) => priority.clone(),
+            None if args.priority.is_some() => Priority::None,
+            None => self.config()?.min_priority.clone(),
+        };
+
+        let links = &self.config()?.links;
+
+        let is_filtered = !args.tags.is_empty()
+            || args.created_since.is_some()
+            || args.completed_since.is_some()
+            || args.priority.is_some()
+            || args.checked
+            || args.unchecked
+            || args.contains.is_some();
+
+        let columns = if args.columns.is_empty() { &self.config()?.columns } else { &args.columns };
+
+        let render = |todo: &Todo| {
+            if args.table {
+                todo.render_table(columns)
+            } else {
+                todo.render_with(&args.output, links)
+            }
+        };
+
+        let pager = Pager::new(!args.no_pager);
+
+        let display = |todo: &Todo| {
+            let Some(by) = &args.group_by else {
+                return pager.print(&render(todo)?);
+            };
+
+            let groups = todo
+                .grouped(by)
+                .iter()
+                .map(|(label, group)| {
+                    Ok(format!("{} ({})\n{}", label.bold(), group.tasks.len(), render(group)?))
+                })
+                .collect::<super::Result<Vec<String>>>()?;
+
+            pager.print(&groups.join("\n"))
+        };
+
+        let is_plain = args.output == Output::Plain
+            && links.0.is_empty()
+            && !args.table
+            && args.group_by.is_none()
+            && !pager.is_enabled();
+
+        // The view of the persister already hides the snoozed tasks
+        if is_plain
+            && !hide_checked
+            && min_priority == Priority::None
+            && !is_filtered
+            && args.sort.is_none()
+            && args.limit.is_none()
+            && !args.show_snoozed
+        {
+            return persister.view();
         }
 
-        let right = Self::get_persister(Some(right_path))?;
+        let mut total = 0;
 
-        if !right.exists()? {
-            right.create()?;
+        let keep = |task: &Task| {
+            !(hide_checked && task.checked)
+                && (args.show_snoozed || !task.is_snoozed())
+                && task.priority <= min_priority
+                && args.matches(task)
+        };
+
+        let tasks = persister
+            .stream()?
+            .inspect(|_| total += 1)
+            .filter(|task| task.as_ref().map_or(true, keep));
+
+        // Sorting needs every task, so the limit is applied afterwards
+        let limit = args.limit.filter(|_| args.sort.is_none());
+        let mut todo = Todo::from_stream(tasks, limit)?;
+
+        // Scripts reading JSON get an empty array instead of an explanation
+        let explain = todo.tasks.is_empty() && args.output != Output::Json;
+
+        if explain && !args.tags.is_empty() {
+            warning!("There are no tasks tagged with '{}'", args.tags.join("', '"));
+            return Ok(());
         }
 
-        if !config.force_copy && right.tasks()? != Vec::new() {
-            let msg = format!(
-                "The persister '{}' already has tasks.\nSet 'force_copy' to 'true' to overwrite them.",
-                right.to_string()
-            );
+        if explain && is_filtered {
+            warning!("There are no tasks matching the filters");
+            return Ok(());
+        }
 
-            return Err(super::Error::wrap(msg));
+        if explain && total > 0 {
+            warning!(
+                "All tasks are hidden; use '--show-checked', '--show-snoozed' or '--min-priority \
+                 none' to display them"
+            );
+            return Ok(());
         }
 
-        right.replace(&Todo::from(left.as_ref())?)?;
+        if let Some(field) = &args.sort {
+            todo.sort_by(field, args.reverse);
+        }
 
-        if config.drop_after_copy {
-            left.remove()?;
+        if let Some(limit) = args.limit {
+            todo.truncate(limit);
         }
 
-        println!("The tasks of '{left_path}' have been copied to '{right_path}'");
+        display(&todo)
+    }
+
+    /// Shows the tasks whose content matches a text or a regular expression.
+    fn search(&self, args: args::Search) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        let pattern = if args.regex { args.pattern.clone() } else { regex::escape(&args.pattern) };
 
-        right.view()
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(args.ignore_case)
+            .build()
+            .map_err(super::Error::wrap)?;
+
+        let checked = (args.checked || args.unchecked).then_some(args.checked);
+
+        let tasks: Vec<Task> = persister
+            .stream()?
+            .filter(|task| {
+                task.as_ref().map_or(true, |task| {
+                    regex.is_match(&task.content)
+                        && checked.is_none_or(|checked| task.checked == checked)
+                        && args
+                            .priority
+                            .as_ref()
+                            .is_none_or(|priority| task.priority == *priority)
+                })
+            })
+            .take(args.limit.unwrap_or(usize::MAX))
+            .collect::<super::Result<_>>()?;
+
+        if tasks.is_empty() && args.output != Output::Json {
+            warning!("There are no tasks matching '{}'", args.pattern);
+            return Ok(());
+        }
+
+        Todo::new(tasks).view_with(&args.output, &self.config()?.links)
     }
 
-    /// Populates the persister with fake data for testing purposes.
-    fn sample(args: args::Persister) -> super::Result<()> {
-        let persister = Self::get_persister(args.persister)?;
+    /// Shows counts and completion percentages of the tasks.
+    fn stats(&self, args: args::Stats) -> super::Result<()> {
+        let todo = Todo::from(self.persister(args.persister)?.as_ref())?;
 
-        if !persister.exists()? {
-            persister.create()?;
+        if todo.tasks.is_empty() {
+            return Err(super::Error::NoTasks("count"));
+        }
+
+        if args.output == Output::Json {
+            let mut summary = todo.stats().as_json();
+
+            if let (Some(by), Some(fields)) = (&args.by, summary.as_object_mut()) {
+                let groups: Vec<Value> = todo.stats_by(by).iter().map(Stats::as_json).collect();
+
+                fields.insert(String::from("by"), Value::from(by.to_string()));
+                fields.insert(String::from("groups"), Value::from(groups));
+            }
+
+            let json = serde_json::to_string_pretty(&summary).map_err(super::Error::wrap)?;
+            println!("{json}");
+
+            return Ok(());
+        }
+
+        println!("{}", todo.stats());
+
+        if let Some(by) = args.by {
+            println!();
+            println!("{}", Stats::table(&by.to_string(), &todo.stats_by(&by)));
         }
 
-        persister.replace(&Todo::sample())?;
+        Ok(())
+    }
+
+    /// Shows the tasks added, completed and dropped since a moment, based on
+    /// the timestamps of the tasks and the ones in the trash.
+    fn report(&self, args: args::Report) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+        let tasks = self.snapshot(&conn)?;
+        let report = Report::new(&tasks, &Trash::load()?, &conn, args.since);
 
-        println!("Sample generated at '{}'", persister.to_string());
+        if args.output == Output::Json {
+            let json =
+                serde_json::to_string_pretty(&report.as_json()).map_err(super::Error::wrap)?;
+            println!("{json}");
+        } else {
+            println!("{report}");
+        }
 
-        persister.view()
+        Ok(())
     }
 
-    /// Cleans the tasks from a file.
-    fn clean(args: args::Persister) -> super::Result<()> {
-        Self::get_persister(args.persister)?.clean()
+    /// Shows the most urgent unchecked tasks, using the weights of the config file.
+    fn next(&self, args: args::Next) -> super::Result<()> {
+        let todo = Todo::from(self.persister(args.persister)?.as_ref())?;
+        let urgency = &self.config()?.urgency;
+
+        let tasks = todo.next(urgency, args.count);
+
+        match args.output {
+            Output::Plain if tasks.is_empty() => println!("There are no unchecked tasks"),
+            Output::Plain => {
+                for task in tasks {
+                    println!("{task}");
+                }
+            }
+            Output::Markdown | Output::Tsv | Output::Csv if tasks.is_empty() => {
+                warning!("There are no unchecked tasks");
+            }
+            Output::Markdown | Output::Tsv | Output::Csv | Output::Json => {
+                let todo = Todo::new(tasks.into_iter().cloned().collect::<Vec<Task>>());
+                todo.view_with(&args.output, &self.config()?.links)?;
+            }
+        }
+
+        Ok(())
     }
 
-    /// Removes a persister completely (file or table).
-    fn remove(args: args::Persister) -> super::Result<()> {
-        Self::get_persister(args.persister)?.remove()
+    /// Shows the unchecked tasks grouped by their due date, like
+    /// 'view --group-by' displays its groups.
+    fn agenda(&self, args: args::Agenda) -> super::Result<()> {
+        let todo = Todo::from(self.persister(args.persister)?.as_ref())?;
+        let links = &self.config()?.links;
+
+        let groups = todo.agenda(args.week);
+
+        if groups.is_empty() {
+            println!("There are no unchecked tasks in the agenda");
+            return Ok(());
+        }
+
+        let groups = groups
+            .iter()
+            .map(|(label, group)| {
+                let tasks = group.render_with(&Output::Plain, links)?;
+                Ok(format!("{} ({})\n{tasks}", label.bold(), group.tasks.len()))
+            })
+            .collect::<super::Result<Vec<String>>>()?;
+
+        print!("{}", groups.join("\n"));
+
+        Ok(())
     }
 
-    /// Manages the configuration file.   
-    fn config(args: args::Config) -> super::Result<()> {
-        Config::manage(args.subcommand)?;
+    /// Shows a month calendar with the number of unchecked tasks due on each day.
+    fn cal(&self, args: args::Cal) -> super::Result<()> {
+        let todo = Todo::from(self.persister(args.persister)?.as_ref())?;
+        let month = args.month.unwrap_or_else(Calendar::current_month);
+
+        println!("{}", Calendar::new(month, &todo.tasks));
 
         Ok(())
     }
+
+    /// Shows every field of a task, its notes and the changes recorded in the
+    /// journal that affected it.
+    fn show_task(&self, args: args::Show) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+        let todo = Todo::from(self.persister(Some(&conn))?.as_ref())?;
+
+        let Some(task) = todo.get(&[args.id]).into_iter().next() else {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        };
+
+        println!("Task {}", task.id);
+
+        for (label, value) in task.details() {
+            println!("  {:<11}{value}", format!("{label}:"));
+        }
+
+        if !task.notes.is_empty() {
+            println!("\nNotes:");
+
+            for line in task.notes.lines() {
+                println!("  {line}");
+            }
+        }
+
+        let journal = Journal::load()?;
+        let history = journal.history(&conn, task.id);
+
+        if !history.is_empty() {
+            println!("\nHistory:");
+
+            for entry in history {
+                let at = entry.recorded_at.map_or_else(
+                    || String::from("(unknown time)  "),
+                    |at| {
+                        at.with_timezone(&Local)
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                    },
+                );
+
+                println!("  {at}  {}", entry.command);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a new task to the list.
+    ///
+    /// If the content isn't passed, the values of the task are asked in an
+    /// interactive [`Prompt`].
+    fn add(&self, args: args::Add) -> super::Result<Changes> {
+        if args.clipboard {
+            return self.add_clipboard(args);
+        }
+
+        let (priority, content) = match args.task.as_slice() {
+            [] => (None, None),
+            [value] => Priority::from_str(value, true)
+                .map_or_else(|_| (None, Some(value.clone())), |priority| (Some(priority), None)),
+            [priority, content, ..] => {
+                let priority = Priority::from_str(priority, true)
+                    .map_err(|_| super::Error::InvalidPriority(priority.clone()))?;
+
+                (Some(priority), Some(content.clone()))
+            }
+        };
+
+        let content = match content {
+            Some(content) if content == "-" => Some(Self::read_content(io::stdin().lock())?),
+            content => content,
+        };
+
+        let draft = match (content, priority) {
+            (Some(content), Some(priority)) => Draft {
+                content,
+                priority,
+                due: None,
+                tags: args.tags,
+            },
+            (Some(content), None) => {
+                let priority = self.config()?.default_priority.clone();
+                Draft {
+                    content,
+                    priority,
+                    due: None,
+                    tags: args.tags,
+                }
+            }
+            (None, priority) => {
+                let default = self.config()?.default_priority.clone();
+                let prompt = Prompt::new(priority, args.tags).with_default(default);
+
+                let Some(draft) = prompt.run(io::stdin().lock(), io::stdout())? else {
+                    info!("No task was added");
+                    return Ok(Changes::default());
+                };
+
+                draft
+            }
+        };
+
+        let persister = self.persister(args.persister)?;
+        let changes = Self::add_draft(persister.as_ref(), draft)?;
+
+        self.hook(Hook::Add, &changes.refs())?;
+        Self::show(persister.as_ref())?;
+
+        Ok(changes)
+    }
+
+    /// Reads the content of a task passed as '-', joining the lines of the
+    /// input with spaces.
+    ///
+    /// # Errors
+    /// - The input can't be read or doesn't have any text.
+    fn read_content<R: io::Read>(mut reader: R) -> super::Result<String> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+
+        let lines: Vec<&str> = input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if lines.is_empty() {
+            return Err(super::Error::NoText("standard input"));
+        }
+
+        Ok(lines.join(" "))
+    }
+
+    /// Adds a task per line of the clipboard, skipping the blank ones. The
+    /// priority is the only value that can be passed.
+    fn add_clipboard(&self, args: args::Add) -> super::Result<Changes> {
+        let priority = match args.task.as_slice() {
+            [] => self.config()?.default_priority.clone(),
+            [priority] => Priority::from_str(priority, true)
+                .map_err(|_| super::Error::InvalidPriority(priority.clone()))?,
+            [..] => {
+                let msg =
+                    "The content of the tasks is read from the clipboard; only pass a priority";
+                return Err(super::Error::wrap(msg));
+            }
+        };
+
+        let contents: Vec<String> = Self::clipboard()?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+
+        if contents.is_empty() {
+            return Err(super::Error::NoText("clipboard"));
+        }
+
+        let persister = self.persister(args.persister)?;
+        let changes = Self::add_tasks(persister.as_ref(), contents, &priority, &args.tags)?;
+
+        info!("Added {} tasks from the clipboard", changes.tasks.len());
+
+        self.hook(Hook::Add, &changes.refs())?;
+        Self::show(persister.as_ref())?;
+
+        Ok(changes)
+    }
+
+    /// Returns the text of the clipboard.
+    ///
+    /// # Errors
+    /// - The clipboard can't be read or doesn't have text.
+    /// - The 'clipboard' feature is disabled.
+    fn clipboard() -> super::Result<String> {
+        #[cfg(feature = "clipboard")]
+        {
+            arboard::Clipboard::new()
+                .and_then(|mut clipboard| clipboard.get_text())
+                .map_err(|e| super::Error::Clipboard(format!("Can't read the clipboard: {e}")))
+        }
+
+        #[cfg(not(feature = "clipboard"))]
+        {
+            let msg = "Can't read the clipboard; the 'clipboard' feature is disabled";
+            Err(super::Error::Clipboard(msg.to_owned()))
+        }
+    }
+
+    /// Adds unchecked copies of tasks at the end of the list (see
+    /// [`Postit::dup_tasks`]).
+    fn dup(&self, args: args::Dup) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+        let changes = Self::dup_tasks(
+            persister.as_ref(),
+            &args.ids,
+            args.priority.as_ref(),
+            args.content.as_deref(),
+        )?;
+
+        self.hook(Hook::Add, &changes.refs())?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Changes the values of a task depending on the `Set` variant.
+    fn set(&self, args: args::Set) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        self.set_values(persister.as_ref(), &args.subcommand)?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Hides tasks from 'view' until a moment, or shows them again if `--wake`
+    /// is passed.
+    fn snooze(&self, args: args::Snooze) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        if !persister.exists()? {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        let mut todo = Todo::from(persister.as_ref())?;
+        let ids = self.find_ids(&todo, &args.ids)?;
+
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        todo.snooze(&ids, args.until)?;
+        todo.stamp(&ids, &Action::Snooze);
+        persister.edit(&todo, &ids, &Action::Snooze)?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Changes the position of a task, which is kept in the `order` of every
+    /// task so all persisters display the same order.
+    fn move_task(&self, args: args::Move) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        if !persister.exists()? {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        let mut todo = Todo::from(persister.as_ref())?;
+
+        let changed_ids = match (args.before, args.after, args.to) {
+            (Some(other), _, _) => todo.move_next_to(args.id, other, false)?,
+            (_, Some(other), _) => todo.move_next_to(args.id, other, true)?,
+            (_, _, position) => {
+                let position = position.and_then(|p| usize::try_from(p).ok()).unwrap_or(1);
+                todo.move_to(args.id, position.saturating_sub(1))?
+            }
+        };
+
+        if changed_ids.is_empty() {
+            warning!("Task {} is already at that position", args.id);
+            return Ok(());
+        }
+
+        todo.stamp(&[args.id], &Action::Move);
+        persister.edit(&todo, &changed_ids, &Action::Move)?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Opens a task as TOML in the editor (see [`Postit::open_editor`]) and
+    /// saves the fields that changed once it closes.
+    ///
+    /// The id, the order and the timestamps are managed by postit, so changes
+    /// to them are ignored.
+    fn edit_task(&self, args: args::EditTask) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+        let mut todo = Todo::from(persister.as_ref())?;
+
+        let Some(task) = todo.get_mut(&[args.id]).into_iter().next() else {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        };
+
+        let draft = format!(
+            "# Change the task and close the editor to save it. The id, the order and\n\
+             # the timestamps can't be changed.\n{}",
+            toml::to_string(task).map_err(super::Error::wrap)?
+        );
+
+        let edited = Self::open_editor(&draft, "task.toml")
+            .map_err(|err| super::Error::Editor(format!("{err}; the task wasn't changed")))?;
+
+        let mut new: Task = toml::from_str(&edited).map_err(|err| {
+            super::Error::Editor(format!("The edited task isn't valid; it wasn't changed\n{err}"))
+        })?;
+
+        if new.content.trim().is_empty() {
+            let msg = "The content of the task can't be empty; it wasn't changed";
+            return Err(super::Error::Editor(msg.to_owned()));
+        }
+
+        new.id = task.id;
+        new.order = task.order;
+        new.created_at = task.created_at;
+        new.updated_at = task.updated_at;
+        new.completed_at = task.completed_at;
+
+        let changes = [
+            (task.content != new.content, Action::SetContent),
+            (task.priority != new.priority, Action::SetPriority),
+            (
+                task.checked != new.checked,
+                if new.checked { Action::Check } else { Action::Uncheck },
+            ),
+            (task.refs != new.refs, Action::SetRefs),
+            (task.tags != new.tags, Action::SetTags),
+            (task.notes != new.notes, Action::SetNotes),
+            (task.estimate != new.estimate, Action::SetEstimate),
+            (task.snoozed_until != new.snoozed_until, Action::Snooze),
+            (task.pinned != new.pinned, if new.pinned { Action::Pin } else { Action::Unpin }),
+            (task.attachments != new.attachments, Action::Attach),
+            (task.due != new.due, Action::SetDue),
+        ];
+
+        let actions: Vec<Action> = changes
+            .into_iter()
+            .filter_map(|(changed, action)| changed.then_some(action))
+            .collect();
+
+        if actions.is_empty() {
+            info!("Task {} wasn't changed", args.id);
+            return Ok(());
+        }
+
+        *task = new;
+
+        for action in &actions {
+            todo.stamp(&[args.id], action);
+            persister.edit(&todo, &[args.id], action)?;
+        }
+
+        if actions.iter().any(|action| matches!(action, Action::Check)) {
+            self.hook(Hook::Check, &todo.get(&[args.id]))?;
+        }
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Opens a text in the editor of the `VISUAL` or `EDITOR` env vars (`vi`
+    /// if none is present), through a temporary file whose name ends with
+    /// `suffix`, and returns the text once the editor closes.
+    ///
+    /// # Errors
+    /// - The temporary file can't be written or read.
+    /// - The editor can't be started or fails.
+    fn open_editor(text: &str, suffix: &str) -> super::Result<String> {
+        let path = env::temp_dir().join(format!("postit-{}-{suffix}", process::id()));
+        fs::write(&path, text)?;
+
+        let editor = env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .unwrap_or_else(|_| String::from("vi"));
+
+        let mut words = editor.split_whitespace();
+        let program = words.next().unwrap_or("vi");
+
+        let status = process::Command::new(program)
+            .args(words)
+            .arg(&path)
+            .status();
+
+        let edited = fs::read_to_string(&path);
+        fs::remove_file(&path)?;
+
+        if !status?.success() {
+            return Err(super::Error::Editor(format!("The editor '{editor}' failed")));
+        }
+
+        Ok(edited?)
+    }
+
+    /// Shows the notes of a task, or changes them if a text, `--edit` or
+    /// `--clear` is passed.
+    ///
+    /// `--edit` opens the notes in the editor of the `VISUAL` or `EDITOR`
+    /// env vars (`vi` if none is present) and saves the file when it closes.
+    fn note(&self, args: args::Note) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+        let mut todo = Todo::from(persister.as_ref())?;
+
+        let Some(task) = todo.get_mut(&[args.id]).into_iter().next() else {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        };
+
+        if let Some(text) = args.text {
+            if !task.notes.is_empty() {
+                task.notes.push('\n');
+            }
+
+            task.notes.push_str(&text);
+        } else if args.clear {
+            task.notes.clear();
+        } else if args.edit {
+            let notes = Self::open_editor(&task.notes, "note.txt")
+                .map_err(|err| super::Error::Editor(format!("{err}; the notes weren't changed")))?;
+
+            notes.trim_end().clone_into(&mut task.notes);
+        } else {
+            if task.notes.is_empty() {
+                println!("Task {} has no notes", task.id);
+            } else {
+                println!("{}", task.notes);
+            }
+
+            return Ok(());
+        }
+
+        todo.stamp(&[args.id], &Action::SetNotes);
+        persister.edit(&todo, &[args.id], &Action::SetNotes)?;
+
+        info!("Updated the notes of task {}", args.id);
+
+        Ok(())
+    }
+
+    /// Runs a pomodoro for a task: waits for its minutes, appends the interval
+    /// to the notes of the task and runs the `on_pomo` hook.
+    ///
+    /// The persister isn't locked during the countdown, so other commands can
+    /// change the tasks in the meantime.
+    fn pomo(&self, args: &args::Pomo) -> super::Result<()> {
+        let todo = Todo::from(self.persister(args.persister.clone())?.as_ref())?;
+
+        if todo.get(&[args.id]).is_empty() {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        }
+
+        let start = Local::now();
+        Self::countdown(args.id, Duration::from_secs(u64::from(args.minutes) * 60));
+
+        self.journaled("pomo", args.persister.clone(), |postit| postit.log_pomo(args, start))?;
+
+        // Rings the bell of the terminal
+        eprint!("\x07");
+        info!("The pomodoro of task {} finished", args.id);
+
+        let todo = Todo::from(self.persister(args.persister.clone())?.as_ref())?;
+        self.hook(Hook::Pomo, &todo.get(&[args.id]))
+    }
+
+    /// Appends the interval of a finished pomodoro to the notes of its task.
+    ///
+    /// # Errors
+    /// - The task doesn't exist anymore.
+    /// - The persister can't be read or edited.
+    fn log_pomo(&self, args: &args::Pomo, start: DateTime<Local>) -> super::Result<()> {
+        let persister = self.persister(args.persister.clone())?;
+        let mut todo = Todo::from(persister.as_ref())?;
+
+        let Some(task) = todo.get_mut(&[args.id]).into_iter().next() else {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        };
+
+        if !task.notes.is_empty() {
+            task.notes.push('\n');
+        }
+
+        let line = format!(
+            "Pomodoro: {} ({} - {})",
+            Task::format_estimate(args.minutes),
+            start.format("%Y-%m-%d %H:%M"),
+            Local::now().format("%H:%M")
+        );
+
+        task.notes.push_str(&line);
+
+        todo.stamp(&[args.id], &Action::SetNotes);
+        persister.edit(&todo, &[args.id], &Action::SetNotes)?;
+
+        Ok(())
+    }
+
+    /// Waits until a duration passes. If stderr is a terminal, the time left
+    /// is shown and updated every second.
+    fn countdown(id: u32, total: Duration) {
+        if !io::stderr().is_terminal() {
+            thread::sleep(total);
+            return;
+        }
+
+        let end = Instant::now() + total;
+
+        loop {
+            let left = end.saturating_duration_since(Instant::now());
+            let secs = left.as_secs();
+
+            eprint!("\r{:02}:{:02} left on task {id} ", secs / 60, secs % 60);
+
+            if left.is_zero() {
+                break;
+            }
+
+            thread::sleep(left.min(Duration::from_secs(1)));
+        }
+
+        eprintln!();
+    }
+
+    /// Adds file paths or URLs to the attachments of a task, or removes them
+    /// if `--remove` is passed. Relative paths of existing files are stored
+    /// as absolute paths, so they can be opened from any directory.
+    fn attach(&self, args: args::Attach) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+        let mut todo = Todo::from(persister.as_ref())?;
+
+        let Some(task) = todo.get_mut(&[args.id]).into_iter().next() else {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        };
+
+        let before = task.attachments.clone();
+
+        for uri in args.uris {
+            let uri = if uri.contains("://") {
+                uri
+            } else {
+                fs::canonicalize(&uri).map_or(uri, |path| path.to_string_lossy().into_owned())
+            };
+
+            if args.remove {
+                task.attachments.retain(|attachment| *attachment != uri);
+            } else if !task.attachments.contains(&uri) {
+                task.attachments.push(uri);
+            }
+        }
+
+        if task.attachments == before {
+            info!("Task {} wasn't changed", args.id);
+            return Ok(());
+        }
+
+        todo.stamp(&[args.id], &Action::Attach);
+        persister.edit(&todo, &[args.id], &Action::Attach)?;
+
+        info!("Updated the attachments of task {}", args.id);
+
+        Ok(())
+    }
+
+    /// Opens the first attachment of a task or, if it has none, the URLs of
+    /// its references with the default program of the OS (usually, the web
+    /// browser).
+    fn open(&self, args: args::Open) -> super::Result<()> {
+        let todo = Todo::from(self.persister(args.persister)?.as_ref())?;
+
+        let Some(task) = todo.get(&[args.id]).into_iter().next() else {
+            return Err(super::Error::TaskNotFound(vec![args.id]));
+        };
+
+        if let Some(attachment) = task.attachments.first() {
+            Self::launch(attachment)?;
+            info!("Opened '{attachment}'");
+
+            return Ok(());
+        }
+
+        if task.refs.is_empty() {
+            let msg = format!("Task {} has no attachments or references", task.id);
+            return Err(super::Error::wrap(msg));
+        }
+
+        let links = &self.config()?.links;
+
+        for reference in &task.refs {
+            let Some(url) = links.url(reference) else {
+                warning!("There is no URL template for '{reference}' in the 'links' table");
+                continue;
+            };
+
+            Self::launch(&url)?;
+
+            info!("Opened '{url}'");
+        }
+
+        Ok(())
+    }
+
+    /// Opens a file path or a URL with the default program of the OS.
+    ///
+    /// # Errors
+    /// - The program of the OS can't be run.
+    fn launch(target: &str) -> super::Result<()> {
+        let mut command = if cfg!(target_os = "macos") {
+            process::Command::new("open")
+        } else if cfg!(windows) {
+            let mut cmd = process::Command::new("cmd");
+            cmd.args(["/C", "start", ""]);
+            cmd
+        } else {
+            process::Command::new("xdg-open")
+        };
+
+        command.arg(target).status()?;
+
+        Ok(())
+    }
+
+    /// Drops the tasks with the passed ids or, if filters are passed, the
+    /// ones (among the passed ids, if any) that match every filter. Tasks
+    /// without a creation date are never older than '--older-than'.
+    fn drop(&self, args: args::Drop) -> super::Result<()> {
+        let filtered = args.checked || args.priority.is_some() || args.older_than.is_some();
+
+        let ids = if filtered {
+            let tasks = self.persister(args.persister.as_ref())?.tasks()?;
+
+            tasks
+                .iter()
+                .filter(|task| {
+                    (args.ids.is_empty() || args.ids.contains(&task.id))
+                        && (!args.checked || task.checked)
+                        && args
+                            .priority
+                            .as_ref()
+                            .is_none_or(|priority| task.priority == *priority)
+                        && args.older_than.is_none_or(|date| {
+                            task.created_at.is_some_and(|created| created < date)
+                        })
+                })
+                .map(|task| task.id)
+                .collect()
+        } else {
+            args.ids
+        };
+
+        if ids.is_empty() {
+            warning!("There are no tasks matching the filters");
+            return Ok(());
+        }
+
+        self.edit(args::Edit { persister: args.persister, ids }, &Action::Drop)
+    }
+
+    /// Edits tasks based on the action passed (see [`Postit::edit_tasks`]).
+    fn edit(&self, args: args::Edit, action: &Action) -> super::Result<()> {
+        let persister = self.persister(args.persister.as_ref())?;
+
+        if !persister.exists()? {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        if matches!(action, Action::Drop) && args.ids.len() > 1 {
+            let ids: Vec<String> = args.ids.iter().map(ToString::to_string).collect();
+
+            if !self.confirm(&format!("Drop the tasks {}?", ids.join(", ")))? {
+                return Ok(());
+            }
+        }
+
+        let changes = self.edit_tasks(persister.as_ref(), &args.ids, action)?;
+
+        if matches!(action, Action::Check) {
+            self.hook(Hook::Check, &changes.refs())?;
+        }
+
+        if matches!(action, Action::Drop) && !changes.tasks.is_empty() {
+            self.discard(&self.conn(args.persister)?, &changes.tasks)?;
+        }
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Moves the tasks dropped from a persister to the [`Trash`], unless the
+    /// `trash_days` config is `0`. Tasks older than `trash_days` are removed
+    /// from the trash.
+    ///
+    /// # Errors
+    /// - The config can't be loaded or the trash can't be read or saved.
+    fn discard(&self, conn: &str, tasks: &[Task]) -> super::Result<()> {
+        let days = self.config()?.trash_days;
+
+        if days == 0 {
+            return Ok(());
+        }
+
+        let mut trash = Trash::load()?;
+        trash.expire(days);
+        trash.add(conn, tasks);
+        trash.save()
+    }
+
+    /// Manages the tasks dropped to the [`Trash`].
+    fn trash(&self, args: args::Trash) -> super::Result<()> {
+        match args.subcommand {
+            sub::Trash::List(args) => self.trash_list(args),
+            sub::Trash::Restore(args) => {
+                self.journaled("trash restore", args.persister.clone(), |postit| {
+                    postit.trash_restore(args)
+                })
+            }
+            sub::Trash::Empty(args) => self.trash_empty(args),
+        }
+    }
+
+    /// Displays the tasks dropped from a persister and when they were dropped.
+    fn trash_list(&self, args: args::Persister) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+
+        let mut trash = Trash::load()?;
+
+        if trash.expire(self.config()?.trash_days) > 0 {
+            trash.save()?;
+        }
+
+        let items = trash.of(&conn);
+
+        if items.is_empty() {
+            info!("The trash of '{conn}' is empty");
+            return Ok(());
+        }
+
+        for item in items {
+            let at = item
+                .dropped_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M");
+            println!("{at}  {}", item.task);
+        }
+
+        Ok(())
+    }
+
+    /// Adds dropped tasks back to their persister, with new ids if theirs are
+    /// already used.
+    fn trash_restore(&self, args: args::TrashRestore) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+        let persister = self.persister(Some(&conn))?;
+
+        let mut trash = Trash::load()?;
+        let mut tasks = trash.take(&conn, &args.ids);
+
+        if tasks.is_empty() {
+            return Err(super::Error::NotInTrash(conn));
+        }
+
+        let missing: Vec<String> = args
+            .ids
+            .iter()
+            .filter(|id| !tasks.iter().any(|task| task.id == **id))
+            .map(ToString::to_string)
+            .collect();
+
+        if !missing.is_empty() {
+            warning!("The trash doesn't have the tasks {}", missing.join(", "));
+        }
+
+        let used: Vec<u32> = persister.tasks()?.iter().map(|task| task.id).collect();
+        let mut next_id = persister.next_id()?;
+
+        for task in &mut tasks {
+            if used.contains(&task.id) {
+                task.id = next_id;
+                next_id += 1;
+            }
+        }
+
+        persister.append(&tasks)?;
+        trash.save()?;
+
+        let ids: Vec<String> = tasks.iter().map(|task| task.id.to_string()).collect();
+        info!("Restored the tasks {}", ids.join(", "));
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Deletes the tasks dropped from a persister, or from every persister.
+    fn trash_empty(&self, args: args::TrashEmpty) -> super::Result<()> {
+        let conn = if args.all { None } else { Some(self.conn(args.persister)?) };
+
+        let mut trash = Trash::load()?;
+        let count = trash.of(conn.as_deref().unwrap_or_default()).len();
+        let count = if args.all { trash.items.len() } else { count };
+
+        if count == 0 {
+            info!("The trash is already empty");
+            return Ok(());
+        }
+
+        if !self.confirm(&format!("Delete {count} dropped task(s) permanently?"))? {
+            return Ok(());
+        }
+
+        trash.empty(conn.as_deref());
+        trash.save()?;
+
+        info!("Deleted {count} dropped task(s)");
+
+        Ok(())
+    }
+
+    /// Asks for confirmation before a destructive change, returning `true` if
+    /// it can be made.
+    ///
+    /// Nothing is asked if '--yes' is passed, if `stdin` or `stdout` isn't a
+    /// terminal (so scripts aren't blocked) or if the `confirm` config is
+    /// `false`.
+    ///
+    /// # Errors
+    /// - The config can't be loaded or the answer can't be read.
+    fn confirm(&self, question: &str) -> super::Result<bool> {
+        if self.yes
+            || !io::stdin().is_terminal()
+            || !io::stdout().is_terminal()
+            || !self.config()?.confirm
+        {
+            return Ok(true);
+        }
+
+        let confirmed = Prompt::confirm(io::stdin().lock(), io::stdout(), question)?;
+
+        if !confirmed {
+            info!("Nothing was changed");
+        }
+
+        Ok(confirmed)
+    }
+
+    /// Runs a hook defined in the config file. Does nothing if there are no
+    /// tasks.
+    ///
+    /// The tasks have already been saved when hooks run, so a failing hook
+    /// is reported without making the command fail.
+    fn hook(&self, hook: Hook, tasks: &[&Task]) -> super::Result<()> {
+        if tasks.is_empty() {
+            return Ok(());
+        }
+
+        debug!("Running the '{hook}' hooks");
+
+        if let Err(e) = self.config()?.hooks.run(hook, tasks) {
+            warning!("{e}");
+        }
+
+        Ok(())
+    }
+
+    /// Sends the change made by a command to the webhook defined in the config
+    /// file.
+    ///
+    /// The tasks have already been saved when the webhook is called, so a
+    /// failing request is reported without making the command fail.
+    fn webhook(
+        &self,
+        command: &str,
+        persister: &str,
+        before: &[Task],
+        after: &[Task],
+    ) -> super::Result<()> {
+        debug!("Sending the '{command}' change to the webhook");
+
+        if let Err(e) = self.config()?.hooks.post(command, persister, before, after) {
+            warning!("{e}");
+        }
+
+        Ok(())
+    }
+
+    /// Displays the tasks of a persister after a command changed them, unless
+    /// the verbosity is [`Verbosity::Quiet`].
+    fn show(persister: &dyn Persister) -> super::Result<()> {
+        if Verbosity::Normal.allows() {
+            persister.view()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Picks tasks with a fuzzy finder and applies the subcommand's action to them.
+    fn pick(&self, args: args::Pick) -> super::Result<()> {
+        let persister = self.persister(args.persister.as_ref())?;
+
+        if !persister.exists()? {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        let tasks = persister.tasks()?;
+        let ids = Picker::new(&tasks).run(io::stdin().lock(), io::stdout())?;
+
+        if ids.is_empty() {
+            info!("No tasks were picked");
+            return Ok(());
+        }
+
+        let persister = args.persister;
+
+        match args.subcommand {
+            sub::Pick::Check => self.edit(args::Edit { persister, ids }, &Action::Check),
+            sub::Pick::Uncheck => self.edit(args::Edit { persister, ids }, &Action::Uncheck),
+            sub::Pick::Drop => self.edit(args::Edit { persister, ids }, &Action::Drop),
+            sub::Pick::Set(sub::PickSet::Content(set)) => self.set(args::Set {
+                persister,
+                subcommand: sub::Set::Content(args::SetContent { ids, content: set.content }),
+            }),
+            sub::Pick::Set(sub::PickSet::Priority(set)) => self.set(args::Set {
+                persister,
+                subcommand: sub::Set::Priority(args::SetPriority { ids, priority: set.priority }),
+            }),
+        }
+    }
+
+    /// Sorts the tasks, moves the checked ones to the bottom and renumbers
+    /// their ids, replacing the contents of the persister in a single write.
+    fn tidy(&self, args: args::Persister) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        if !persister.exists()? {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        let mut todo = Todo::from(persister.as_ref())?;
+
+        if todo.tasks.is_empty() {
+            return Err(super::Error::NoTasks("tidy"));
+        }
+
+        todo.tidy();
+        persister.replace(&todo)?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Reverts the last change made to the tasks of a persister.
+    fn undo(&self, args: args::Persister) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+        let mut journal = Journal::load()?;
+
+        let Some(entry) = journal.pop_undo(&conn) else {
+            info!("There is nothing to undo");
+            return Ok(());
+        };
+
+        self.apply(&journal, &entry, "undo", &entry.after, &entry.before)?;
+
+        info!("Undid '{}'", entry.command);
+        journal.redo.push(entry);
+        journal.save()
+    }
+
+    /// Applies again the last change reverted with 'undo'.
+    fn redo(&self, args: args::Persister) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+        let mut journal = Journal::load()?;
+
+        let Some(entry) = journal.pop_redo(&conn) else {
+            info!("There is nothing to redo");
+            return Ok(());
+        };
+
+        self.apply(&journal, &entry, "redo", &entry.before, &entry.after)?;
+
+        info!("Redid '{}'", entry.command);
+        journal.undo.push(entry);
+        journal.save()
+    }
+
+    /// Replaces the tasks changed by a journal entry in its persister, which
+    /// are still as they are in `from`, with the ones of `to` (see
+    /// [`Entry::replay`]), and sends the change to the webhook of the config.
+    ///
+    /// If the tasks were changed by something that isn't in the journal (e.g.:
+    /// the file was edited by hand), the entry is discarded instead, as
+    /// replacing the tasks would lose those changes.
+    ///
+    /// # Errors
+    /// - The changed tasks aren't as they are in `from`.
+    /// - The tasks can't be read or replaced.
+    fn apply(
+        &self,
+        journal: &Journal,
+        entry: &Entry,
+        command: &str,
+        from: &[Task],
+        to: &[Task],
+    ) -> super::Result<()> {
+        let before = self.snapshot(&entry.persister)?;
+
+        let Some(after) = Entry::replay(&before, from, to) else {
+            journal.save()?;
+
+            let msg = format!(
+                "The tasks changed after '{}' outside of postit; it can't be undone or redone",
+                entry.command
+            );
+            return Err(super::Error::Journal(msg));
+        };
+
+        let persister = self.persister(Some(&entry.persister))?;
+
+        persister.replace(&Todo::new(after.clone()))?;
+        self.webhook(command, &entry.persister, &before, &after)?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Adds the tasks read from the standard input, giving them new ids.
+    fn import(&self, args: args::Import) -> super::Result<()> {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+
+        let tasks = args.format.parse(&input)?;
+
+        if tasks.is_empty() {
+            return Err(super::Error::NoTasks("import"));
+        }
+
+        let persister = self.persister(args.persister)?;
+
+        if !persister.exists()? {
+            persister.create()?;
+        }
+
+        let mut todo = Todo::from(persister.as_ref())?;
+        let ids = todo.append(tasks);
+
+        persister.replace(&todo)?;
+
+        info!("Imported {} tasks into '{}'", ids.len(), persister.to_string());
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Writes the tasks of a persister to a standalone file, without changing
+    /// the persister.
+    fn export(&self, args: &args::Export) -> super::Result<()> {
+        let persister = self.persister(args.persister.as_ref())?;
+        let todo = Todo::from(persister.as_ref())?;
+
+        if todo.tasks.is_empty() {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        let config = self.config()?;
+        let pretty = !self.compact && config.json_pretty;
+
+        args.format.write(&todo, &config.links, &args.out, pretty)?;
+
+        info!("Exported '{}' to '{}'", persister.to_string(), args.out.display());
+
+        Ok(())
+    }
+
+    /// Bundles the config file, the tasks of the persister, the journal and
+    /// the trash into an archive.
+    fn dump(&self, args: args::Archive) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        let path = match args.path {
+            Some(path) => path,
+            None => Dump::backup_path()?,
+        };
+
+        Dump::collect(persister.tasks()?)?.write(&path)?;
+
+        info!("Dumped '{}' into '{}'", persister.to_string(), path.display());
+
+        Ok(())
+    }
+
+    /// Restores the config file, the tasks, the journal and the trash from an
+    /// archive. The config is restored first, so the tasks go to the persister
+    /// defined in it unless the `--persister` flag is passed. The rest is
+    /// restored while holding the lock of that persister, like the commands
+    /// that change its tasks (see [`Postit::journaled`]).
+    ///
+    /// If no archive is passed, the backups are listed to choose one (or the
+    /// newest one is restored if `--yes` is passed), and the restore must be
+    /// confirmed like the other destructive commands (see [`Postit::confirm`]).
+    fn restore(&self, args: args::Restore) -> super::Result<()> {
+        let path = if let Some(from) = &args.from {
+            Dump::find(from)?
+        } else {
+            let backups = Dump::backups()?;
+
+            if backups.is_empty() {
+                let msg = "There are no backups; make one with 'postit dump'";
+                return Err(super::Error::Archive(msg.to_owned()));
+            }
+
+            let names: Vec<String> = backups
+                .iter()
+                .map(|path| {
+                    path.file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect();
+
+            let chosen = if self.yes {
+                Some(0)
+            } else {
+                let question = "Backup to restore";
+                Prompt::choose(io::stdin().lock(), io::stdout(), question, &names)?
+            };
+
+            let Some(backup) = chosen.and_then(|i| backups.get(i)) else {
+                info!("Nothing was restored");
+                return Ok(());
+            };
+
+            backup.clone()
+        };
+
+        let dump = Dump::read(&path)?;
+
+        let parts: Vec<&str> = [
+            (dump.config.is_some(), "the config file"),
+            (true, "the tasks"),
+            (dump.journal.is_some(), "the journal"),
+            (dump.trash.is_some(), "the trash"),
+        ]
+        .into_iter()
+        .filter_map(|(included, part)| included.then_some(part))
+        .collect();
+
+        let replaced = match parts.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+            _ => String::from("the tasks"),
+        };
+
+        let question = format!(
+            "Replace {replaced} with the {} tasks of '{}'?",
+            dump.tasks.len(),
+            path.display()
+        );
+
+        if !self.confirm(&question)? {
+            return Ok(());
+        }
+
+        if let Some(config) = &dump.config {
+            let path = Config::path()?;
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::write(&path, config)?;
+
+            info!("Restored the config file at '{}'", path.display());
+        }
+
+        let persister = self.persister(args.persister)?;
+        let timeout = Duration::from_secs(self.config()?.lock_timeout);
+
+        let _lock = persister.lock(timeout)?;
+
+        dump.unpack()?;
+
+        if !persister.exists()? {
+            persister.create()?;
+        }
+
+        persister.replace(&Todo::new(dump.tasks))?;
+
+        if persister.tasks()?.is_empty() {
+            info!("The archive doesn't contain tasks");
+            return Ok(());
+        }
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Copies the contents of a persister to another.
+    ///
+    /// # Errors
+    /// - Both persisters are the same.
+    /// - The left persister has no tasks.
+    /// - The right persister has tasks.    
+    fn copy(&self, args: &args::Copy) -> super::Result<()> {
+        let config = self.config()?;
+
+        let (left_path, right_path) = match args.left.as_ref() {
+            "from" => (&args.right, &config.persister),
+            "to" => (&config.persister, &args.right),
+            _ => (&args.left, &args.right),
+        };
+
+        if left_path == right_path {
+            let msg = "Both persisters are the same";
+            return Err(super::Error::Copy(msg.to_owned()));
+        }
+
+        let mut loaded = self.load_all(&[left_path, right_path])?;
+        let right_tasks = loaded.pop().unwrap_or_default();
+        let left_tasks = loaded.pop().unwrap_or_default();
+
+        let left = self.persister(Some(left_path))?;
+
+        if left_tasks.is_empty() {
+            return Err(super::Error::PersisterDoesntExist(left.to_string()));
+        }
+
+        let right = self.persister(Some(right_path))?;
+
+        if !right.exists()? {
+            right.create()?;
+        }
+
+        if args.merge {
+            let mut todo = Todo::new(right_tasks);
+            todo.append(left_tasks);
+
+            right.replace(&todo)?;
+        } else {
+            if !config.force_copy && !right_tasks.is_empty() {
+                let msg = format!(
+                    "The persister '{}' already has tasks.\nSet 'force_copy' to 'true' to overwrite them or use '--merge' to append to them.",
+                    right.to_string()
+                );
+
+                return Err(super::Error::Copy(msg));
+            }
+
+            if !right_tasks.is_empty() {
+                let question = format!(
+                    "Overwrite the {} tasks of '{}'?",
+                    right_tasks.len(),
+                    right.to_string()
+                );
+
+                if !self.confirm(&question)? {
+                    return Ok(());
+                }
+            }
+
+            right.replace(&Todo::new(left_tasks))?;
+        }
+
+        if config.drop_after_copy {
+            left.remove()?;
+        }
+
+        if args.merge {
+            info!("The tasks of '{left_path}' have been merged into '{right_path}'");
+        } else {
+            info!("The tasks of '{left_path}' have been copied to '{right_path}'");
+        }
+
+        Self::show(right.as_ref())
+    }
+
+    /// Populates the persister with fake data for testing purposes.
+    ///
+    /// The four tasks of [`Todo::sample`] are used unless a count or a seed
+    /// is passed, in which case the tasks are generated by [`Todo::generate`].
+    fn sample(&self, args: args::Sample) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        if !persister.exists()? {
+            persister.create()?;
+        }
+
+        if args.count.is_none() && args.seed.is_none() {
+            persister.replace(&Todo::sample())?;
+
+            info!("Sample generated at '{}'", persister.to_string());
+        } else {
+            let seed = args.seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs() ^ u64::from(d.subsec_nanos()))
+            });
+
+            persister.replace(&Todo::generate(args.count.unwrap_or(4), seed))?;
+
+            info!("Sample generated at '{}' (seed: {seed})", persister.to_string());
+        }
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Drops the checked tasks of a persister, whether `force_drop` is set
+    /// or not, and moves them to the [`Trash`] like 'drop' does.
+    fn purge(&self, args: args::Persister) -> super::Result<()> {
+        let conn = self.conn(args.persister)?;
+        let persister = self.persister(Some(&conn))?;
+        let checked = persister
+            .tasks()?
+            .iter()
+            .filter(|task| task.checked)
+            .count();
+
+        if checked == 0 {
+            warning!("There are no checked tasks");
+            return Ok(());
+        }
+
+        let question = format!("Drop the {checked} checked tasks of '{}'?", persister.to_string());
+
+        if !self.confirm(&question)? {
+            return Ok(());
+        }
+
+        let changes = Self::purge_tasks(persister.as_ref())?;
+        self.discard(&conn, &changes.tasks)?;
+
+        Self::show(persister.as_ref())
+    }
+
+    /// Cleans the tasks from a file.
+    fn clean(&self, args: args::Persister) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        if !self.confirm(&format!("Delete every task of '{}'?", persister.to_string()))? {
+            return Ok(());
+        }
+
+        persister.clean()
+    }
+
+    /// Removes a persister completely (file or table).
+    fn remove(&self, args: args::Persister) -> super::Result<()> {
+        let persister = self.persister(args.persister)?;
+
+        if !self.confirm(&format!("Remove '{}' and its tasks?", persister.to_string()))? {
+            return Ok(());
+        }
+
+        persister.remove()
+    }
+
+    /// Manages the configuration file.   
+    fn manage_config(args: args::Config) -> super::Result<()> {
+        if matches!(args.subcommand, sub::Config::Check) {
+            return Self::new(None).check_config();
+        }
+
+        Config::manage(args.subcommand)?;
+
+        Ok(())
+    }
+
+    /// Checks the configuration like [`Config::check`], and also checks that
+    /// the persister, its mirrors and the persisters of the lists can be used (see
+    /// [`Persister::check`]).
+    fn check_config(&self) -> super::Result<()> {
+        let mut problems = Config::check()?;
+
+        if let Ok(config) = self.config() {
+            let conns = std::iter::once(&config.persister)
+                .chain(&config.mirrors)
+                .chain(config.lists.0.values());
+
+            for conn in conns {
+                if let Err(e) = self.build(conn).and_then(|p| p.check()) {
+                    problems.push(format!("The persister '{conn}' can't be used: {e}"));
+                }
+            }
+        }
+
+        Config::report(&problems)?;
+
+        Ok(())
+    }
+
+    /// Manages the named task lists.
+    fn manage_lists(args: args::List) -> super::Result<()> {
+        Config::manage_lists(args.subcommand)?;
+
+        Ok(())
+    }
+}
+
+/// Functions that run the logic of the commands and return what changed,
+/// without printing the tasks or asking for confirmation, so other programs
+/// can use them. The persisters are built with [`Postit::persister`].
+///
+/// Unlike the commands, the changes aren't recorded in the journal, so
+/// 'undo' can't revert them, and the hooks of the config aren't run.
+impl Postit {
+    /// Returns the tasks of a persister in their manual order (see
+    /// [`Todo::move_to`]), or no tasks if it doesn't exist.
+    ///
+    /// # Errors
+    /// - The tasks can't be read.
+    #[inline]
+    pub fn tasks(persister: &dyn Persister) -> super::Result<Vec<Task>> {
+        if !persister.exists()? {
+            return Ok(Vec::new());
+        }
+
+        Ok(Todo::from(persister)?.tasks)
+    }
+
+    /// Adds an unchecked task at the end of the list of a persister, creating
+    /// the persister if it doesn't exist. Returns the added task.
+    ///
+    /// The task is appended (see [`Persister::append`]), so CSV and JSONL
+    /// files aren't rewritten.
+    ///
+    /// # Errors
+    /// - The persister can't be created, read or saved.
+    #[inline]
+    pub fn add_task(
+        persister: &dyn Persister,
+        content: String,
+        priority: Priority,
+        tags: Vec<String>,
+    ) -> super::Result<Changes> {
+        Self::add_draft(persister, Draft { content, priority, due: None, tags })
+    }
+
+    /// Adds an unchecked task with the values of a [`Draft`] (e.g.: the
+    /// answers of the prompt), like [`Postit::add_task`]. Returns the added
+    /// task.
+    ///
+    /// # Errors
+    /// - The persister can't be created, read or saved.
+    #[inline]
+    pub fn add_draft(persister: &dyn Persister, draft: Draft) -> super::Result<Changes> {
+        if !persister.exists()? {
+            persister.create()?;
+        }
+
+        let mut task = Task::new(persister.next_id()?, draft.content, draft.priority, false);
+        task.tags = draft.tags;
+        task.due = draft.due;
+        task.created_at = Some(Utc::now().trunc_subsecs(0));
+
+        persister.append(&[task.clone()])?;
+
+        Ok(Changes::new(vec![task]))
+    }
+
+    /// Adds an unchecked task per content at the end of the list of a
+    /// persister, like [`Postit::add_task`], with consecutive ids and the same
+    /// priority and tags. Returns the added tasks.
+    ///
+    /// # Errors
+    /// - The persister can't be created, read or saved.
+    #[inline]
+    pub fn add_tasks(
+        persister: &dyn Persister,
+        contents: Vec<String>,
+        priority: &Priority,
+        tags: &[String],
+    ) -> super::Result<Changes> {
+        if !persister.exists()? {
+            persister.create()?;
+        }
+
+        let created_at = Some(Utc::now().trunc_subsecs(0));
+        let tasks: Vec<Task> = (persister.next_id()?..)
+            .zip(contents)
+            .map(|(id, content)| {
+                let mut task = Task::new(id, content, priority.clone(), false);
+                task.tags = tags.to_vec();
+                task.created_at = created_at;
+                task
+            })
+            .collect();
+
+        persister.append(&tasks)?;
+
+        Ok(Changes::new(tasks))
+    }
+
+    /// Adds unchecked copies of tasks at the end of the list, with new ids
+    /// and without the dates, the snooze and the position of the originals.
+    /// The `priority` and `content` of every copy are replaced if they are
+    /// passed. Returns the copies.
+    ///
+    /// # Errors
+    /// - The persister doesn't exist.
+    /// - Some of the tasks don't exist ([`Error::TaskNotFound`][super::Error::TaskNotFound]).
+    /// - The persister can't be read or saved.
+    #[inline]
+    pub fn dup_tasks(
+        persister: &dyn Persister,
+        ids: &[u32],
+        priority: Option<&Priority>,
+        content: Option<&str>,
+    ) -> super::Result<Changes> {
+        let todo = Self::existing(persister)?;
+        let mut copies = Vec::new();
+
+        let missing = todo.missing(ids);
+
+        if !missing.is_empty() {
+            return Err(super::Error::TaskNotFound(missing));
+        }
+
+        for (id, new_id) in ids.iter().zip(persister.next_id()?..) {
+            let Some(task) = todo.get(&[*id]).into_iter().next() else {
+                continue;
+            };
+
+            let mut copy = task.clone();
+            copy.id = new_id;
+            copy.checked = false;
+            copy.created_at = Some(Utc::now().trunc_subsecs(0));
+            copy.updated_at = None;
+            copy.completed_at = None;
+            copy.snoozed_until = None;
+            copy.order = None;
+
+            if let Some(priority) = priority {
+                copy.priority = priority.clone();
+            }
+
+            if let Some(content) = content {
+                content.clone_into(&mut copy.content);
+            }
+
+            copies.push(copy);
+        }
+
+        persister.append(&copies)?;
+
+        Ok(Changes::new(copies))
+    }
+
+    /// Changes the values of tasks depending on the `Set` variant. Returns
+    /// the changed tasks.
+    ///
+    /// # Errors
+    /// - The persister doesn't exist or the values can't be set.
+    /// - Some of the tasks don't exist and the `strict` config is `true`.
+    /// - The persister can't be read or edited.
+    #[inline]
+    pub fn set_values(
+        &self,
+        persister: &dyn Persister,
+        subcommand: &sub::Set,
+    ) -> super::Result<Changes> {
+        let mut todo = Self::existing(persister)?;
+
+        let (ids, action) = match subcommand {
+            sub::Set::Content(args) => (&args.ids, Action::SetContent),
+            sub::Set::Priority(args) => (&args.ids, Action::SetPriority),
+            sub::Set::Ref(args) => (&args.ids, Action::SetRefs),
+            sub::Set::Tags(args) => (&args.ids, Action::SetTags),
+            sub::Set::Estimate(args) => (&args.ids, Action::SetEstimate),
+            sub::Set::Due(args) => (&args.ids, Action::SetDue),
+        };
+
+        let ids = self.find_ids(&todo, ids)?;
+
+        if ids.is_empty() {
+            return Ok(Changes::default());
+        }
+
+        todo.set(subcommand)?;
+
+        todo.stamp(&ids, &action);
+        persister.edit(&todo, &ids, &action)?;
+
+        Ok(Changes::new(todo.get(&ids).into_iter().cloned().collect()))
+    }
+
+    /// Checks, unchecks, drops, pins or unpins tasks. Tasks that already had
+    /// that state, or checked tasks that can't be dropped because
+    /// `force_drop` isn't set, are left as they are. Returns the changed
+    /// tasks.
+    ///
+    /// # Errors
+    /// - The persister doesn't exist or it has no tasks.
+    /// - Some of the tasks don't exist and the `strict` config is `true`.
+    /// - The action needs values (e.g.: [`Action::SetContent`]).
+    /// - The persister can't be read or edited.
+    #[inline]
+    pub fn edit_tasks(
+        &self,
+        persister: &dyn Persister,
+        ids: &[u32],
+        action: &Action,
+    ) -> super::Result<Changes> {
+        let mut todo = Self::existing(persister)?;
+        let before = todo.tasks.clone();
+
+        let ids = self.find_ids(&todo, ids)?;
+
+        let changed_ids = match action {
+            Action::Check => todo.check(&ids),
+            Action::Uncheck => todo.uncheck(&ids),
+            Action::Drop => todo.drop_with(&ids, self.config()?.force_drop),
+            Action::Pin => todo.pin(&ids),
+            Action::Unpin => todo.unpin(&ids),
+            Action::SetContent
+            | Action::SetPriority
+            | Action::SetRefs
+            | Action::SetTags
+            | Action::SetNotes
+            | Action::SetEstimate
+            | Action::Snooze
+            | Action::Move
+            | Action::Attach
+            | Action::SetDue => {
+                let msg = format!("The '{action}' action needs values; use its command instead");
+                return Err(super::Error::wrap(msg));
+            }
+        }?;
+
+        if changed_ids.is_empty() {
+            return Ok(Changes::default());
+        }
+
+        todo.stamp(&changed_ids, action);
+        persister.edit(&todo, &changed_ids, action)?;
+
+        let tasks = if matches!(action, Action::Drop) {
+            before
+                .into_iter()
+                .filter(|task| changed_ids.contains(&task.id))
+                .collect()
+        } else {
+            todo.get(&changed_ids).into_iter().cloned().collect()
+        };
+
+        Ok(Changes::new(tasks))
+    }
+
+    /// Drops the checked tasks of a persister, whether `force_drop` is set
+    /// or not. Returns the dropped tasks.
+    ///
+    /// # Errors
+    /// - The persister can't be read or edited.
+    #[inline]
+    pub fn purge_tasks(persister: &dyn Persister) -> super::Result<Changes> {
+        let mut todo = Todo::new(persister.tasks()?);
+
+        let dropped: Vec<Task> = todo
+            .tasks
+            .iter()
+            .filter(|task| task.checked)
+            .cloned()
+            .collect();
+
+        if dropped.is_empty() {
+            return Ok(Changes::default());
+        }
+
+        let ids: Vec<u32> = dropped.iter().map(|task| task.id).collect();
+        let changed_ids = todo.drop_with(&ids, true)?;
+        persister.edit(&todo, &changed_ids, &Action::Drop)?;
+
+        Ok(Changes::new(dropped))
+    }
+
+    /// Returns the passed ids that match a task. The ones that don't are an
+    /// error if the `strict` config is `true`, or are skipped with a warning
+    /// if it isn't.
+    ///
+    /// # Errors
+    /// - Some of the tasks don't exist and the `strict` config is `true`.
+    /// - The config can't be loaded.
+    fn find_ids(&self, todo: &Todo, ids: &[u32]) -> super::Result<Vec<u32>> {
+        let missing = todo.missing(ids);
+
+        if !missing.is_empty() {
+            let err = super::Error::TaskNotFound(missing.clone());
+
+            if self.config()?.strict {
+                return Err(err);
+            }
+
+            warning!("{err}; skipping");
+        }
+
+        Ok(ids
+            .iter()
+            .filter(|id| !missing.contains(id))
+            .copied()
+            .collect())
+    }
+
+    /// Returns the tasks of a persister that has to exist.
+    ///
+    /// # Errors
+    /// - The persister doesn't exist or its tasks can't be read.
+    fn existing(persister: &dyn Persister) -> super::Result<Todo> {
+        if !persister.exists()? {
+            return Err(super::Error::PersisterDoesntExist(persister.to_string()));
+        }
+
+        Todo::from(persister)
+    }
 }
@@ -0,0 +1,249 @@
+//! Contains the `Prompt` struct, which asks for the values of a new task when
+//! the 'add' command doesn't receive them as arguments, and for the answers
+//! of other interactive questions.
+
+use std::io::{BufRead, Write};
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum as _;
+
+use crate::models::{Priority, Task};
+use crate::tr;
+
+/// Values of a task answered in the prompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Draft {
+    /// The content or description of the task.
+    pub content: String,
+    /// Priority of the task.
+    pub priority: Priority,
+    /// Date when the task is due, if any.
+    pub due: Option<DateTime<Utc>>,
+    /// Tags of the task.
+    pub tags: Vec<String>,
+}
+
+/// Interactive prompt that asks for the values of a new task, one at a time.
+///
+/// Values that were already passed as arguments aren't asked again.
+#[derive(Debug, Default)]
+pub struct Prompt {
+    /// Priority passed as an argument.
+    priority: Option<Priority>,
+    /// Priority used when the answer is empty (`med` if it is `None`).
+    default: Option<Priority>,
+    /// Tags passed as arguments.
+    tags: Vec<String>,
+}
+
+impl Prompt {
+    /// Constructor of the `Prompt` struct.
+    #[inline]
+    pub const fn new(priority: Option<Priority>, tags: Vec<String>) -> Self {
+        Self { priority, default: None, tags }
+    }
+
+    /// Sets the priority used when the priority question is answered with
+    /// an empty line.
+    #[inline]
+    #[must_use]
+    pub const fn with_default(mut self, priority: Priority) -> Self {
+        self.default = Some(priority);
+        self
+    }
+
+    /// Runs the prompt, reading the answers from `input` and writing the
+    /// questions to `output`. Returns `None` if the input ends before every
+    /// question is answered.
+    ///
+    /// Invalid answers are explained and the question is asked again:
+    /// - The content can't be empty.
+    /// - The priority must be `high`, `med`, `low` or `none` (the default
+    ///   priority if empty).
+    /// - The due date must be an amount of time after now or a date (see
+    ///   [`Task::parse_until`]) that isn't in the past, or empty for no due
+    ///   date.
+    /// - Tags are separated by spaces or commas, and can be empty.
+    ///
+    /// # Errors
+    /// - The input can't be read or the output can't be written.
+    #[inline]
+    pub fn run<R: BufRead, W: Write>(
+        self,
+        mut input: R,
+        mut output: W,
+    ) -> crate::Result<Option<Draft>> {
+        let Some(content) = Self::ask(&mut input, &mut output, &tr!("Content"), |answer| {
+            if answer.is_empty() {
+                Err(tr!("The content can't be empty"))
+            } else {
+                Ok(answer.to_owned())
+            }
+        })?
+        else {
+            return Ok(None);
+        };
+
+        let default = self.default.unwrap_or(Priority::Med);
+
+        let priority = self.priority.map_or_else(
+            || {
+                let question =
+                    tr!("Priority (high, med, low or none) [{default}]", default = default);
+
+                Self::ask(&mut input, &mut output, &question, |answer| {
+                    if answer.is_empty() {
+                        return Ok(default.clone());
+                    }
+
+                    Priority::from_str(answer, true)
+                        .map_err(|_| tr!("'{answer}' is not a valid priority", answer = answer))
+                })
+            },
+            |priority| Ok(Some(priority)),
+        )?;
+
+        let Some(priority) = priority else {
+            return Ok(None);
+        };
+
+        let question = tr!("Due date (e.g.: 2d, 1w or 2025-01-31, optional)");
+
+        let Some(due) = Self::ask(&mut input, &mut output, &question, |answer| {
+            if answer.is_empty() {
+                return Ok(None);
+            }
+
+            match Task::parse_until(answer) {
+                Ok(date) if date.date_naive() < Utc::now().date_naive() => {
+                    Err(tr!("'{answer}' is in the past", answer = answer))
+                }
+                Ok(date) => Ok(Some(date)),
+                Err(_) => {
+                    Err(tr!("'{answer}' is not an amount of time or a date", answer = answer))
+                }
+            }
+        })?
+        else {
+            return Ok(None);
+        };
+
+        let tags = if self.tags.is_empty() {
+            let question = tr!("Tags (separated by spaces, optional)");
+
+            Self::ask(&mut input, &mut output, &question, |answer| {
+                Ok(answer
+                    .split([' ', ','])
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_owned)
+                    .collect())
+            })?
+        } else {
+            Some(self.tags)
+        };
+
+        let Some(tags) = tags else {
+            return Ok(None);
+        };
+
+        Ok(Some(Draft { content, priority, due, tags }))
+    }
+
+    /// Asks a yes or no question, where an empty answer means no (`s` and
+    /// `si` are also accepted as yes, for Spanish). Returns `false` if the
+    /// input ends.
+    ///
+    /// # Errors
+    /// - The input can't be read or the output can't be written.
+    #[inline]
+    pub fn confirm<R: BufRead, W: Write>(
+        mut input: R,
+        mut output: W,
+        question: &str,
+    ) -> crate::Result<bool> {
+        let question = tr!("{question} [y/N]", question = question);
+
+        let answer = Self::ask(&mut input, &mut output, &question, |answer| {
+            match answer.to_lowercase().as_str() {
+                "y" | "yes" | "s" | "si" | "sí" => Ok(true),
+                "" | "n" | "no" => Ok(false),
+                _ => Err(tr!("'{answer}' is not 'y' or 'n'", answer = answer)),
+            }
+        })?;
+
+        Ok(answer.unwrap_or(false))
+    }
+
+    /// Shows a numbered list of options and asks for one of them, where an
+    /// empty answer selects the first one. Returns the index of the option,
+    /// or `None` if the input ends.
+    ///
+    /// # Errors
+    /// - The input can't be read or the output can't be written.
+    #[inline]
+    pub fn choose<R: BufRead, W: Write>(
+        mut input: R,
+        mut output: W,
+        question: &str,
+        options: &[String],
+    ) -> crate::Result<Option<usize>> {
+        for (number, option) in (1..).zip(options) {
+            writeln!(output, "{number:>3}. {option}")?;
+        }
+
+        let question = format!("{question} (1-{}) [1]", options.len());
+
+        Self::ask(&mut input, &mut output, &question, |answer| {
+            if answer.is_empty() {
+                return Ok(0);
+            }
+
+            answer
+                .parse::<usize>()
+                .ok()
+                .filter(|number| (1..=options.len()).contains(number))
+                .map(|number| number - 1)
+                .ok_or_else(|| {
+                    tr!(
+                        "'{answer}' is not a number between 1 and {max}",
+                        answer = answer,
+                        max = options.len()
+                    )
+                })
+        })
+    }
+
+    /// Asks a question until `parse` accepts the answer, which is trimmed.
+    /// Returns `None` if the input ends.
+    ///
+    /// # Errors
+    /// - The input can't be read or the output can't be written.
+    fn ask<R, W, T, F>(
+        input: &mut R,
+        output: &mut W,
+        question: &str,
+        parse: F,
+    ) -> crate::Result<Option<T>>
+    where
+        R: BufRead,
+        W: Write,
+        F: Fn(&str) -> Result<T, String>,
+    {
+        loop {
+            write!(output, "{question}: ")?;
+            output.flush()?;
+
+            let mut line = String::new();
+
+            if input.read_line(&mut line)? == 0 {
+                writeln!(output)?;
+                return Ok(None);
+            }
+
+            match parse(line.trim()) {
+                Ok(value) => return Ok(Some(value)),
+                Err(msg) => writeln!(output, "{msg}")?,
+            }
+        }
+    }
+}
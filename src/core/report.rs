@@ -0,0 +1,110 @@
+//! Contains the `Report` struct, which summarizes the tasks added, completed
+//! and dropped over a period of time, used by the 'report' command.
+
+use std::fmt;
+
+use chrono::{DateTime, Local, Utc};
+use serde_json::{json, Value};
+
+use super::Trash;
+use crate::models::Task;
+
+/// Tasks added, completed and dropped in a persister since a moment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    /// Start of the period.
+    pub since: DateTime<Utc>,
+    /// Tasks added during the period.
+    pub added: Vec<Task>,
+    /// Tasks checked during the period.
+    pub completed: Vec<Task>,
+    /// Tasks dropped during the period.
+    pub dropped: Vec<Task>,
+}
+
+impl fmt::Display for Report {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let since = self.since.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+        write!(f, "Since {since}:")?;
+
+        let sections =
+            [("Added", &self.added), ("Completed", &self.completed), ("Dropped", &self.dropped)];
+
+        for (title, tasks) in sections {
+            write!(f, "\n{title}: {}", tasks.len())?;
+
+            for task in tasks {
+                write!(f, "\n  {task}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Report {
+    /// Creates a report from the tasks of a persister and the ones dropped
+    /// from it since a moment.
+    ///
+    /// Added and completed tasks are found by their `created_at` and
+    /// `completed_at` timestamps, so tasks without them are ignored. Dropped
+    /// tasks are taken from the trash, so they are reported while they are
+    /// kept in it.
+    #[inline]
+    pub fn new(tasks: &[Task], trash: &Trash, persister: &str, since: DateTime<Utc>) -> Self {
+        let after = |at: Option<&DateTime<Utc>>| at.is_some_and(|at| *at >= since);
+
+        let added = tasks
+            .iter()
+            .filter(|task| after(task.created_at.as_ref()))
+            .cloned()
+            .collect();
+        let completed = tasks
+            .iter()
+            .filter(|task| task.checked && after(task.completed_at.as_ref()))
+            .cloned()
+            .collect();
+
+        let dropped = trash
+            .of(persister)
+            .into_iter()
+            .filter(|item| item.dropped_at >= since)
+            .map(|item| item.task.clone())
+            .collect();
+
+        Self { since, added, completed, dropped }
+    }
+
+    /// Parses the start of a period, passed as an amount of time before now
+    /// (see [`Task::parse_duration`]) or as a date (see [`Task::parse_date`]).
+    ///
+    /// # Errors
+    /// - The value isn't an amount of time or a date.
+    #[inline]
+    pub fn parse_since(value: &str) -> Result<DateTime<Utc>, String> {
+        Task::parse_duration(value)
+            .and_then(|duration| Utc::now().checked_sub_signed(duration))
+            .map_or_else(
+                || {
+                    Task::parse_date(value).map_err(|_| {
+                        format!(
+                            "'{value}' is not an amount of time (e.g.: '7d' or '12h') or a date"
+                        )
+                    })
+                },
+                Ok,
+            )
+    }
+
+    /// Returns the report as a JSON object, with the tasks of each list.
+    #[inline]
+    pub fn as_json(&self) -> Value {
+        json!({
+            "since": Task::format_timestamp(Some(&self.since)),
+            "added": self.added,
+            "completed": self.completed,
+            "dropped": self.dropped,
+        })
+    }
+}
@@ -0,0 +1,159 @@
+//! Contains the `Trash` struct, which keeps the tasks dropped with the 'drop'
+//! command so they can be restored later with 'trash restore'.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, SubsecRound as _, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::models::Task;
+
+/// Name of the trash file, stored next to the config file.
+const FILE: &str = ".postit_trash.jsonl";
+
+/// Task dropped from a persister.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trashed {
+    /// Persister the task was dropped from, as passed to the command.
+    pub persister: String,
+    /// When the task was dropped.
+    pub dropped_at: DateTime<Utc>,
+    /// Dropped task.
+    pub task: Task,
+}
+
+impl Trashed {
+    /// Constructor of the `Trashed` struct, dropped at the current time.
+    #[inline]
+    pub fn new<T: Into<String>>(persister: T, task: Task) -> Self {
+        Self {
+            persister: persister.into(),
+            dropped_at: Utc::now().trunc_subsecs(0),
+            task,
+        }
+    }
+}
+
+/// Dropped tasks of every persister, from the oldest to the newest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trash {
+    /// Tasks in the trash.
+    pub items: Vec<Trashed>,
+}
+
+impl Trash {
+    /// Returns the path of the trash file, which is stored in the same
+    /// directory as the config file.
+    ///
+    /// # Errors
+    /// - The path can't be obtained from the `POSTIT_ROOT` env var.
+    #[inline]
+    pub fn path() -> crate::Result<PathBuf> {
+        Ok(Config::path_from_env()?.join(FILE))
+    }
+
+    /// Loads the trash file, which has a JSON object per line, or returns an
+    /// empty trash if it doesn't exist.
+    ///
+    /// # Errors
+    /// - The trash file can't be read or parsed.
+    #[inline]
+    pub fn load() -> crate::Result<Self> {
+        let path = Self::path()?;
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let items = fs::read_to_string(path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(crate::Error::wrap))
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { items })
+    }
+
+    /// Saves the trash to its file, creating its directory if needed.
+    ///
+    /// # Errors
+    /// - The trash can't be serialized or written.
+    #[inline]
+    pub fn save(&self) -> crate::Result<()> {
+        let path = Self::path()?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut content = String::new();
+
+        for item in &self.items {
+            content.push_str(&serde_json::to_string(item).map_err(crate::Error::wrap)?);
+            content.push('\n');
+        }
+
+        Ok(fs::write(path, content)?)
+    }
+
+    /// Adds the tasks dropped from a persister.
+    #[inline]
+    pub fn add(&mut self, persister: &str, tasks: &[Task]) {
+        self.items.extend(
+            tasks
+                .iter()
+                .map(|task| Trashed::new(persister, task.clone())),
+        );
+    }
+
+    /// Removes the tasks dropped more than `days` days ago. Returns how many
+    /// tasks were removed.
+    #[inline]
+    pub fn expire(&mut self, days: u32) -> usize {
+        let limit = Utc::now() - Duration::days(days.into());
+        let len = self.items.len();
+
+        self.items.retain(|item| item.dropped_at >= limit);
+
+        len - self.items.len()
+    }
+
+    /// Returns the tasks dropped from a persister, from the oldest to the newest.
+    #[inline]
+    pub fn of(&self, persister: &str) -> Vec<&Trashed> {
+        self.items
+            .iter()
+            .filter(|item| item.persister == persister)
+            .collect()
+    }
+
+    /// Removes and returns the last tasks dropped from a persister with the
+    /// passed ids, in the same order as the ids. Ids that aren't in the trash
+    /// are skipped.
+    #[inline]
+    pub fn take(&mut self, persister: &str, ids: &[u32]) -> Vec<Task> {
+        ids.iter()
+            .filter_map(|id| {
+                let index = self
+                    .items
+                    .iter()
+                    .rposition(|item| item.persister == persister && item.task.id == *id)?;
+                Some(self.items.remove(index).task)
+            })
+            .collect()
+    }
+
+    /// Removes the tasks dropped from a persister, or from every persister
+    /// if it is `None`. Returns how many tasks were removed.
+    #[inline]
+    pub fn empty(&mut self, persister: Option<&str>) -> usize {
+        let len = self.items.len();
+
+        self.items
+            .retain(|item| persister.is_some_and(|persister| item.persister != persister));
+
+        len - self.items.len()
+    }
+}
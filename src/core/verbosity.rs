@@ -0,0 +1,99 @@
+//! Contains the `Verbosity` enum, which defines which messages are printed,
+//! and the macros used to print them instead of `println!` and `eprintln!`.
+//!
+//! Only the messages that report what a command did are affected. The output
+//! requested by a command (e.g.: the tasks of 'view') and the errors it
+//! returns are always printed.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Verbosity used by the macros, stored as the value of [`Verbosity::level`].
+static LEVEL: AtomicU8 = AtomicU8::new(1);
+
+/// Amount of messages printed while running commands.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only prints the output of commands (set by '--quiet').
+    Quiet,
+    /// Also prints messages about what commands did and why they did nothing.
+    #[default]
+    Normal,
+    /// Also prints details about how commands run (set by '--verbose').
+    Verbose,
+}
+
+impl Verbosity {
+    /// Returns the verbosity selected by the '--quiet' and '--verbose' flags.
+    #[inline]
+    pub const fn from_flags(quiet: bool, verbose: bool) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Self::Quiet,
+            (false, true) => Self::Verbose,
+            (false, false) => Self::Normal,
+        }
+    }
+
+    /// Returns the verbosity used to print messages.
+    #[inline]
+    pub fn current() -> Self {
+        match LEVEL.load(Ordering::Relaxed) {
+            0 => Self::Quiet,
+            1 => Self::Normal,
+            _ => Self::Verbose,
+        }
+    }
+
+    /// Sets the verbosity used to print messages for the rest of the process.
+    #[inline]
+    pub fn set(self) {
+        LEVEL.store(self.level(), Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the messages of this verbosity are printed.
+    #[inline]
+    pub fn allows(self) -> bool {
+        Self::current() >= self
+    }
+
+    /// Returns the number that represents the verbosity.
+    #[inline]
+    pub const fn level(self) -> u8 {
+        match self {
+            Self::Quiet => 0,
+            Self::Normal => 1,
+            Self::Verbose => 2,
+        }
+    }
+}
+
+/// Prints a message about what a command did to `stdout`, unless the
+/// verbosity is [`Verbosity::Quiet`].
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::Normal.allows() {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Prints a message about why a command did nothing or failed partially to
+/// `stderr`, unless the verbosity is [`Verbosity::Quiet`].
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::Normal.allows() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints details about how a command runs to `stderr`, only if the
+/// verbosity is [`Verbosity::Verbose`].
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::Verbosity::Verbose.allows() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use {debug, info, warning};
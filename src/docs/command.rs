@@ -3,8 +3,12 @@
 
 #![allow(clippy::single_call_fn)]
 
+use chrono::{Datelike as _, Duration, Utc};
+
+use super::Page;
 use crate::cli::subcommands as sub;
-use crate::models::{Priority, Task, Todo};
+use crate::models::{Calendar, GroupBy, Links, Priority, Stats, Task, Todo, Urgency};
+use crate::{tr, Action, Output, Report, Trash};
 
 /// Contains use cases for every command.
 #[non_exhaustive]
@@ -16,38 +20,87 @@ impl Command {
     pub fn run(cmnd: &sub::Docs) {
         match *cmnd {
             sub::Docs::Config => Self::config(),
+            sub::Docs::List => Self::list(),
             sub::Docs::View => Self::view(),
+            sub::Docs::Search => Self::search(),
+            sub::Docs::Stats => Self::stats(),
+            sub::Docs::Report => Self::report(),
+            sub::Docs::Next => Self::next(),
+            sub::Docs::Agenda => Self::agenda(),
+            sub::Docs::Cal => Self::cal(),
+            sub::Docs::Show => Self::show(),
             sub::Docs::Add => Self::add(),
+            sub::Docs::Dup => Self::dup(),
             sub::Docs::Set => Self::set(),
+            sub::Docs::Note => Self::note(),
+            sub::Docs::Pomo => Self::pomo(),
+            sub::Docs::Snooze => Self::snooze(),
+            sub::Docs::Edit => Self::edit(),
+            sub::Docs::Move => Self::move_task(),
+            sub::Docs::Attach => Self::attach(),
+            sub::Docs::Open => Self::open(),
             sub::Docs::Check => Self::check(),
             sub::Docs::Uncheck => Self::uncheck(),
+            sub::Docs::Pin => Self::pin(),
+            sub::Docs::Unpin => Self::unpin(),
             sub::Docs::Drop => Self::drop(),
+            sub::Docs::Pick => Self::pick(),
             sub::Docs::Sample => Self::sample(),
+            sub::Docs::Tidy => Self::tidy(),
+            sub::Docs::Undo => Self::undo(),
+            sub::Docs::Redo => Self::redo(),
+            sub::Docs::Import => Self::import(),
+            sub::Docs::Export => Self::export(),
+            sub::Docs::Dump => Self::dump(),
+            sub::Docs::Restore => Self::restore(),
             sub::Docs::Copy => Self::copy(),
+            sub::Docs::Trash => Self::trash(),
+            sub::Docs::Purge => Self::purge(),
             sub::Docs::Clean => Self::clean(),
             sub::Docs::Remove => Self::remove(),
+            sub::Docs::Errors => Self::errors(),
         }
     }
 
-    /// Use case of the 'sample' command.
+    /// Prints the "Before:" and "After:" states of the sample tasks, where
+    /// `action` modifies the tasks.
     ///
     /// # Panics
     /// If there is an unexpected error while displaying the example.
-    #[inline]
-    pub fn sample() {
-        println!(
-            "
-Usage: postit sample [--persister|-p]
-Alias: postit sa ...
+    fn before_after<F: FnOnce(&mut Todo)>(mut todo: Todo, action: F) {
+        println!("{}", tr!("Before:"));
 
-Description:
-    Populates a persister with fake data so you can test other commands.
+        todo.view().unwrap();
 
-How to use:
-    postit sample -p tasks.csv
+        println!();
+        println!("{}", tr!("After:"));
 
-Sample:"
-        );
+        action(&mut todo);
+        todo.view().unwrap();
+    }
+
+    /// Use case of the 'sample' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn sample() {
+        Page::new("postit sample [--persister|-p] [--count|-c] [--seed|-s]")
+            .alias("postit sa ...")
+            .section("Description")
+            .text("Populates a persister with fake data so you can test other commands.")
+            .text(
+                "By default, it writes the four tasks shown below. If '--count' or '--seed' \
+                 are passed, it generates that many tasks (4 by default) with varied contents, \
+                 priorities and states. The seed is printed so the same tasks can be generated \
+                 again; if it isn't passed, a random one is used.",
+            )
+            .section("How to use")
+            .code("postit sample -p tasks.csv")
+            .code("postit sample -p tasks.json --count 1000")
+            .code("postit sample -p tasks.json --count 50 --seed 42")
+            .section("Sample")
+            .print();
 
         Todo::sample().view().unwrap();
     }
@@ -58,171 +111,852 @@ Sample:"
     /// If there is an unexpected error while displaying the example.
     #[inline]
     pub fn view() {
-        println!(
-            "
-Usage: postit view [--persister|-p]
-Alias: postit v ...
+        let page = Page::new(
+            "postit view [--persister|-p] [--output|-o] [--show-checked] [--show-snoozed] \
+             [--min-priority] [--tag|-t] [--created-since] [--completed-since] [--priority] \
+             [--checked] [--unchecked] [--contains] [--sort] [--reverse] [--limit] [--table] \
+             [--columns] [--group-by] [--format] [--no-pager]",
+        )
+        .alias("postit v ...")
+        .section("Description")
+        .text("Shows the list of tasks stored in a persister.")
+        .text("The '--output' flag changes how tasks are displayed:")
+        .item("plain: colored tasks, one per line (default).")
+        .item("markdown: a checklist that can be pasted into issues or pull requests.")
+        .item(
+            "tsv: raw tab-separated fields (id, content, priority and checked), \
+                 without colors, to pipe into tools like awk, cut or fzf.",
+        )
+        .item(
+            "csv: raw comma-separated fields, like the lines of a CSV persister, \
+                 including the 'created_at', 'updated_at' and 'completed_at' timestamps.",
+        )
+        .item(
+            "json: an array with every field of the tasks, to read from scripts or \
+                 tools like jq. If no task matches the filters, the array is empty.",
+        );
 
-Description:
-    Shows the list of tasks stored in a persister.
+        Self::view_layouts(page)
+            .text(
+                "References of tasks are displayed after their content, linked with the \
+                 templates of the 'links' table of the config file (see 'postit docs open'). \
+                 Estimates are displayed after the tags (e.g.: '~1h30m').",
+            )
+            .text(
+                "If 'hide_checked' is 'true' in the config file, checked tasks are not \
+                 displayed unless the '--show-checked' flag is passed. The '--hide-done' \
+                 flag is the opposite of '--show-checked': it hides them for a single call, \
+                 as if 'hide_checked' was 'true'.",
+            )
+            .text(
+                "Tasks snoozed with 'postit snooze' are not displayed until their snooze \
+                 ends, unless the '--show-snoozed' flag is passed.",
+            )
+            .text(
+                "The '--min-priority' flag hides the tasks with a lower priority than the \
+                 one passed (e.g.: 'med' shows 'high' and 'med' tasks). Its default value \
+                 is the 'min_priority' of the config file.",
+            )
+            .text(
+                "The '--tag' flag only shows the tasks that have that tag. If it is passed \
+                 several times, tasks must have all of them.",
+            )
+            .text("These flags narrow the list to the tasks that match all of them:")
+            .entry("--priority", "Only shows the tasks with that priority.")
+            .entry("--checked", "Only shows checked tasks, even if 'hide_checked' is 'true'.")
+            .entry("--unchecked", "Only shows unchecked tasks.")
+            .entry(
+                "--contains",
+                "Only shows the tasks whose content contains a text, ignoring case.",
+            )
+            .text(
+                "The '--sort' flag changes the order of the tasks without changing their \
+                 ids, and '--reverse' inverts it (tasks without the date used to sort are \
+                 still shown last). Tasks can be sorted by these fields:",
+            )
+            .entry("id", "The order in which they are stored.")
+            .entry("priority", "From the highest priority to the lowest.")
+            .entry("content", "Alphabetically, ignoring case.")
+            .entry("checked", "Unchecked tasks first.")
+            .entry("created", "From the oldest to the newest.")
+            .entry("updated", "From the least to the most recently updated.")
+            .entry("completed", "From the first to the last completed.")
+            .entry("due", "From the earliest to the latest due date.")
+            .text(
+                "The '--limit' flag only shows the first tasks that are displayed, after \
+                 filtering and sorting them (e.g.: '--limit 10').",
+            )
+            .text(
+                "Tasks record when they were created, last updated and completed. The \
+                 '--created-since' and '--completed-since' flags only show the tasks \
+                 created or completed since a date, passed as 'YYYY-MM-DD' (the start of \
+                 that day in the local timezone) or as an RFC 3339 timestamp.",
+            )
+            .section("How to use")
+            .code("postit view -p tasks.csv")
+            .code("postit view -o markdown")
+            .code("postit view -o tsv | cut -f2")
+            .code("postit view -o json | jq '.[].content'")
+            .code("postit view --table --columns id,status,content,created")
+            .code("postit view --group-by tag")
+            .code("postit view --format '{id} [{priority}] {content}'")
+            .code("postit view --min-priority med")
+            .code("postit view --tag work")
+            .code("postit view --unchecked --contains deploy")
+            .code("postit view --sort created --reverse")
+            .code("postit view --sort priority --limit 5")
+            .code("postit view --completed-since 2025-01-01")
+            .print();
 
-How to use:
-    postit view -p tasks.csv
-"
-        );
+        println!();
 
         Todo::sample().view().unwrap();
+
+        println!();
+
+        Todo::sample().view_as(&Output::Markdown).unwrap();
     }
 
-    /// Use case of the 'add' command.
+    /// Adds the flags that change the layout of the tasks to the description
+    /// of the 'view' command.
+    fn view_layouts(page: Page) -> Page {
+        page.text(
+            "The '--table' flag displays the tasks in aligned columns instead, which are \
+             the 'columns' of the config file unless the '--columns' flag selects \
+             them (e.g.: '--columns id,content,tags,created'). The available columns \
+             are 'id', 'priority', 'status', 'content', 'tags', 'refs', 'created', \
+             'updated', 'completed', 'estimate' and 'due'.",
+        )
+        .text(
+            "The '--group-by' flag displays the tasks under a header for each group, \
+             followed by its number of tasks. Tasks can be grouped by 'priority', \
+             'tag' (tasks with several tags are shown in each of their groups and \
+             tasks without tags in an 'untagged' group) or 'status' ('todo' or 'done').",
+        )
+        .text(
+            "The '--format' flag changes how each task is displayed in the plain output \
+             (it overrides the 'format' of the config file). Its placeholders are the \
+             names of the columns between braces (e.g.: '{id} [{priority}] {content}').",
+        )
+        .text(
+            "If the tasks don't fit in the terminal, they are displayed through the \
+             pager of the 'PAGER' env var ('less -R' by default), unless the \
+             '--no-pager' flag is passed or the output isn't a terminal.",
+        )
+    }
+
+    /// Use case of the 'search' command.
+    #[inline]
+    pub fn search() {
+        Page::new(
+            "postit search <PATTERN> [--persister|-p] [--regex|-r] [--ignore-case|-i] \
+             [--checked] [--unchecked] [--priority] [--output|-o] [--limit]",
+        )
+        .alias("postit se ...")
+        .section("Description")
+        .text(
+            "Shows the tasks whose content contains the pattern. Works with every \
+                 persister and displays the tasks like the 'view' command, so it accepts \
+                 the same '--output' values.",
+        )
+        .text("These flags change how tasks are matched:")
+        .entry("--regex", "The pattern is a regular expression instead of a plain text.")
+        .entry("--ignore-case", "Uppercase and lowercase letters are considered equal.")
+        .entry("--checked", "Only shows checked tasks.")
+        .entry("--unchecked", "Only shows unchecked tasks.")
+        .entry("--priority", "Only shows the tasks with that priority.")
+        .text(
+            "The '--limit' flag stops the search after finding that number of tasks. CSV \
+             files are read line by line, so searching a large file with it doesn't load \
+             every task.",
+        )
+        .section("How to use")
+        .code("postit search deploy -i")
+        .code("postit search '^Fix (bug|typo)' --regex --unchecked")
+        .code("postit search release --priority high -o markdown")
+        .code("postit search bug --limit 1")
+        .print();
+    }
+
+    /// Use case of the 'stats' command.
+    #[inline]
+    pub fn stats() {
+        let todo = Todo::sample();
+
+        Page::new("postit stats [--persister|-p] [--by] [--output|-o]")
+            .alias("postit st ...")
+            .section("Description")
+            .text("Shows how many tasks there are and how many of them are checked.")
+            .text("The '--by' flag groups the counts by a criteria, rendered as a table:")
+            .item("priority: one row per priority (high, med, low and none).")
+            .item("tag: one row per tag, plus an 'untagged' row for tasks without tags.")
+            .item("status: one row for unchecked ('todo') and one for checked ('done') tasks.")
+            .text(
+                "The '--output json' flag prints the counts as a JSON object with the \
+                 'total', 'checked', 'unchecked' and 'percentage' fields. If '--by' is \
+                 passed, the rows are added to its 'groups' array.",
+            )
+            .text(
+                "If tasks have estimates, the sum of them is shown too, along with the \
+                 part that belongs to unchecked tasks (the 'estimate' and 'remaining' \
+                 fields of the JSON, in minutes).",
+            )
+            .section("How to use")
+            .code("postit stats -p tasks.csv")
+            .code("postit stats --by priority")
+            .code("postit stats -o json | jq .percentage")
+            .section("Sample")
+            .code(todo.stats().to_string())
+            .code(Stats::table("priority", &todo.stats_by(&GroupBy::Priority)))
+            .print();
+    }
+
+    /// Use case of the 'report' command.
     ///
     /// # Panics
     /// If there is an unexpected error while displaying the example.
     #[inline]
-    pub fn add() {
-        let line = "5,New task,low,false";
-        let task = Task::from(line);
+    pub fn report() {
+        let mut todo = Todo::sample();
 
-        println!(
-            "
-Usage: postit add <PRIORITY> <CONTENT> [--persister|-p]
-Alias: postit a ...
+        let mut trash = Trash::default();
+        trash.add("tasks.csv", &todo.tasks.split_off(3));
 
-Description:
-    Creates a task with the format 'id,content,priority,checked': 
-    - id: a unique unsigned integer.
-    - content: description of the task.
-    - priority: high, med, low or none.
-    - checked: true or false.
+        let mut task = Task::from("5,Task,med,false");
+        task.created_at = Some(Utc::now());
+        todo.add(task);
 
-    To add a task, just provide the priority and the content of the task.
+        todo.check(&[1]).unwrap();
+        todo.stamp(&[1], &Action::Check);
+
+        let since = Utc::now() - Duration::days(7);
+
+        Page::new("postit report [--persister|-p] [--since|-s] [--output|-o]")
+            .alias("postit rp ...")
+            .section("Description")
+            .text(
+                "Summarizes the tasks that were added, completed and dropped in a persister \
+                 over a period. It is useful for weekly reviews.",
+            )
+            .text(
+                "The '--since' flag sets the start of the period, which is the last 7 days \
+                 by default. It can be an amount of minutes (m), hours (h), days (d) or \
+                 weeks (w) before now, or a date passed as 'YYYY-MM-DD' or as an RFC 3339 \
+                 timestamp.",
+            )
+            .text(
+                "Added and completed tasks are found by their 'created_at' and \
+                 'completed_at' timestamps, so tasks added before they had them aren't \
+                 reported. Dropped tasks are taken from the trash (see 'postit docs trash'), \
+                 so they are reported while they are kept in it.",
+            )
+            .text(
+                "The '--output json' flag prints the report as a JSON object with the \
+                 'since' timestamp and the 'added', 'completed' and 'dropped' tasks.",
+            )
+            .section("How to use")
+            .code("postit report")
+            .code("postit report --since 2w")
+            .code("postit report --since 2025-01-01 -o json | jq '.completed | length'")
+            .section("Sample")
+            .code(Report::new(&todo.tasks, &trash, "tasks.csv", since).to_string())
+            .print();
+    }
 
-How to use:
-    postit add low \"New task\" -p tasks.csv
+    /// Use case of the 'next' command.
+    #[inline]
+    pub fn next() {
+        let sample = Todo::sample()
+            .next(&Urgency::default(), 3)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Page::new("postit next [COUNT] [--persister|-p] [--output|-o]")
+            .alias("postit n ...")
+            .section("Description")
+            .text("Shows the most urgent unchecked tasks (one by default).")
+            .text(
+                "Tasks are scored using the 'urgency' table of the config file, which \
+                 assigns points to each priority, to tasks that are due (a part of them if \
+                 they are due in the next 14 days) and to tasks created a year ago (a part of \
+                 them if they are newer):",
+            )
+            .code(
+                "    [urgency]\n    high = 6\n    med = 4\n    low = 2\n    none = 0\n    \
+                 due = 12\n    age = 2",
+            )
+            .text("Tasks with the same score are shown in the order of the list.")
+            .text("The '--output' flag accepts the same values as the 'view' command.")
+            .section("How to use")
+            .code("postit next")
+            .code("postit next 3 -p tasks.json")
+            .code("postit next -o json")
+            .section("Sample")
+            .code(sample)
+            .print();
+    }
 
-    The new task will be displayed like this: {task}
-"
-        );
+    /// Use case of the 'agenda' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn agenda() {
+        let mut todo = Todo::sample();
+        let now = Utc::now();
+
+        todo.set_due(&[1], now.checked_sub_signed(Duration::days(1)))
+            .unwrap();
+        todo.set_due(&[2], Some(now)).unwrap();
+        todo.set_due(&[3], now.checked_add_signed(Duration::days(4)))
+            .unwrap();
+
+        let sample = todo
+            .agenda(false)
+            .iter()
+            .map(|(label, group)| {
+                let tasks = group
+                    .render_with(&Output::Plain, &Links::default())
+                    .unwrap();
+                format!("{label} ({})\n{tasks}", group.tasks.len())
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        Page::new("postit agenda [--week|-w] [--persister|-p]")
+            .alias("postit ag ...")
+            .section("Description")
+            .text(
+                "Shows the unchecked tasks grouped by their due date (see 'postit docs \
+                 set'), sorted from the closest date to the furthest one:",
+            )
+            .entry("overdue", "Tasks whose due date has passed.")
+            .entry("today", "Tasks due today.")
+            .entry("tomorrow", "Tasks due tomorrow.")
+            .entry("this week", "Tasks due in the next 7 days.")
+            .entry("later", "Tasks due after the next 7 days.")
+            .entry("no date", "Tasks without a due date.")
+            .text(
+                "Groups without tasks are not displayed, and neither are snoozed tasks. \
+                 The '--week' flag only shows the tasks due in the next 7 days.",
+            )
+            .section("How to use")
+            .code("postit agenda")
+            .code("postit agenda --week -p tasks.json")
+            .section("Sample")
+            .code(sample)
+            .print();
+    }
 
+    /// Use case of the 'cal' command.
+    #[inline]
+    pub fn cal() {
         let mut todo = Todo::sample();
+        let month = Calendar::current_month();
 
-        println!("Before:");
+        for (task, day) in todo.tasks.iter_mut().zip([3, 3, 10, 20]) {
+            task.due = Task::parse_date(&month.with_day(day).unwrap_or(month).to_string()).ok();
+            task.checked = false;
+        }
 
-        todo.view().unwrap();
+        Page::new("postit cal [MONTH] [--persister|-p]")
+            .alias("postit ca ...")
+            .section("Description")
+            .text(
+                "Shows a month calendar, like the 'cal' program, where every day with \
+                 unchecked tasks due on it (see 'postit docs set') is followed by their \
+                 number (e.g.: '14*2'). Weeks start on Monday.",
+            )
+            .text(
+                "The month is passed as YYYY-MM and is the current one by default. The \
+                 number of tasks due in the month is displayed below the calendar.",
+            )
+            .section("How to use")
+            .code("postit cal")
+            .code("postit cal 2025-01 -p tasks.json")
+            .section("Sample")
+            .code(Calendar::new(month, &todo.tasks).to_string())
+            .print();
+    }
+
+    /// Use case of the 'show' command.
+    #[inline]
+    pub fn show() {
+        Page::new("postit show <ID> [--persister|-p]")
+            .alias("postit sh ...")
+            .section("Description")
+            .text(
+                "Shows a card with every field of a task, since 'view' only displays one \
+                 line per task. Fields without a value are omitted.",
+            )
+            .text(
+                "The card ends with the notes of the task and its history: the commands \
+                 that changed it, taken from the changes that can be undone (see 'postit \
+                 docs undo').",
+            )
+            .section("How to use")
+            .code("postit show 2")
+            .code("postit show 3 -p tasks.json")
+            .section("Sample")
+            .code(
+                "Task 2\n  \
+                 Content:   Review the release notes\n  \
+                 Priority:  med\n  \
+                 Status:    done\n  \
+                 Tags:      work\n  \
+                 Created:   2025-01-31 18:30\n  \
+                 Updated:   2025-02-01 09:15\n  \
+                 Completed: 2025-02-01 09:15\n\n\
+                 Notes:\n  \
+                 Check the changelog first\n\n\
+                 History:\n  \
+                 2025-01-31 18:30  add\n  \
+                 2025-02-01 09:15  check",
+            )
+            .print();
+    }
+
+    /// Use case of the 'add' command.
+    #[inline]
+    pub fn add() {
+        let line = "5,New task,low,false";
+        let task = Task::from(line);
+
+        Page::new("postit add [PRIORITY] [CONTENT] [--persister|-p] [--tag|-t] [--clipboard]")
+            .alias("postit a ...")
+            .section("Description")
+            .text("Creates a task with the format 'id,content,priority,checked':")
+            .item("id: a unique unsigned integer.")
+            .item("content: description of the task.")
+            .item("priority: high, med, low or none.")
+            .item("checked: true or false.")
+            .text(
+                "To add a task, just provide the priority and the content of the task. The \
+                 priority can be omitted to use the 'default_priority' of the config file \
+                 ('med' by default).",
+            )
+            .text(
+                "Tasks can also have tags, which are used to filter them with 'postit view \
+                 --tag'. Each '--tag' flag adds one tag.",
+            )
+            .text(
+                "If the content is missing, the values of the task are asked one at a time, \
+                 skipping the ones that were passed. Invalid answers are asked again, an \
+                 empty priority means the 'default_priority' of the config file and an empty \
+                 due date means that the task isn't due (see 'postit set due').",
+            )
+            .text(
+                "If the content is '-', it is read from the standard input, so the output of \
+                 other commands can be added as a task. Its lines are joined with spaces.",
+            )
+            .text(
+                "With '--clipboard', a task is added per line of the text in the clipboard, \
+                 skipping the blank ones. Only the priority can be passed, and it is used for \
+                 every task. It needs postit to be installed with the 'clipboard' feature.",
+            )
+            .section("How to use")
+            .code("postit add low \"New task\" -p tasks.csv")
+            .code("postit add high \"Deploy the release\" --tag work --tag ops")
+            .code("postit add \"Water the plants\"")
+            .code("git log -1 --format=%s | postit add med -")
+            .code("postit add low --clipboard")
+            .code("postit add")
+            .code(format!("The new task will be displayed like this: {task}"))
+            .print();
 
         println!();
-        println!("After:");
 
-        todo.add(task);
-        todo.view().unwrap();
+        Self::before_after(Todo::sample(), |todo| todo.add(task));
+    }
+
+    /// Use case of the 'dup' command.
+    #[inline]
+    pub fn dup() {
+        Page::new("postit dup <IDS> [--priority] [--content] [--persister|-p]")
+            .alias("postit dp ...")
+            .section("Description")
+            .text(
+                "Adds copies of tasks at the end of the list, with new ids. Useful for chores \
+                 that are done again and again, since a checked task can be copied instead \
+                 of being written from scratch.",
+            )
+            .text(
+                "The copies keep the content, priority, tags, references, notes and estimate \
+                 of the originals, but they are unchecked, have a new creation date and \
+                 aren't snoozed. The '--priority' and '--content' flags change those values \
+                 in every copy.",
+            )
+            .section("How to use")
+            .code("postit dup 3")
+            .code("postit dup 1,2 --priority low -p tasks.csv")
+            .code("postit dup 4 --content \"Water the plants again\"")
+            .print();
+
+        println!();
+
+        Self::before_after(Todo::sample(), |todo| {
+            todo.add(Task::from("5,Task,low,false"));
+        });
     }
 
     /// Use case of the 'set' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
     #[inline]
     pub fn set() {
-        fn set_content() {
-            let mut todo = Todo::sample();
-            let new_content = "New content";
-            let line = format!("2,{new_content},med,false");
-            let task = Task::from(line);
-
-            println!(
-                "
-How to use (content):
-    postit set content \"{}\" 2
-
-    Old task: {}
-    New task: {}
-",
-                new_content, todo.tasks[1], task
-            );
+        let todo = Todo::sample();
+        let new_content = "New content";
+        let new_priority = Priority::Low;
+
+        Page::new("postit set <COMMAND> [--persister|-p]")
+            .alias("postit s ...")
+            .section("Description")
+            .text("Changes the value of task's properties.")
+            .text("These are the available subcommands:")
+            .item("content: postit set content <CONTENT> [IDS]...")
+            .item("priority: postit set priority <PRIORITY> [IDS]...")
+            .item("ref: postit set ref <IDS> [REFS]...")
+            .item("tags: postit set tags <IDS> [TAGS]...")
+            .item("estimate: postit set estimate <IDS> <ESTIMATE>")
+            .item("due: postit set due <IDS> [--on|-o <TIME>] [--clear]")
+            .section("How to use (content)")
+            .code(format!("postit set content \"{new_content}\" 2"))
+            .code(format!(
+                "Old task: {}\nNew task: {}",
+                todo.tasks[1],
+                Task::from(format!("2,{new_content},med,false"))
+            ))
+            .print();
 
-            println!("Before:");
+        println!();
 
-            todo.view().unwrap();
+        Self::before_after(todo.clone(), |todo| todo.set_content(&[2], new_content).unwrap());
 
-            println!();
-            println!("After:");
+        Page::default()
+            .section("How to use (priority)")
+            .code("postit set priority low 2")
+            .code(format!(
+                "Old task: {}\nNew task: {}",
+                todo.tasks[1],
+                Task::from(format!("2,Task,{new_priority},false"))
+            ))
+            .print();
 
-            todo.set_content(&[2], new_content).unwrap();
-            todo.view().unwrap();
-        }
+        println!();
 
-        fn set_priority() {
-            let mut todo = Todo::sample();
-            let new_priority = Priority::Low;
-            let line = format!("2,Task,{new_priority},false");
-            let task = Task::from(line);
-
-            println!(
-                "
-How to use (priority):
-    postit set priority low 3
-
-    Old task: {}
-    New task: {}
-",
-                todo.tasks[1], task
-            );
+        Self::before_after(todo.clone(), |todo| {
+            todo.set_priority(&[2], &new_priority).unwrap();
+        });
 
-            println!("Before:");
+        let refs = vec![String::from("JIRA-123"), String::from("gh#42")];
 
-            todo.view().unwrap();
+        Page::default()
+            .section("How to use (ref)")
+            .text(
+                "Replaces the external references of tasks (e.g.: issue identifiers). \
+                 Passing no references removes them. See 'postit docs open' to turn \
+                 them into links.",
+            )
+            .code("postit set ref 2 JIRA-123 gh#42")
+            .code("postit set ref 2")
+            .print();
 
-            println!();
-            println!("After:");
+        println!();
 
-            todo.set_priority(&[2], &new_priority).unwrap();
-            todo.view().unwrap();
-        }
+        Self::before_after(todo.clone(), |todo| todo.set_refs(&[2], &refs).unwrap());
 
-        println!(
-            "
-Usage: postit set <COMMAND> [--persister|-p]
-Alias: postit s ...
-
-Description:
-    Changes the value of task's properties.
-    
-    These are the available subcommands:
-    - content: postit set content <CONTENT> [IDS]...
-    - priority: postit set priority <PRIORITY> [IDS]..."
-        );
+        let tags = vec![String::from("work"), String::from("ops")];
+
+        Page::default()
+            .section("How to use (tags)")
+            .text("Replaces the tags of tasks. Passing no tags removes them.")
+            .code("postit set tags 2,3 work ops")
+            .code("postit set tags 2")
+            .print();
 
-        set_content();
-        set_priority();
+        println!();
+
+        Self::before_after(todo.clone(), |todo| todo.set_tags(&[2, 3], &tags).unwrap());
+
+        Page::default()
+            .section("How to use (estimate)")
+            .text(
+                "Sets how long tasks are expected to take (e.g.: '30m', '2h' or '1h30m'). \
+                 Passing 'none' removes it.",
+            )
+            .code("postit set estimate 2,3 1h30m")
+            .code("postit set estimate 2 none")
+            .print();
+
+        println!();
+
+        Self::before_after(todo.clone(), |todo| todo.set_estimate(&[2, 3], 90).unwrap());
+
+        println!();
+
+        Self::set_due(todo);
     }
 
-    /// Use case of the 'check' command.
+    /// Use case of the 'set due' subcommand, shown after the other ones of 'set'.
+    fn set_due(todo: Todo) {
+        Page::default()
+            .section("How to use (due)")
+            .text(
+                "Sets when tasks should be done with '--on', as an amount of time after \
+                 now (e.g.: '3d' or '1w') or a date (YYYY-MM-DD). The '--clear' flag \
+                 removes it.",
+            )
+            .text(
+                "The 'view' command shows how many days are left next to unchecked tasks \
+                 (e.g.: '(due in 3d)', '(due today)' or '(overdue 2d)'), and displays \
+                 overdue tasks with the 'overdue' style of the 'colors' config, whatever \
+                 their priority is.",
+            )
+            .code("postit set due 2 --on 3d")
+            .code("postit set due 3 --on 2025-01-31")
+            .code("postit set due 2,3 --clear")
+            .print();
+
+        println!();
+
+        Self::before_after(todo, |todo| {
+            let due = Utc::now().checked_add_signed(Duration::days(3));
+            todo.set_due(&[2], due).unwrap();
+        });
+    }
+
+    /// Use case of the 'note' command.
+    #[inline]
+    pub fn note() {
+        Page::new("postit note <ID> [TEXT] [--edit|-e] [--clear] [--persister|-p]")
+            .alias("postit nt ...")
+            .section("Description")
+            .text("Manages the notes of a task, a long-form text that can have several lines.")
+            .item("Without flags or text, it shows the notes.")
+            .item("With a text, it appends it as a new line of the notes.")
+            .item(
+                "With '--edit', it opens the notes in the editor of the VISUAL or EDITOR env \
+                 vars ('vi' if none is present) and saves them when the editor closes.",
+            )
+            .item("With '--clear', it removes the notes.")
+            .text(
+                "In CSV files, notes are stored in the last column, where '%', commas, tabs \
+                 and line breaks are percent-encoded (e.g.: a line break is stored as '%0A').",
+            )
+            .section("How to use")
+            .code("postit note 2 \"Ask for the staging credentials\"")
+            .code("postit note 2")
+            .code("postit note 2 --edit")
+            .code("postit note 2 --clear")
+            .print();
+    }
+
+    /// Use case of the 'pomo' command.
+    #[inline]
+    pub fn pomo() {
+        Page::new("postit pomo <ID> [--minutes|-m] [--persister|-p]")
+            .alias("postit po ...")
+            .section("Description")
+            .text(
+                "Runs a pomodoro for a task: it waits for the minutes passed with '--minutes' \
+                 (25 by default), showing the time left if the output is a terminal.",
+            )
+            .text(
+                "When it finishes, it rings the bell of the terminal, appends the interval to \
+                 the notes of the task (e.g.: 'Pomodoro: 25m (2025-01-31 10:00 - 10:25)') and \
+                 runs the 'on_pomo' hook, which can be used to show a notification.",
+            )
+            .text(
+                "The tasks aren't locked during the pomodoro, so other commands can be used \
+                 in the meantime. Pressing Ctrl+C stops it without logging anything.",
+            )
+            .section("How to use")
+            .code("postit pomo 2")
+            .code("postit pomo 2 --minutes 50")
+            .print();
+    }
+
+    /// Use case of the 'snooze' command.
     ///
     /// # Panics
     /// If there is an unexpected error while displaying the example.
     #[inline]
-    pub fn check() {
-        println!(
-            "
-Usage: postit check <IDS> [--persister|-p]
-Alias: postit c ...
+    pub fn snooze() {
+        Page::new("postit snooze <IDS> [--until|-u] [--wake] [--persister|-p]")
+            .alias("postit sz ...")
+            .section("Description")
+            .text(
+                "Hides tasks from 'view' until a moment, to defer the ones that can't be \
+                 done yet. Snoozed tasks are shown again when the moment comes.",
+            )
+            .text(
+                "The '--until' flag can be an amount of minutes (m), hours (h), days (d) or \
+                 weeks (w) after now, or a date passed as 'YYYY-MM-DD' (the start of that \
+                 day in the local timezone) or as an RFC 3339 timestamp.",
+            )
+            .text("The '--wake' flag shows the tasks again before their snooze ends.")
+            .text(
+                "The '--show-snoozed' flag of 'view' displays the snoozed tasks, followed \
+                 by the moment when their snooze ends.",
+            )
+            .section("How to use")
+            .code("postit snooze 2,3 --until 2d")
+            .code("postit snooze 2 --until 2025-01-31")
+            .code("postit snooze 2 --wake")
+            .code("postit view --show-snoozed")
+            .print();
 
-Description:
-    Checks tasks if they are unchecked.
+        println!();
 
-How to use:
-    postit check 2,3 -p tasks.csv
-"
-        );
+        let until = Utc::now() + Duration::days(2);
+        Self::before_after(Todo::sample(), |todo| todo.snooze(&[2, 3], Some(until)).unwrap());
+    }
 
-        let mut todo = Todo::sample();
+    /// Use case of the 'edit' command.
+    #[inline]
+    pub fn edit() {
+        Page::new("postit edit <ID> [--persister|-p]")
+            .alias("postit e ...")
+            .section("Description")
+            .text(
+                "Opens a task as TOML in the editor of the 'VISUAL' or 'EDITOR' env vars \
+                 ('vi' if none is set). When the editor closes, the fields that changed are \
+                 saved, as if they were changed with 'set', 'check', 'note' or 'snooze'.",
+            )
+            .text(
+                "Fields without a value are omitted, but they can be added (e.g.: \
+                 'estimate = 90', in minutes). The id, the order and the timestamps are \
+                 managed by postit, so changes to them are ignored.",
+            )
+            .text("If the result isn't a valid task, nothing is changed.")
+            .section("How to use")
+            .code("postit edit 2")
+            .code("EDITOR=nano postit edit 3 -p tasks.json")
+            .section("Sample")
+            .code(
+                "id = 2\n\
+                 content = \"Review the release notes\"\n\
+                 priority = \"med\"\n\
+                 checked = false\n\
+                 tags = [\"work\"]\n\
+                 notes = \"Check the changelog first\"\n\
+                 created_at = \"2025-01-31T18:30:00Z\"",
+            )
+            .print();
+    }
 
-        println!("Before:");
+    /// Use case of the 'move' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn move_task() {
+        Page::new("postit move <ID> [--before|-b] [--after|-a] [--to|-t] [--persister|-p]")
+            .alias("postit mv ...")
+            .section("Description")
+            .text(
+                "Changes the position of a task in the list, right before ('--before') or \
+                 after ('--after') another task, or at a position starting at 1 ('--to'). \
+                 The ids of the tasks don't change.",
+            )
+            .text(
+                "The position of every task is saved in its 'order' field, so the order is \
+                 kept by every persister and by 'copy'. Tasks added later go to the end of \
+                 the list, and 'tidy' removes the manual order.",
+            )
+            .section("How to use")
+            .code("postit move 4 --before 1")
+            .code("postit move 1 --after 3")
+            .code("postit move 3 --to 1 -p tasks.db")
+            .print();
 
-        todo.view().unwrap();
+        println!();
+
+        Self::before_after(Todo::sample(), |todo| {
+            todo.move_next_to(4, 1, false).unwrap();
+        });
+    }
+
+    /// Use case of the 'open' command.
+    #[inline]
+    pub fn open() {
+        Page::new("postit open <ID> [--persister|-p]")
+            .alias("postit o ...")
+            .section("Description")
+            .text(
+                "Opens the first attachment of a task (see 'postit docs attach') with the \
+                 default program of the OS. If the task has no attachments, the URLs of its \
+                 references (see 'postit set ref') are opened, usually in the web browser.",
+            )
+            .text(
+                "URLs are built from the templates of the 'links' table of the config file, \
+                 indexed by the prefix of the references. A reference is split into its \
+                 prefix and its identifier at its first '-' or '#', and templates can use \
+                 these placeholders:",
+            )
+            .entry("{ref}", "The whole reference (e.g.: 'JIRA-123').")
+            .entry("{id}", "The identifier of the reference (e.g.: '123').")
+            .code(
+                "[links]\n\
+                 JIRA = \"https://jira.example.com/browse/{ref}\"\n\
+                 gh = \"https://github.com/owner/repo/issues/{id}\"",
+            )
+            .text(
+                "The 'view' command also uses these templates to display references as \
+                 hyperlinks in terminals that support them, and as links in the \
+                 'markdown' output.",
+            )
+            .section("How to use")
+            .code("postit set ref 2 JIRA-123 gh#42")
+            .code("postit open 2")
+            .print();
+    }
+
+    /// Use case of the 'attach' command.
+    #[inline]
+    pub fn attach() {
+        Page::new("postit attach <ID> <URIS> [--remove|-r] [--persister|-p]")
+            .alias("postit at ...")
+            .section("Description")
+            .text(
+                "Attaches file paths or URLs to a task, which are stored with it and shown \
+                 by 'show'. 'open' opens the first attachment of a task with the default \
+                 program of the OS.",
+            )
+            .text(
+                "Relative paths of existing files are stored as absolute paths, so they can \
+                 be opened from any directory. '--remove' removes the passed attachments \
+                 instead of adding them.",
+            )
+            .section("How to use")
+            .code("postit attach 2 ./design.pdf https://example.com/spec")
+            .code("postit open 2")
+            .code("postit attach 2 https://example.com/spec --remove")
+            .print();
+    }
+
+    /// Use case of the 'check' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn check() {
+        Page::new("postit check <IDS> [--persister|-p]")
+            .alias("postit c ...")
+            .section("Description")
+            .text("Checks tasks if they are unchecked.")
+            .section("How to use")
+            .code("postit check 2,3 -p tasks.csv")
+            .print();
 
         println!();
-        println!("After:");
 
-        todo.check(&[2, 3]).unwrap();
-        todo.view().unwrap();
+        Self::before_after(Todo::sample(), |todo| {
+            todo.check(&[2, 3]).unwrap();
+        });
     }
 
     /// Use case of the 'uncheck' command.
@@ -231,30 +965,68 @@ How to use:
     /// If there is an unexpected error while displaying the example.
     #[inline]
     pub fn uncheck() {
-        println!(
-            "
-Usage: postit uncheck <IDS> [--persister|-p]
-Alias: postit uc ...
+        Page::new("postit uncheck <IDS> [--persister|-p]")
+            .alias("postit uc ...")
+            .section("Description")
+            .text("Unchecks tasks if they are checked.")
+            .section("How to use")
+            .code("postit uncheck 2,3 -p tasks.csv")
+            .print();
 
-Description:
-    Unchecks tasks if they are checked.
+        println!();
 
-How to use:
-    postit uncheck 2,3 -p tasks.csv
-"
-        );
+        Self::before_after(Todo::sample(), |todo| {
+            todo.uncheck(&[2, 3]).unwrap();
+        });
+    }
 
-        let mut todo = Todo::sample();
+    /// Use case of the 'pin' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn pin() {
+        Page::new("postit pin <IDS> [--persister|-p]")
+            .alias("postit pn ...")
+            .section("Description")
+            .text(
+                "Pins tasks, so they are displayed at the top of the list. Pinned tasks stay \
+                 at the top even when 'view --sort' or 'move' change the order of the rest.",
+            )
+            .text("Use 'postit unpin' to put them back in their place.")
+            .section("How to use")
+            .code("postit pin 3 -p tasks.csv")
+            .print();
 
-        println!("Before:");
+        println!();
 
-        todo.view().unwrap();
+        Self::before_after(Todo::sample(), |todo| {
+            todo.pin(&[3]).unwrap();
+        });
+    }
+
+    /// Use case of the 'unpin' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn unpin() {
+        let mut todo = Todo::sample();
+        todo.pin(&[3]).unwrap();
+
+        Page::new("postit unpin <IDS> [--persister|-p]")
+            .alias("postit upn ...")
+            .section("Description")
+            .text("Unpins tasks if they are pinned, so they go back to their place in the list.")
+            .section("How to use")
+            .code("postit unpin 3 -p tasks.csv")
+            .print();
 
         println!();
-        println!("After:");
 
-        todo.uncheck(&[2, 3]).unwrap();
-        todo.view().unwrap();
+        Self::before_after(todo, |todo| {
+            todo.unpin(&[3]).unwrap();
+        });
     }
 
     /// Use case of the 'drop' command.
@@ -263,190 +1035,841 @@ How to use:
     /// If there is an unexpected error while displaying the example.
     #[inline]
     pub fn drop() {
-        fn force_drop() {
-            println!(
-                "
-Config:
-    You can set the 'force_drop' config to 'true' to drop tasks whether 
-    they are checked or not.
-"
-            );
+        Page::new("postit drop [IDS] [--checked] [--priority] [--older-than] [--persister|-p]")
+            .alias("postit d ...")
+            .section("Description")
+            .text("By default, only checked tasks can be dropped.")
+            .text(
+                "Instead of ids, filters can pick the tasks to drop, and only the tasks that \
+                 match every filter are dropped (if ids are passed too, only those tasks are \
+                 filtered):",
+            )
+            .item("--checked: the checked tasks.")
+            .item("--priority: the tasks with that priority.")
+            .item(
+                "--older-than: the tasks added before that moment, as an amount of time \
+                 before now (e.g.: '30d' or '2w') or a date.",
+            )
+            .text(
+                "Dropping more than one task asks for confirmation when run in a terminal \
+                 (see 'postit flag yes').",
+            )
+            .section("How to use")
+            .code("postit drop 2,3 -p tasks.csv")
+            .code("postit drop --checked")
+            .code("postit drop --priority low --older-than 30d")
+            .print();
 
-            let mut todo = Todo::sample();
-
-            println!("Before:");
+        println!();
 
-            todo.view().unwrap();
+        Self::before_after(Todo::sample(), |todo| {
+            todo.drop(&[2, 3]).unwrap();
+        });
+
+        Page::default()
+            .section("Config")
+            .text(
+                "You can set the 'force_drop' config to 'true' to drop tasks whether \
+                 they are checked or not.",
+            )
+            .text(
+                "Dropped tasks are kept in the trash for 'trash_days' days, so they can be \
+                 restored (see 'postit docs trash').",
+            )
+            .print();
 
-            println!();
-            println!("After:");
+        println!();
 
+        Self::before_after(Todo::sample(), |todo| {
             todo.check(&[2]).unwrap();
             todo.drop(&[2, 3]).unwrap();
-            todo.view().unwrap();
-        }
-
-        println!(
-            "
-Usage: postit drop <IDS> [--persister|-p]
-Alias: postit d ...
-
-Description:
-    By default, only checked tasks can be dropped.
-
-How to use:
-    postit drop 2,3 -p tasks.csv
-"
-        );
+        });
+    }
 
-        let mut todo = Todo::sample();
+    /// Use case of the 'pick' command.
+    #[inline]
+    pub fn pick() {
+        Page::new("postit pick <COMMAND> [--persister|-p]")
+            .alias("postit pk ...")
+            .section("Description")
+            .text(
+                "Opens an inline fuzzy finder over the current tasks and applies an action \
+                 to the picked ones, so you don't need to look up their ids first.",
+            )
+            .text(
+                "Type some characters to filter the tasks (they don't need to be contiguous, \
+                 'bmlk' matches 'Buy milk'), then pick them by their position in the list.",
+            )
+            .text("These are the available subcommands:")
+            .item("check: postit pick check")
+            .item("uncheck: postit pick uncheck")
+            .item("drop: postit pick drop")
+            .item("set content: postit pick set content <CONTENT>")
+            .item("set priority: postit pick set priority <PRIORITY>")
+            .section("How to use")
+            .code("postit pick check")
+            .code("[1] 1. Buy milk\n[2] 2. Write the report")
+            .code("Filter, pick by position (e.g.: 1,3) or quit with 'q': milk\n[1] 1. Buy milk")
+            .code("Filter, pick by position (e.g.: 1,3) or quit with 'q': 1")
+            .print();
+    }
 
-        println!("Before:");
+    /// Use case of the 'tidy' command.
+    ///
+    /// # Panics
+    /// If there is an unexpected error while displaying the example.
+    #[inline]
+    pub fn tidy() {
+        Page::new("postit tidy [--persister|-p]")
+            .alias("postit td ...")
+            .section("Description")
+            .text("Cleans up the list in a single write:")
+            .item("Unchecked tasks are sorted by priority (high, med, low and none).")
+            .item("Checked tasks are moved to the bottom of the list.")
+            .item("Ids are renumbered starting from 1.")
+            .text("Tasks with the same priority keep their relative order.")
+            .section("How to use")
+            .code("postit tidy")
+            .code("postit tidy -p tasks.json")
+            .section("Sample")
+            .print();
+
+        let mut todo = Todo::new(vec![
+            Task::from("1,Task,low,true"),
+            Task::from("2,Task,none,false"),
+            Task::from("4,Task,high,false"),
+            Task::from("7,Task,med,false"),
+        ]);
 
         todo.view().unwrap();
 
-        println!();
-        println!("After:");
+        println!("\nAfter running 'postit tidy':");
 
-        todo.drop(&[2, 3]).unwrap();
+        todo.tidy();
         todo.view().unwrap();
-
-        force_drop();
     }
 
-    /// Use case of the 'copy' command.
+    /// Use case of the 'list' command.
     #[inline]
-    pub fn copy() {
-        println!(
-            "
-Usage: postit copy <LEFT> <RIGHT>
-Alias: postit cp ...
+    pub fn list() {
+        Page::new("postit list <COMMAND>")
+            .alias("postit li ...")
+            .section("Description")
+            .text(
+                "Manages named task lists, where each list stores its tasks in its own \
+                 persister. Lists are defined in the '[lists]' table of the config file, \
+                 indexed by their name:",
+            )
+            .code("      [lists]\n      work = \"work.csv\"\n      home = \"tasks.db\"")
+            .text(
+                "Any command uses a list instead of the default persister with the global \
+                 '--list' flag. The name of a list can also be passed to '--persister', so \
+                 names can't contain '.', '/', '\\' or ':'.",
+            )
+            .section("Available subcommands")
+            .entry("create", "Adds a list to the config file ('<NAME>.csv' by default)")
+            .entry("show", "Shows the lists and their persisters (alias: ls)")
+            .entry("remove", "Removes a list from the config file, keeping its tasks (alias: rm)")
+            .section("How to use")
+            .code("postit list create <NAME> [--persister|-p]")
+            .code("postit list show")
+            .code("postit list remove <NAME>")
+            .section("Examples")
+            .code("postit list create work")
+            .code("postit --list work add high \"Review the pull request\"")
+            .code("postit view --list work")
+            .print();
+    }
 
-Description:
-    Copies a persister's contents into another, meaning you can use this
-    command to 'translate' tasks to a different format.
+    /// Use case of the 'trash' command.
+    #[inline]
+    pub fn trash() {
+        Page::new("postit trash <COMMAND>")
+            .alias("postit tr ...")
+            .section("Description")
+            .text(
+                "Manages the tasks dropped with the 'drop' command, which are kept in the \
+                 '.postit_trash.jsonl' file, next to the config file, instead of being \
+                 deleted permanently. Each persister has its own trash.",
+            )
+            .text(
+                "Tasks are deleted from the trash after the days of the 'trash_days' config \
+                 (30 by default). If it is 0, dropped tasks aren't kept.",
+            )
+            .section("Available subcommands")
+            .entry("list", "Shows the dropped tasks and when they were dropped (alias: ls)")
+            .entry(
+                "restore",
+                "Adds dropped tasks back to their persister, with new ids if theirs are used",
+            )
+            .entry("empty", "Deletes the dropped tasks permanently ('--all' for every persister)")
+            .section("How to use")
+            .code("postit trash list [--persister|-p]")
+            .code("postit trash restore <IDS> [--persister|-p]")
+            .code("postit trash empty [--persister|-p] [--all]")
+            .section("Examples")
+            .code("postit drop 2")
+            .code("postit trash restore 2")
+            .print();
+    }
 
-How to use:
-    postit copy tasks.csv tasks.json
-    
-    postit copy tasks.xml tasks.db
+    /// Use case of the 'undo' command.
+    #[inline]
+    pub fn undo() {
+        Page::new("postit undo [--persister|-p]")
+            .alias("postit u ...")
+            .section("Description")
+            .text("Reverts the last change made to the tasks of a persister.")
+            .text(
+                "The commands that change tasks (add, set, note, check, uncheck, drop, pick, \
+                 tidy, import and clean) record the tasks that they added, changed or \
+                 removed, as they were before and after the change, in the \
+                 '.postit_journal.json' file, next to the config file. Each persister \
+                 has its own history, and only the last 50 changes are kept.",
+            )
+            .text(
+                "If those tasks were changed outside of postit after the command (e.g.: by \
+                 editing the file), the change is discarded instead of undone. The other \
+                 tasks are kept as they are.",
+            )
+            .section("How to use")
+            .code("postit drop 2")
+            .code("postit undo")
+            .print();
+    }
 
-    postit copy tasks.db tasks.json
+    /// Use case of the 'redo' command.
+    #[inline]
+    pub fn redo() {
+        Page::new("postit redo [--persister|-p]")
+            .alias("postit r ...")
+            .section("Description")
+            .text("Applies again the last change of a persister reverted with 'undo'.")
+            .text(
+                "Running another command that changes the tasks of the persister forgets \
+                 the changes that can be redone.",
+            )
+            .section("How to use")
+            .code("postit undo")
+            .code("postit redo")
+            .print();
+    }
 
-    ...
+    /// Use case of the 'import' command.
+    #[inline]
+    pub fn import() {
+        Page::new("postit import --stdin --format <FORMAT> [--persister|-p]")
+            .alias("postit im ...")
+            .section("Description")
+            .text(
+                "Adds the tasks read from the standard input to the persister, so other \
+                 tools can feed postit without creating temporary files.",
+            )
+            .text(
+                "The input can be written in any of the supported formats (csv, json, \
+                 jsonl or xml), using the same structure as the files postit generates. \
+                 The header of CSV input is optional.",
+            )
+            .text(
+                "Imported tasks keep their content, priority and status, but get new ids \
+                 after the last task of the persister.",
+            )
+            .section("How to use")
+            .code("postit import --stdin --format json < tasks.json")
+            .code("curl https://example.com/tasks.csv | postit import --stdin -f csv -p tasks.json")
+            .section("Sample")
+            .code("echo '3,Review PR,high,false' | postit import --stdin -f csv")
+            .print();
+    }
 
-Config:
-    By default, if the persister at '<RIGHT>' exists, 'postit' will refuse to
-    overwrite its tasks in case you are using that persister as a backup or you
-    simply don't want to overwrite it.
+    /// Use case of the 'export' command.
+    #[inline]
+    pub fn export() {
+        Page::new("postit export --format|-f <FORMAT> --out|-o <PATH> [--persister|-p]")
+            .alias("postit ex ...")
+            .section("Description")
+            .text(
+                "Writes the tasks of a persister to a standalone file, without changing the \
+                 persister or the config file. If the file already exists, it is overwritten.",
+            )
+            .text("The '--format' flag accepts these values:")
+            .entry("json", "A JSON file, like the ones of a JSON persister.")
+            .entry("csv", "A CSV file, like the ones of a CSV persister.")
+            .entry("xml", "An XML file, like the ones of an XML persister.")
+            .entry(
+                "markdown",
+                "A checklist with the tags and references of the tasks, linked with the \
+                 templates of the 'links' table of the config file.",
+            )
+            .entry(
+                "ics",
+                "An iCalendar file with a to-do per task, which calendar apps can import or \
+                 subscribe to. Priorities are mapped to 1 (high), 5 (med), 9 (low) and 0 \
+                 (none), and checked tasks are marked as completed.",
+            )
+            .text(
+                "Files exported as json, csv or xml can also be used as persisters. Relative \
+                 paths are resolved from the current directory.",
+            )
+            .section("How to use")
+            .code("postit export -f markdown -o TODO.md")
+            .code("postit export -f json -o backup.json -p tasks.db")
+            .code("postit export -f ics -o tasks.ics")
+            .print();
+    }
 
-    You can set the 'force_copy' config to 'true' to overwrite it anyways.
+    /// Use case of the 'dump' command.
+    #[inline]
+    pub fn dump() {
+        Page::new("postit dump [PATH] [--persister|-p]")
+            .section("Description")
+            .text(
+                "Bundles the config file, the tasks of a persister, the journal and the \
+                 trash into a single '.tar.gz' archive, which can be moved to another \
+                 machine and loaded with 'postit restore'.",
+            )
+            .text("The archive contains:")
+            .item(".postit.toml: the config file and its templates (if it exists).")
+            .item("tasks.json: the tasks of the persister.")
+            .item("journal.json: the changes that can be undone and redone (if any).")
+            .item("trash.jsonl: the dropped tasks that can be restored (if any).")
+            .text("If the archive already exists, it is overwritten.")
+            .text(
+                "If no path is passed, the archive is saved as a backup in the 'backups' \
+                 directory, next to the config file, named after the current time (e.g.: \
+                 'postit-20250131-183000.tar.gz').",
+            )
+            .section("How to use")
+            .code("postit dump")
+            .code("postit dump postit.tar.gz")
+            .code("postit dump backup.tar.gz -p tasks.db")
+            .print();
+    }
 
-    If you want to copy your tasks and delete the '<LEFT>' persister, you can do so
-    by setting the 'drop_after_copy' config to 'true'. This will delete the file or
-    table located at '<LEFT>'.
+    /// Use case of the 'restore' command.
+    #[inline]
+    pub fn restore() {
+        Page::new("postit restore [--from] [--yes|-y] [--persister|-p]")
+            .section("Description")
+            .text(
+                "Restores the config file, the tasks, the journal and the trash from an \
+                 archive generated with 'postit dump'.",
+            )
+            .text(
+                "The '--from' flag can be the path of an archive or the name of a backup. \
+                 If it isn't passed, the backups are listed from the newest to the oldest \
+                 to choose one of them.",
+            )
+            .text(
+                "The config file is restored first (overwriting the current one), so the \
+                 tasks are written to the persister defined in it, unless the '--persister' \
+                 flag is passed. The tasks of that persister are replaced.",
+            )
+            .text(
+                "The journal, the trash and the tasks are restored while holding the lock \
+                 of the persister, so other postit processes wait until it finishes. \
+                 Archives without a journal or a trash keep the current ones.",
+            )
+            .text(
+                "Since the current tasks are lost, it asks for confirmation when run in a \
+                 terminal (see 'postit flag yes'). The '--yes' flag also restores the \
+                 newest backup if '--from' isn't passed.",
+            )
+            .section("How to use")
+            .code("postit restore")
+            .code("postit restore --from postit-20250131-183000.tar.gz")
+            .code("postit restore --from backup.tar.gz -p tasks.json --yes")
+            .print();
+    }
 
-Special parameters:
-    There are two special parameters that go into the '<LEFT>' argument.
+    /// Use case of the 'copy' command.
+    #[inline]
+    pub fn copy() {
+        Page::new("postit copy <LEFT> <RIGHT> [--merge]")
+            .alias("postit cp ...")
+            .section("Description")
+            .text(
+                "Copies a persister's contents into another, meaning you can use this \
+                 command to 'translate' tasks to a different format.",
+            )
+            .section("How to use")
+            .code("postit copy tasks.csv tasks.json")
+            .code("postit copy tasks.xml tasks.db")
+            .code("postit copy tasks.db tasks.json")
+            .code("postit copy work.csv tasks.csv --merge")
+            .code("...")
+            .section("Merging")
+            .text(
+                "With '--merge', the tasks of '<LEFT>' are appended after the existing tasks \
+                 of '<RIGHT>' (with new ids) instead of replacing them, so 'force_copy' isn't \
+                 needed.",
+            )
+            .section("Config")
+            .text(
+                "By default, if the persister at '<RIGHT>' exists, 'postit' will refuse to \
+                 overwrite its tasks in case you are using that persister as a backup or you \
+                 simply don't want to overwrite it.",
+            )
+            .text(
+                "You can set the 'force_copy' config to 'true' to overwrite it anyways, which \
+                 asks for confirmation when run in a terminal (see 'postit flag yes').",
+            )
+            .text(
+                "If you want to copy your tasks and delete the '<LEFT>' persister, you can do \
+                 so by setting the 'drop_after_copy' config to 'true'. This will delete the \
+                 file or table located at '<LEFT>'.",
+            )
+            .section("Special parameters")
+            .text("There are two special parameters that go into the '<LEFT>' argument.")
+            .text("Assuming the persister defined at the configuration file is 'tasks.csv':")
+            .item(
+                "'from': copies the tasks from '<RIGHT>' to the persister defined at the \
+                 config.\n'postit copy from tasks.json' is the same as \
+                 'postit copy tasks.json tasks.csv'",
+            )
+            .item(
+                "'to': copies the tasks from the persister defined at the config to \
+                 '<RIGHT>'.\n'postit copy to tasks.json' is the same as \
+                 'postit copy tasks.csv tasks.json'",
+            )
+            .print();
+    }
 
-    Assuming the persister defined at the configuration file is 'tasks.csv':
+    /// Use case of the 'purge' command.
+    #[inline]
+    pub fn purge() {
+        Page::new("postit purge [--persister|-p]")
+            .alias("postit pu ...")
+            .section("Description")
+            .text(
+                "Drops every checked task and keeps the unchecked ones, a middle ground \
+                 between 'drop', which needs the ids of the tasks, and 'clean', which \
+                 deletes every task. It is the same as 'postit drop --checked', so the \
+                 dropped tasks can be restored with 'postit trash restore'.",
+            )
+            .text("It asks for confirmation when run in a terminal (see 'postit flag yes').")
+            .section("How to use")
+            .code("postit purge -p tasks.csv")
+            .print();
 
-    - 'from': copies the tasks from '<RIGHT>' to the persister defined at the config.
-      'postit copy from tasks.json' is the same as 'postit copy tasks.json tasks.csv'
+        println!();
 
-    - 'to': copies the tasks from the persister defined at the config to '<RIGHT>'.
-      'postit copy to tasks.json' is the same as 'postit copy tasks.csv tasks.json'"
-        );
+        Self::before_after(Todo::sample(), |todo| {
+            todo.tasks.retain(|task| !task.checked);
+        });
     }
 
     /// Use case of the 'clean' command.
     #[inline]
     pub fn clean() {
-        println!(
-            "
-Usage: postit clean [--persister|-p]
-Alias: postit cl ...
-
-Description:
-    Deletes all tasks from a persister.
-
-How to use:
-    postit clean"
-        );
+        Page::new("postit clean [--persister|-p]")
+            .alias("postit cl ...")
+            .section("Description")
+            .text("Deletes all tasks from a persister.")
+            .text("It asks for confirmation when run in a terminal (see 'postit flag yes').")
+            .section("How to use")
+            .code("postit clean")
+            .print();
     }
 
     /// Use case of the 'remove' command.
     #[inline]
     pub fn remove() {
-        println!(
-            "
-Usage: postit remove [--persister|-p]
-Alias: postit rm ...
-
-Description:
-    Deletes the persister completely (file or table).
-
-How to use:
-    postit remove"
-        );
+        Page::new("postit remove [--persister|-p]")
+            .alias("postit rm ...")
+            .section("Description")
+            .text("Deletes the persister completely (file or table).")
+            .text("It asks for confirmation when run in a terminal (see 'postit flag yes').")
+            .section("How to use")
+            .code("postit remove")
+            .print();
     }
 
     /// Use case of the 'config' command.
     #[inline]
     pub fn config() {
-        println!(
-            "
-Usage: postit config <COMMAND>
-Alias: postit conf ...
-
-Description:
-    Manages the config file. Uses the 'POSTIT_ROOT' environment variable to
-    locate the file.
-
-Available subcommands:
-    env       Shows the value of the 'POSTIT_ROOT' env var
-    path      Shows the path of the config file
-    init      Creates the .postit.toml file
-    list      Shows the current config values     (alias: ls)
-    set       Changes config values               (alias: s)
-    remove    Deletes the config file             (alias: rm)
-
-How to use:
-    postit config env
-
-    postit config path
+        let page = Page::new("postit config <COMMAND>")
+            .alias("postit conf ...")
+            .section("Description")
+            .text(
+                "Manages the config file. Uses the 'POSTIT_ROOT' environment variable to \
+                 locate the file.",
+            )
+            .section("Available subcommands")
+            .entry("env", "Shows the value of the 'POSTIT_ROOT' env var")
+            .entry("path", "Shows the path of the config file")
+            .entry("init", "Creates the .postit.toml file")
+            .entry("list", "Shows the current config values (alias: ls)")
+            .entry("set", "Changes config values (alias: s)")
+            .entry("remove", "Deletes the config file (alias: rm)")
+            .entry(
+                "check",
+                "Validates the config files and env vars, and checks that the persister and \
+                 the persisters of the lists can be used",
+            )
+            .section("How to use")
+            .code("postit config env")
+            .code("postit config path")
+            .code("postit config init")
+            .code("postit config list")
+            .code("postit config set [OPTIONS]")
+            .code("postit config remove")
+            .code("postit config check")
+            .section("Examples")
+            .code("postit config set --persister tasks.json --force-copy true")
+            .code("postit config set  // You must provide a flag and value to set")
+            .section("Config files")
+            .text(
+                "The configuration is merged from the following files, where each one \
+                 overrides the values of the previous ones:",
+            )
+            .entry(
+                "1.",
+                "System config: '/etc/postit/.postit.toml' ('%ProgramData%\\postit' on Windows).",
+            )
+            .entry(
+                "2.",
+                "User config: the one managed by these subcommands (see 'postit config path').",
+            )
+            .entry(
+                "3.",
+                "Project config: '.postit.toml' in the current directory. Since it may come \
+                 from a repository you don't trust, its 'hooks', 'plugins', 'password', \
+                 'default_command', 'persister', 'mirrors' and 'plugin://' lists are ignored \
+                 with a warning.",
+            )
+            .entry(
+                "4.",
+                "Env vars: 'POSTIT_<KEY>' overrides the value of a key (e.g.: \
+                 POSTIT_PERSISTER or POSTIT_FORCE_DROP), except for 'password' and the \
+                 tables. Lists like 'columns' are separated by commas.",
+            )
+            .text(
+                "Missing files and keys are skipped, so a file only needs the values it \
+                 overrides. 'config set' only changes the user config file, where it writes \
+                 the passed keys and the ones that file already had, so the rest keep \
+                 falling back to the system config.",
+            );
 
-    postit config init
+        Self::config_values(page)
+            .section("More info")
+            .text("https://docs.rs/postit/latest/postit/struct.Config.html")
+            .print();
+    }
 
-    postit config list
+    /// Adds the "Config values" section to the documentation of the 'config' command.
+    fn config_values(page: Page) -> Page {
+        let page = page.section("Config values").text(
+            "After running 'postit config init', postit will generate a file with the \
+             default settings commented out, which you can change by uncommenting them or \
+             by using 'postit config set [OPTIONS]':",
+        );
 
-    postit config set [OPTIONS]
+        let page = Self::config_persister(page)
+            .item(
+                "version (integer): the latest version by default.\n\
+                 Version of the layout of the config file. Files written by older versions \
+                 of postit (or without this value) are upgraded when they are loaded, so \
+                 renamed values keep working (e.g.: 'path' from 0.1.x is read as 'persister').",
+            )
+            .item(
+                "default_command (string): not set by default.\n\
+                 Command run when postit is invoked without arguments, with its arguments \
+                 separated by spaces (e.g.: 'view' or 'view --table'). If it isn't set, \
+                 postit prints its help. An empty string unsets it.",
+            )
+            .item(
+                "force_drop (bool): false by default.\n\
+                 If 'true', allows dropping tasks even if they are not checked.",
+            )
+            .item(
+                "trash_days (integer): 30 by default.\n\
+                 Days that the tasks dropped with 'drop' are kept in the trash (see 'postit \
+                 docs trash'). If it is 0, dropped tasks are deleted permanently.",
+            )
+            .item(
+                "force_copy (bool): false by default.\n\
+                 If 'true', allows overwriting persisters when using the 'copy' command.",
+            )
+            .item(
+                "drop_after_copy (bool): false by default.\n\
+                 If 'true', drops a persister (file or table) after copying.",
+            )
+            .item(
+                "lock_timeout (integer): 5 by default.\n\
+                 Seconds that a command waits for other postit processes to finish \
+                 changing a file before failing. Databases don't use it.",
+            )
+            .item(
+                "confirm (bool): true by default.\n\
+                 If 'false', destructive commands (e.g.: 'remove' or 'clean') don't ask for \
+                 confirmation, as if '--yes' was always passed.",
+            )
+            .item(
+                "strict (bool): false by default.\n\
+                 If 'true', commands that receive ids (e.g.: 'check' or 'set') fail with the \
+                 E003 error when some of them don't match any task, so scripts can detect \
+                 typos. If 'false', those ids are skipped with a warning.",
+            )
+            .item(
+                "hide_checked (bool): false by default.\n\
+                 If 'true', the 'view' command doesn't display checked tasks (the \
+                 '--show-checked' flag can override this). 'hide_done' is another name of \
+                 this key, so 'hide_done = true' does the same; if a file has both, \
+                 'hide_checked' is used.",
+            )
+            .item(
+                "min_priority (string): 'none' by default.\n\
+                 The 'view' command hides tasks with a lower priority than this one (the \
+                 '--min-priority' flag can override this).",
+            )
+            .item(
+                "default_priority (string): 'med' by default.\n\
+                 Priority of the tasks added without one (e.g.: 'postit add \"New task\"'). \
+                 It is also the answer used when the priority question of the 'add' command \
+                 is left empty.",
+            )
+            .item(
+                "urgency (table): high = 6, med = 4, low = 2, none = 0, due = 12, age = 2 by \
+                 default.\n\
+                 Points given to unchecked tasks based on their priority, how close their due \
+                 date is and how old they are, used by the 'next' command. It must be edited \
+                 in the config file, under the '[urgency]' table.",
+            );
 
-    postit config remove
+        let page = Self::config_hooks(Self::config_display(page));
 
-Examples:
-    postit config set --persister tasks.json --force-copy true
+        Self::config_database(Self::config_tables(page))
+    }
 
-    postit config set  // You must provide a flag and value to set
+    /// Adds the hooks to the "Config values" section of the documentation of
+    /// the 'config' command.
+    fn config_hooks(page: Page) -> Page {
+        page.item(
+            "hooks (table): empty by default.\n\
+             Shell commands executed after tasks are saved. Commands can use the '{id}', \
+             '{content}' and '{priority}' placeholders, which are replaced by quoted values \
+             (so they must not be quoted again) and are also available as the POSTIT_ID, \
+             POSTIT_CONTENT and POSTIT_PRIORITY env vars. A failing hook is reported, but \
+             the tasks are still saved. They are defined under the '[hooks]' table:",
+        )
+        .subitem("on_add: runs after a task is added.")
+        .subitem("on_check: runs once per checked task.")
+        .subitem(
+            "on_overdue: runs once per task that a command leaves overdue (e.g.: by \
+             setting a past due date).",
+        )
+        .subitem("on_pomo: runs when the pomodoro of a task finishes.")
+        .subitem(
+            "webhook_url: receives a POST request after any command that changes tasks, \
+             with a JSON body that has the 'command', the 'persister', the 'ids' of the \
+             changed tasks and those tasks 'before' and 'after' the change.",
+        )
+        .code(
+            "      [hooks]\n      \
+             on_check = \"notify-send Done: {content}\"\n      \
+             webhook_url = \"https://example.com/postit\"",
+        )
+    }
 
-Config values:
-    After running 'postit config init', postit will generate a file with the
-    default settings, which you can change by using 'postit config set [OPTIONS]':
+    /// Adds the values that define where and how tasks are stored to the
+    /// "Config values" section of the documentation of the 'config' command.
+    fn config_persister(page: Page) -> Page {
+        page.item(
+            "persister (string): 'tasks.csv' by default.\n\
+             Defines where tasks are stored (the '-p' or '--persister' flag can override \
+             this). It can be the path to a file or a database connection string \
+             (including protocol). A list of persisters can be passed instead (e.g.: \
+             '[\"tasks.csv\", \"sqlite:///backup.db\"]'), where the first one is the \
+             persister and the rest are its 'mirrors'.",
+        )
+        .item(
+            "mirrors (list): empty by default.\n\
+             Persisters that receive a copy of every change made to the tasks of the \
+             persister, which are only read from the persister. Mirrors that don't exist \
+             are created with its tasks, and a mirror that can't be written only shows a \
+             warning. Since they are backups, 'clean' and 'remove' don't change them.",
+        )
+        .item(
+            "json_pretty (bool): true by default.\n\
+             If 'false', JSON files are written minified instead of indented, which makes \
+             them smaller but harder to diff. The '--compact' flag sets it to 'false' for a \
+             single call.",
+        )
+    }
 
-    - persister (string): 'tasks.csv' by default.
-      Defines where tasks are stored (the '-p' or '--persister' flag can override this).
-      It can be the path to a file or a database connection string (including protocol).
+    /// Adds the values defined as tables indexed by a name to the "Config
+    /// values" section of the documentation of the 'config' command.
+    fn config_tables(page: Page) -> Page {
+        page.item(
+            "links (table): empty by default.\n\
+             URL templates used by the 'open' and 'view' commands to link the \
+             references of tasks, indexed by the prefix of the references (see \
+             'postit docs open'). They are defined under the '[links]' table:",
+        )
+        .code("      [links]\n      gh = \"https://github.com/owner/repo/issues/{id}\"")
+        .item(
+            "lists (table): empty by default.\n\
+             Persisters of the named task lists used by the '--list' flag, indexed by \
+             their name (see 'postit docs list'). They are defined under the '[lists]' \
+             table, which can be managed with the 'list' command:",
+        )
+        .code("      [lists]\n      work = \"work.csv\"")
+        .item(
+            "plugins (table): empty by default.\n\
+             Commands of the external persisters used by 'plugin://NAME' connections, \
+             indexed by their name. Each operation runs the command, which receives a \
+             JSON request with the 'op' and the 'conn' in its standard input and answers \
+             with a JSON object in its standard output (an 'error' key makes the \
+             operation fail). They are defined under the '[plugins]' table:",
+        )
+        .code("      [plugins]\n      notion = \"postit-persister-notion\"")
+        .item(
+            "csv (table): comma-separated with a header by default.\n\
+             Format of the CSV files used as persisters, so files exported by spreadsheets \
+             can be used without converting them. It is defined under the '[csv]' table:",
+        )
+        .subitem("delimiter: character that separates the fields (',' by default).")
+        .subitem(
+            "quoting: 'needed' (the default) only quotes the fields that have the \
+             delimiter, while 'always' quotes every field.",
+        )
+        .subitem("header: if false, the first line of the file is a task instead of the header.")
+        .code("      [csv]\n      delimiter = \";\"\n      quoting = \"always\"\n      header = false")
+    }
 
-    - force_drop (bool): false by default.
-      If 'true', allows dropping tasks even if they are not checked.
+    /// Adds the values used by database persisters to the "Config values"
+    /// section of the documentation of the 'config' command.
+    fn config_database(page: Page) -> Page {
+        page.item(
+            "database (string): 'test' by default.\n\
+                 Database used by MongoDB persisters whose connection string doesn't end with \
+                 the name of a database (e.g.: 'mongodb://localhost:27017/postit' uses the \
+                 'postit' database, while 'mongodb://localhost:27017' uses this value).",
+        )
+        .item(
+            "password (string): not set by default.\n\
+                 Password used when the connection string of a database persister has a user \
+                 but no password (e.g.: 'mongodb://user@localhost:27017'). Instead of the \
+                 password itself, it can reference where to read it from (the POSTIT_PASSWORD \
+                 env var takes precedence over it):",
+        )
+        .subitem("'env:NAME': the value of the NAME env var.")
+        .subitem("'file:PATH': the first line of the file at PATH.")
+        .subitem(
+            "'keyring:SERVICE/ACCOUNT': an entry of the OS keyring ('secret-tool' on \
+                 Linux, 'security' on macOS).",
+        )
+        .subitem("Any other value is the password itself, which shows a warning when used.")
+    }
 
-    - force_copy (bool): false by default.
-      If 'true', allows overwriting persisters when using the 'copy' command.
+    /// Adds the values that change how tasks are displayed to the "Config
+    /// values" section of the documentation of the 'config' command.
+    fn config_display(page: Page) -> Page {
+        page.item(
+            "columns (list): ['id', 'priority', 'status', 'content'] by default.\n\
+             Columns displayed by 'view --table' (the '--columns' flag can override \
+             this). The available columns are 'id', 'priority', 'status', 'content', \
+             'tags', 'refs', 'created', 'updated', 'completed', 'estimate' and 'due'.",
+        )
+        .item(
+            "format (string): '{id}. {content}' by default.\n\
+             Template used to display tasks. Its placeholders are the names of the \
+             columns between braces, and '{{' and '}}' are displayed as single braces.",
+        )
+        .item(
+            "color (string): 'auto' by default.\n\
+             Defines when the output is colored: 'auto' colors it if it is a terminal \
+             and the NO_COLOR env var isn't set, 'always' colors it even if it is \
+             piped, and 'never' doesn't color it. The '--no-color' flag overrides it.",
+        )
+        .item(
+            "colors (table): high = 'red bold', med = 'yellow bold', low = 'blue bold', \
+             none = 'white bold', checked = 'strikethrough', overdue = 'magenta bold' \
+             by default.\n\
+             Styles of tasks based on their priority, plus the style added to checked \
+             tasks and the one that replaces the priority style of overdue tasks. A style is a color (e.g.: 'red', 'bright blue' or 'purple') followed \
+             by any of the effects 'bold', 'dimmed', 'italic', 'underline', \
+             'strikethrough' and 'reversed'. An empty style disables it (e.g.: \
+             checked = '' doesn't strike checked tasks through). It must be edited in \
+             the config file, under the '[colors]' table:",
+        )
+        .code("      [colors]\n      high = \"bright red bold\"\n      checked = \"dimmed\"")
+        .item(
+            "lang (string): 'en' by default.\n\
+             Language of the errors, prompts and headings of the documentation: 'en' \
+             (English) or 'es' (Spanish). Messages without a translation, like the text \
+             of the documentation, are shown in English.",
+        )
+    }
 
-    - drop_after_copy (bool): false by default.
-      If 'true', drops a persister (file or table) after copying.
-    
-You can also check https://docs.rs/postit/latest/postit/struct.Config.html for more info."
-        );
+    /// List of the error codes and their meaning.
+    #[inline]
+    pub fn errors() {
+        Page::new("postit docs errors")
+            .section("Description")
+            .text(
+                "Every error is printed with a stable code (e.g.: '[E104] The configuration \
+                 file doesn't exist at ...'), so scripts can match on it and you can search \
+                 for fixes.",
+            )
+            .section("General")
+            .entry("E001", "I/O error while reading or writing a file.")
+            .entry("E002", "Any other error.")
+            .entry("E003", "Some of the passed ids don't match any task.")
+            .entry("E004", "A persister plugin failed or answered with an invalid response.")
+            .entry("E005", "There are no tasks to use with the command.")
+            .entry("E006", "The persister doesn't exist yet; add a task first.")
+            .entry("E007", "The trash doesn't have the passed tasks.")
+            .entry("E008", "A hook or the webhook failed.")
+            .entry("E009", "The dump archive doesn't exist or there are no backups.")
+            .entry("E010", "The passed priority is not valid.")
+            .entry("E011", "The standard input or the clipboard doesn't have any text.")
+            .entry("E012", "The clipboard can't be read.")
+            .entry("E013", "The editor failed or the edited task isn't valid.")
+            .entry("E014", "The tasks changed outside of postit, so they can't be undone.")
+            .entry("E015", "The tasks can't be copied to the other persister.")
+            .section("Configuration")
+            .entry("E101", "The 'POSTIT_ROOT' env var is empty.")
+            .entry("E102", "The 'POSTIT_ROOT' env var is not a valid absolute path.")
+            .entry("E103", "The 'POSTIT_ROOT' env var is not valid unicode.")
+            .entry("E104", "The config file doesn't exist; create it with 'postit config init'.")
+            .entry("E105", "The config file already exists.")
+            .entry("E106", "'postit config set' was used without any values to set.")
+            .entry("E107", "I/O error while reading or writing the config file.")
+            .entry("E108", "An env var referenced by the config couldn't be read.")
+            .entry("E109", "The config couldn't be serialized to TOML.")
+            .entry("E110", "The config file is not valid TOML or has invalid values.")
+            .entry("E111", "There is no list with the passed name.")
+            .entry("E112", "'config check' found problems in the configuration.")
+            .entry("E113", "The passed name can't be the name of a list.")
+            .entry("E114", "A list with the passed name already exists.")
+            .entry("E115", "The keyring reference is malformed or the keyring can't be read.")
+            .entry("E199", "Any other configuration error.")
+            .section("Files")
+            .entry("E201", "The persister is a directory.")
+            .entry("E202", "The file format is not supported; use csv, json, jsonl or xml.")
+            .entry("E203", "The file doesn't exist.")
+            .entry("E204", "I/O error while reading or writing the file.")
+            .entry("E205", "The JSON file is malformed.")
+            .entry("E206", "The XML file is malformed.")
+            .entry("E207", "The file is locked by another postit process.")
+            .entry("E208", "The XML file doesn't match its DTD (the line is reported).")
+            .entry("E209", "A CSV line isn't a valid task (the line is reported).")
+            .entry("E210", "The file already exists.")
+            .entry("E211", "The file or its directory is read-only.")
+            .entry("E299", "Any other file error.")
+            .section("Databases")
+            .entry("E301", "The database is not supported.")
+            .entry("E302", "The connection string is incorrect.")
+            .entry("E303", "Support for the persister was disabled at compile time.")
+            .entry("E304", "Error returned by SQLite.")
+            .entry("E305", "Error returned by MongoDB.")
+            .entry("E306", "Error returned by PostgreSQL.")
+            .entry("E307", "Error returned by Redis.")
+            .entry("E308", "Error returned by a libSQL server.")
+            .entry("E309", "The table doesn't exist yet; add a task first.")
+            .entry("E399", "Any other database error.")
+            .print();
     }
 }
@@ -6,6 +6,8 @@
 
 mod command;
 mod flag;
+mod page;
 
 pub use command::Command;
 pub use flag::Flag;
+pub use page::Page;
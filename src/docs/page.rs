@@ -0,0 +1,272 @@
+//! Contains the `Page` struct, which defines the documentation of a command
+//! or flag as structured data and renders it to fit the terminal width.
+
+use std::mem;
+
+use colored::Colorize as _;
+
+use crate::{tr, Lang};
+
+/// Width used when the terminal width can't be detected (e.g.: when the
+/// output is piped).
+const DEFAULT_WIDTH: usize = 80;
+
+/// Narrowest width text is wrapped to, so very small terminals still get
+/// readable lines.
+const MIN_WIDTH: usize = 40;
+
+/// Indentation of the contents of every section.
+const INDENT: usize = 4;
+
+/// Piece of content inside a section.
+#[derive(Debug)]
+enum Block {
+    /// Paragraph wrapped to the available width.
+    Text(String),
+    /// List item, wrapped with a hanging indent. New lines inside the text
+    /// start new lines of the item.
+    Item(String),
+    /// List item nested inside the previous item.
+    SubItem(String),
+    /// Term followed by its description, aligned with the other entries of the section.
+    Entry(String, String),
+    /// Lines printed as they are (commands, file contents, outputs...).
+    Code(String),
+}
+
+impl Block {
+    /// Returns `true` if the block is printed right after `previous`, without
+    /// a blank line in between.
+    ///
+    /// Lists follow the paragraph that introduces them, nested items always
+    /// follow their parent and single-line items and entries are printed
+    /// together. Any other block is separated by a blank line.
+    fn follows(&self, previous: &Self) -> bool {
+        let is_short = |block: &Self| match *block {
+            Self::Item(ref text) | Self::SubItem(ref text) => !text.contains('\n'),
+            Self::Entry(..) => true,
+            Self::Text(_) | Self::Code(_) => false,
+        };
+
+        match (previous, self) {
+            (_, &Self::SubItem(_)) | (&Self::Text(_), &Self::Item(_) | &Self::Entry(..)) => true,
+            _ => is_short(previous) && is_short(self),
+        }
+    }
+}
+
+/// Titled group of blocks.
+#[derive(Debug)]
+struct Section {
+    /// Title of the section, printed before its blocks.
+    title: String,
+    /// Contents of the section.
+    blocks: Vec<Block>,
+}
+
+/// Documentation page of a command or flag.
+///
+/// Pages are built by chaining sections and blocks, and rendered with
+/// [`Page::render`] (or printed with [`Page::print`]), which wraps the text to
+/// the terminal width.
+#[derive(Debug, Default)]
+pub struct Page {
+    /// Usage line of the command.
+    usage: Option<String>,
+    /// Alias line of the command.
+    alias: Option<String>,
+    /// Sections of the page.
+    sections: Vec<Section>,
+}
+
+impl Page {
+    /// Constructor of the `Page` struct.
+    #[inline]
+    #[must_use]
+    pub fn new<T: Into<String>>(usage: T) -> Self {
+        Self {
+            usage: Some(usage.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the alias line of the page.
+    #[inline]
+    #[must_use]
+    pub fn alias<T: Into<String>>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Starts a new section, where the next blocks will be added.
+    #[inline]
+    #[must_use]
+    pub fn section<T: Into<String>>(mut self, title: T) -> Self {
+        self.sections
+            .push(Section { title: title.into(), blocks: Vec::new() });
+        self
+    }
+
+    /// Adds a block to the last section.
+    fn push(mut self, block: Block) -> Self {
+        match self.sections.last_mut() {
+            Some(section) => section.blocks.push(block),
+            None => self.sections.push(Section {
+                title: String::new(),
+                blocks: vec![block],
+            }),
+        }
+
+        self
+    }
+
+    /// Adds a paragraph, which is wrapped to the terminal width.
+    #[inline]
+    #[must_use]
+    pub fn text<T: Into<String>>(self, text: T) -> Self {
+        self.push(Block::Text(text.into()))
+    }
+
+    /// Adds a list item. New lines inside the text start new lines of the item.
+    #[inline]
+    #[must_use]
+    pub fn item<T: Into<String>>(self, text: T) -> Self {
+        self.push(Block::Item(text.into()))
+    }
+
+    /// Adds a list item nested inside the previous one.
+    #[inline]
+    #[must_use]
+    pub fn subitem<T: Into<String>>(self, text: T) -> Self {
+        self.push(Block::SubItem(text.into()))
+    }
+
+    /// Adds a term and its description, aligned with the other entries of
+    /// the section.
+    #[inline]
+    #[must_use]
+    pub fn entry<T: Into<String>, U: Into<String>>(self, term: T, description: U) -> Self {
+        self.push(Block::Entry(term.into(), description.into()))
+    }
+
+    /// Adds lines that are printed without being wrapped.
+    #[inline]
+    #[must_use]
+    pub fn code<T: Into<String>>(self, code: T) -> Self {
+        self.push(Block::Code(code.into()))
+    }
+
+    /// Returns the width of the terminal, or a default value if it can't be detected.
+    #[inline]
+    pub fn width() -> usize {
+        terminal_size::terminal_size().map_or(DEFAULT_WIDTH, |(w, _)| usize::from(w.0))
+    }
+
+    /// Prints the page wrapped to the terminal width.
+    #[inline]
+    pub fn print(&self) {
+        println!("{}", self.render(Self::width()));
+    }
+
+    /// Renders the page, wrapping its text to `width` columns.
+    #[inline]
+    pub fn render(&self, width: usize) -> String {
+        let width = width.max(MIN_WIDTH) - INDENT;
+        let pad = " ".repeat(INDENT);
+        let mut lines = vec![String::new()];
+
+        if let Some(usage) = &self.usage {
+            lines.push(tr!("Usage: {usage}", usage = usage));
+        }
+
+        if let Some(alias) = &self.alias {
+            lines.push(format!("Alias: {alias}"));
+        }
+
+        for section in &self.sections {
+            if lines.len() > 1 {
+                lines.push(String::new());
+            }
+
+            if !section.title.is_empty() {
+                let title = Lang::current().tr(&section.title);
+                lines.push(format!("{}", format!("{title}:").bold()));
+            }
+
+            let term_width = section
+                .blocks
+                .iter()
+                .filter_map(|block| match *block {
+                    Block::Entry(ref term, _) => Some(term.chars().count()),
+                    Block::Text(_) | Block::Item(_) | Block::SubItem(_) | Block::Code(_) => None,
+                })
+                .max()
+                .unwrap_or_default();
+
+            let mut previous: Option<&Block> = None;
+
+            for block in &section.blocks {
+                if previous.is_some_and(|prev| !block.follows(prev)) {
+                    lines.push(String::new());
+                }
+
+                let rendered = match *block {
+                    Block::Text(ref text) => Self::hanging(text, "", "", width),
+                    Block::Item(ref text) => Self::hanging(text, "- ", "  ", width),
+                    Block::SubItem(ref text) => Self::hanging(text, "  - ", "    ", width),
+                    Block::Entry(ref term, ref description) => {
+                        let first = format!("{term:<term_width$}    ");
+                        let rest = " ".repeat(first.chars().count());
+                        Self::hanging(description, &first, &rest, width)
+                    }
+                    Block::Code(ref code) => code.lines().map(str::to_owned).collect(),
+                };
+
+                lines.extend(rendered.into_iter().map(|line| {
+                    if line.is_empty() {
+                        line
+                    } else {
+                        format!("{pad}{line}")
+                    }
+                }));
+
+                previous = Some(block);
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Wraps every line of `text` to `width` columns, prefixing the first
+    /// line with `first` and the rest with `rest`.
+    fn hanging(text: &str, first: &str, rest: &str, width: usize) -> Vec<String> {
+        let available = width.saturating_sub(rest.chars().count()).max(1);
+        let mut lines = Vec::new();
+
+        for paragraph in text.lines() {
+            let mut line = String::new();
+
+            for word in paragraph.split_whitespace() {
+                let len = line.chars().count();
+
+                if len > 0 && len + 1 + word.chars().count() > available {
+                    lines.push(mem::take(&mut line));
+                }
+
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+
+                line.push_str(word);
+            }
+
+            lines.push(line);
+        }
+
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| format!("{}{line}", if i == 0 { first } else { rest }))
+            .collect()
+    }
+}
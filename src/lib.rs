@@ -51,6 +51,8 @@ mod core;
 pub mod docs;
 pub mod models;
 mod persisters;
+#[cfg(feature = "test-utils")]
+pub mod testing;
 
 pub use core::*;
 
@@ -1,9 +1,11 @@
-use clap::Parser as _;
 use postit::{Cli, Postit};
 
 fn main() {
-    if let Err(e) = Postit::run(Cli::parse()) {
-        eprintln!("{e}");
+    let postit = Postit::new(None);
+    let cli = Cli::parse_or_default(|| postit.default_command());
+
+    if let Err(e) = postit.execute(cli) {
+        eprintln!("[{}] {e}", e.code());
         std::process::exit(1);
     }
 }
@@ -0,0 +1,103 @@
+//! Month calendar annotated with the due dates of tasks, used by the 'cal' command.
+
+use std::fmt;
+
+use chrono::{Datelike as _, Local, Months, NaiveDate};
+
+use super::Task;
+
+/// Names of the days of the week, starting on Monday.
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Month calendar with the number of unchecked tasks due on each day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Calendar {
+    /// First day of the month.
+    pub month: NaiveDate,
+    /// Number of unchecked tasks due on each day of the month (the first
+    /// item is the first day).
+    pub due: Vec<usize>,
+}
+
+impl fmt::Display for Calendar {
+    /// Displays the month like the `cal` program, with a week per line that
+    /// starts on Monday and the number of tasks due after each day (e.g.:
+    /// `14*2`), followed by the total of the month.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.month.format("%B %Y"))?;
+
+        let header: Vec<String> = WEEKDAYS.iter().map(|day| format!("{day:>3}   ")).collect();
+        writeln!(f, "{}", header.concat().trim_end())?;
+
+        let offset = usize::try_from(self.month.weekday().num_days_from_monday());
+        let mut cells = vec![String::from("      "); offset.unwrap_or_default()];
+
+        for (day, count) in (1..).zip(&self.due) {
+            let mark = if *count > 0 { format!("*{count}") } else { String::new() };
+            cells.push(format!("{day:>3}{mark:<3}"));
+        }
+
+        for week in cells.chunks(WEEKDAYS.len()) {
+            writeln!(f, "{}", week.concat().trim_end())?;
+        }
+
+        write!(f, "\nDue this month: {}", self.total())
+    }
+}
+
+impl Calendar {
+    /// Constructor of the `Calendar` struct, which counts the unchecked tasks
+    /// due on each day of the month that starts on `month`. Dates are taken
+    /// in the local timezone.
+    #[inline]
+    pub fn new(month: NaiveDate, tasks: &[Task]) -> Self {
+        let month = month.with_day(1).unwrap_or(month);
+
+        let days = month
+            .checked_add_months(Months::new(1))
+            .map_or(31, |next| (next - month).num_days());
+
+        let mut due = vec![0; usize::try_from(days).unwrap_or_default()];
+
+        for task in tasks.iter().filter(|task| !task.checked) {
+            let Some(date) = task.due.map(|due| due.with_timezone(&Local).date_naive()) else {
+                continue;
+            };
+
+            if date.year() == month.year() && date.month() == month.month() {
+                let day = usize::try_from(date.day0()).unwrap_or_default();
+
+                if let Some(count) = due.get_mut(day) {
+                    *count += 1;
+                }
+            }
+        }
+
+        Self { month, due }
+    }
+
+    /// Returns the first day of the current month in the local timezone.
+    #[inline]
+    pub fn current_month() -> NaiveDate {
+        let today = Local::now().date_naive();
+        today.with_day(1).unwrap_or(today)
+    }
+
+    /// Parses a month passed as an argument with the `YYYY-MM` format,
+    /// returning its first day.
+    ///
+    /// # Errors
+    /// If the month doesn't have the `YYYY-MM` format.
+    #[inline]
+    pub fn parse_month(month: &str) -> Result<NaiveDate, String> {
+        NaiveDate::parse_from_str(&format!("{}-01", month.trim()), "%Y-%m-%d")
+            .map_err(|_| format!("'{month}' is not a YYYY-MM month"))
+    }
+
+    /// Returns the number of unchecked tasks due in the month.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.due.iter().sum()
+    }
+}
@@ -0,0 +1,189 @@
+//! Colors and styles used to display tasks, based on their priority and on
+//! wether they are checked or overdue.
+
+use std::fmt;
+use std::str::FromStr as _;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use colored::{Color, ColoredString, Colorize as _};
+use serde::{Deserialize, Serialize};
+
+use super::{Priority, Task};
+
+/// Palette used by the [`Task`] display, set by [`Colors::install`].
+static PALETTE: OnceLock<Colors> = OnceLock::new();
+
+/// Palette used by the [`Task`] display if none was installed.
+static DEFAULT_PALETTE: OnceLock<Colors> = OnceLock::new();
+
+/// Color mode of the process, set by the first call to [`ColorMode::install`].
+static MODE: OnceLock<ColorMode> = OnceLock::new();
+
+/// Defines when the output is colored.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// Colors the output only if it is a terminal and the `NO_COLOR` env var
+    /// isn't set (`CLICOLOR_FORCE` forces colors).
+    #[default]
+    Auto,
+    /// Always colors the output, even if it is piped.
+    Always,
+    /// Never colors the output.
+    Never,
+}
+
+impl fmt::Display for ColorMode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Auto => write!(f, "auto"),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl ColorMode {
+    /// Sets the color mode for the rest of the process. It does nothing if a
+    /// mode was already set, so the '--no-color' flag, which is set first,
+    /// overrides the config.
+    #[inline]
+    pub fn install(self) {
+        if MODE.set(self.clone()).is_err() {
+            return;
+        }
+
+        match self {
+            Self::Auto => (),
+            Self::Always => colored::control::set_override(true),
+            Self::Never => colored::control::set_override(false),
+        }
+    }
+
+    /// Returns the color mode set with [`ColorMode::install`], if any.
+    #[inline]
+    pub fn current() -> Option<&'static Self> {
+        MODE.get()
+    }
+}
+
+/// Styles of tasks, written as words separated by spaces (e.g.: `red bold`).
+///
+/// A style can have a color (e.g.: `red`, `bright blue` or `purple`) and any
+/// of the effects `bold`, `dimmed`, `italic`, `underline`, `strikethrough`
+/// and `reversed`. Words that aren't effects are read as the color, which is
+/// ignored if it isn't valid, and an empty style leaves the text as is.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Colors {
+    /// Style of `high` priority tasks.
+    pub high: String,
+    /// Style of `med` priority tasks.
+    pub med: String,
+    /// Style of `low` priority tasks.
+    pub low: String,
+    /// Style of `none` priority tasks.
+    pub none: String,
+    /// Style added to checked tasks, on top of the one of their priority.
+    pub checked: String,
+    /// Style of unchecked tasks whose due date has passed, which replaces
+    /// the one of their priority.
+    pub overdue: String,
+}
+
+impl Default for Colors {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            high: String::from("red bold"),
+            med: String::from("yellow bold"),
+            low: String::from("blue bold"),
+            none: String::from("white bold"),
+            checked: String::from("strikethrough"),
+            overdue: String::from("magenta bold"),
+        }
+    }
+}
+
+impl fmt::Display for Colors {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "high='{}' med='{}' low='{}' none='{}' checked='{}' overdue='{}'",
+            self.high, self.med, self.low, self.none, self.checked, self.overdue
+        )
+    }
+}
+
+impl Colors {
+    /// Returns the palette used to display tasks.
+    ///
+    /// It is the one set with [`Colors::install`] (the `colors` of the config,
+    /// once [`Postit`][crate::Postit] loads it) or the default one.
+    #[inline]
+    pub fn current() -> &'static Self {
+        PALETTE
+            .get()
+            .unwrap_or_else(|| DEFAULT_PALETTE.get_or_init(Self::default))
+    }
+
+    /// Sets the palette used to display tasks. It does nothing if a palette
+    /// was already set.
+    #[inline]
+    pub fn install(self) {
+        let _ = PALETTE.set(self);
+    }
+
+    /// Returns the style of the tasks with the passed priority.
+    #[inline]
+    pub fn style(&self, priority: &Priority) -> &str {
+        match priority {
+            Priority::High => &self.high,
+            Priority::Med => &self.med,
+            Priority::Low => &self.low,
+            Priority::None => &self.none,
+        }
+    }
+
+    /// Applies the style of a task to the passed text.
+    #[inline]
+    pub fn paint(&self, text: &str, task: &Task) -> ColoredString {
+        let style = if task.is_overdue() { &self.overdue } else { self.style(&task.priority) };
+        let styled = Self::apply(text.normal(), style);
+
+        if task.checked {
+            Self::apply(styled, &self.checked)
+        } else {
+            styled
+        }
+    }
+
+    /// Applies the color and the effects of a style to the passed text.
+    #[inline]
+    pub fn apply(mut text: ColoredString, style: &str) -> ColoredString {
+        let mut color = Vec::new();
+
+        for word in style.split_whitespace() {
+            text = match word.to_lowercase().as_str() {
+                "bold" => text.bold(),
+                "dimmed" => text.dimmed(),
+                "italic" => text.italic(),
+                "underline" => text.underline(),
+                "strikethrough" => text.strikethrough(),
+                "reversed" => text.reversed(),
+                _ => {
+                    color.push(word);
+                    text
+                }
+            };
+        }
+
+        match Color::from_str(&color.join(" ")) {
+            Ok(color) => text.color(color),
+            Err(()) => text,
+        }
+    }
+}
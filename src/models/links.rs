@@ -0,0 +1,90 @@
+//! URL templates used to turn the references of tasks into links, used by
+//! the 'view' and 'open' commands.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// URL templates indexed by the prefix of the references they apply to.
+///
+/// A reference is split into a prefix and an identifier at its first `-` or
+/// `#` (e.g.: `JIRA-123` is split into `JIRA` and `123`, and `gh#42` into `gh`
+/// and `42`). Templates can use the `{ref}` and `{id}` placeholders, which are
+/// replaced by the whole reference and its identifier.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Links(pub BTreeMap<String, String>);
+
+impl fmt::Display for Links {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+
+        let links: Vec<String> = self
+            .0
+            .iter()
+            .map(|(prefix, template)| format!("{prefix}='{template}'"))
+            .collect();
+
+        write!(f, "{}", links.join(" "))
+    }
+}
+
+impl Links {
+    /// Splits a reference into its prefix and its identifier.
+    ///
+    /// References without a `-` or `#` have no identifier.
+    #[inline]
+    pub fn split(reference: &str) -> (&str, &str) {
+        reference.split_once(['-', '#']).unwrap_or((reference, ""))
+    }
+
+    /// Returns the URL of a reference, or `None` if there is no template for its prefix.
+    #[inline]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    pub fn url(&self, reference: &str) -> Option<String> {
+        let (prefix, id) = Self::split(reference);
+
+        self.0
+            .get(prefix)
+            .map(|template| template.replace("{ref}", reference).replace("{id}", id))
+    }
+
+    /// Formats references to be displayed next to a task, as terminal
+    /// hyperlinks if they have a URL and the output is colored.
+    #[inline]
+    pub fn plain(&self, refs: &[String]) -> String {
+        if refs.is_empty() {
+            return String::new();
+        }
+
+        let linked = colored::control::SHOULD_COLORIZE.should_colorize();
+
+        let refs: Vec<String> = refs
+            .iter()
+            .map(|reference| match self.url(reference) {
+                Some(url) if linked => format!("\x1b]8;;{url}\x1b\\{reference}\x1b]8;;\x1b\\"),
+                Some(_) | None => reference.clone(),
+            })
+            .collect();
+
+        format!(" [{}]", refs.join(" "))
+    }
+
+    /// Formats references to be appended to a Markdown checklist item, as
+    /// links if they have a URL.
+    #[inline]
+    pub fn markdown(&self, refs: &[String]) -> String {
+        refs.iter()
+            .map(|reference| {
+                self.url(reference).map_or_else(
+                    || format!(" `{reference}`"),
+                    |url| format!(" [{reference}]({url})"),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,54 @@
+//! Named task lists, each one stored in its own persister, used by the
+//! '--list' flag and the 'list' command.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Persisters indexed by the name of the list they store.
+///
+/// A name can be used instead of a persister in the '--persister' flag, so
+/// names can't contain the characters used by paths and connection strings
+/// (`.`, `/`, `\` and `:`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Lists(pub BTreeMap<String, String>);
+
+impl fmt::Display for Lists {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+
+        let lists: Vec<String> = self
+            .0
+            .iter()
+            .map(|(name, persister)| format!("{name}='{persister}'"))
+            .collect();
+
+        write!(f, "{}", lists.join(" "))
+    }
+}
+
+impl Lists {
+    /// Returns `true` if the value can be the name of a list.
+    #[inline]
+    pub fn is_name(value: &str) -> bool {
+        !value.is_empty() && !value.contains(['.', '/', '\\', ':'])
+    }
+
+    /// Returns the persister of a list, or `None` if there is no list with that name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&String> {
+        self.0.get(name)
+    }
+
+    /// Returns the persister of the list named `value`, or `value` itself if
+    /// it isn't the name of a list.
+    #[inline]
+    pub fn resolve(&self, value: &str) -> String {
+        self.get(value).cloned().unwrap_or_else(|| value.to_owned())
+    }
+}
@@ -1,7 +1,25 @@
 //! Representations of objects that store specific data related to tasks and their information.
 
+mod calendar;
+mod colors;
+mod links;
+mod lists;
+mod plugins;
+mod sort;
+mod stats;
+mod table;
 mod task;
 mod todo;
+mod urgency;
 
+pub use calendar::Calendar;
+pub use colors::{ColorMode, Colors};
+pub use links::Links;
+pub use lists::Lists;
+pub use plugins::Plugins;
+pub use sort::SortBy;
+pub use stats::{GroupBy, Stats};
+pub use table::Column;
 pub use task::{Priority, Task};
 pub use todo::Todo;
+pub use urgency::Urgency;
@@ -0,0 +1,40 @@
+//! External persisters, registered in the config file and used through the
+//! `plugin://NAME` connections (see [`Plugin`][`crate::plugin::Plugin`]).
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Commands that run the external persisters, indexed by the name of the plugin.
+///
+/// The command is run by the shell, so it can be the name of a binary in the
+/// `PATH` (e.g.: `postit-persister-notion`) or have arguments.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Plugins(pub BTreeMap<String, String>);
+
+impl fmt::Display for Plugins {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+
+        let plugins: Vec<String> = self
+            .0
+            .iter()
+            .map(|(name, command)| format!("{name}='{command}'"))
+            .collect();
+
+        write!(f, "{}", plugins.join(" "))
+    }
+}
+
+impl Plugins {
+    /// Returns the command of a plugin, or `None` if there is no plugin with that name.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&String> {
+        self.0.get(name)
+    }
+}
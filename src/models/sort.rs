@@ -0,0 +1,44 @@
+//! Fields that tasks can be sorted by.
+
+use std::fmt;
+
+use clap::ValueEnum;
+
+/// Field used to sort tasks.
+#[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SortBy {
+    /// Sorts tasks by their id.
+    Id,
+    /// Sorts tasks from the highest priority to the lowest.
+    Priority,
+    /// Sorts tasks alphabetically by their content, ignoring case.
+    Content,
+    /// Sorts unchecked tasks before checked tasks.
+    Checked,
+    /// Sorts tasks from the oldest to the newest.
+    Created,
+    /// Sorts tasks from the least to the most recently updated.
+    Updated,
+    /// Sorts tasks from the first to the last completed.
+    Completed,
+    /// Sorts tasks from the earliest to the latest due date.
+    Due,
+}
+
+impl fmt::Display for SortBy {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = match *self {
+            Self::Id => "id",
+            Self::Priority => "priority",
+            Self::Content => "content",
+            Self::Checked => "checked",
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Completed => "completed",
+            Self::Due => "due",
+        };
+
+        write!(f, "{field}")
+    }
+}
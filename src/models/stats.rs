@@ -0,0 +1,137 @@
+//! Counts and completion percentages of tasks, used by the 'stats' command.
+
+use std::fmt;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+use super::Task;
+
+/// Criteria used to group tasks.
+#[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    /// Groups tasks by their priority.
+    Priority,
+    /// Groups tasks by their tags (tasks with several tags are in each group).
+    Tag,
+    /// Groups tasks by wether they are checked or not.
+    Status,
+}
+
+impl fmt::Display for GroupBy {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Priority => write!(f, "priority"),
+            Self::Tag => write!(f, "tag"),
+            Self::Status => write!(f, "status"),
+        }
+    }
+}
+
+/// Number of tasks and checked tasks of a group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    /// Name of the group.
+    pub label: String,
+    /// Number of tasks in the group.
+    pub total: usize,
+    /// Number of checked tasks in the group.
+    pub checked: usize,
+    /// Minutes estimated for the tasks of the group.
+    pub estimate: u32,
+    /// Minutes estimated for the unchecked tasks of the group.
+    pub remaining: u32,
+}
+
+impl fmt::Display for Stats {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Tasks: {} ({} checked, {} unchecked)",
+            self.total,
+            self.checked,
+            self.unchecked()
+        )?;
+        write!(f, "Completed: {}%", self.percentage())?;
+
+        if self.estimate > 0 {
+            let estimate = Task::format_estimate(self.estimate);
+            let remaining = Task::format_estimate(self.remaining);
+            write!(f, "\nEstimated: {estimate} ({remaining} left)")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Stats {
+    /// Constructor of the `Stats` struct.
+    #[inline]
+    pub fn new<T: Into<String>>(label: T, total: usize, checked: usize) -> Self {
+        Self {
+            label: label.into(),
+            total,
+            checked,
+            estimate: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Returns the number of unchecked tasks.
+    #[inline]
+    pub const fn unchecked(&self) -> usize {
+        self.total - self.checked
+    }
+
+    /// Returns the percentage of checked tasks (rounded down).
+    #[inline]
+    pub const fn percentage(&self) -> usize {
+        if self.total == 0 {
+            return 0;
+        }
+
+        self.checked * 100 / self.total
+    }
+
+    /// Returns the counts and the percentage of the group as a JSON object.
+    #[inline]
+    pub fn as_json(&self) -> Value {
+        json!({
+            "label": self.label,
+            "total": self.total,
+            "checked": self.checked,
+            "unchecked": self.unchecked(),
+            "percentage": self.percentage(),
+            "estimate": self.estimate,
+            "remaining": self.remaining,
+        })
+    }
+
+    /// Renders a list of groups as a table, using `header` as the title of the
+    /// first column.
+    #[inline]
+    pub fn table(header: &str, rows: &[Self]) -> String {
+        let width = rows
+            .iter()
+            .map(|row| row.label.len())
+            .chain([header.len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut lines = vec![format!("{header:<width$}  total  checked  completed")];
+
+        for row in rows {
+            lines.push(format!(
+                "{:<width$}  {:>5}  {:>7}  {:>8}%",
+                row.label,
+                row.total,
+                row.checked,
+                row.percentage()
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,148 @@
+//! Columns of the table of tasks displayed by 'view --table'.
+
+use std::fmt;
+
+use chrono::{DateTime, Local, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::Task;
+
+/// Field of the tasks displayed in a column of the table.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    /// Identifier of the task.
+    Id,
+    /// Priority of the task.
+    Priority,
+    /// `done` if the task is checked, or `todo` if it isn't.
+    Status,
+    /// Content of the task.
+    Content,
+    /// Tags of the task, separated by spaces.
+    Tags,
+    /// References of the task, separated by spaces.
+    Refs,
+    /// Local date when the task was added.
+    Created,
+    /// Local date when the task was last changed.
+    Updated,
+    /// Local date when the task was checked.
+    Completed,
+    /// Time that the task is expected to take (e.g.: `1h30m`).
+    Estimate,
+    /// Local date by which the task should be done.
+    Due,
+}
+
+impl fmt::Display for Column {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = match *self {
+            Self::Id => "id",
+            Self::Priority => "priority",
+            Self::Status => "status",
+            Self::Content => "content",
+            Self::Tags => "tags",
+            Self::Refs => "refs",
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Completed => "completed",
+            Self::Estimate => "estimate",
+            Self::Due => "due",
+        };
+
+        write!(f, "{column}")
+    }
+}
+
+impl Column {
+    /// Returns the columns displayed when none are selected.
+    #[inline]
+    pub fn defaults() -> Vec<Self> {
+        vec![Self::Id, Self::Priority, Self::Status, Self::Content]
+    }
+
+    /// Returns the value of the column for a task.
+    #[inline]
+    pub fn value(&self, task: &Task) -> String {
+        let date = |date: Option<&DateTime<Utc>>| {
+            date.map(|date| date.with_timezone(&Local).format("%Y-%m-%d").to_string())
+                .unwrap_or_default()
+        };
+
+        match *self {
+            Self::Id => task.id.to_string(),
+            Self::Priority => task.priority.to_string(),
+            Self::Status => String::from(if task.checked { "done" } else { "todo" }),
+            Self::Content => task.content.clone(),
+            Self::Tags => task.tags.join(" "),
+            Self::Refs => task.refs.join(" "),
+            Self::Created => date(task.created_at.as_ref()),
+            Self::Updated => date(task.updated_at.as_ref()),
+            Self::Completed => date(task.completed_at.as_ref()),
+            Self::Estimate => task.estimate.map(Task::format_estimate).unwrap_or_default(),
+            Self::Due => date(task.due.as_ref()),
+        }
+    }
+
+    /// Renders the columns of the tasks as aligned lines, where the first
+    /// line has the names of the columns. Ids and estimates are aligned to the
+    /// right and the rest of the values to the left.
+    #[inline]
+    pub fn table(columns: &[Self], tasks: &[Task]) -> Vec<String> {
+        let header: Vec<String> = columns
+            .iter()
+            .map(|column| column.to_string().to_uppercase())
+            .collect();
+
+        let rows: Vec<Vec<String>> = tasks
+            .iter()
+            .map(|task| columns.iter().map(|column| column.value(task)).collect())
+            .collect();
+
+        let widths: Vec<usize> = (0..columns.len())
+            .map(|i| {
+                rows.iter()
+                    .chain([&header])
+                    .filter_map(|row| row.get(i))
+                    .map(|value| value.chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        std::iter::once(header)
+            .chain(rows)
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(columns.iter().zip(&widths))
+                    .map(|(value, (column, &width))| match *column {
+                        Self::Id | Self::Estimate => format!("{value:>width$}"),
+                        Self::Priority
+                        | Self::Status
+                        | Self::Content
+                        | Self::Tags
+                        | Self::Refs
+                        | Self::Created
+                        | Self::Updated
+                        | Self::Completed
+                        | Self::Due => format!("{value:<width$}"),
+                    })
+                    .collect();
+
+                cells.join("  ").trim_end().to_owned()
+            })
+            .collect()
+    }
+
+    /// Formats a list of columns separated by commas, as they are passed to
+    /// the '--columns' flag.
+    #[inline]
+    pub fn join(columns: &[Self]) -> String {
+        let names: Vec<String> = columns.iter().map(ToString::to_string).collect();
+        names.join(",")
+    }
+}
@@ -1,16 +1,23 @@
 //! The core unit for task management.
 
 use std::fmt;
+use std::sync::OnceLock;
 
+use chrono::{DateTime, Duration, Local, NaiveDate, SecondsFormat, Utc};
 use clap::ValueEnum;
-use colored::Colorize as _;
 use serde::{Deserialize, Serialize};
 
+use super::{Colors, Column};
+
+/// Template used by the [`Task`] display, set by [`Task::install_format`].
+static FORMAT: OnceLock<String> = OnceLock::new();
+
 /// Defines errors related to task management.
 pub mod error {
     use std::fmt;
 
     /// Errors related to task management.
+    #[allow(clippy::enum_variant_names)]
     pub enum Error {
         /// Thrown when `task.checked == true` and the user checks it again.
         AlreadyChecked {
@@ -22,20 +29,34 @@ pub mod error {
             /// Identifier of the task.
             id: u32,
         },
+        /// Thrown when `task.pinned == true` and the user pins it again.
+        AlreadyPinned {
+            /// Identifier of the task.
+            id: u32,
+        },
+        /// Thrown when `task.pinned == false` and the user unpins it again.
+        AlreadyUnpinned {
+            /// Identifier of the task.
+            id: u32,
+        },
     }
 
     impl fmt::Display for Error {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match *self {
                 Self::AlreadyChecked { id } => write!(f, "Task {id} was already checked"),
-                Self::AlreadyUnchecked { id } => write!(f, "Task {id} was already unchecked",),
+                Self::AlreadyUnchecked { id } => write!(f, "Task {id} was already unchecked"),
+                Self::AlreadyPinned { id } => write!(f, "Task {id} was already pinned"),
+                Self::AlreadyUnpinned { id } => write!(f, "Task {id} was already unpinned"),
             }
         }
     }
 }
 
 /// Priority of the Task, which is used to define the task's color and importance.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, ValueEnum)]
+///
+/// Variants are ordered from most to least important.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum Priority {
     /// High priority tasks are colored red.
@@ -99,23 +120,51 @@ pub struct Task {
     pub priority: Priority,
     /// Defines wether the task is checked or not.
     pub checked: bool,
+    /// External identifiers linked to the task (e.g.: `JIRA-123` or `gh#42`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub refs: Vec<String>,
+    /// Labels used to group and filter tasks (e.g.: `work` or `home`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Long-form text of the task, which can have several lines.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    /// Moment when the task was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// Last moment when the task was changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Moment when the task was checked, if it is checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Minutes that the task is expected to take.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<u32>,
+    /// Moment until which the task is hidden from 'view'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// Position of the task in the manual order set with 'move'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<u32>,
+    /// Defines wether the task is pinned to the top of the list or not.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+    /// File paths or URLs attached to the task, opened with 'open'.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
+    /// Moment by which the task should be done.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<DateTime<Utc>>,
 }
 
 impl fmt::Display for Task {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let msg = format!("{}. {}", self.id, self.content);
-
-        let colored = match self.priority {
-            Priority::High => msg.red(),
-            Priority::Med => msg.yellow(),
-            Priority::Low => msg.blue(),
-            Priority::None => msg.white(),
-        };
-
-        let bold = colored.bold();
+        let format = FORMAT.get().map_or(Self::DEFAULT_FORMAT, String::as_str);
+        let msg = self.render(format);
 
-        let styled = if self.checked { bold.strikethrough() } else { bold };
+        let styled = Colors::current().paint(&msg, self);
 
         write!(f, "{styled}")
     }
@@ -129,22 +178,248 @@ impl Default for Task {
             content: String::new(),
             priority: Priority::Med,
             checked: false,
+            refs: Vec::new(),
+            tags: Vec::new(),
+            notes: String::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
+            estimate: None,
+            snoozed_until: None,
+            order: None,
+            pinned: false,
+            attachments: Vec::new(),
+            due: None,
         }
     }
 }
 
 impl Task {
+    /// Template used to display tasks if none is set.
+    pub const DEFAULT_FORMAT: &str = "{id}. {content}";
+
     /// Constructor of the `Task` struct.
     #[inline]
     pub const fn new(id: u32, content: String, priority: Priority, checked: bool) -> Self {
-        Self { id, content, priority, checked }
+        Self {
+            id,
+            content,
+            priority,
+            checked,
+            refs: Vec::new(),
+            tags: Vec::new(),
+            notes: String::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
+            estimate: None,
+            snoozed_until: None,
+            order: None,
+            pinned: false,
+            attachments: Vec::new(),
+            due: None,
+        }
     }
 
-    /// Transforms a line with the format `id,content,priority,checked` to a Task.
+    /// Transforms a line with the format `id,content,priority,checked` to a
+    /// Task. The line can be followed by the optional `refs` and `tags` (lists
+    /// of values separated by spaces), `notes` (escaped with [`Task::escape`])
+    /// `created_at`, `updated_at` and `completed_at` (RFC 3339 timestamps),
+    /// `estimate` (minutes), `snoozed_until` (an RFC 3339 timestamp), `order`
+    /// (a position), `pinned` (`true` or empty), `attachments` (see
+    /// [`Task::attachments_field`]) and `due` (an RFC 3339 timestamp).
     #[inline]
     pub fn from<T: AsRef<str>>(line: T) -> Self {
         let (id, content, priority, checked) = Self::split(line.as_ref());
-        Self { id, content, priority, checked }
+
+        let list: Vec<&str> = line.as_ref().split(',').collect();
+
+        let words = |i: usize| -> Vec<String> {
+            list.get(i)
+                .map(|words| words.split_whitespace().map(str::to_owned).collect())
+                .unwrap_or_default()
+        };
+
+        Self {
+            id,
+            content,
+            priority,
+            checked,
+            refs: words(4),
+            tags: words(5),
+            notes: list
+                .get(6)
+                .map(|notes| Self::unescape(notes))
+                .unwrap_or_default(),
+            created_at: list.get(7).and_then(|date| Self::parse_timestamp(date)),
+            updated_at: list.get(8).and_then(|date| Self::parse_timestamp(date)),
+            completed_at: list.get(9).and_then(|date| Self::parse_timestamp(date)),
+            estimate: list.get(10).and_then(|minutes| minutes.trim().parse().ok()),
+            snoozed_until: list.get(11).and_then(|date| Self::parse_timestamp(date)),
+            order: list.get(12).and_then(|order| order.trim().parse().ok()),
+            pinned: list.get(13).is_some_and(|pinned| pinned.trim() == "true"),
+            attachments: list
+                .get(14)
+                .map(|attachments| Self::parse_attachments(attachments))
+                .unwrap_or_default(),
+            due: list.get(15).and_then(|date| Self::parse_timestamp(date)),
+        }
+    }
+
+    /// Parses an RFC 3339 timestamp, returning `None` if it is empty or invalid.
+    #[inline]
+    pub fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(timestamp.trim())
+            .ok()
+            .map(|date| date.with_timezone(&Utc))
+    }
+
+    /// Parses a date passed as an argument, which can be an RFC 3339 timestamp
+    /// or a `YYYY-MM-DD` date (the start of that day in the local timezone).
+    ///
+    /// # Errors
+    /// If the date doesn't have any of the accepted formats.
+    #[inline]
+    pub fn parse_date(date: &str) -> Result<DateTime<Utc>, String> {
+        if let Some(timestamp) = Self::parse_timestamp(date) {
+            return Ok(timestamp);
+        }
+
+        NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+            .ok()
+            .and_then(|day| day.and_hms_opt(0, 0, 0))
+            .and_then(|day| day.and_local_timezone(Local).earliest())
+            .map(|day| day.with_timezone(&Utc))
+            .ok_or_else(|| format!("'{date}' is not a YYYY-MM-DD date or an RFC 3339 timestamp"))
+    }
+
+    /// Parses an amount of minutes (`m`), hours (`h`), days (`d`) or weeks
+    /// (`w`), like `30m` or `7d`. Returns `None` if it isn't an amount of time.
+    #[inline]
+    pub fn parse_duration(value: &str) -> Option<Duration> {
+        let value = value.trim();
+
+        value
+            .char_indices()
+            .last()
+            .and_then(|(i, unit)| Some((value.get(..i)?.parse::<i64>().ok()?, unit)))
+            .and_then(|(amount, unit)| match unit {
+                'm' => Duration::try_minutes(amount),
+                'h' => Duration::try_hours(amount),
+                'd' => Duration::try_days(amount),
+                'w' => Duration::try_weeks(amount),
+                _ => None,
+            })
+    }
+
+    /// Parses the end of a snooze, passed as an amount of time after now
+    /// (see [`Task::parse_duration`]) or as a date (see [`Task::parse_date`]).
+    ///
+    /// # Errors
+    /// If the value isn't an amount of time or a date.
+    #[inline]
+    pub fn parse_until(value: &str) -> Result<DateTime<Utc>, String> {
+        Self::parse_duration(value)
+            .and_then(|duration| Utc::now().checked_add_signed(duration))
+            .map_or_else(
+                || {
+                    Self::parse_date(value).map_err(|_| {
+                        format!("'{value}' is not an amount of time (e.g.: '2d' or '3h') or a date")
+                    })
+                },
+                Ok,
+            )
+    }
+
+    /// Formats a timestamp as RFC 3339 (e.g.: `2025-01-31T18:30:00Z`), or
+    /// returns an empty string if there is none.
+    #[inline]
+    pub fn format_timestamp(timestamp: Option<&DateTime<Utc>>) -> String {
+        timestamp.map_or_else(String::new, |date| date.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    /// Parses an estimate passed as an argument, which can be an amount of
+    /// hours and minutes (e.g.: `30m`, `2h` or `1h30m`) or a number of minutes.
+    /// Returns `0` for `none`, which removes the estimate of tasks.
+    ///
+    /// # Errors
+    /// If the estimate doesn't have any of the accepted formats.
+    #[inline]
+    pub fn parse_estimate(estimate: &str) -> Result<u32, String> {
+        let err = || format!("'{estimate}' is not an estimate like '30m', '2h' or '1h30m'");
+        let value = estimate.trim().to_lowercase();
+
+        if value == "none" {
+            return Ok(0);
+        }
+
+        if let Ok(minutes) = value.parse() {
+            return Ok(minutes);
+        }
+
+        let (hours, rest) = value.split_once('h').unwrap_or(("0", &value));
+        let minutes = rest.strip_suffix('m').unwrap_or(rest);
+
+        // Minutes without their unit are only accepted on their own (e.g.: `1h30` isn't)
+        if !value.contains(|c: char| c.is_ascii_digit()) || (rest == minutes && !rest.is_empty()) {
+            return Err(err());
+        }
+
+        let parse = |n: &str| if n.is_empty() { Some(0) } else { n.parse::<u32>().ok() };
+
+        parse(hours)
+            .zip(parse(minutes))
+            .and_then(|(hours, minutes)| hours.checked_mul(60)?.checked_add(minutes))
+            .ok_or_else(err)
+    }
+
+    /// Formats an amount of minutes as hours and minutes (e.g.: `1h30m`).
+    #[inline]
+    pub fn format_estimate(minutes: u32) -> String {
+        match (minutes / 60, minutes % 60) {
+            (0, minutes) => format!("{minutes}m"),
+            (hours, 0) => format!("{hours}h"),
+            (hours, minutes) => format!("{hours}h{minutes}m"),
+        }
+    }
+
+    /// Escapes the characters that can't be stored inside of a single field
+    /// of a line (`%`, `,`, tabs and line breaks) with their percent-encoding.
+    #[inline]
+    pub fn escape(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                '%' | ',' | '\t' | '\n' | '\r' => format!("%{:02X}", u32::from(c)),
+                _ => c.to_string(),
+            })
+            .collect()
+    }
+
+    /// Reverts the escaping made by [`Task::escape`].
+    #[inline]
+    pub fn unescape(text: &str) -> String {
+        let mut bytes = Vec::with_capacity(text.len());
+        let mut rest = text.as_bytes();
+
+        while let Some((&byte, tail)) = rest.split_first() {
+            let decoded = tail
+                .get(..2)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+            match decoded {
+                Some(value) if byte == b'%' => {
+                    bytes.push(value);
+                    rest = tail.get(2..).unwrap_or_default();
+                }
+                Some(_) | None => {
+                    bytes.push(byte);
+                    rest = tail;
+                }
+            }
+        }
+
+        String::from_utf8_lossy(&bytes).into_owned()
     }
 
     /// Splits a line with the format `id,content,priority,checked` and handles each value.
@@ -173,10 +448,279 @@ impl Task {
         (id, content, priority, checked)
     }
 
-    /// Formats the Task into a String.
+    /// Returns the optional fields formatted to be stored in a line, without
+    /// the trailing ones that are empty.
+    fn lists(&self) -> Vec<String> {
+        let mut lists = vec![
+            self.refs.join(" "),
+            self.tags.join(" "),
+            Self::escape(&self.notes),
+            Self::format_timestamp(self.created_at.as_ref()),
+            Self::format_timestamp(self.updated_at.as_ref()),
+            Self::format_timestamp(self.completed_at.as_ref()),
+            self.estimate_field(),
+            Self::format_timestamp(self.snoozed_until.as_ref()),
+            self.order_field(),
+            self.pinned_field(),
+            self.attachments_field(),
+            Self::format_timestamp(self.due.as_ref()),
+        ];
+
+        while lists.last().is_some_and(String::is_empty) {
+            lists.pop();
+        }
+
+        lists
+    }
+
+    /// Returns the fields of the task in the order of the columns of a CSV
+    /// file. The optional fields are only added if the task has any.
+    #[inline]
+    pub fn fields(&self) -> Vec<String> {
+        let fields = vec![
+            self.id.to_string(),
+            self.content.clone(),
+            self.priority.to_string(),
+            self.checked.to_string(),
+        ];
+
+        [fields, self.lists()].concat()
+    }
+
+    /// Formats the Task into a String. The optional fields are only added if
+    /// the task has any.
     #[inline]
     pub fn as_line(&self) -> String {
-        format!("{},{},{},{}", self.id, self.content, self.priority, self.checked)
+        self.fields().join(",")
+    }
+
+    /// Formats the Task into tab-separated fields without styles. Tabs and
+    /// line breaks inside the content are replaced by spaces, and the
+    /// optional fields are only added if the task has any.
+    #[inline]
+    pub fn as_tsv(&self) -> String {
+        let content = self.content.replace(['\t', '\n', '\r'], " ");
+
+        let line = format!("{}\t{}\t{}\t{}", self.id, content, self.priority, self.checked);
+
+        [vec![line], self.lists()].concat().join("\t")
+    }
+
+    /// Returns the estimate as it is stored (a number of minutes), or an empty
+    /// string if the task has none.
+    #[inline]
+    pub fn estimate_field(&self) -> String {
+        self.estimate
+            .map(|minutes| minutes.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Returns the manual position as it is stored, or an empty string if the
+    /// task has none.
+    #[inline]
+    pub fn order_field(&self) -> String {
+        self.order
+            .map(|order| order.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` as it is stored if the task is pinned, or an empty string
+    /// if it isn't.
+    #[inline]
+    pub fn pinned_field(&self) -> String {
+        if self.pinned {
+            String::from("true")
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns the attachments as they are stored: separated by spaces and
+    /// escaped with [`Task::escape`], with their own spaces as `%20`.
+    #[inline]
+    pub fn attachments_field(&self) -> String {
+        self.attachments
+            .iter()
+            .map(|attachment| Self::escape(attachment).replace(' ', "%20"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Parses the attachments stored by [`Task::attachments_field`].
+    #[inline]
+    pub fn parse_attachments(field: &str) -> Vec<String> {
+        field.split_whitespace().map(Self::unescape).collect()
+    }
+
+    /// Formats the estimate of the task (e.g.: ` ~1h30m`), or returns an empty
+    /// string if it has none.
+    #[inline]
+    pub fn estimate_badge(&self) -> String {
+        self.estimate
+            .map(|minutes| format!(" ~{}", Self::format_estimate(minutes)))
+            .unwrap_or_default()
+    }
+
+    /// Returns the name and the value of every field of the task that has one,
+    /// except the notes, as displayed by the 'show' command. Dates are
+    /// formatted in the local timezone.
+    #[inline]
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let date = |date: Option<&DateTime<Utc>>| {
+            date.map(|date| {
+                date.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default()
+        };
+
+        let status = if self.checked { "done" } else { "todo" };
+
+        let fields = [
+            ("Content", self.content.clone()),
+            ("Priority", self.priority.to_string()),
+            ("Status", String::from(status)),
+            ("Pinned", String::from(if self.pinned { "yes" } else { "" })),
+            ("Tags", self.tags.join(" ")),
+            ("Refs", self.refs.join(" ")),
+            ("Attached", self.attachments.join(", ")),
+            ("Due", date(self.due.as_ref())),
+            ("Estimate", self.estimate.map(Self::format_estimate).unwrap_or_default()),
+            ("Snoozed", date(self.snoozed_until.filter(|_| self.is_snoozed()).as_ref())),
+            ("Order", self.order_field()),
+            ("Created", date(self.created_at.as_ref())),
+            ("Updated", date(self.updated_at.as_ref())),
+            ("Completed", date(self.completed_at.as_ref())),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .collect()
+    }
+
+    /// Returns `true` if the task is snoozed until a moment that hasn't come yet.
+    #[inline]
+    pub fn is_snoozed(&self) -> bool {
+        self.snoozed_until.is_some_and(|until| until > Utc::now())
+    }
+
+    /// Formats the end of the snooze of the task in the local timezone (e.g.:
+    /// ` (snoozed until 2025-01-31 18:30)`), or returns an empty string if it
+    /// isn't snoozed.
+    #[inline]
+    pub fn snooze_badge(&self) -> String {
+        self.snoozed_until
+            .filter(|_| self.is_snoozed())
+            .map(|until| {
+                let until = until.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+                format!(" (snoozed until {until})")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the days left until the due date of the task, counted between
+    /// local dates (`0` if it is due today and negative if it is overdue), or
+    /// `None` if it has no due date.
+    #[inline]
+    pub fn days_left(&self) -> Option<i64> {
+        let today = Local::now().date_naive();
+
+        self.due
+            .map(|due| (due.with_timezone(&Local).date_naive() - today).num_days())
+    }
+
+    /// Returns `true` if the task is unchecked and its due date was before today.
+    #[inline]
+    pub fn is_overdue(&self) -> bool {
+        !self.checked && self.days_left().is_some_and(|days| days < 0)
+    }
+
+    /// Formats the due date of an unchecked task relative to today (e.g.:
+    /// ` (due in 3d)`, ` (due today)` or ` (overdue 2d)`), or returns an empty
+    /// string if it is checked or has no due date.
+    #[inline]
+    pub fn due_badge(&self) -> String {
+        match self.days_left().filter(|_| !self.checked) {
+            None => String::new(),
+            Some(0) => String::from(" (due today)"),
+            Some(days) if days < 0 => format!(" (overdue {}d)", -days),
+            Some(days) => format!(" (due in {days}d)"),
+        }
+    }
+
+    /// Formats the tags of the task as hashtags (e.g.: ` #work #home`), or
+    /// returns an empty string if it has none.
+    #[inline]
+    pub fn hashtags(&self) -> String {
+        if self.tags.is_empty() {
+            String::new()
+        } else {
+            format!(" #{}", self.tags.join(" #"))
+        }
+    }
+
+    /// Sets the template used to display tasks for the rest of the process. It
+    /// does nothing if a template was already set, so the '--format' flag,
+    /// which is set first, overrides the config.
+    #[inline]
+    pub fn install_format(format: String) {
+        let _ = FORMAT.set(format);
+    }
+
+    /// Replaces the placeholders of a template with the values of the task.
+    ///
+    /// Placeholders are the names of the columns of 'view --table' between
+    /// braces (e.g.: `{id}`, `{status}` or `{created}`). Unknown placeholders
+    /// are kept as they are, and `{{` and `}}` are written as single braces.
+    #[inline]
+    pub fn render(&self, template: &str) -> String {
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(i) = rest.find(['{', '}']) {
+            let (text, tail) = rest.split_at(i);
+            rendered.push_str(text);
+
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                rendered.push_str(&tail[..1]);
+                rest = &tail[2..];
+                continue;
+            }
+
+            let placeholder = tail
+                .strip_prefix('{')
+                .and_then(|tail| tail.split_once('}'))
+                .and_then(|(name, after)| {
+                    Column::from_str(name.trim(), true).ok().zip(Some(after))
+                });
+
+            if let Some((column, after)) = placeholder {
+                rendered.push_str(&column.value(self));
+                rest = after;
+            } else {
+                rendered.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+
+        rendered.push_str(rest);
+        rendered
+    }
+
+    /// Formats the Task into a Markdown checklist item, adding the priority as
+    /// a badge unless it is `none`.
+    #[inline]
+    pub fn as_markdown(&self) -> String {
+        let mark = if self.checked { "x" } else { " " };
+
+        match self.priority {
+            Priority::High | Priority::Med | Priority::Low => {
+                format!("- [{mark}] `{}` {}", self.priority, self.content)
+            }
+            Priority::None => format!("- [{mark}] {}", self.content),
+        }
     }
 
     /// Marks the task as checked.
@@ -206,4 +750,32 @@ impl Task {
             Err(error::Error::AlreadyUnchecked { id: self.id })
         }
     }
+
+    /// Pins the task to the top of the list.
+    ///
+    /// # Errors
+    /// - The task is already pinned.
+    #[inline]
+    pub const fn pin(&mut self) -> Result<&Self, error::Error> {
+        if self.pinned {
+            Err(error::Error::AlreadyPinned { id: self.id })
+        } else {
+            self.pinned = true;
+            Ok(self)
+        }
+    }
+
+    /// Unpins the task, so it goes back to its place in the list.
+    ///
+    /// # Errors
+    /// - The task is already unpinned.
+    #[inline]
+    pub const fn unpin(&mut self) -> Result<&Self, error::Error> {
+        if self.pinned {
+            self.pinned = false;
+            Ok(self)
+        } else {
+            Err(error::Error::AlreadyUnpinned { id: self.id })
+        }
+    }
 }
@@ -1,10 +1,18 @@
 //! Collection of existing tasks. This is where major task management is made.
 
-use super::Priority;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SubsecRound as _, Utc};
+use clap::ValueEnum as _;
+use colored::Colorize as _;
+
+use super::{Colors, Column, GroupBy, Links, Priority, SortBy, Stats, Urgency};
 use crate::cli::subcommands as sub;
 use crate::config::Config;
 use crate::models::task::Task;
 use crate::traits::Persister;
+use crate::{warning, Action, Output};
 
 /// Contains all the Tasks.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -32,7 +40,7 @@ impl Todo {
     /// Creates a `Todo` instance from a vector of tasks.
     #[inline]
     pub fn new<T: Into<Vec<Task>>>(tasks: T) -> Self {
-        Self { tasks: tasks.into() }
+        Self { tasks: Self::arrange(tasks.into()) }
     }
 
     /// Creates a `Todo` instance from a persister's contents.
@@ -41,7 +49,66 @@ impl Todo {
     /// - The tasks can't be obtained from the persister.
     #[inline]
     pub fn from(persister: &dyn Persister) -> crate::Result<Self> {
-        Ok(Self { tasks: persister.tasks()? })
+        Ok(Self { tasks: Self::arrange(persister.tasks()?) })
+    }
+
+    /// Creates a `Todo` instance from an iterator of tasks (see
+    /// [`Persister::stream`]), keeping only the first `limit` tasks in the
+    /// order they are displayed, like [`Todo::truncate`] does.
+    ///
+    /// Only `limit` tasks are kept in memory at once, so showing the first
+    /// tasks of a large list doesn't load all of them.
+    ///
+    /// # Errors
+    /// - A task can't be read.
+    #[inline]
+    pub fn from_stream<I>(tasks: I, limit: Option<usize>) -> crate::Result<Self>
+    where
+        I: Iterator<Item = crate::Result<Task>>,
+    {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut kept = BTreeMap::new();
+
+        for (index, task) in tasks.enumerate() {
+            let task = task?;
+
+            kept.insert((!task.pinned, task.order.is_none(), task.order, index), task);
+
+            if kept.len() > limit {
+                kept.pop_last();
+            }
+        }
+
+        let mut kept: Vec<_> = kept.into_iter().collect();
+        kept.sort_by_key(|((.., index), _)| *index);
+
+        Ok(Self::new(
+            kept.into_iter()
+                .map(|(_, task)| task)
+                .collect::<Vec<Task>>(),
+        ))
+    }
+
+    /// Sorts tasks by their manual order (see [`Todo::move_to`]). Tasks
+    /// without one go after the rest, keeping their relative order.
+    fn arrange(mut tasks: Vec<Task>) -> Vec<Task> {
+        tasks.sort_by_key(|task| (task.order.is_none(), task.order));
+        tasks
+    }
+
+    /// Returns the ids passed that don't match any task, without repeating
+    /// them.
+    #[inline]
+    pub fn missing(&self, ids: &[u32]) -> Vec<u32> {
+        let mut missing = Vec::new();
+
+        for id in ids {
+            if !missing.contains(id) && self.tasks.iter().all(|task| task.id != *id) {
+                missing.push(*id);
+            }
+        }
+
+        missing
     }
 
     /// Returns tasks based on the ids passed.
@@ -73,37 +140,520 @@ impl Todo {
         ])
     }
 
-    /// Shows the current list of tasks.
+    /// Initializes a `Todo` instance with `count` fake tasks of varied
+    /// contents, priorities and states.
+    ///
+    /// The tasks are generated from `seed`, so the same seed always returns
+    /// the same tasks.
+    #[inline]
+    pub fn generate(count: usize, seed: u64) -> Self {
+        const VERBS: [&str; 10] =
+            ["Review", "Write", "Fix", "Plan", "Update", "Call", "Buy", "Clean", "Book", "Test"];
+        const OBJECTS: [&str; 10] = [
+            "the release notes",
+            "the quarterly budget",
+            "the login bug",
+            "the team meeting",
+            "the dentist",
+            "groceries for the week",
+            "the project roadmap",
+            "the database backups",
+            "the flight to Berlin",
+            "the onboarding guide",
+        ];
+        const PRIORITIES: [Priority; 8] = [
+            Priority::High,
+            Priority::Med,
+            Priority::Med,
+            Priority::Low,
+            Priority::Low,
+            Priority::Low,
+            Priority::None,
+            Priority::None,
+        ];
+
+        let mut state = seed;
+
+        // SplitMix64, which is more than enough to get varied fake data.
+        let mut random = |len: usize| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+
+            usize::try_from(z % u64::try_from(len).unwrap_or(u64::MAX)).unwrap_or_default()
+        };
+
+        let tasks = (1..)
+            .zip(0..count)
+            .map(|(id, _)| {
+                let verb = VERBS[random(VERBS.len())];
+                let object = OBJECTS[random(OBJECTS.len())];
+                let priority = PRIORITIES[random(PRIORITIES.len())].clone();
+                let checked = random(10) < 3;
+
+                Task::new(id, format!("{verb} {object}"), priority, checked)
+            })
+            .collect::<Vec<_>>();
+
+        Self::new(tasks)
+    }
+
+    /// Shows the current list of tasks, except the snoozed ones.
     ///
     /// # Errors
     /// - There are no tasks stored in the instance.
     #[inline]
     pub fn view(&self) -> crate::Result<()> {
+        let awake = self.awake();
+
+        if awake.tasks.is_empty() && !self.tasks.is_empty() {
+            warning!("All tasks are snoozed; use 'view --show-snoozed' to display them");
+            return Ok(());
+        }
+
+        awake.view_as(&Output::Plain)
+    }
+
+    /// Returns a copy of the list without the tasks that are snoozed.
+    #[inline]
+    #[must_use]
+    pub fn awake(&self) -> Self {
+        let tasks = self.tasks.iter().filter(|task| !task.is_snoozed()).cloned();
+        Self::new(tasks.collect::<Vec<_>>())
+    }
+
+    /// Shows the current list of tasks using the passed output format.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn view_as(&self, output: &Output) -> crate::Result<()> {
+        self.view_with(output, &Links::default())
+    }
+
+    /// Shows the current list of tasks using the passed output format,
+    /// linking their references with the passed URL templates.
+    ///
+    /// The `json` format prints every task in a single array, which is empty
+    /// if there are no tasks.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    /// - The tasks can't be serialized as JSON.
+    #[inline]
+    pub fn view_with(&self, output: &Output, links: &Links) -> crate::Result<()> {
+        print!("{}", self.render_with(output, links)?);
+        Ok(())
+    }
+
+    /// Formats the tasks like [`Todo::view_with`] displays them, ending each
+    /// line with a line break.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    /// - The tasks can't be serialized as JSON.
+    #[inline]
+    pub fn render_with(&self, output: &Output, links: &Links) -> crate::Result<String> {
+        let displayed = self.displayed();
+
+        if *output == Output::Json {
+            return Ok(displayed.as_json()? + "\n");
+        }
+
         if self.tasks.is_empty() {
-            let err = "There are no tasks to print";
-            return Err(crate::Error::wrap(err));
+            return Err(crate::Error::NoTasks("print"));
         }
 
-        self.tasks.iter().for_each(|task| println!("{task}"));
+        let items: Vec<String> = displayed
+            .tasks
+            .iter()
+            .map(|task| match *output {
+                Output::Plain => {
+                    let badges = task.hashtags()
+                        + &task.estimate_badge()
+                        + &task.due_badge()
+                        + &task.snooze_badge();
+                    format!("{task}{}{}\n", badges.dimmed(), links.plain(&task.refs))
+                }
+                Output::Markdown => Self::markdown_item(task, links) + "\n",
+                Output::Tsv => task.as_tsv() + "\n",
+                Output::Csv => task.as_line() + "\n",
+                Output::Json => unreachable!(),
+            })
+            .collect();
+
+        Ok(items.concat())
+    }
+
+    /// Formats the tasks as a Markdown checklist, one item per line, with
+    /// their references linked with `links`.
+    #[inline]
+    pub fn as_markdown(&self, links: &Links) -> String {
+        let items: Vec<String> = self
+            .tasks
+            .iter()
+            .map(|task| Self::markdown_item(task, links) + "\n")
+            .collect();
+
+        items.concat()
+    }
 
+    /// Shows the current list of tasks as a table with the passed columns,
+    /// where every row is styled like the [`Task`] display.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn view_table(&self, columns: &[Column]) -> crate::Result<()> {
+        print!("{}", self.render_table(columns)?);
         Ok(())
     }
 
+    /// Formats the tasks like [`Todo::view_table`] displays them, ending each
+    /// line with a line break.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn render_table(&self, columns: &[Column]) -> crate::Result<String> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("print"));
+        }
+
+        let palette = Colors::current();
+        let displayed = self.displayed();
+
+        let lines: Vec<String> = Column::table(columns, &displayed.tasks)
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                // The first line is the header, which doesn't belong to a task
+                i.checked_sub(1)
+                    .and_then(|i| displayed.tasks.get(i))
+                    .map_or_else(
+                        || format!("{}\n", line.bold()),
+                        |task| format!("{}\n", palette.paint(line, task)),
+                    )
+            })
+            .collect();
+
+        Ok(lines.concat())
+    }
+
+    /// Keeps the first `n` tasks in the order they are displayed (see
+    /// [`Todo::render_with`]), without changing their order.
+    #[inline]
+    pub fn truncate(&mut self, n: usize) {
+        let shown: Vec<u32> = self
+            .displayed()
+            .tasks
+            .iter()
+            .take(n)
+            .map(|task| task.id)
+            .collect();
+
+        self.tasks.retain(|task| shown.contains(&task.id));
+    }
+
+    /// Returns a copy of the list in the order it is displayed, with the
+    /// pinned tasks before the rest. The stored order isn't changed, so
+    /// unpinned tasks go back to their place.
+    fn displayed(&self) -> Self {
+        let (pinned, rest): (Vec<Task>, Vec<Task>) =
+            self.tasks.iter().cloned().partition(|task| task.pinned);
+
+        Self {
+            tasks: pinned.into_iter().chain(rest).collect(),
+        }
+    }
+
+    /// Formats the tasks as a JSON array.
+    ///
+    /// # Errors
+    /// - The tasks can't be serialized.
+    #[inline]
+    pub fn as_json(&self) -> crate::Result<String> {
+        serde_json::to_string_pretty(&self.tasks).map_err(crate::Error::wrap)
+    }
+
+    /// Formats a task as a Markdown checklist item, followed by its tags and
+    /// references.
+    fn markdown_item(task: &Task, links: &Links) -> String {
+        let (tags, refs) = (task.hashtags(), links.markdown(&task.refs));
+        format!("{}{tags}{refs}", task.as_markdown())
+    }
+
+    /// Returns the number of tasks and checked tasks of the list, and the sum
+    /// of their estimates.
+    #[inline]
+    pub fn stats(&self) -> Stats {
+        let checked = self.tasks.iter().filter(|task| task.checked).count();
+        let minutes = |unchecked: bool| -> u32 {
+            self.tasks
+                .iter()
+                .filter(|task| !(unchecked && task.checked))
+                .filter_map(|task| task.estimate)
+                .fold(0, u32::saturating_add)
+        };
+
+        Stats {
+            estimate: minutes(false),
+            remaining: minutes(true),
+            ..Stats::new("all", self.tasks.len(), checked)
+        }
+    }
+
+    /// Returns the number of tasks and checked tasks of each group.
+    ///
+    /// Groups without tasks are included, so the result always has the same
+    /// rows for the same criteria.
+    #[inline]
+    pub fn stats_by(&self, by: &GroupBy) -> Vec<Stats> {
+        match *by {
+            GroupBy::Priority => Priority::value_variants()
+                .iter()
+                .map(|priority| {
+                    let todo = Self::new(
+                        self.tasks
+                            .iter()
+                            .filter(|t| &t.priority == priority)
+                            .cloned()
+                            .collect::<Vec<Task>>(),
+                    );
+
+                    Stats {
+                        label: priority.to_string(),
+                        ..todo.stats()
+                    }
+                })
+                .collect(),
+            GroupBy::Tag | GroupBy::Status => self
+                .grouped(by)
+                .into_iter()
+                .map(|(label, todo)| Stats { label, ..todo.stats() })
+                .collect(),
+        }
+    }
+
+    /// Splits the tasks into groups, returned with their names in the order
+    /// they are displayed. Tasks keep their relative order inside each group.
+    ///
+    /// Groups are sorted by priority (from `high` to `none`), by tag name (with
+    /// the tasks without tags in an `untagged` group at the end) or by status
+    /// (`todo` before `done`). Groups without tasks are omitted.
+    #[inline]
+    pub fn grouped(&self, by: &GroupBy) -> Vec<(String, Self)> {
+        let group = |label: String, matches: &dyn Fn(&Task) -> bool| {
+            let tasks: Vec<Task> = self.tasks.iter().filter(|t| matches(t)).cloned().collect();
+            (label, Self::new(tasks))
+        };
+
+        let groups: Vec<(String, Self)> = match *by {
+            GroupBy::Priority => Priority::value_variants()
+                .iter()
+                .map(|priority| group(priority.to_string(), &|t| &t.priority == priority))
+                .collect(),
+            GroupBy::Tag => {
+                let mut tags: Vec<&String> = self.tasks.iter().flat_map(|t| &t.tags).collect();
+                tags.sort();
+                tags.dedup();
+
+                tags.into_iter()
+                    .map(|tag| group(tag.clone(), &|t| t.tags.contains(tag)))
+                    .chain(std::iter::once(group(String::from("untagged"), &|t| t.tags.is_empty())))
+                    .collect()
+            }
+            GroupBy::Status => vec![
+                group(String::from("todo"), &|t| !t.checked),
+                group(String::from("done"), &|t| t.checked),
+            ],
+        };
+
+        groups
+            .into_iter()
+            .filter(|(_, todo)| !todo.tasks.is_empty())
+            .collect()
+    }
+
+    /// Groups the unchecked tasks that aren't snoozed by their due date, in the
+    /// `overdue`, `today`, `tomorrow`, `this week` (due in the next 7 days),
+    /// `later` and `no date` groups. Tasks are sorted by their due date and
+    /// groups without tasks are omitted.
+    ///
+    /// If `week` is `true`, only the tasks due in the next 7 days (including
+    /// the overdue ones) are grouped.
+    #[inline]
+    pub fn agenda(&self, week: bool) -> Vec<(String, Self)> {
+        let labels = ["overdue", "today", "tomorrow", "this week", "later", "no date"];
+
+        let label = |days: Option<i64>| match days {
+            None => "no date",
+            Some(days) if days < 0 => "overdue",
+            Some(0) => "today",
+            Some(1) => "tomorrow",
+            Some(2..=6) => "this week",
+            Some(_) => "later",
+        };
+
+        let mut tasks: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|task| !task.checked && !task.is_snoozed())
+            .collect();
+
+        tasks.sort_by_key(|task| (task.due.is_none(), task.due));
+
+        labels
+            .into_iter()
+            .take(if week { 4 } else { labels.len() })
+            .map(|name| {
+                let group: Vec<Task> = tasks
+                    .iter()
+                    .filter(|task| label(task.days_left()) == name)
+                    .map(|task| (*task).clone())
+                    .collect();
+
+                (String::from(name), Self::new(group))
+            })
+            .filter(|(_, todo)| !todo.tasks.is_empty())
+            .collect()
+    }
+
+    /// Returns up to `count` unchecked tasks, sorted from most to least urgent.
+    ///
+    /// Tasks with the same score keep the order of the list.
+    #[inline]
+    pub fn next(&self, urgency: &Urgency, count: usize) -> Vec<&Task> {
+        let mut tasks: Vec<&Task> = self.tasks.iter().filter(|task| !task.checked).collect();
+
+        tasks.sort_by_key(|task| Reverse(urgency.score(task)));
+        tasks.truncate(count);
+
+        tasks
+    }
+
+    /// Sorts the unchecked tasks by priority, moves the checked tasks to the
+    /// bottom of the list and renumbers the ids starting from `1`.
+    ///
+    /// Tasks with the same priority and status keep their relative order.
+    #[inline]
+    pub fn tidy(&mut self) {
+        self.tasks
+            .sort_by(|a, b| (a.checked, &a.priority).cmp(&(b.checked, &b.priority)));
+
+        for (id, task) in (1..).zip(self.tasks.iter_mut()) {
+            task.id = id;
+            task.order = None;
+        }
+    }
+
+    /// Sorts the tasks by a field, or in the opposite order if `reverse` is
+    /// `true`. Ids are not changed.
+    ///
+    /// Pinned tasks are placed before the rest, tasks without the timestamp
+    /// used to sort (e.g.: tasks without a due date) are placed after the
+    /// rest in both directions, and tasks with equal values keep their
+    /// relative order.
+    #[inline]
+    pub fn sort_by(&mut self, field: &SortBy, reverse: bool) {
+        let by_date = |a: Option<&DateTime<Utc>>, b: Option<&DateTime<Utc>>| {
+            (a.is_none().cmp(&b.is_none()), a.cmp(&b))
+        };
+
+        self.tasks.sort_by(|a, b| {
+            let (missing, order) = match *field {
+                SortBy::Id => (Ordering::Equal, a.id.cmp(&b.id)),
+                SortBy::Priority => (Ordering::Equal, a.priority.cmp(&b.priority)),
+                SortBy::Content => {
+                    (Ordering::Equal, a.content.to_lowercase().cmp(&b.content.to_lowercase()))
+                }
+                SortBy::Checked => (Ordering::Equal, a.checked.cmp(&b.checked)),
+                SortBy::Created => by_date(a.created_at.as_ref(), b.created_at.as_ref()),
+                SortBy::Updated => by_date(a.updated_at.as_ref(), b.updated_at.as_ref()),
+                SortBy::Completed => by_date(a.completed_at.as_ref(), b.completed_at.as_ref()),
+                SortBy::Due => by_date(a.due.as_ref(), b.due.as_ref()),
+            };
+
+            // Pinned tasks stay at the top and tasks without the timestamp at
+            // the bottom, whatever the direction
+            b.pinned.cmp(&a.pinned).then(missing).then(if reverse {
+                order.reverse()
+            } else {
+                order
+            })
+        });
+    }
+
     /// Adds a task to the task list.
     #[inline]
     pub fn add(&mut self, task: Task) {
         self.tasks.push(task);
     }
 
+    /// Updates the timestamps of tasks (selected by using `ids`) after an
+    /// action is applied to them.
+    ///
+    /// Every action sets `updated_at`, checking a task also sets
+    /// `completed_at` and unchecking it removes it. Dropped tasks aren't changed.
+    #[inline]
+    pub fn stamp(&mut self, ids: &[u32], action: &Action) {
+        let now = Utc::now().trunc_subsecs(0);
+
+        for task in self.get_mut(ids) {
+            match action {
+                Action::Drop => continue,
+                Action::Check => task.completed_at = Some(now),
+                Action::Uncheck => task.completed_at = None,
+                Action::SetContent
+                | Action::SetPriority
+                | Action::SetRefs
+                | Action::SetTags
+                | Action::SetNotes
+                | Action::SetEstimate
+                | Action::Snooze
+                | Action::Move
+                | Action::Pin
+                | Action::Unpin
+                | Action::Attach
+                | Action::SetDue => {}
+            }
+
+            task.updated_at = Some(now);
+        }
+    }
+
+    /// Appends tasks to the list, giving them new ids after the last task.
+    /// Returns the ids assigned to the appended tasks.
+    #[inline]
+    pub fn append(&mut self, tasks: Vec<Task>) -> Vec<u32> {
+        let next = self.tasks.last().map_or(1, |last| last.id + 1);
+        let mut ids = vec![];
+
+        for (id, mut task) in (next..).zip(tasks) {
+            task.id = id;
+            ids.push(id);
+            self.tasks.push(task);
+        }
+
+        ids
+    }
+
     /// Changes values of tasks based on the `set` subcommand used.
     ///
     /// # Errors
-    /// - Bubbled up from [`Todo::set_priority`] or [`Todo::set_content`].
+    /// - Bubbled up from the `set_*` methods of [`Todo`].
     #[inline]
     pub fn set(&mut self, cmnd: &sub::Set) -> crate::Result<()> {
         match cmnd {
             sub::Set::Priority(args) => self.set_priority(&args.ids, &args.priority),
             sub::Set::Content(args) => self.set_content(&args.ids, &args.content),
+            sub::Set::Ref(args) => self.set_refs(&args.ids, &args.refs),
+            sub::Set::Tags(args) => self.set_tags(&args.ids, &args.tags),
+            sub::Set::Estimate(args) => self.set_estimate(&args.ids, args.estimate),
+            sub::Set::Due(args) => self.set_due(&args.ids, args.on),
         }
     }
 
@@ -114,8 +664,7 @@ impl Todo {
     #[inline]
     pub fn set_priority(&mut self, ids: &[u32], priority: &Priority) -> crate::Result<()> {
         if self.tasks.is_empty() {
-            let err = "There are no tasks to edit";
-            return Err(crate::Error::wrap(err));
+            return Err(crate::Error::NoTasks("edit"));
         }
 
         for task in self.get_mut(ids) {
@@ -125,6 +674,115 @@ impl Todo {
         Ok(())
     }
 
+    /// Changes the `estimate` property of tasks (selected by using `ids`), in
+    /// minutes. Passing `0` removes it.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn set_estimate(&mut self, ids: &[u32], minutes: u32) -> crate::Result<()> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("edit"));
+        }
+
+        for task in self.get_mut(ids) {
+            task.estimate = (minutes > 0).then_some(minutes);
+        }
+
+        Ok(())
+    }
+
+    /// Changes the `due` property of tasks (selected by using `ids`). Passing
+    /// `None` removes it.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn set_due(&mut self, ids: &[u32], due: Option<DateTime<Utc>>) -> crate::Result<()> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("edit"));
+        }
+
+        for task in self.get_mut(ids) {
+            task.due = due;
+        }
+
+        Ok(())
+    }
+
+    /// Moves a task to a position of the list (starting at 0, or the end if
+    /// it is greater than the number of tasks), and saves the new order of
+    /// every task in its `order`.
+    ///
+    /// Returns the ids of the tasks whose `order` changed.
+    ///
+    /// # Errors
+    /// - There is no task with the passed id.
+    #[inline]
+    pub fn move_to(&mut self, id: u32, position: usize) -> crate::Result<Vec<u32>> {
+        let Some(index) = self.tasks.iter().position(|task| task.id == id) else {
+            return Err(crate::Error::TaskNotFound(vec![id]));
+        };
+
+        let task = self.tasks.remove(index);
+        self.tasks.insert(position.min(self.tasks.len()), task);
+
+        let mut changed_ids = Vec::new();
+
+        for (order, task) in (1..).zip(self.tasks.iter_mut()) {
+            if task.order != Some(order) {
+                task.order = Some(order);
+                changed_ids.push(task.id);
+            }
+        }
+
+        Ok(changed_ids)
+    }
+
+    /// Moves a task right before or, if `after` is `true`, right after
+    /// another one (see [`Todo::move_to`]).
+    ///
+    /// # Errors
+    /// - Any of the tasks doesn't exist.
+    /// - Both ids are the same.
+    #[inline]
+    pub fn move_next_to(&mut self, id: u32, other: u32, after: bool) -> crate::Result<Vec<u32>> {
+        if id == other {
+            let err = "A task can't be moved next to itself";
+            return Err(crate::Error::wrap(err));
+        }
+
+        let index = |id: u32| self.tasks.iter().position(|task| task.id == id);
+
+        let (Some(from), Some(to)) = (index(id), index(other)) else {
+            let missing = if index(id).is_none() { id } else { other };
+            return Err(crate::Error::TaskNotFound(vec![missing]));
+        };
+
+        // The other task shifts back once the moved one is taken out
+        let to = if from < to { to - 1 } else { to };
+
+        self.move_to(id, if after { to + 1 } else { to })
+    }
+
+    /// Snoozes tasks (selected by using `ids`) until a moment, hiding them
+    /// from [`Todo::view`] until then. Passing `None` wakes them up.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn snooze(&mut self, ids: &[u32], until: Option<DateTime<Utc>>) -> crate::Result<()> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("edit"));
+        }
+
+        for task in self.get_mut(ids) {
+            task.snoozed_until = until;
+        }
+
+        Ok(())
+    }
+
     /// Changes the `content` property of tasks (selected by using `ids`).
     ///
     /// # Errors
@@ -132,8 +790,7 @@ impl Todo {
     #[inline]
     pub fn set_content(&mut self, ids: &[u32], content: &str) -> crate::Result<()> {
         if self.tasks.is_empty() {
-            let err = "There are no tasks to edit";
-            return Err(crate::Error::wrap(err));
+            return Err(crate::Error::NoTasks("edit"));
         }
 
         for task in self.get_mut(ids) {
@@ -143,6 +800,42 @@ impl Todo {
         Ok(())
     }
 
+    /// Replaces the `refs` property of tasks (selected by using `ids`).
+    /// Passing no references removes them.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn set_refs(&mut self, ids: &[u32], refs: &[String]) -> crate::Result<()> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("edit"));
+        }
+
+        for task in self.get_mut(ids) {
+            task.refs = refs.to_vec();
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the `tags` property of tasks (selected by using `ids`).
+    /// Passing no tags removes them.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn set_tags(&mut self, ids: &[u32], tags: &[String]) -> crate::Result<()> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("edit"));
+        }
+
+        for task in self.get_mut(ids) {
+            task.tags = tags.to_vec();
+        }
+
+        Ok(())
+    }
+
     /// Marks a task as checked.
     /// Returns a `Vec<u32>` containing the IDs of the tasks that changed.
     ///
@@ -151,8 +844,7 @@ impl Todo {
     #[inline]
     pub fn check(&mut self, ids: &[u32]) -> crate::Result<Vec<u32>> {
         if self.tasks.is_empty() {
-            let err = "There are no tasks to check";
-            return Err(crate::Error::wrap(err));
+            return Err(crate::Error::NoTasks("check"));
         }
 
         let mut changed_ids = vec![];
@@ -160,7 +852,7 @@ impl Todo {
         for task in self.get_mut(ids) {
             match task.check() {
                 Ok(_) => changed_ids.push(task.id),
-                Err(e) => eprintln!("{e}"),
+                Err(e) => warning!("{e}"),
             }
         }
 
@@ -175,8 +867,7 @@ impl Todo {
     #[inline]
     pub fn uncheck(&mut self, ids: &[u32]) -> crate::Result<Vec<u32>> {
         if self.tasks.is_empty() {
-            let err = "There are no tasks to uncheck";
-            return Err(crate::Error::wrap(err));
+            return Err(crate::Error::NoTasks("uncheck"));
         }
 
         let mut changed_ids = vec![];
@@ -184,14 +875,60 @@ impl Todo {
         for task in self.get_mut(ids) {
             match task.uncheck() {
                 Ok(_) => changed_ids.push(task.id),
-                Err(e) => eprintln!("{e}"),
+                Err(e) => warning!("{e}"),
             }
         }
 
         Ok(changed_ids)
     }
 
-    /// Drops a task from the list.
+    /// Pins tasks, so they are displayed before the rest.
+    /// Returns a `Vec<u32>` containing the IDs of the tasks that changed.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn pin(&mut self, ids: &[u32]) -> crate::Result<Vec<u32>> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("pin"));
+        }
+
+        let mut changed_ids = vec![];
+
+        for task in self.get_mut(ids) {
+            match task.pin() {
+                Ok(_) => changed_ids.push(task.id),
+                Err(e) => warning!("{e}"),
+            }
+        }
+
+        Ok(changed_ids)
+    }
+
+    /// Unpins tasks.
+    /// Returns a `Vec<u32>` containing the IDs of the tasks that changed.
+    ///
+    /// # Errors
+    /// - There are no tasks stored in the instance.
+    #[inline]
+    pub fn unpin(&mut self, ids: &[u32]) -> crate::Result<Vec<u32>> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("unpin"));
+        }
+
+        let mut changed_ids = vec![];
+
+        for task in self.get_mut(ids) {
+            match task.unpin() {
+                Ok(_) => changed_ids.push(task.id),
+                Err(e) => warning!("{e}"),
+            }
+        }
+
+        Ok(changed_ids)
+    }
+
+    /// Drops a task from the list, using the `force_drop` value of the config file.
     /// Returns a `Vec<u32>` containing the IDs of the tasks that changed.    
     ///
     /// # Errors
@@ -200,11 +937,24 @@ impl Todo {
     #[inline]
     pub fn drop(&mut self, ids: &[u32]) -> crate::Result<Vec<u32>> {
         if self.tasks.is_empty() {
-            let err = "There are no tasks to drop";
-            return Err(crate::Error::wrap(err));
+            return Err(crate::Error::NoTasks("drop"));
+        }
+
+        self.drop_with(ids, Config::load()?.force_drop)
+    }
+
+    /// Drops a task from the list. If `force_drop` is `false`, only checked
+    /// tasks are dropped.
+    /// Returns a `Vec<u32>` containing the IDs of the tasks that changed.
+    ///
+    /// # Errors
+    /// - If there are no tasks stored in the instance.
+    #[inline]
+    pub fn drop_with(&mut self, ids: &[u32], force_drop: bool) -> crate::Result<Vec<u32>> {
+        if self.tasks.is_empty() {
+            return Err(crate::Error::NoTasks("drop"));
         }
 
-        let force_drop = Config::load()?.force_drop;
         let mut changed_ids = vec![];
 
         self.tasks.retain(|task| {
@@ -217,7 +967,7 @@ impl Todo {
                 }
 
                 if !task.checked {
-                    eprintln!("Task {} can't be dropped; must be checked first", &task.id);
+                    warning!("Task {} can't be dropped; must be checked first", &task.id);
                     return true;
                 }
             }
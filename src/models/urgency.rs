@@ -0,0 +1,96 @@
+//! Weights used to compute how urgent a task is, used by the 'next' command.
+
+use std::fmt;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::{Priority, Task};
+
+/// Days before the due date of a task since it starts getting the `due` points.
+const DUE_DAYS: i64 = 14;
+
+/// Days since a task was created until it gets all the `age` points.
+const AGE_DAYS: i64 = 365;
+
+/// Points added to the urgency score of a task based on its priority, how
+/// close its due date is and how old it is.
+///
+/// Checked tasks are never urgent, so their score is always `0`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Urgency {
+    /// Points of `high` priority tasks.
+    pub high: u32,
+    /// Points of `med` priority tasks.
+    pub med: u32,
+    /// Points of `low` priority tasks.
+    pub low: u32,
+    /// Points of `none` priority tasks.
+    pub none: u32,
+    /// Points of tasks that are due today or overdue. Tasks due in the next
+    /// 14 days get a part of them, which grows as the due date gets closer.
+    pub due: u32,
+    /// Points of tasks created a year ago or more. Newer tasks get a part of
+    /// them, which grows with their age.
+    pub age: u32,
+}
+
+impl Default for Urgency {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            high: 6,
+            med: 4,
+            low: 2,
+            none: 0,
+            due: 12,
+            age: 2,
+        }
+    }
+}
+
+impl fmt::Display for Urgency {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "high={} med={} low={} none={} due={} age={}",
+            self.high, self.med, self.low, self.none, self.due, self.age
+        )
+    }
+}
+
+impl Urgency {
+    /// Returns the urgency score of a task, which is the sum of the points
+    /// of its priority, its due date and its age.
+    #[inline]
+    pub fn score(&self, task: &Task) -> u32 {
+        if task.checked {
+            return 0;
+        }
+
+        let priority = match task.priority {
+            Priority::High => self.high,
+            Priority::Med => self.med,
+            Priority::Low => self.low,
+            Priority::None => self.none,
+        };
+
+        let due = task
+            .days_left()
+            .map_or(0, |days| Self::part(self.due, DUE_DAYS - days.clamp(0, DUE_DAYS), DUE_DAYS));
+
+        let age = task.created_at.map_or(0, |created| {
+            let days = (Utc::now() - created).num_days();
+            Self::part(self.age, days.clamp(0, AGE_DAYS), AGE_DAYS)
+        });
+
+        priority.saturating_add(due).saturating_add(age)
+    }
+
+    /// Returns the part of `points` that `days` are of `total` days.
+    fn part(points: u32, days: i64, total: i64) -> u32 {
+        u32::try_from(i64::from(points) * days / total).unwrap_or(points)
+    }
+}
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::tr;
+
 /// Convenience type for database related operations.
 pub type Result<T> = std::result::Result<T, self::Error>;
 
@@ -9,21 +11,53 @@ pub type Result<T> = std::result::Result<T, self::Error>;
 #[derive(Error, Debug)]
 pub enum Error {
     /// Used when the provided connection string is not supported.
-    #[error("Unsupported database; defaulting to Sqlite")]
+    #[error("{}", tr!("Unsupported database; defaulting to Sqlite"))]
     UnsupportedDatabase,
 
     /// Used when the provided connection string is incorrect.
-    #[error("The provided connection string is incorrect")]
+    #[error("{}", tr!("The provided connection string is incorrect"))]
     IncorrectConnectionString,
 
+    /// Used when the table (or collection) of the tasks doesn't exist yet.
+    #[error(
+        "{}",
+        tr!("The '{table}' table has no tasks; add a task first to use this command", table = .0)
+    )]
+    TableDoesntExist(String),
+
+    /// Used when the persister requires a cargo feature that wasn't enabled
+    /// when postit was compiled.
+    #[error(
+        "Support for this persister is disabled; reinstall postit with the '{0}' feature enabled:\n\
+         cargo install postit --features {0}"
+    )]
+    FeatureDisabled(&'static str),
+
     /// Represent a `SQLite` error.
+    #[cfg(feature = "sqlite")]
     #[error("Error on SQLite: {0}")]
     Sqlite(#[from] sqlite::Error),
 
     /// Represent a `MongoDB` error.
+    #[cfg(feature = "mongo")]
     #[error("Error on MongoDB: {0}")]
     Mongo(#[from] mongodb::error::Error),
 
+    /// Represent a `PostgreSQL` error.
+    #[cfg(feature = "postgres")]
+    #[error("Error on PostgreSQL: {0}")]
+    Postgres(#[from] postgres::Error),
+
+    /// Represent a `Redis` error.
+    #[cfg(feature = "redis")]
+    #[error("Error on Redis: {0}")]
+    Redis(#[from] redis::RedisError),
+
+    /// Represent an error returned by a `libSQL` server.
+    #[cfg(feature = "libsql")]
+    #[error("Error on libSQL: {0}")]
+    Libsql(String),
+
     /// Any error that doesn't belong into the previous variants.
     #[error("{0}")]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -38,4 +72,27 @@ impl Error {
     {
         Self::Other(err.into())
     }
+
+    /// Returns the stable code of the error, which can be looked up with
+    /// 'postit docs errors'.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        match *self {
+            Self::UnsupportedDatabase => "E301",
+            Self::IncorrectConnectionString => "E302",
+            Self::FeatureDisabled(_) => "E303",
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(_) => "E304",
+            #[cfg(feature = "mongo")]
+            Self::Mongo(_) => "E305",
+            #[cfg(feature = "postgres")]
+            Self::Postgres(_) => "E306",
+            #[cfg(feature = "redis")]
+            Self::Redis(_) => "E307",
+            #[cfg(feature = "libsql")]
+            Self::Libsql(_) => "E308",
+            Self::TableDoesntExist(_) => "E309",
+            Self::Other(_) => "E399",
+        }
+    }
 }
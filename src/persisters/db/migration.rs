@@ -0,0 +1,59 @@
+//! Contains the `Migration` struct, which describes the changes made to the
+//! schema of the tasks table since its first version.
+//!
+//! Each DB persister stores the version of its table in a `schema_version`
+//! table (or collection), and applies the pending migrations when the
+//! persister is obtained (see [`DbPersister::migrate`][crate::traits::DbPersister::migrate]).
+
+/// Change to the schema of the tasks table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Migration {
+    /// Version of the schema after applying the migration.
+    pub version: u32,
+    /// Column added to the table, which is empty in the existing rows.
+    pub column: &'static str,
+}
+
+impl Migration {
+    /// Migrations in the order they are applied, one per version.
+    pub const ALL: [Self; 12] = [
+        Self::new(1, "refs"),
+        Self::new(2, "tags"),
+        Self::new(3, "notes"),
+        Self::new(4, "created_at"),
+        Self::new(5, "updated_at"),
+        Self::new(6, "completed_at"),
+        Self::new(7, "estimate"),
+        Self::new(8, "snoozed_until"),
+        Self::new(9, "order"),
+        Self::new(10, "pinned"),
+        Self::new(11, "attachments"),
+        Self::new(12, "due"),
+    ];
+
+    /// Constructor of the `Migration` struct.
+    #[inline]
+    #[must_use]
+    pub const fn new(version: u32, column: &'static str) -> Self {
+        Self { version, column }
+    }
+
+    /// Returns the version of the tables created by this version of postit.
+    #[inline]
+    pub const fn latest() -> u32 {
+        Self::ALL[Self::ALL.len() - 1].version
+    }
+
+    /// Returns the migrations that a table with the `version` schema lacks.
+    ///
+    /// Tables created before the schema was versioned have the version `0`,
+    /// so every migration is pending for them; migrations must check if their
+    /// column already exists.
+    #[inline]
+    pub fn pending(version: u32) -> Vec<Self> {
+        Self::ALL
+            .into_iter()
+            .filter(|migration| migration.version > version)
+            .collect()
+    }
+}
@@ -1,14 +1,36 @@
 //! Module for database management.
 //!
 //! The currently supported databases are:
-//! - sqlite
+//! - sqlite (requires the `sqlite` feature)
+//! - mongodb (requires the `mongo` feature)
+//! - postgresql (requires the `postgres` feature)
+//! - redis (requires the `redis` feature)
+//! - libsql (requires the `libsql` feature)
 
 mod error;
+#[cfg(feature = "libsql")]
+mod libsql;
+mod migration;
+#[cfg(feature = "mongo")]
 mod mongo;
 mod orm;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "sqlite")]
 mod sqlite;
 
 pub use error::{Error, Result};
+#[cfg(feature = "libsql")]
+pub use libsql::Libsql;
+pub use migration::Migration;
+#[cfg(feature = "mongo")]
 pub use mongo::Mongo;
 pub use orm::{Orm, Protocol};
+#[cfg(feature = "postgres")]
+pub use postgres::Postgres;
+#[cfg(feature = "redis")]
+pub use redis::Redis;
+#[cfg(feature = "sqlite")]
 pub use sqlite::Sqlite;
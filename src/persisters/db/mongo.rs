@@ -4,13 +4,15 @@
 
 use std::time::Duration;
 
-use mongodb::bson::{doc, Bson, Document};
-use mongodb::options::ClientOptions;
-use mongodb::sync::{Client, Collection, Database};
+use mongodb::bson::{self, doc, Bson, Document};
+use mongodb::options::{ClientOptions, ReturnDocument};
+use mongodb::sync::{Client, ClientSession, Collection, Database};
 
+use super::Migration;
+use crate::config::Config;
 use crate::models::{Task, Todo};
 use crate::traits::DbPersister;
-use crate::Action;
+use crate::{info, Action};
 
 /// Representation of a `Mongo` database.
 #[derive(Debug)]
@@ -19,6 +21,8 @@ pub struct Mongo {
     conn_str: String,
     /// Connection to the `Mongo` database.
     connection: Client,
+    /// Name of the database where tasks are stored.
+    database: String,
 }
 
 impl Clone for Mongo {
@@ -27,6 +31,7 @@ impl Clone for Mongo {
         Self {
             conn_str: self.conn_str.clone(),
             connection: self.connection.clone(),
+            database: self.database.clone(),
         }
     }
 }
@@ -34,20 +39,41 @@ impl Clone for Mongo {
 impl Mongo {
     /// Creates a `Mongo` instance from a URI.
     ///
+    /// The database is the one at the end of the URI (e.g.: `postit` in
+    /// `mongodb://localhost:27017/postit`), or the default `database` of the
+    /// config if the URI doesn't have one.
+    ///
     /// # Errors
     /// - [`ClientOptions`] can't be parsed.
     /// - [`Client`] couldn't be opened.
     #[inline]
     pub fn from<T: AsRef<str>>(uri: T) -> super::Result<Self> {
+        Self::with(uri, &Config::default().database)
+    }
+
+    /// Creates a `Mongo` instance from a URI, like [`Mongo::from`], but
+    /// `database` is used if the URI doesn't have one.
+    ///
+    /// # Errors
+    /// - [`ClientOptions`] can't be parsed.
+    /// - [`Client`] couldn't be opened.
+    #[inline]
+    pub fn with<T: AsRef<str>>(uri: T, database: &str) -> super::Result<Self> {
         let uri = uri.as_ref();
 
         let mut options = ClientOptions::parse(uri).run()?;
         options.server_selection_timeout = Some(Duration::from_secs(5));
         options.connect_timeout = Some(Duration::from_secs(5));
 
+        let database = options
+            .default_database
+            .clone()
+            .unwrap_or_else(|| database.to_owned());
+
         let instance = Self {
             conn_str: uri.to_owned(),
             connection: Client::with_options(options)?,
+            database,
         };
 
         Ok(instance)
@@ -64,6 +90,110 @@ impl Mongo {
     pub fn collection<T: Send + Sync>(&self) -> Collection<T> {
         self.db().collection::<T>(&self.table())
     }
+
+    /// Gets a handle to the collection that stores the sequences used to
+    /// generate ids, which has a document per tasks collection.
+    #[inline]
+    pub fn counters(&self) -> Collection<Document> {
+        self.db().collection::<Document>("counters")
+    }
+
+    /// Gets a handle to the collection that stores the schema version of each
+    /// tasks collection.
+    #[inline]
+    pub fn versions(&self) -> Collection<Document> {
+        self.db().collection::<Document>("schema_version")
+    }
+
+    /// Records the schema version of the tasks collection.
+    ///
+    /// # Errors
+    /// - The version can't be written.
+    #[inline]
+    pub fn set_version(&self, version: u32) -> super::Result<()> {
+        self.versions()
+            .update_one(
+                doc! { "_id": self.table() },
+                doc! { "$set": { "version": i64::from(version) } },
+            )
+            .upsert(true)
+            .run()?;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the server is a member of a replica set or a `mongos`
+    /// router, which are the deployments that support transactions.
+    #[inline]
+    pub fn supports_transactions(&self) -> bool {
+        self.connection
+            .database("admin")
+            .run_command(doc! { "hello": 1 })
+            .run()
+            .is_ok_and(|reply| {
+                reply.contains_key("setName") || reply.get_str("msg").is_ok_and(|m| m == "isdbgrid")
+            })
+    }
+
+    /// Runs `op` inside a transaction, so its writes are applied all at once
+    /// or not at all. Standalone servers don't support transactions, so `op`
+    /// runs with a plain session on them.
+    ///
+    /// # Errors
+    /// - The session or the transaction can't be started or committed.
+    /// - Any error returned by `op` (the transaction is aborted).
+    #[inline]
+    pub fn atomic<F>(&self, op: F) -> super::Result<()>
+    where
+        F: FnOnce(&mut ClientSession) -> super::Result<()>,
+    {
+        let mut session = self.connection.start_session().run()?;
+
+        if !self.supports_transactions() {
+            return op(&mut session);
+        }
+
+        session.start_transaction().run()?;
+
+        match op(&mut session) {
+            Ok(()) => Ok(session.commit_transaction().run()?),
+            Err(e) => {
+                session.abort_transaction().run()?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Inserts the tasks using the passed session.
+    fn insert_with(&self, todo: &Todo, session: &mut ClientSession) -> super::Result<()> {
+        let docs = todo
+            .tasks
+            .iter()
+            .map(bson::to_document)
+            .collect::<Result<Vec<Document>, _>>()
+            .map_err(super::Error::wrap)?;
+
+        if docs.is_empty() {
+            return Ok(());
+        }
+
+        self.collection::<Document>()
+            .insert_many(&docs)
+            .session(session)
+            .run()?;
+
+        Ok(())
+    }
+
+    /// Deletes the tasks that match the query using the passed session.
+    fn delete_with(&self, query: Document, session: &mut ClientSession) -> super::Result<()> {
+        self.collection::<Document>()
+            .delete_many(query)
+            .session(session)
+            .run()?;
+
+        Ok(())
+    }
 }
 
 impl DbPersister for Mongo {
@@ -84,7 +214,7 @@ impl DbPersister for Mongo {
 
     #[inline]
     fn database(&self) -> String {
-        String::from("test")
+        self.database.clone()
     }
 
     #[inline]
@@ -94,14 +224,34 @@ impl DbPersister for Mongo {
         Ok(names.contains(&self.table()))
     }
 
+    #[inline]
+    fn version(&self) -> super::Result<u32> {
+        let version = self
+            .versions()
+            .find_one(doc! { "_id": self.table() })
+            .run()?
+            .and_then(|doc| doc.get_i64("version").ok())
+            .unwrap_or_default();
+
+        u32::try_from(version).map_err(super::Error::wrap)
+    }
+
+    /// Documents don't need to change when a field is added, since the fields
+    /// they lack are read with their default value, so only the version of
+    /// the collection is recorded.
+    #[inline]
+    fn migrate(&self) -> super::Result<()> {
+        if !self.exists()? || self.version()? >= Migration::latest() {
+            return Ok(());
+        }
+
+        self.set_version(Migration::latest())
+    }
+
     #[inline]
     fn tasks(&self) -> super::Result<Vec<Task>> {
         if !self.exists()? {
-            let err = format!(
-                "The '{}' collection doesn't exist; add a task first to use this command",
-                self.table()
-            );
-            return Err(super::Error::wrap(err));
+            return Err(super::Error::TableDoesntExist(self.table()));
         }
 
         let tasks = self
@@ -135,30 +285,16 @@ impl DbPersister for Mongo {
         let table = self.table();
 
         self.db().create_collection(&table).run()?;
+        self.set_version(Migration::latest())?;
 
-        println!("Created the '{table}' table in the '{}' collection", self.database());
+        info!("Created the '{table}' table in the '{}' collection", self.database());
 
         Ok(())
     }
 
     #[inline]
     fn insert(&self, todo: &Todo) -> super::Result<()> {
-        let docs: Vec<Document> = todo
-            .tasks
-            .iter()
-            .map(|task| {
-                doc! {
-                    "id": task.id,
-                    "content": &task.content,
-                    "priority": task.priority.to_str(),
-                    "checked": task.checked,
-                }
-            })
-            .collect();
-
-        self.collection::<Document>().insert_many(&docs).run()?;
-
-        Ok(())
+        self.atomic(|session| self.insert_with(todo, session))
     }
 
     #[inline]
@@ -167,38 +303,90 @@ impl DbPersister for Mongo {
             return self.delete(ids);
         }
 
+        // Each task has its own position, so they are updated one by one
+        if matches!(action, Action::Move) && ids.len() > 1 {
+            return ids
+                .iter()
+                .try_for_each(|id| self.update(todo, &[*id], action));
+        }
+
+        let task = todo.get(ids)[0];
+
         let (field, value) = match action {
             Action::Check => ("checked", Bson::Boolean(true)),
             Action::Uncheck => ("checked", Bson::Boolean(false)),
-            Action::SetContent => ("content", Bson::String(todo.get(ids)[0].content.clone())),
-            Action::SetPriority => {
-                ("priority", Bson::String(todo.get(ids)[0].priority.to_string()))
+            Action::SetContent => ("content", Bson::String(task.content.clone())),
+            Action::SetPriority => ("priority", Bson::String(task.priority.to_string())),
+            Action::SetRefs => {
+                let refs = task.refs.iter().cloned().map(Bson::String).collect();
+                ("refs", Bson::Array(refs))
+            }
+            Action::SetTags => {
+                let tags = task.tags.iter().cloned().map(Bson::String).collect();
+                ("tags", Bson::Array(tags))
+            }
+            Action::SetNotes => ("notes", Bson::String(task.notes.clone())),
+            Action::SetEstimate => {
+                let minutes = task
+                    .estimate
+                    .map_or(Bson::Null, |m| Bson::Int64(i64::from(m)));
+                ("estimate", minutes)
+            }
+            Action::Snooze => {
+                let until = bson::to_bson(&task.snoozed_until).map_err(super::Error::wrap)?;
+                ("snoozed_until", until)
+            }
+            Action::Move => {
+                let order = task.order.map_or(Bson::Null, |o| Bson::Int64(i64::from(o)));
+                ("order", order)
+            }
+            Action::Pin | Action::Unpin => ("pinned", Bson::Boolean(task.pinned)),
+            Action::Attach => {
+                let attachments = task.attachments.iter().cloned().map(Bson::String).collect();
+                ("attachments", Bson::Array(attachments))
+            }
+            Action::SetDue => {
+                let due = bson::to_bson(&task.due).map_err(super::Error::wrap)?;
+                ("due", due)
             }
             Action::Drop => unreachable!(),
         };
 
+        let updated_at = bson::to_bson(&task.updated_at).map_err(super::Error::wrap)?;
+        let mut set = doc! { field: value, "updated_at": updated_at };
+
+        // The other tasks keep their own completion date
+        if matches!(action, Action::Check | Action::Uncheck) {
+            let completed_at = bson::to_bson(&task.completed_at).map_err(super::Error::wrap)?;
+            set.insert("completed_at", completed_at);
+        }
+
         let query = doc! { "id": { "$in": ids } };
-        let update = doc! { "$set": { field: value } };
+        let update = doc! { "$set": set };
 
-        self.collection::<Document>()
-            .update_many(query, update)
-            .run()?;
+        self.atomic(|session| {
+            self.collection::<Document>()
+                .update_many(query, update)
+                .session(session)
+                .run()?;
 
-        Ok(())
+            Ok(())
+        })
     }
 
     #[inline]
     fn delete(&self, ids: &[u32]) -> super::Result<()> {
         let query = doc! { "id": {"$in": ids }};
 
-        self.collection::<String>().delete_many(query).run()?;
-
-        Ok(())
+        self.atomic(|session| self.delete_with(query, session))
     }
 
     #[inline]
     fn drop_table(&self) -> super::Result<()> {
         self.collection::<Task>().drop().run()?;
+        self.counters()
+            .delete_one(doc! { "_id": self.table() })
+            .run()?;
 
         // println!("Removed the '{}' collection", self.table());
 
@@ -209,15 +397,63 @@ impl DbPersister for Mongo {
     fn drop_database(&self) -> super::Result<()> {
         self.db().drop().run()?;
 
-        println!("Removed the '{}' database", self.database());
+        info!("Removed the '{}' database", self.database());
 
         Ok(())
     }
 
     #[inline]
     fn clean(&self) -> super::Result<()> {
-        self.collection::<String>().delete_many(doc! {}).run()?;
+        self.atomic(|session| {
+            self.delete_with(doc! {}, session)?;
 
-        Ok(())
+            self.counters()
+                .delete_one(doc! { "_id": self.table() })
+                .session(session)
+                .run()?;
+
+            Ok(())
+        })
+    }
+
+    /// Generates the id with the `counters` collection, so concurrent adds
+    /// from different machines can't get the same id.
+    ///
+    /// The counter is raised to the greatest id of the collection before
+    /// incrementing it, which keeps it in sync with tasks inserted with an
+    /// explicit id (e.g. by 'copy' or 'import').
+    #[inline]
+    fn next_id(&self) -> super::Result<u32> {
+        let max = self
+            .collection::<Task>()
+            .find_one(doc! {})
+            .sort(doc! { "id": -1 })
+            .run()?
+            .map_or(0, |task| task.id);
+
+        let key = doc! { "_id": self.table() };
+
+        self.counters()
+            .update_one(key.clone(), doc! { "$max": { "seq": i64::from(max) } })
+            .upsert(true)
+            .run()?;
+
+        let seq = self
+            .counters()
+            .find_one_and_update(key, doc! { "$inc": { "seq": 1_i64 } })
+            .return_document(ReturnDocument::After)
+            .run()?
+            .and_then(|counter| counter.get_i64("seq").ok())
+            .ok_or_else(|| super::Error::wrap("The id counter couldn't be incremented"))?;
+
+        u32::try_from(seq).map_err(super::Error::wrap)
+    }
+
+    #[inline]
+    fn replace(&self, todo: &Todo) -> super::Result<()> {
+        self.atomic(|session| {
+            self.delete_with(doc! {}, session)?;
+            self.insert_with(todo, session)
+        })
     }
 }
@@ -6,11 +6,22 @@
 use std::fmt;
 use std::path::Path;
 
-use super::{Mongo, Sqlite};
+#[cfg(feature = "libsql")]
+use super::Libsql;
+#[cfg(feature = "mongo")]
+use super::Mongo;
+#[cfg(feature = "postgres")]
+use super::Postgres;
+#[cfg(feature = "redis")]
+use super::Redis;
+#[cfg(feature = "sqlite")]
+use super::Sqlite;
+use crate::config::{Config, Secret};
 use crate::db;
 use crate::models::{Task, Todo};
+use crate::registry::Registry;
 use crate::traits::{DbPersister, Persister};
-use crate::Action;
+use crate::{info, warning, Action};
 
 /// A database protocol.
 #[derive(Debug, PartialEq, Eq)]
@@ -21,6 +32,13 @@ pub enum Protocol {
     Mongo,
     /// A `MongoDB` database on a remote server (associated persister: [`Mongo`]).
     MongoSrv,
+    /// A `PostgreSQL` database (associated persister: [`Postgres`]).
+    Postgres,
+    /// A `Redis` database (associated persister: [`Redis`]).
+    Redis,
+    /// A `libSQL` database, such as the ones hosted by Turso (associated
+    /// persister: [`Libsql`]).
+    Libsql,
 }
 
 impl<T: AsRef<str>> From<T> for Protocol {
@@ -31,8 +49,11 @@ impl<T: AsRef<str>> From<T> for Protocol {
             "sqlite" => Self::Sqlite,
             "mongodb" => Self::Mongo,
             "mongodb+srv" => Self::MongoSrv,
+            "postgres" | "postgresql" => Self::Postgres,
+            "redis" | "rediss" => Self::Redis,
+            "libsql" => Self::Libsql,
             _ => {
-                eprintln!("{}", db::Error::UnsupportedDatabase);
+                warning!("{}", db::Error::UnsupportedDatabase);
                 Self::Sqlite
             }
         }
@@ -47,6 +68,9 @@ impl Protocol {
             Self::Sqlite => "sqlite",
             Self::Mongo => "mongo",
             Self::MongoSrv => "mongo+srv",
+            Self::Postgres => "postgres",
+            Self::Redis => "redis",
+            Self::Libsql => "libsql",
         }
     }
 }
@@ -58,6 +82,9 @@ impl fmt::Display for Protocol {
             Self::Sqlite => write!(f, "sqlite"),
             Self::Mongo => write!(f, "mongo"),
             Self::MongoSrv => write!(f, "mongo+srv"),
+            Self::Postgres => write!(f, "postgres"),
+            Self::Redis => write!(f, "redis"),
+            Self::Libsql => write!(f, "libsql"),
         }
     }
 }
@@ -94,14 +121,26 @@ impl Orm {
         Ok(Self { db: Self::get_persister(conn)? })
     }
 
+    /// Creates a `Orm` instance from a connection string, like [`Orm::from`],
+    /// but `database` is used by the connections that don't specify one
+    /// (e.g.: `mongodb://localhost:27017`).
+    ///
+    /// # Errors
+    /// - The database persister can't be obtained.
+    #[inline]
+    pub fn with<T: AsRef<str>>(conn: T, database: &str) -> crate::Result<Self> {
+        Ok(Self {
+            db: Self::get_persister_with(conn, database)?,
+        })
+    }
+
     /// Checks if the passed connection string has an Sqlite format.
     ///
     /// # Panics
     /// - The extension can't be converted to `&str`.
     #[inline]
     pub fn is_sqlite(conn: &str) -> bool {
-        conn.eq(":memory:")
-            || conn.starts_with("sqlite:///")
+        conn.starts_with("sqlite:///")
             || Path::new(conn)
                 .extension()
                 .is_some_and(|ext| matches!(ext.to_str().unwrap(), "db" | "sqlite3" | "sqlite"))
@@ -110,29 +149,103 @@ impl Orm {
     /// Returns a struct that implements the [`DbPersister`] trait based on
     /// a connection string.
     ///
+    /// Persisters are only available if their cargo feature (`sqlite`,
+    /// `mongo`, `postgres`, `redis` or `libsql`) was enabled at compile time. Tables
+    /// created by older versions are migrated to the latest schema. Persisters
+    /// added to the [`Registry`] are used before the included ones.
+    ///
     /// # Errors
     /// - If the persister can't be obtained.
+    /// - If the table can't be migrated.
     /// - If the connection string is empty.
+    /// - If the persister's feature is disabled.
     #[inline]
     pub fn get_persister<T: AsRef<str>>(conn: T) -> crate::Result<Box<dyn DbPersister>> {
-        let conn = conn.as_ref();
-
-        if Self::is_sqlite(conn) {
-            return Ok(Sqlite::from(conn.replace("sqlite:///", ""))?.boxed());
-        }
-
-        let parts: Vec<&str> = conn.split("://").collect();
+        Self::get_persister_with(conn, &Config::default().database)
+    }
 
-        if parts[0].is_empty() {
-            return Err(crate::Error::Db(db::Error::IncorrectConnectionString));
-        }
+    /// Returns a struct that implements the [`DbPersister`] trait based on
+    /// a connection string, like [`Orm::get_persister`], but `database` is
+    /// used by the connections that don't specify one.
+    ///
+    /// # Errors
+    /// - If the persister can't be obtained.
+    /// - If the table can't be migrated.
+    /// - If the connection string is empty.
+    /// - If the persister's feature is disabled.
+    #[inline]
+    pub fn get_persister_with<T: AsRef<str>>(
+        conn: T,
+        database: &str,
+    ) -> crate::Result<Box<dyn DbPersister>> {
+        let conn = conn.as_ref();
+        let registered = conn
+            .split_once("://")
+            .and_then(|(scheme, _)| Registry::db(scheme));
+
+        // Persisters included in postit are used if the scheme isn't registered
+        let persister = if let Some(factory) = registered {
+            factory(conn)?
+        } else {
+            let parts: Vec<&str> = conn.split("://").collect();
+
+            let protocol = if Self::is_sqlite(conn) {
+                Protocol::Sqlite
+            } else if parts[0].is_empty() {
+                return Err(crate::Error::Db(db::Error::IncorrectConnectionString));
+            } else {
+                Protocol::from(parts[0])
+            };
+
+            match protocol {
+                #[cfg(feature = "sqlite")]
+                Protocol::Sqlite if Self::is_sqlite(conn) => {
+                    Sqlite::from(conn.replace("sqlite:///", ""))?.boxed()
+                }
+                #[cfg(feature = "sqlite")]
+                Protocol::Sqlite => Sqlite::from("tasks.db")?.boxed(),
+                #[cfg(not(feature = "sqlite"))]
+                Protocol::Sqlite => {
+                    return Err(crate::Error::Db(db::Error::FeatureDisabled("sqlite")));
+                }
+
+                #[cfg(feature = "mongo")]
+                Protocol::Mongo | Protocol::MongoSrv => Mongo::with(conn, database)?.boxed(),
+                #[cfg(not(feature = "mongo"))]
+                Protocol::Mongo | Protocol::MongoSrv => {
+                    let _ = database;
+                    return Err(crate::Error::Db(db::Error::FeatureDisabled("mongo")));
+                }
+
+                #[cfg(feature = "postgres")]
+                Protocol::Postgres => Postgres::from(conn)?.boxed(),
+                #[cfg(not(feature = "postgres"))]
+                Protocol::Postgres => {
+                    return Err(crate::Error::Db(db::Error::FeatureDisabled("postgres")));
+                }
+
+                #[cfg(feature = "redis")]
+                Protocol::Redis => Redis::from(conn)?.boxed(),
+                #[cfg(not(feature = "redis"))]
+                Protocol::Redis => {
+                    return Err(crate::Error::Db(db::Error::FeatureDisabled("redis")));
+                }
+
+                #[cfg(feature = "libsql")]
+                Protocol::Libsql => Libsql::from(conn)?.boxed(),
+                #[cfg(not(feature = "libsql"))]
+                Protocol::Libsql => {
+                    return Err(crate::Error::Db(db::Error::FeatureDisabled("libsql")));
+                }
+            }
+        };
 
-        let protocol = parts[0];
+        persister.migrate().map_err(|e| {
+            warning!("Can't migrate the table to the latest schema");
+            crate::Error::Db(e)
+        })?;
 
-        match Protocol::from(protocol) {
-            Protocol::Mongo | Protocol::MongoSrv => Ok(Mongo::from(conn)?.boxed()),
-            Protocol::Sqlite => Ok(Sqlite::from("tasks.db")?.boxed()),
-        }
+        Ok(persister)
     }
 }
 
@@ -144,13 +257,13 @@ impl Persister for Orm {
 
     #[inline]
     fn to_string(&self) -> String {
-        self.db.conn()
+        Secret::redact(&self.db.conn())
     }
 
     #[inline]
     fn create(&self) -> crate::Result<()> {
         self.db.create().map_err(|e| {
-            eprintln!("Can't create the table");
+            warning!("Can't create the table");
             crate::Error::Db(e)
         })
     }
@@ -158,7 +271,7 @@ impl Persister for Orm {
     #[inline]
     fn exists(&self) -> crate::Result<bool> {
         self.db.exists().map_err(|e| {
-            eprintln!("The table doesn't exist; add a task first to use this command");
+            warning!("The table doesn't exist; add a task first to use this command");
             crate::Error::Db(e)
         })
     }
@@ -178,7 +291,7 @@ impl Persister for Orm {
     #[inline]
     fn edit(&self, todo: &Todo, ids: &[u32], action: &Action) -> crate::Result<()> {
         self.db.update(todo, ids, action).map_err(|e| {
-            eprintln!("Can't perform the '{action}' action");
+            warning!("Can't perform the '{action}' action");
             crate::Error::Db(e)
         })
     }
@@ -187,7 +300,7 @@ impl Persister for Orm {
     fn save(&self, todo: &Todo) -> crate::Result<()> {
         if self.db.count()? == 0 {
             return self.db.insert(todo).map_err(|e| {
-                eprintln!("Can't insert into the table");
+                warning!("Can't insert into the table");
                 crate::Error::Db(e)
             });
         }
@@ -196,23 +309,34 @@ impl Persister for Orm {
         let task = Todo::new(last);
 
         self.db.insert(&task).map_err(|e| {
-            eprintln!("Can't insert into the table");
+            warning!("Can't insert into the table");
+            crate::Error::Db(e)
+        })
+    }
+
+    #[inline]
+    fn append(&self, tasks: &[Task]) -> crate::Result<()> {
+        self.db.insert(&Todo::new(tasks.to_vec())).map_err(|e| {
+            warning!("Can't insert into the table");
             crate::Error::Db(e)
         })
     }
 
+    #[inline]
+    fn next_id(&self) -> crate::Result<u32> {
+        self.db.next_id().map_err(crate::Error::Db)
+    }
+
     #[inline]
     fn replace(&self, todo: &Todo) -> crate::Result<()> {
-        if self.exists()? {
-            self.db.clean()?;
-        }
+        let result = if self.exists()? { self.db.replace(todo) } else { self.db.insert(todo) };
 
-        self.db.insert(todo).map_err(|e| {
-            eprintln!("Can't insert into the table");
+        result.map_err(|e| {
+            warning!("Can't insert into the table");
             crate::Error::Db(e)
         })?;
 
-        println!("Replaced the tasks of '{}'", self.db.conn());
+        info!("Replaced the tasks of '{}'", self.to_string());
 
         Ok(())
     }
@@ -220,35 +344,42 @@ impl Persister for Orm {
     #[inline]
     fn clean(&self) -> crate::Result<()> {
         if self.tasks()?.is_empty() {
-            eprintln!("There are no tasks to delete in the table");
+            warning!("There are no tasks to delete in the table");
             return Ok(());
         }
 
         self.db.clean().map_err(|e| {
-            eprintln!("Can't clean the table");
+            warning!("Can't clean the table");
             crate::Error::Db(e)
         })?;
 
-        println!("Cleaned the tasks from the '{}' table", self.db.table());
+        info!("Cleaned the tasks from the '{}' table", self.db.table());
 
         Ok(())
     }
 
+    /// Builds the persister again from its connection, which keeps the
+    /// password hidden by [`Persister::to_string`].
+    #[inline]
+    fn reopen(&self) -> crate::Result<Box<dyn Persister>> {
+        Ok(Self::with(self.db.conn(), &self.db.database())?.boxed())
+    }
+
     #[inline]
     fn remove(&self) -> crate::Result<()> {
         let table = self.db.table();
 
         if !self.exists()? {
-            eprintln!("There is no '{table}' table to remove at '{}'", self.to_string());
+            warning!("There is no '{table}' table to remove at '{}'", self.to_string());
             return Ok(());
         }
 
         self.db.drop_table().map_err(|e| {
-            eprintln!("Can't drop the table");
+            warning!("Can't drop the table");
             crate::Error::Db(e)
         })?;
 
-        print!("Removed the '{}' table from '{}'", self.db.table(), self.db.conn());
+        print!("Removed the '{}' table from '{}'", self.db.table(), self.to_string());
 
         Ok(())
     }
@@ -0,0 +1,271 @@
+//! Utilities to handle `Redis` databases.
+//!
+//! The `Redis` struct implements the [`DbPersister`] trait.
+//!
+//! Every key used by postit starts with `postit:`, so the database can be
+//! shared with other applications. The tasks of a table are stored in:
+//! - `postit:{table}`: hash of tasks serialized as JSON, keyed by task id.
+//! - `postit:{table}:ids`: sorted set of task ids, used to keep them in order.
+//! - `postit:tables`: set with the names of the tables created.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::time::Duration;
+
+use redis::{Client, Commands as _, Connection, IntoConnectionInfo as _};
+
+use crate::models::{Task, Todo};
+use crate::traits::DbPersister;
+use crate::{info, Action};
+
+/// Prefix of the keys used by postit.
+const PREFIX: &str = "postit";
+
+/// Representation of a `Redis` database.
+pub struct Redis {
+    /// URL used to connect to the `Redis` server.
+    conn_str: String,
+    /// Connection to the `Redis` server. Commands need a mutable reference
+    /// to it, while [`DbPersister`] only lends a shared one.
+    connection: RefCell<Connection>,
+}
+
+impl fmt::Debug for Redis {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redis")
+            .field("conn_str", &self.conn_str)
+            .field("connection", &"[connection omitted]")
+            .finish()
+    }
+}
+
+impl Clone for Redis {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from(&self.conn_str).unwrap()
+    }
+}
+
+impl Redis {
+    /// Creates a `Redis` instance from a URL (e.g.: `redis://localhost:6379/0`).
+    ///
+    /// # Errors
+    /// - The URL can't be parsed.
+    /// - A connection to the server can't be opened.
+    #[inline]
+    pub fn from<T: AsRef<str>>(conn: T) -> super::Result<Self> {
+        let conn = conn.as_ref();
+
+        let client = Client::open(conn)?;
+        let connection = client.get_connection_with_timeout(Duration::from_secs(5))?;
+
+        Ok(Self {
+            conn_str: conn.to_owned(),
+            connection: RefCell::new(connection),
+        })
+    }
+
+    /// Returns the key of the hash that stores the tasks.
+    #[inline]
+    pub fn tasks_key(&self) -> String {
+        format!("{PREFIX}:{}", self.table())
+    }
+
+    /// Returns the key of the sorted set that stores the ids of the tasks.
+    #[inline]
+    pub fn ids_key(&self) -> String {
+        format!("{PREFIX}:{}:ids", self.table())
+    }
+
+    /// Returns the key of the set that stores the names of the tables.
+    #[inline]
+    pub fn tables_key(&self) -> String {
+        format!("{PREFIX}:tables")
+    }
+
+    /// Serializes a task as JSON, which is how it is stored in the hash.
+    ///
+    /// # Errors
+    /// - The task can't be serialized.
+    #[inline]
+    pub fn serialize(&self, task: &Task) -> super::Result<String> {
+        serde_json::to_string(task).map_err(super::Error::wrap)
+    }
+
+    /// Stores the tasks, replacing the ones that have the same id.
+    ///
+    /// # Errors
+    /// - The tasks can't be serialized or stored.
+    #[inline]
+    pub fn store(&self, tasks: &[&Task]) -> super::Result<()> {
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+
+        for task in tasks {
+            pipe.hset(self.tasks_key(), task.id, self.serialize(task)?)
+                .zadd(self.ids_key(), task.id, task.id);
+        }
+
+        pipe.query::<()>(&mut *self.connection.borrow_mut())?;
+
+        Ok(())
+    }
+}
+
+impl DbPersister for Redis {
+    #[inline]
+    fn boxed(self) -> Box<dyn DbPersister> {
+        Box::new(self)
+    }
+
+    #[inline]
+    fn conn(&self) -> String {
+        self.conn_str.clone()
+    }
+
+    #[inline]
+    fn table(&self) -> String {
+        String::from("tasks")
+    }
+
+    /// Returns the number of the database selected by the URL (`0` by default).
+    #[inline]
+    fn database(&self) -> String {
+        self.conn_str
+            .as_str()
+            .into_connection_info()
+            .map_or_else(|_| String::from("0"), |info| info.redis.db.to_string())
+    }
+
+    #[inline]
+    fn exists(&self) -> super::Result<bool> {
+        let exists = self
+            .connection
+            .borrow_mut()
+            .sismember(self.tables_key(), self.table())?;
+
+        Ok(exists)
+    }
+
+    #[inline]
+    fn tasks(&self) -> super::Result<Vec<Task>> {
+        if !self.exists()? {
+            return Err(super::Error::TableDoesntExist(self.table()));
+        }
+
+        let mut connection = self.connection.borrow_mut();
+
+        let ids: Vec<u32> = connection.zrange(self.ids_key(), 0, -1)?;
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tasks: Vec<String> = redis::cmd("HMGET")
+            .arg(self.tasks_key())
+            .arg(&ids)
+            .query(&mut *connection)?;
+
+        tasks
+            .iter()
+            .map(|task| serde_json::from_str(task).map_err(super::Error::wrap))
+            .collect()
+    }
+
+    #[inline]
+    fn count(&self) -> super::Result<u32> {
+        if !self.exists()? {
+            return Ok(0);
+        }
+
+        let n: u64 = self.connection.borrow_mut().zcard(self.ids_key())?;
+
+        Ok(n.try_into().unwrap_or(0))
+    }
+
+    #[inline]
+    fn create(&self) -> super::Result<()> {
+        self.connection
+            .borrow_mut()
+            .sadd::<_, _, ()>(self.tables_key(), self.table())?;
+
+        info!("Created the '{}' table in the '{}' database", self.table(), self.database());
+
+        Ok(())
+    }
+
+    #[inline]
+    fn insert(&self, todo: &Todo) -> super::Result<()> {
+        if todo.tasks.is_empty() {
+            return Ok(());
+        }
+
+        let tasks: Vec<&Task> = todo.tasks.iter().collect();
+
+        self.store(&tasks)
+    }
+
+    /// Updates the tasks by storing them again, as every task is stored as
+    /// a whole.
+    #[inline]
+    fn update(&self, todo: &Todo, ids: &[u32], action: &Action) -> super::Result<()> {
+        if matches!(action, Action::Drop) {
+            return self.delete(ids);
+        }
+
+        self.store(&todo.get(ids))
+    }
+
+    #[inline]
+    fn delete(&self, ids: &[u32]) -> super::Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        redis::pipe()
+            .atomic()
+            .hdel(self.tasks_key(), ids)
+            .zrem(self.ids_key(), ids)
+            .query::<()>(&mut *self.connection.borrow_mut())?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn drop_table(&self) -> super::Result<()> {
+        redis::pipe()
+            .atomic()
+            .del(&[self.tasks_key(), self.ids_key()])
+            .srem(self.tables_key(), self.table())
+            .query::<()>(&mut *self.connection.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Removes every key used by postit, but not the keys of other
+    /// applications that share the database.
+    #[inline]
+    fn drop_database(&self) -> super::Result<()> {
+        let mut connection = self.connection.borrow_mut();
+
+        let keys: Vec<String> = connection.scan_match(format!("{PREFIX}:*"))?.collect();
+
+        if !keys.is_empty() {
+            connection.del::<_, ()>(keys)?;
+        }
+
+        info!("Removed the postit keys of the '{}' database", self.database());
+
+        Ok(())
+    }
+
+    #[inline]
+    fn clean(&self) -> super::Result<()> {
+        self.connection
+            .borrow_mut()
+            .del::<_, ()>(&[self.tasks_key(), self.ids_key()])?;
+
+        Ok(())
+    }
+}
@@ -1,16 +1,22 @@
 //! Utilities to handle `SQLite` files.
 //!
 //! The `Sqlite` struct implements the [`DbPersister`] trait.
+//!
+//! The tags and notes of the tasks are stored in the `tags` and `notes`
+//! tables, whose rows reference the task they belong to and are deleted
+//! along with it (see [`Sqlite::relate`]).
 
+use std::collections::HashMap;
 use std::path::Path;
 use std::{fmt, fs};
 
 use sqlite::{Connection, State, Statement};
 
+use super::Migration;
 use crate::config::Config;
-use crate::models::{Task, Todo};
+use crate::models::{Priority, Task, Todo};
 use crate::traits::DbPersister;
-use crate::Action;
+use crate::{info, Action};
 
 /// Representation of a `SQLite` database.
 pub struct Sqlite {
@@ -35,7 +41,7 @@ impl Clone for Sqlite {
     fn clone(&self) -> Self {
         Self {
             conn_str: self.conn_str.clone(),
-            connection: sqlite::open(&self.conn_str).unwrap(),
+            connection: Self::connect(&self.conn_str).unwrap(),
         }
     }
 }
@@ -57,38 +63,343 @@ impl Sqlite {
             fs::create_dir_all(path.parent().unwrap())?;
         }
 
-        let instance = Self {
+        Ok(Self {
             conn_str: path.to_string_lossy().into_owned(),
-            connection: sqlite::open(path).map_err(super::Error::Sqlite)?,
-        };
+            connection: Self::connect(&path).map_err(super::Error::Sqlite)?,
+        })
+    }
 
-        Ok(instance)
+    /// Opens a connection to the `SQLite` file with the foreign keys enabled,
+    /// which `SQLite` disables by default, so the rows of the `tags` and
+    /// `notes` tables are deleted along with their task.
+    ///
+    /// # Errors
+    /// - The file can't be opened.
+    fn connect<T: AsRef<Path>>(path: T) -> sqlite::Result<Connection> {
+        let connection = sqlite::open(path)?;
+        connection.execute("PRAGMA foreign_keys = ON")?;
+
+        Ok(connection)
     }
 
-    /// Returns the desired ids format to be used in a query.
+    /// Returns one `?` placeholder per id, used in the `IN` clause of a query
+    /// whose ids are bound with [`Sqlite::bind_ids`].
     #[inline]
     pub fn format_ids(&self, ids: &[u32]) -> String {
-        ids.iter()
-            .map(|&n| n.to_string())
-            .collect::<Vec<String>>()
-            .join(", ")
+        vec!["?"; ids.len()].join(", ")
     }
 
-    /// Reads one row from the current statement.
+    /// Binds the ids to the placeholders of [`Sqlite::format_ids`], which
+    /// start at the `first` parameter of the statement.
+    ///
+    /// # Errors
+    /// - An id can't be bound.
+    #[inline]
+    pub fn bind_ids(stmt: &mut Statement, ids: &[u32], first: usize) -> sqlite::Result<()> {
+        for (index, id) in (first..).zip(ids) {
+            stmt.bind((index, i64::from(*id)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads one row from the current statement. The tags and notes are left
+    /// empty, since they are read from their tables by [`Sqlite::read_related`].
     ///
     /// # Errors
     /// - A value can't be read.
     #[inline]
-    pub fn read_row(&self, stmt: &Statement) -> super::Result<String> {
-        let row = format!(
-            "{},{},{},{}",
-            stmt.read::<i64, _>("id")?,
-            stmt.read::<String, _>("content")?,
-            stmt.read::<String, _>("priority")?,
-            stmt.read::<String, _>("checked")?,
+    pub fn read_row(&self, stmt: &Statement) -> super::Result<Task> {
+        let text = |column: &str| stmt.read::<String, _>(column);
+        let words = |column: &str| -> super::Result<Vec<String>> {
+            Ok(text(column)?
+                .split_whitespace()
+                .map(str::to_owned)
+                .collect())
+        };
+        let timestamp =
+            |column: &str| -> super::Result<_> { Ok(Task::parse_timestamp(&text(column)?)) };
+
+        Ok(Task {
+            id: u32::try_from(stmt.read::<i64, _>("id")?).map_err(super::Error::wrap)?,
+            content: text("content")?,
+            priority: Priority::from(text("priority")?.as_str()),
+            checked: matches!(text("checked")?.trim(), "1" | "true"),
+            refs: words("refs")?,
+            tags: Vec::new(),
+            notes: String::new(),
+            created_at: timestamp("created_at")?,
+            updated_at: timestamp("updated_at")?,
+            completed_at: timestamp("completed_at")?,
+            estimate: text("estimate")?.trim().parse().ok(),
+            snoozed_until: timestamp("snoozed_until")?,
+            order: text("order")?.trim().parse().ok(),
+            pinned: text("pinned")?.trim() == "true",
+            attachments: Task::parse_attachments(&text("attachments")?),
+            due: timestamp("due")?,
+        })
+    }
+
+    /// Fills the tags and notes of the tasks with the rows of the `tags` and
+    /// `notes` tables.
+    ///
+    /// # Errors
+    /// - The tables can't be read.
+    #[inline]
+    pub fn read_related(&self, tasks: &mut [Task]) -> super::Result<()> {
+        let mut by_id: HashMap<u32, &mut Task> =
+            tasks.iter_mut().map(|task| (task.id, task)).collect();
+
+        let id = |stmt: &Statement| -> super::Result<u32> {
+            u32::try_from(stmt.read::<i64, _>("task_id")?).map_err(super::Error::wrap)
+        };
+
+        let query = "SELECT task_id, tag FROM tags ORDER BY task_id, position";
+        let mut stmt = self.connection.prepare(query)?;
+
+        while matches!(stmt.next()?, State::Row) {
+            if let Some(task) = by_id.get_mut(&id(&stmt)?) {
+                task.tags.push(stmt.read::<String, _>("tag")?);
+            }
+        }
+
+        let mut stmt = self
+            .connection
+            .prepare("SELECT task_id, notes FROM notes")?;
+
+        while matches!(stmt.next()?, State::Row) {
+            if let Some(task) = by_id.get_mut(&id(&stmt)?) {
+                task.notes = stmt.read::<String, _>("notes")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the tags of the tasks with the passed ids.
+    ///
+    /// # Errors
+    /// - The rows of the `tags` table can't be deleted or inserted.
+    #[inline]
+    pub fn set_tags(&self, ids: &[u32], tags: &[String]) -> super::Result<()> {
+        let query = format!("DELETE FROM tags WHERE task_id IN ({})", self.format_ids(ids));
+
+        let mut stmt = self.connection.prepare(query)?;
+        Self::bind_ids(&mut stmt, ids, 1)?;
+        stmt.next()?;
+
+        let query = "INSERT INTO tags (task_id, position, tag) VALUES (?, ?, ?)";
+        let mut stmt = self.connection.prepare(query)?;
+
+        for id in ids {
+            for (position, tag) in (0_i64..).zip(tags) {
+                stmt.reset()?;
+                stmt.bind((1, i64::from(*id)))?;
+                stmt.bind((2, position))?;
+                stmt.bind((3, tag.as_str()))?;
+                stmt.next()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the notes of the tasks with the passed ids. Empty notes
+    /// don't have a row.
+    ///
+    /// # Errors
+    /// - The rows of the `notes` table can't be deleted or inserted.
+    #[inline]
+    pub fn set_notes(&self, ids: &[u32], notes: &str) -> super::Result<()> {
+        let query = format!("DELETE FROM notes WHERE task_id IN ({})", self.format_ids(ids));
+
+        let mut stmt = self.connection.prepare(query)?;
+        Self::bind_ids(&mut stmt, ids, 1)?;
+        stmt.next()?;
+
+        if notes.is_empty() {
+            return Ok(());
+        }
+
+        let mut stmt = self
+            .connection
+            .prepare("INSERT INTO notes (task_id, notes) VALUES (?, ?)")?;
+
+        for id in ids {
+            stmt.reset()?;
+            stmt.bind((1, i64::from(*id)))?;
+            stmt.bind((2, notes))?;
+            stmt.next()?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the `tags` and `notes` tables if they don't exist, and moves
+    /// the values of the `tags` and `notes` columns of the tasks table, where
+    /// older versions stored them, to their rows. The columns are kept empty.
+    ///
+    /// Their rows reference the task they belong to, so they are deleted
+    /// along with it.
+    ///
+    /// # Errors
+    /// - The tables can't be created.
+    /// - The old values can't be read or moved.
+    #[inline]
+    pub fn relate(&self) -> super::Result<()> {
+        if self.has_table("tags")? && self.has_table("notes")? {
+            return Ok(());
+        }
+
+        #[rustfmt::skip]
+        let query = format!("
+            CREATE TABLE IF NOT EXISTS tags (
+                task_id     INTEGER NOT NULL REFERENCES {table} (id) ON DELETE CASCADE,
+                position    INTEGER NOT NULL,
+                tag         TEXT NOT NULL,
+                PRIMARY KEY (task_id, position)
+            );
+            CREATE TABLE IF NOT EXISTS notes (
+                task_id     INTEGER PRIMARY KEY REFERENCES {table} (id) ON DELETE CASCADE,
+                notes       TEXT NOT NULL
+            );
+        ", table = self.table());
+
+        self.atomic(|| {
+            self.connection.execute(query)?;
+
+            let query = format!(
+                "SELECT id, tags, notes FROM {} WHERE tags != '' OR notes != ''",
+                self.table()
+            );
+            let mut stmt = self.connection.prepare(query)?;
+
+            while matches!(stmt.next()?, State::Row) {
+                let id = u32::try_from(stmt.read::<i64, _>("id")?).map_err(super::Error::wrap)?;
+                let tags: Vec<String> = stmt
+                    .read::<String, _>("tags")?
+                    .split_whitespace()
+                    .map(str::to_owned)
+                    .collect();
+
+                self.set_tags(&[id], &tags)?;
+                self.set_notes(&[id], &stmt.read::<String, _>("notes")?)?;
+            }
+
+            let query = format!("UPDATE {} SET tags = '', notes = ''", self.table());
+
+            Ok(self.connection.execute(query)?)
+        })
+    }
+
+    /// Checks if the database has a table called `name`.
+    ///
+    /// # Errors
+    /// - The statement can't be prepared.
+    /// - The name column can't be read.
+    #[inline]
+    pub fn has_table(&self, name: &str) -> super::Result<bool> {
+        #[rustfmt::skip]
+        let query = "
+            SELECT name
+            FROM sqlite_master
+            WHERE type = 'table'
+              AND name = ?
+        ";
+
+        let mut stmt = self.connection.prepare(query)?;
+        stmt.bind((1, name))?;
+
+        Ok(matches!(stmt.next()?, State::Row))
+    }
+
+    /// Runs an operation inside a savepoint, so its queries are written to the
+    /// file at once and discarded together if one of them fails.
+    ///
+    /// Savepoints can be nested, which lets the operation call other methods
+    /// that are atomic too.
+    ///
+    /// # Errors
+    /// - The savepoint can't be opened or released.
+    /// - The operation fails.
+    #[inline]
+    pub fn atomic<F>(&self, op: F) -> super::Result<()>
+    where
+        F: FnOnce() -> super::Result<()>,
+    {
+        self.connection.execute("SAVEPOINT postit")?;
+
+        match op() {
+            Ok(()) => Ok(self.connection.execute("RELEASE postit")?),
+            Err(e) => {
+                self.connection
+                    .execute("ROLLBACK TO postit; RELEASE postit")?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Creates the table that stores the schema version of each table.
+    ///
+    /// # Errors
+    /// - The table can't be created.
+    #[inline]
+    pub fn create_versions(&self) -> sqlite::Result<()> {
+        #[rustfmt::skip]
+        let query = "
+            CREATE TABLE IF NOT EXISTS schema_version (
+                name    TEXT PRIMARY KEY,
+                version INTEGER NOT NULL
+            )
+        ";
+
+        self.connection.execute(query)
+    }
+
+    /// Records the schema version of the table.
+    ///
+    /// # Errors
+    /// - The version can't be written.
+    #[inline]
+    pub fn set_version(&self, version: u32) -> super::Result<()> {
+        self.create_versions()?;
+
+        let query = "INSERT OR REPLACE INTO schema_version (name, version) VALUES (?, ?)";
+
+        let mut stmt = self.connection.prepare(query)?;
+        stmt.bind((1, self.table().as_str()))?;
+        stmt.bind((2, i64::from(version)))?;
+        stmt.next()?;
+
+        Ok(())
+    }
+
+    /// Adds a column to the table if it doesn't have it.
+    ///
+    /// # Errors
+    /// - The columns of the table can't be read or the column can't be added.
+    #[inline]
+    pub fn add_column(&self, column: &str) -> sqlite::Result<()> {
+        #[rustfmt::skip]
+        let query = "
+            SELECT name
+            FROM pragma_table_info(?)
+            WHERE name = ?
+        ";
+
+        let mut stmt = self.connection.prepare(query)?;
+        stmt.bind(&[self.table().as_str(), column][..])?;
+
+        if matches!(stmt.next()?, State::Row) {
+            return Ok(());
+        }
+
+        let query = format!(
+            "ALTER TABLE {} ADD COLUMN \"{column}\" TEXT NOT NULL DEFAULT ''",
+            self.table()
         );
 
-        Ok(row)
+        self.connection.execute(query)
     }
 
     /// Resets the autoincrement value.
@@ -98,13 +409,15 @@ impl Sqlite {
     #[inline]
     pub fn reset_autoincrement(&self, table: &str) -> sqlite::Result<State> {
         #[rustfmt::skip]
-        let query = format!("
+        let query = "
             UPDATE sqlite_sequence
             SET SEQ=0
-            WHERE NAME='{table}'
-        ");
+            WHERE NAME = ?
+        ";
 
-        self.connection.prepare(query)?.next()
+        let mut stmt = self.connection.prepare(query)?;
+        stmt.bind((1, table))?;
+        stmt.next()
     }
 }
 
@@ -134,40 +447,59 @@ impl DbPersister for Sqlite {
             .to_owned()
     }
 
-    /// Checks if a table exists.
+    /// Checks if the tasks table exists.
     ///
     /// # Errors
     /// - The statement can't be prepared.
     /// - The name column can't be read.
     #[inline]
     fn exists(&self) -> super::Result<bool> {
-        #[rustfmt::skip]
-        let query = format!("
-            SELECT *
-            FROM sqlite_master
-            WHERE type='table'
-              AND name='{}'
-        ", self.table());
+        self.has_table(&self.table())
+    }
 
-        let mut stmt = self.connection.prepare(query)?;
+    #[inline]
+    fn version(&self) -> super::Result<u32> {
+        self.create_versions()?;
 
-        let mut result = vec![];
+        let mut stmt = self
+            .connection
+            .prepare("SELECT version FROM schema_version WHERE name = ?")?;
+        stmt.bind((1, self.table().as_str()))?;
 
-        while matches!(stmt.next(), Ok(State::Row)) {
-            result.push(stmt.read::<String, _>("name")?);
+        if !matches!(stmt.next()?, State::Row) {
+            return Ok(0);
+        }
+
+        let version = stmt.read::<i64, _>("version")?;
+
+        u32::try_from(version).map_err(super::Error::wrap)
+    }
+
+    #[inline]
+    fn migrate(&self) -> super::Result<()> {
+        if !self.exists()? {
+            return Ok(());
+        }
+
+        let version = self.version()?;
+
+        if version < Migration::latest() {
+            self.atomic(|| {
+                for migration in Migration::pending(version) {
+                    self.add_column(migration.column)?;
+                }
+
+                self.set_version(Migration::latest())
+            })?;
         }
 
-        Ok(!result.is_empty())
+        self.relate()
     }
 
     #[inline]
     fn tasks(&self) -> super::Result<Vec<Task>> {
         if !self.exists()? {
-            let err = format!(
-                "The '{}' table has no tasks; add a task first to use this command",
-                self.table()
-            );
-            return Err(super::Error::wrap(err));
+            return Err(super::Error::TableDoesntExist(self.table()));
         }
 
         let query = format!("SELECT * FROM {}", self.table());
@@ -176,9 +508,11 @@ impl DbPersister for Sqlite {
         let mut result = vec![];
 
         while matches!(stmt.next(), Ok(State::Row)) {
-            result.push(Task::from(self.read_row(&stmt)?));
+            result.push(self.read_row(&stmt)?);
         }
 
+        self.read_related(&mut result)?;
+
         Ok(result)
     }
 
@@ -206,13 +540,27 @@ impl DbPersister for Sqlite {
                 id          INTEGER PRIMARY KEY AUTOINCREMENT,
                 content     TEXT NOT NULL,
                 priority    TEXT NOT NULL,
-                checked     BOOLEAN NOT NULL CHECK (checked IN (0, 1))
+                checked     BOOLEAN NOT NULL CHECK (checked IN (0, 1)),
+                refs        TEXT NOT NULL DEFAULT '',
+                tags        TEXT NOT NULL DEFAULT '',
+                notes       TEXT NOT NULL DEFAULT '',
+                created_at  TEXT NOT NULL DEFAULT '',
+                updated_at  TEXT NOT NULL DEFAULT '',
+                completed_at TEXT NOT NULL DEFAULT '',
+                estimate    TEXT NOT NULL DEFAULT '',
+                snoozed_until TEXT NOT NULL DEFAULT '',
+                \"order\"     TEXT NOT NULL DEFAULT '',
+                pinned      TEXT NOT NULL DEFAULT '',
+                attachments TEXT NOT NULL DEFAULT '',
+                due         TEXT NOT NULL DEFAULT ''
             )
         ", self.table());
 
         self.connection.execute(query)?;
+        self.set_version(Migration::latest())?;
+        self.relate()?;
 
-        println!("Created the '{}' table in the '{}' database", self.table(), self.database());
+        info!("Created the '{}' table in the '{}' database", self.table(), self.database());
 
         Ok(())
     }
@@ -221,26 +569,48 @@ impl DbPersister for Sqlite {
     fn insert(&self, todo: &Todo) -> super::Result<()> {
         #[rustfmt::skip]
         let query = format!("
-            INSERT INTO {} (content, priority, checked)
-            VALUES (?, ?, ?)
+            INSERT INTO {} (
+                id, content, priority, checked, refs, tags, notes,
+                created_at, updated_at, completed_at, estimate, snoozed_until, \"order\", pinned,
+                attachments, due
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         ", self.table());
 
-        let mut stmt = self.connection.prepare(query)?;
-
-        for task in &todo.tasks {
-            stmt.reset()?;
-
-            #[rustfmt::skip]
-            stmt.bind(&[
-                &task.content,
-                task.priority.to_str(),
-                i32::from(task.checked).to_string().as_str()
-            ][..])?;
-
-            stmt.next()?;
-        }
-
-        Ok(())
+        self.atomic(|| {
+            let mut stmt = self.connection.prepare(query)?;
+
+            for task in &todo.tasks {
+                stmt.reset()?;
+
+                #[rustfmt::skip]
+                stmt.bind(&[
+                    task.id.to_string().as_str(),
+                    &task.content,
+                    task.priority.to_str(),
+                    i32::from(task.checked).to_string().as_str(),
+                    task.refs.join(" ").as_str(),
+                    "",
+                    "",
+                    Task::format_timestamp(task.created_at.as_ref()).as_str(),
+                    Task::format_timestamp(task.updated_at.as_ref()).as_str(),
+                    Task::format_timestamp(task.completed_at.as_ref()).as_str(),
+                    task.estimate_field().as_str(),
+                    Task::format_timestamp(task.snoozed_until.as_ref()).as_str(),
+                    task.order_field().as_str(),
+                    task.pinned_field().as_str(),
+                    task.attachments_field().as_str(),
+                    Task::format_timestamp(task.due.as_ref()).as_str()
+                ][..])?;
+
+                stmt.next()?;
+
+                self.set_tags(&[task.id], &task.tags)?;
+                self.set_notes(&[task.id], &task.notes)?;
+            }
+
+            Ok(())
+        })
     }
 
     #[inline]
@@ -249,27 +619,79 @@ impl DbPersister for Sqlite {
             return self.delete(ids);
         }
 
+        // Each task has its own position, so they are updated one by one
+        if matches!(action, Action::Move) && ids.len() > 1 {
+            return self.atomic(|| {
+                ids.iter()
+                    .try_for_each(|id| self.update(todo, &[*id], action))
+            });
+        }
+
+        let task = todo.get(ids)[0];
+
+        let refs = task.refs.join(" ");
+        let minutes = task.estimate_field();
+        let until = Task::format_timestamp(task.snoozed_until.as_ref());
+        let order = task.order_field();
+        let pinned = task.pinned_field();
+        let attachments = task.attachments_field();
+        let due = Task::format_timestamp(task.due.as_ref());
+        let updated_at = Task::format_timestamp(task.updated_at.as_ref());
+        let completed_at = Task::format_timestamp(task.completed_at.as_ref());
+
         let (field, value) = match action {
             Action::Check => ("checked", "1"),
             Action::Uncheck => ("checked", "0"),
-            Action::SetContent => ("content", todo.get(ids)[0].content.as_str()),
-            Action::SetPriority => ("priority", todo.get(ids)[0].priority.to_str()),
+            Action::SetContent => ("content", task.content.as_str()),
+            Action::SetPriority => ("priority", task.priority.to_str()),
+            Action::SetRefs => ("refs", refs.as_str()),
+            // Their values are written to their own tables below
+            Action::SetTags => ("tags", ""),
+            Action::SetNotes => ("notes", ""),
+            Action::SetEstimate => ("estimate", minutes.as_str()),
+            Action::Snooze => ("snoozed_until", until.as_str()),
+            Action::Move => ("\"order\"", order.as_str()),
+            Action::Pin | Action::Unpin => ("pinned", pinned.as_str()),
+            Action::Attach => ("attachments", attachments.as_str()),
+            Action::SetDue => ("due", due.as_str()),
             Action::Drop => unreachable!(),
         };
 
+        let mut columns = format!("{field} = ?, updated_at = ?");
+        let mut values = vec![value, updated_at.as_str()];
+
+        // The other tasks keep their own completion date
+        if matches!(action, Action::Check | Action::Uncheck) {
+            columns.push_str(", completed_at = ?");
+            values.push(completed_at.as_str());
+        }
+
         #[rustfmt::skip]
         let query = format!("
             UPDATE {}
-            SET {field} = \"{value}\"
+            SET {columns}
             WHERE id
             IN ({})
         ", self.table(), self.format_ids(ids));
 
-        let mut stmt = self.connection.prepare(query)?;
+        self.atomic(|| {
+            if matches!(action, Action::SetTags) {
+                self.set_tags(ids, &task.tags)?;
+            }
 
-        stmt.next()?;
+            if matches!(action, Action::SetNotes) {
+                self.set_notes(ids, &task.notes)?;
+            }
 
-        Ok(())
+            let mut stmt = self.connection.prepare(query)?;
+
+            stmt.bind(&values[..])?;
+            Self::bind_ids(&mut stmt, ids, values.len() + 1)?;
+
+            stmt.next()?;
+
+            Ok(())
+        })
     }
 
     #[inline]
@@ -282,6 +704,7 @@ impl DbPersister for Sqlite {
         ", self.table(), self.format_ids(ids));
 
         let mut stmt = self.connection.prepare(query)?;
+        Self::bind_ids(&mut stmt, ids, 1)?;
 
         stmt.next()?;
 
@@ -291,20 +714,17 @@ impl DbPersister for Sqlite {
     #[inline]
     fn drop_table(&self) -> super::Result<()> {
         let table = self.table();
-        let query = format!("DROP TABLE {table}");
+        let query =
+            format!("DROP TABLE IF EXISTS tags; DROP TABLE IF EXISTS notes; DROP TABLE {table}");
 
-        let mut stmt = self.connection.prepare(query)?;
-
-        stmt.next()?;
-
-        Ok(())
+        self.atomic(|| Ok(self.connection.execute(query)?))
     }
 
     #[inline]
     fn drop_database(&self) -> super::Result<()> {
         fs::remove_file(self.conn()).map_err(super::Error::wrap)?;
 
-        println!("Removed the '{}' file", self.database());
+        info!("Removed the '{}' file", self.database());
 
         Ok(())
     }
@@ -314,11 +734,21 @@ impl DbPersister for Sqlite {
         let table = self.table();
         let query = format!("DELETE FROM {table}");
 
-        let mut stmt = self.connection.prepare(query)?;
-        stmt.next()?;
+        self.atomic(|| {
+            let mut stmt = self.connection.prepare(query)?;
+            stmt.next()?;
 
-        self.reset_autoincrement(&table)?;
+            self.reset_autoincrement(&table)?;
 
-        Ok(())
+            Ok(())
+        })
+    }
+
+    #[inline]
+    fn replace(&self, todo: &Todo) -> super::Result<()> {
+        self.atomic(|| {
+            self.clean()?;
+            self.insert(todo)
+        })
     }
 }
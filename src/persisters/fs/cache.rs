@@ -0,0 +1,132 @@
+//! Contains the `Cache` struct, which keeps the parsed tasks of a file so they
+//! don't have to be parsed again until the file changes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Task;
+
+/// Values that identify a version of the cached file.
+///
+/// Every write of postit replaces the file (see
+/// [`FilePersister::write_atomic`][`crate::traits::FilePersister::write_atomic`]),
+/// which changes its modification time, so the tasks are parsed again after
+/// any change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Stamp {
+    /// Seconds of the modification time since the UNIX epoch.
+    secs: u64,
+    /// Nanoseconds of the modification time.
+    nanos: u32,
+    /// Size of the file in bytes.
+    len: u64,
+}
+
+/// Contents of a cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    /// Version of the file when the tasks were cached.
+    stamp: Stamp,
+    /// Parsed tasks of the file.
+    tasks: Vec<Task>,
+}
+
+/// Cache of the parsed tasks of a file, stored as `MessagePack` in a
+/// `.<name>.cache` file next to it.
+///
+/// The cache is only an optimization: a missing, outdated or unreadable cache
+/// file makes postit parse the file again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    /// Path of the cached file.
+    source: PathBuf,
+    /// Path of the cache file.
+    path: PathBuf,
+}
+
+impl Cache {
+    /// Constructor of the `Cache` struct.
+    #[inline]
+    pub fn new<T: AsRef<Path>>(source: T) -> Self {
+        let source = source.as_ref().to_path_buf();
+        let path = Self::path_of(&source);
+
+        Self { source, path }
+    }
+
+    /// Returns the path of the cache file of a file.
+    #[inline]
+    pub fn path_of(path: &Path) -> PathBuf {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        path.with_file_name(format!(".{name}.cache"))
+    }
+
+    /// Returns the path of the cache file.
+    #[inline]
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the current version of the cached file, or `None` if its
+    /// metadata can't be read.
+    fn stamp(&self) -> Option<Stamp> {
+        let metadata = fs::metadata(&self.source).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+        Some(Stamp {
+            secs: modified.as_secs(),
+            nanos: modified.subsec_nanos(),
+            len: metadata.len(),
+        })
+    }
+
+    /// Returns the cached tasks if they belong to the current version of the file.
+    #[inline]
+    pub fn load(&self) -> Option<Vec<Task>> {
+        let stamp = self.stamp()?;
+        let bytes = fs::read(&self.path).ok()?;
+        let entry: Entry = rmp_serde::from_slice(&bytes).ok()?;
+
+        (entry.stamp == stamp).then_some(entry.tasks)
+    }
+
+    /// Stores the tasks parsed from the current version of the file.
+    ///
+    /// # Errors
+    /// - The metadata of the file can't be read.
+    /// - The tasks can't be serialized or the cache file can't be written.
+    #[inline]
+    pub fn store(&self, tasks: &[Task]) -> super::Result<()> {
+        let Some(stamp) = self.stamp() else {
+            let name = self
+                .source
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            return Err(super::Error::FileDoesntExist(name.to_string()));
+        };
+
+        let entry = Entry { stamp, tasks: tasks.to_vec() };
+        let bytes = rmp_serde::to_vec_named(&entry).map_err(super::Error::wrap)?;
+
+        fs::write(&self.path, bytes)?;
+
+        Ok(())
+    }
+
+    /// Deletes the cache file, if it exists.
+    ///
+    /// # Errors
+    /// - The cache file exists but can't be removed.
+    #[inline]
+    pub fn clear(&self) -> super::Result<()> {
+        if self.path.exists() {
+            fs::remove_file(&self.path)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,31 +1,292 @@
 //! Utilities to handle CSV files.
 //!
-//! The `Csv` struct implements the [`FilePersister`] trait.
+//! The `Csv` struct implements the [`FilePersister`] trait, and the
+//! `CsvDialect` struct defines how its fields are separated and quoted.
 
-use std::fs;
+use std::io::{BufRead as _, BufReader};
 use std::path::{Path, PathBuf};
+use std::{fmt, fs, mem};
+
+use serde::{Deserialize, Serialize};
 
 use crate::models::{Task, Todo};
 use crate::traits::FilePersister;
 
+/// Names of the columns of a CSV file, in the order of [`Task::fields`].
+const COLUMNS: [&str; 16] = [
+    "id",
+    "content",
+    "priority",
+    "checked",
+    "refs",
+    "tags",
+    "notes",
+    "created_at",
+    "updated_at",
+    "completed_at",
+    "estimate",
+    "snoozed_until",
+    "order",
+    "pinned",
+    "attachments",
+    "due",
+];
+
+/// Defines which fields are quoted when a CSV file is written.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Quoting {
+    /// Only quotes the fields that have the delimiter or a line break, or
+    /// that start with a quote.
+    #[default]
+    Needed,
+    /// Quotes every field.
+    Always,
+}
+
+impl fmt::Display for Quoting {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Needed => write!(f, "needed"),
+            Self::Always => write!(f, "always"),
+        }
+    }
+}
+
+/// Format of the lines of a CSV file, so files exported by spreadsheets
+/// (e.g.: separated by `;` or tabs) can be used without converting them.
+///
+/// Quoted fields can have the delimiter, and quotes inside of them are
+/// written twice (`""`). The file is read line by line, so line breaks are
+/// written as spaces in the content and percent-encoded in the other fields.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvDialect {
+    /// Character that separates the fields.
+    pub delimiter: char,
+    /// Defines which fields are quoted.
+    pub quoting: Quoting,
+    /// If `true`, the first line of the file has the names of the columns.
+    pub header: bool,
+}
+
+impl Default for CsvDialect {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quoting: Quoting::Needed,
+            header: true,
+        }
+    }
+}
+
+impl fmt::Display for CsvDialect {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "delimiter={:?} quoting={} header={}", self.delimiter, self.quoting, self.header)
+    }
+}
+
+impl CsvDialect {
+    /// Returns the first line of a file, which has the names of the columns,
+    /// or an empty string if the dialect has no header.
+    #[inline]
+    pub fn header_row(&self) -> String {
+        if !self.header {
+            return String::new();
+        }
+
+        let columns: Vec<String> = COLUMNS.map(String::from).to_vec();
+
+        format!("{}\n", self.join(&columns))
+    }
+
+    /// Returns `true` if a line has the names of the columns.
+    #[inline]
+    pub fn is_header(&self, line: &str) -> bool {
+        let fields = self.split(line);
+        let names: Vec<&str> = fields.iter().take(2).map(|field| field.trim()).collect();
+
+        names == ["id", "content"]
+    }
+
+    /// Splits a line into its fields. A field is quoted if it starts with a
+    /// quote, and two quotes inside of it are read as one.
+    #[inline]
+    pub fn split(&self, line: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if quoted {
+                if c != '"' {
+                    field.push(c);
+                } else if chars.next_if_eq(&'"').is_some() {
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            } else if c == '"' && field.trim().is_empty() {
+                field.clear();
+                quoted = true;
+            } else if c == self.delimiter {
+                fields.push(mem::take(&mut field));
+            } else {
+                field.push(c);
+            }
+        }
+
+        fields.push(field);
+
+        fields
+    }
+
+    /// Joins fields into a line, quoting them based on the `quoting`.
+    #[inline]
+    pub fn join(&self, fields: &[String]) -> String {
+        let quote = |field: &String| match self.quoting {
+            Quoting::Always => true,
+            Quoting::Needed => {
+                field.starts_with('"') || field.contains([self.delimiter, '\n', '\r'])
+            }
+        };
+
+        fields
+            .iter()
+            .map(|field| {
+                if quote(field) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(&self.delimiter.to_string())
+    }
+
+    /// Formats a task as a line.
+    #[inline]
+    pub fn line(&self, task: &Task) -> String {
+        let mut fields = task.fields();
+
+        if let Some(content) = fields.get_mut(1) {
+            *content = content.replace(['\n', '\r'], " ");
+        }
+
+        self.join(&fields)
+    }
+
+    /// Reads a task from a line.
+    ///
+    /// # Panics
+    /// If the id of the task isn't a number.
+    #[inline]
+    pub fn task(&self, line: &str) -> Task {
+        let fields = self.split(line);
+
+        // Commas are escaped so that the fields keep their positions, and
+        // the content, which isn't escaped, is taken as it is
+        let escaped: Vec<String> = fields
+            .iter()
+            .map(|field| field.replace(',', "%2C"))
+            .collect();
+        let mut task = Task::from(escaped.join(","));
+
+        if let Some(content) = fields.get(1) {
+            content.trim().clone_into(&mut task.content);
+        }
+
+        task
+    }
+
+    /// Parses CSV text into tasks. The header is optional (files written
+    /// by older versions don't have the columns added after them) and blank lines
+    /// are ignored, so the header is the first line that isn't blank.
+    ///
+    /// # Errors
+    /// - A line doesn't have a numeric id or a content.
+    #[inline]
+    pub fn parse(&self, content: &str) -> super::Result<Vec<Task>> {
+        content
+            .lines()
+            .map(str::trim)
+            .enumerate()
+            .filter(|&(_, line)| !line.is_empty())
+            .enumerate()
+            .filter(|&(pos, (_, line))| pos != 0 || !self.is_header(line))
+            .map(|(_, (i, line))| self.parse_line(i + 1, line))
+            .collect()
+    }
+
+    /// Reads the task of a line, checking that it has a numeric id and a
+    /// content so that [`CsvDialect::task`] doesn't panic.
+    ///
+    /// # Errors
+    /// - The line doesn't have a numeric id or a content.
+    fn parse_line(&self, number: usize, line: &str) -> super::Result<Task> {
+        let fields = self.split(line);
+        let mut fields = fields.iter().map(|field| field.trim());
+
+        let has_id = fields.next().is_some_and(|id| id.parse::<u32>().is_ok());
+        let has_content = fields.next().is_some_and(|content| !content.is_empty());
+
+        if has_id && has_content {
+            Ok(self.task(line))
+        } else {
+            Err(super::Error::InvalidCsv(number, line.to_owned()))
+        }
+    }
+}
+
 /// Representation of a CSV file.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Csv {
     /// Location of the CSV file.
     path: PathBuf,
+    /// Format of the lines of the file.
+    dialect: CsvDialect,
 }
 
 impl Csv {
-    /// Constructor of the `Csv` struct.
+    /// Constructor of the `Csv` struct, which uses the default dialect.
     #[inline]
     pub fn new<T: AsRef<Path>>(path: T) -> Self {
-        Self { path: path.as_ref().to_path_buf() }
+        Self::with(path, CsvDialect::default())
+    }
+
+    /// Constructor of the `Csv` struct that reads and writes the file with a
+    /// dialect.
+    #[inline]
+    pub fn with<T: AsRef<Path>>(path: T, dialect: CsvDialect) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            dialect,
+        }
+    }
+
+    /// Returns the dialect used to read and write the file.
+    #[inline]
+    pub const fn dialect(&self) -> &CsvDialect {
+        &self.dialect
     }
 
     /// Returns the header of a the csv file.
     #[inline]
     pub fn header() -> String {
-        String::from("id,content,priority,checked\n")
+        CsvDialect::default().header_row()
+    }
+
+    /// Parses CSV text with the default dialect (see [`CsvDialect::parse`]).
+    ///
+    /// # Errors
+    /// - A line doesn't have a numeric id or a content.
+    #[inline]
+    pub fn parse(content: &str) -> super::Result<Vec<Task>> {
+        CsvDialect::default().parse(content)
     }
 }
 
@@ -42,20 +303,31 @@ impl FilePersister for Csv {
 
     #[inline]
     fn default(&self) -> String {
-        Self::header()
+        self.dialect.header_row()
     }
 
     #[inline]
     fn tasks(&self) -> super::Result<Vec<Task>> {
-        let lines: Vec<String> = fs::read_to_string(&self.path)?
-            .lines()
-            .map(|line| line.trim().to_owned())
-            .filter(|line| !line.is_empty())
-            .collect();
+        self.stream()?.collect()
+    }
 
-        let tasks = lines.iter().skip(1).map(Task::from).collect();
+    /// Reads the file line by line, so only the tasks that the iterator
+    /// returns are kept in memory. Like [`CsvDialect::parse`], the first line
+    /// is skipped only if it's a header, whether the dialect writes one or not.
+    #[inline]
+    fn stream(&self) -> super::Result<Box<dyn Iterator<Item = super::Result<Task>> + '_>> {
+        let lines = BufReader::new(self.open()?)
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+            .enumerate()
+            .filter_map(|(pos, (i, line))| match line {
+                Ok(line) if pos == 0 && self.dialect.is_header(line.trim()) => None,
+                Ok(line) => Some(self.dialect.parse_line(i + 1, line.trim())),
+                Err(e) => Some(Err(e.into())),
+            });
 
-        Ok(tasks)
+        Ok(Box::new(lines))
     }
 
     #[inline]
@@ -67,20 +339,40 @@ impl FilePersister for Csv {
     fn write(&self, todo: &Todo) -> super::Result<()> {
         let sep = if cfg!(windows) { "\r\n" } else { "\n" };
 
-        let mut bytes = Self::header().into_bytes();
+        let mut bytes = self.dialect.header_row().into_bytes();
         let mut tasks = todo
             .tasks
             .iter()
-            .map(Task::as_line)
+            .map(|task| self.dialect.line(task))
             .collect::<Vec<String>>()
             .join(sep)
             .into_bytes();
 
         bytes.append(&mut tasks);
 
-        fs::write(&self.path, bytes)?;
+        self.write_atomic(&bytes)
+    }
 
-        Ok(())
+    /// Appends the line of the task to the end of the file, after a line
+    /// break if the file doesn't end with one (which is how it is written).
+    ///
+    /// The file is replaced with [`FilePersister::write_atomic`], like in
+    /// [`FilePersister::write`], but its tasks aren't parsed.
+    #[inline]
+    fn append(&self, task: &Task) -> super::Result<()> {
+        let sep = if cfg!(windows) { "\r\n" } else { "\n" };
+
+        let mut bytes = fs::read(&self.path)?;
+
+        if bytes.is_empty() {
+            bytes.extend_from_slice(self.dialect.header_row().as_bytes());
+        } else if bytes.last() != Some(&b'\n') {
+            bytes.extend_from_slice(sep.as_bytes());
+        }
+
+        bytes.extend_from_slice(self.dialect.line(task).as_bytes());
+
+        self.write_atomic(&bytes)
     }
 
     #[inline]
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::tr;
+
 /// Convenience type for database related operations.
 pub type Result<T> = std::result::Result<T, self::Error>;
 
@@ -9,17 +11,42 @@ pub type Result<T> = std::result::Result<T, self::Error>;
 #[derive(Error, Debug)]
 pub enum Error {
     /// Used when the file is actually a directory.
-    #[error("The persister can't be a directory")]
+    #[error("{}", tr!("The persister can't be a directory"))]
     IsDirectory,
 
     /// Used for file format related issues.
-    #[error("Unsupported file format; defaulting to CSV")]
+    #[error("{}", tr!("Unsupported file format; defaulting to CSV"))]
     UnsupportedFormat,
 
     /// Used when a file doesn't exist when it was expected to.
-    #[error("The file '{0}' doesn't exist")]
+    #[error("{}", tr!("The file '{path}' doesn't exist", path = .0))]
     FileDoesntExist(String),
 
+    /// Used when an XML file is malformed or doesn't match its DTD, with the
+    /// line where the problem is and its reason.
+    #[error("{}", tr!("Invalid XML at line {line}: {reason}", line = .0, reason = .1))]
+    InvalidXml(usize, String),
+
+    /// Used when a CSV line isn't a valid task, with the number of the line
+    /// and its content.
+    #[error("{}", tr!("Invalid CSV task at line {line}: '{content}'", line = .0, content = .1))]
+    InvalidCsv(usize, String),
+
+    /// Used when a file already exists when it wasn't expected to.
+    #[error("{}", tr!("The file '{path}' already exists", path = .0))]
+    AlreadyExists(String),
+
+    /// Used when the file (or the directory where it goes) can't be written.
+    #[error("{}", tr!("'{path}' is read-only", path = .0))]
+    ReadOnly(String),
+
+    /// Used when another process keeps a file locked for too long.
+    #[error(
+        "{}",
+        tr!("The file '{path}' is being changed by another postit process; try again later", path = .0)
+    )]
+    Locked(String),
+
     /// Used for I/O errors ([`std::io::Error`]).
     #[error("{0}")]
     Io(#[from] std::io::Error),
@@ -46,4 +73,24 @@ impl Error {
     {
         Self::Other(err.into())
     }
+
+    /// Returns the stable code of the error, which can be looked up with
+    /// 'postit docs errors'.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        match *self {
+            Self::IsDirectory => "E201",
+            Self::UnsupportedFormat => "E202",
+            Self::FileDoesntExist(_) => "E203",
+            Self::Io(_) => "E204",
+            Self::Json(_) => "E205",
+            Self::Xml(_) => "E206",
+            Self::Locked(_) => "E207",
+            Self::InvalidXml(..) => "E208",
+            Self::InvalidCsv(..) => "E209",
+            Self::AlreadyExists(_) => "E210",
+            Self::ReadOnly(_) => "E211",
+            Self::Other(_) => "E299",
+        }
+    }
 }
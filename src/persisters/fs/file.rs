@@ -5,21 +5,27 @@
 
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use std::{fmt, fs};
 
-use super::{error, Csv, Json, Xml};
+use clap::ValueEnum;
+
+use super::{error, Csv, CsvDialect, Json, Jsonl, Lock, Xml};
 use crate::config::Config;
 use crate::models::{Task, Todo};
+use crate::registry::Registry;
 use crate::traits::{FilePersister, Persister};
-use crate::Action;
+use crate::{info, warning, Action};
 
 /// Possible file formats.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
 pub enum Format {
     /// A CSV file (associated persister: [`Csv`]).
     Csv,
     /// A JSON file (associated persister: [`Json`]).
     Json,
+    /// A JSON Lines file (associated persister: [`Jsonl`]).
+    Jsonl,
     /// An XML file (associated persister: [`Xml`]).
     Xml,
 }
@@ -30,10 +36,11 @@ impl<T: AsRef<str>> From<T> for Format {
     fn from(s: T) -> Self {
         match s.as_ref().to_lowercase().trim() {
             "json" => Self::Json,
+            "jsonl" => Self::Jsonl,
             "csv" => Self::Csv,
             "xml" => Self::Xml,
             _ => {
-                eprintln!("{}", error::Error::UnsupportedFormat);
+                warning!("{}", error::Error::UnsupportedFormat);
                 Self::Csv
             }
         }
@@ -47,9 +54,24 @@ impl Format {
         match *self {
             Self::Csv => "csv",
             Self::Json => "json",
+            Self::Jsonl => "jsonl",
             Self::Xml => "xml",
         }
     }
+
+    /// Parses text written in this format into tasks.
+    ///
+    /// # Errors
+    /// - The text isn't valid for the format.
+    #[inline]
+    pub fn parse(&self, content: &str) -> super::Result<Vec<Task>> {
+        match *self {
+            Self::Csv => Csv::parse(content),
+            Self::Json => Json::parse(content),
+            Self::Jsonl => Jsonl::parse(content),
+            Self::Xml => Xml::parse(content),
+        }
+    }
 }
 
 /// Representation of a file that is used to manage a [`Todo`] structure.
@@ -73,7 +95,8 @@ impl File {
         Self { file }
     }
 
-    /// Creates a `File` instance from a path.
+    /// Creates a `File` instance from a path. CSV files use the default
+    /// dialect and JSON files are written indented.
     ///
     /// # Errors
     /// - The path of the file can't be constructed from the Config path.
@@ -83,6 +106,20 @@ impl File {
     /// - The parent directory can't be obtained (only in case it has to be created).
     #[inline]
     pub fn from<T: AsRef<str>>(path: T) -> crate::Result<Self> {
+        Self::with(path, CsvDialect::default(), true)
+    }
+
+    /// Creates a `File` instance from a path. CSV files use `dialect` and JSON
+    /// files are written indented if `pretty` is `true`, or minified if it isn't.
+    ///
+    /// # Errors
+    /// - The path of the file can't be constructed from the Config path.
+    /// - The persister can't be obtained.
+    ///
+    /// # Panics
+    /// - The parent directory can't be obtained (only in case it has to be created).
+    #[inline]
+    pub fn with<T: AsRef<str>>(path: T, dialect: CsvDialect, pretty: bool) -> crate::Result<Self> {
         let file_name = Self::check_name(path.as_ref());
         let file_path = Config::build_path(file_name)?;
 
@@ -90,7 +127,9 @@ impl File {
             fs::create_dir_all(file_path.parent().unwrap())?;
         }
 
-        Ok(Self { file: Self::get_persister(file_path)? })
+        Ok(Self {
+            file: Self::get_persister_with(file_path, dialect, pretty)?,
+        })
     }
 
     /// Returns the path of the file.
@@ -99,6 +138,33 @@ impl File {
         self.file.path()
     }
 
+    /// Returns the tasks of the file, which are taken from its [`Cache`][`super::Cache`]
+    /// if the format is cached and the file didn't change since they were parsed.
+    ///
+    /// # Errors
+    /// - The tasks can't be extracted from the file.
+    #[inline]
+    pub fn read(&self) -> super::Result<Vec<Task>> {
+        #[cfg(feature = "cache")]
+        if self.file.cached() {
+            let cache = super::Cache::new(self.path());
+
+            if let Some(tasks) = cache.load() {
+                return Ok(tasks);
+            }
+
+            let tasks = self.file.tasks()?;
+
+            if cache.store(&tasks).is_err() {
+                warning!("Can't cache the tasks of '{}'", cache.path().display());
+            }
+
+            return Ok(tasks);
+        }
+
+        self.file.tasks()
+    }
+
     /// Checks the persister's contents. If the persister is empty or its path
     /// doesn't exists, the persister will get populated by the default contents.
     ///
@@ -115,7 +181,7 @@ impl File {
             return Ok(());
         }
 
-        println!("Creating '{}'", path.file_name().unwrap().to_string_lossy());
+        info!("Creating '{}'", path.file_name().unwrap().to_string_lossy());
 
         fs::write(path, self.file.default())?;
 
@@ -150,6 +216,9 @@ impl File {
     }
 
     /// Returns a struct that implements the `FilePersister` trait based on the file extension.
+    /// CSV files use the default dialect and JSON files are written indented.
+    ///
+    /// Persisters added to the [`Registry`] are used before the included ones.
     ///
     /// # Errors
     /// - The path passed is a directory (a file is expected).
@@ -158,6 +227,24 @@ impl File {
     /// - The file extension can't be converted to `&str`.
     #[inline]
     pub fn get_persister<T: AsRef<Path>>(path: T) -> crate::Result<Box<dyn FilePersister>> {
+        Self::get_persister_with(path, CsvDialect::default(), true)
+    }
+
+    /// Returns a struct that implements the `FilePersister` trait based on the file extension,
+    /// like [`File::get_persister`], but CSV files use `dialect` and JSON files are only written
+    /// indented if `pretty` is `true`.
+    ///
+    /// # Errors
+    /// - The path passed is a directory (a file is expected).
+    ///
+    /// # Panics
+    /// - The file extension can't be converted to `&str`.
+    #[inline]
+    pub fn get_persister_with<T: AsRef<Path>>(
+        path: T,
+        dialect: CsvDialect,
+        pretty: bool,
+    ) -> crate::Result<Box<dyn FilePersister>> {
         let mut file_path = path.as_ref().to_path_buf();
 
         if file_path.is_dir() {
@@ -170,12 +257,17 @@ impl File {
             .to_str()
             .unwrap();
 
+        if let Some(factory) = Registry::file(ext) {
+            return Ok(factory(&file_path));
+        }
+
         let format = Format::from(ext);
         file_path.set_extension(format.to_str());
 
         let file = match format {
-            Format::Csv => Csv::new(file_path).boxed(),
-            Format::Json => Json::new(file_path).boxed(),
+            Format::Csv => Csv::with(file_path, dialect).boxed(),
+            Format::Json => Json::with(file_path, pretty).boxed(),
+            Format::Jsonl => Jsonl::new(file_path).boxed(),
             Format::Xml => Xml::new(file_path).boxed(),
         };
 
@@ -199,11 +291,10 @@ impl Persister for File {
         let path = &self.path();
 
         if path.exists() {
-            let err = "The file already exists";
-            return Err(crate::Error::wrap(err));
+            return Err(super::Error::AlreadyExists(self.to_string()).into());
         }
 
-        println!("Creating '{}'", path.file_name().unwrap().to_string_lossy());
+        info!("Creating '{}'", path.file_name().unwrap().to_string_lossy());
 
         fs::write(path, self.file.default())?;
 
@@ -215,6 +306,23 @@ impl Persister for File {
         Ok(self.path().exists())
     }
 
+    #[inline]
+    fn check(&self) -> crate::Result<()> {
+        let path = self.path();
+        let target = if path.exists() { path.as_path() } else { path.parent().unwrap() };
+
+        if fs::metadata(target)?.permissions().readonly() {
+            return Err(super::Error::ReadOnly(target.display().to_string()).into());
+        }
+
+        self.tasks().map(|_| ())
+    }
+
+    #[inline]
+    fn lock(&self, timeout: Duration) -> crate::Result<Option<Lock>> {
+        Ok(Some(Lock::acquire(self.path(), timeout)?))
+    }
+
     #[inline]
     fn view(&self) -> crate::Result<()> {
         let path = self.path();
@@ -235,7 +343,22 @@ impl Persister for File {
             return Ok(Vec::new());
         }
 
-        self.file.tasks().map_err(crate::Error::Fs)
+        self.read().map_err(crate::Error::Fs)
+    }
+
+    #[inline]
+    fn stream(&self) -> crate::Result<Box<dyn Iterator<Item = crate::Result<Task>> + '_>> {
+        if !self.exists()? {
+            return Ok(Box::new(std::iter::empty()));
+        }
+
+        if self.file.cached() {
+            return Ok(Box::new(self.tasks()?.into_iter().map(Ok)));
+        }
+
+        let tasks = self.file.stream().map_err(crate::Error::Fs)?;
+
+        Ok(Box::new(tasks.map(|task| task.map_err(crate::Error::Fs))))
     }
 
     #[inline]
@@ -248,7 +371,7 @@ impl Persister for File {
         }
 
         self.file.write(todo).map_err(|e| {
-            eprintln!(
+            warning!(
                 "Can't perform the {action} operation on '{}'",
                 path.file_name().unwrap().to_string_lossy()
             );
@@ -262,23 +385,38 @@ impl Persister for File {
             let path = self.path();
             let file = path.file_name().unwrap().to_string_lossy();
 
-            eprintln!("Can't save the '{file}' file");
+            warning!("Can't save the '{file}' file");
 
             crate::Error::Fs(e)
         })
     }
 
+    #[inline]
+    fn append(&self, tasks: &[Task]) -> crate::Result<()> {
+        tasks
+            .iter()
+            .try_for_each(|task| self.file.append(task))
+            .map_err(|e| {
+                let path = self.path();
+                let file = path.file_name().unwrap().to_string_lossy();
+
+                warning!("Can't save the '{file}' file");
+
+                crate::Error::Fs(e)
+            })
+    }
+
     #[inline]
     fn replace(&self, todo: &Todo) -> crate::Result<()> {
         let path = self.path();
         let file = path.file_name().unwrap().to_string_lossy();
 
         self.file.write(todo).map_err(|e| {
-            eprintln!("Can't replace the tasks of '{file}'");
+            warning!("Can't replace the tasks of '{file}'");
             crate::Error::Fs(e)
         })?;
 
-        println!("Replaced the tasks of '{file}'");
+        info!("Replaced the tasks of '{file}'");
 
         Ok(())
     }
@@ -293,11 +431,11 @@ impl Persister for File {
         }
 
         self.file.clean().map_err(|e| {
-            eprintln!("Can't clean '{file}'");
+            warning!("Can't clean '{file}'");
             crate::Error::Fs(e)
         })?;
 
-        println!("Cleaned '{file}'");
+        info!("Cleaned '{file}'");
 
         Ok(())
     }
@@ -312,11 +450,14 @@ impl Persister for File {
         }
 
         self.file.remove().map_err(|e| {
-            eprintln!("Can't delete the '{file}' file");
+            warning!("Can't delete the '{file}' file");
             crate::Error::Fs(e)
         })?;
 
-        println!("Removed the '{file}' file");
+        #[cfg(feature = "cache")]
+        super::Cache::new(path).clear()?;
+
+        info!("Removed the '{file}' file");
 
         Ok(())
     }
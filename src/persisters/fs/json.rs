@@ -13,13 +13,25 @@ use crate::traits::FilePersister;
 pub struct Json {
     /// Location of the JSON file.
     path: PathBuf,
+    /// If `true`, the file is written indented instead of minified.
+    pretty: bool,
 }
 
 impl Json {
-    /// Constructor of the `Json` struct.
+    /// Constructor of the `Json` struct, which writes the file indented.
     #[inline]
     pub fn new<T: AsRef<Path>>(path: T) -> Self {
-        Self { path: path.as_ref().to_path_buf() }
+        Self::with(path, true)
+    }
+
+    /// Constructor of the `Json` struct that writes the file indented if
+    /// `pretty` is `true`, or minified if it isn't.
+    #[inline]
+    pub fn with<T: AsRef<Path>>(path: T, pretty: bool) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            pretty,
+        }
     }
 
     /// Returns the basic structure to initialize a JSON file.
@@ -27,6 +39,15 @@ impl Json {
     pub fn array() -> String {
         String::from("[]")
     }
+
+    /// Parses a JSON array into tasks.
+    ///
+    /// # Errors
+    /// - The text is not a valid JSON array of tasks.
+    #[inline]
+    pub fn parse(content: &str) -> super::Result<Vec<Task>> {
+        Ok(serde_json::from_str(content.trim())?)
+    }
 }
 
 impl FilePersister for Json {
@@ -46,11 +67,13 @@ impl FilePersister for Json {
     }
 
     #[inline]
-    fn tasks(&self) -> super::Result<Vec<Task>> {
-        let content = fs::read_to_string(&self.path)?;
-        let tasks = serde_json::from_str(content.trim())?;
+    fn cached(&self) -> bool {
+        true
+    }
 
-        Ok(tasks)
+    #[inline]
+    fn tasks(&self) -> super::Result<Vec<Task>> {
+        Self::parse(&fs::read_to_string(&self.path)?)
     }
 
     #[inline]
@@ -67,9 +90,13 @@ impl FilePersister for Json {
 
     #[inline]
     fn write(&self, todo: &Todo) -> super::Result<()> {
-        serde_json::to_writer_pretty(self.open()?, &todo.tasks)?;
+        let bytes = if self.pretty {
+            serde_json::to_vec_pretty(&todo.tasks)?
+        } else {
+            serde_json::to_vec(&todo.tasks)?
+        };
 
-        Ok(())
+        self.write_atomic(&bytes)
     }
 
     #[inline]
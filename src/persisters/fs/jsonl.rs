@@ -0,0 +1,133 @@
+//! Utilities to handle JSON Lines files with [serde] and [`serde_json`].
+//!
+//! The `Jsonl` struct implements the [`FilePersister`] trait.
+
+use std::fs;
+use std::io::{BufRead as _, BufReader, Write as _};
+use std::path::{Path, PathBuf};
+
+use crate::models::{Task, Todo};
+use crate::traits::FilePersister;
+
+/// Representation of a JSON Lines file, which stores one task per line.
+///
+/// New tasks are appended to the end of the file, so adding a task doesn't
+/// rewrite the tasks that were already stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jsonl {
+    /// Location of the JSON Lines file.
+    path: PathBuf,
+}
+
+impl Jsonl {
+    /// Constructor of the `Jsonl` struct.
+    #[inline]
+    pub fn new<T: AsRef<Path>>(path: T) -> Self {
+        Self { path: path.as_ref().to_path_buf() }
+    }
+
+    /// Parses JSON Lines into tasks. Blank lines are ignored.
+    ///
+    /// # Errors
+    /// - A line is not a valid JSON task.
+    #[inline]
+    pub fn parse(content: &str) -> super::Result<Vec<Task>> {
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+
+    /// Formats a task as a single line of JSON, including the line break.
+    ///
+    /// # Errors
+    /// - The task can't be serialized.
+    #[inline]
+    pub fn line(task: &Task) -> super::Result<String> {
+        Ok(serde_json::to_string(task)? + "\n")
+    }
+}
+
+impl FilePersister for Jsonl {
+    #[inline]
+    fn boxed(self) -> Box<dyn FilePersister> {
+        Box::new(self)
+    }
+
+    #[inline]
+    fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    #[inline]
+    fn default(&self) -> String {
+        String::new()
+    }
+
+    /// Reads the file line by line, so the whole file is never loaded at once.
+    #[inline]
+    fn tasks(&self) -> super::Result<Vec<Task>> {
+        let mut tasks = Vec::new();
+
+        for line in BufReader::new(fs::File::open(&self.path)?).lines() {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                tasks.push(serde_json::from_str(&line)?);
+            }
+        }
+
+        Ok(tasks)
+    }
+
+    #[inline]
+    fn open(&self) -> super::Result<fs::File> {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.path)?;
+
+        Ok(file)
+    }
+
+    #[inline]
+    fn write(&self, todo: &Todo) -> super::Result<()> {
+        let lines = todo
+            .tasks
+            .iter()
+            .map(Self::line)
+            .collect::<super::Result<Vec<String>>>()?;
+
+        self.write_atomic(lines.concat().as_bytes())
+    }
+
+    /// Appends the task to the end of the file.
+    #[inline]
+    fn append(&self, task: &Task) -> super::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        file.write_all(Self::line(task)?.as_bytes())?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn clean(&self) -> super::Result<()> {
+        fs::write(&self.path, self.default())?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn remove(&self) -> super::Result<()> {
+        fs::remove_file(&self.path)?;
+
+        Ok(())
+    }
+}
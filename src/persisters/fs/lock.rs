@@ -0,0 +1,98 @@
+//! Contains the `Lock` struct, an advisory lock that keeps other postit
+//! processes from changing a file at the same time.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time waited between attempts to take a lock held by another process.
+const RETRY: Duration = Duration::from_millis(50);
+
+/// Exclusive lock on a file, released when it is dropped.
+///
+/// The lock is taken on a separate `.<name>.lock` file next to the locked
+/// one, because the file itself is replaced on every write (see
+/// [`FilePersister::write_atomic`][`crate::traits::FilePersister::write_atomic`]).
+/// The lock file is kept after the lock is released, since removing it could
+/// let two processes lock different files.
+#[derive(Debug)]
+pub struct Lock {
+    /// Open handle of the lock file, which holds the lock.
+    file: fs::File,
+    /// Path of the lock file.
+    path: PathBuf,
+    /// Locks of other files held until this one is dropped (see [`Lock::join`]).
+    joined: Vec<Self>,
+}
+
+impl Lock {
+    /// Returns the path of the lock file of a file.
+    #[inline]
+    pub fn path_of(path: &Path) -> PathBuf {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        path.with_file_name(format!(".{name}.lock"))
+    }
+
+    /// Takes the lock of a file, waiting up to `timeout` if another process
+    /// holds it.
+    ///
+    /// # Errors
+    /// - The lock file can't be created.
+    /// - The lock is still held by another process after `timeout`.
+    #[inline]
+    pub fn acquire(path: &Path, timeout: Duration) -> super::Result<Self> {
+        let lock_path = Self::path_of(path);
+
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)?;
+
+        let start = Instant::now();
+
+        loop {
+            match file.try_lock() {
+                Ok(()) => {
+                    return Ok(Self {
+                        file,
+                        path: lock_path,
+                        joined: Vec::new(),
+                    })
+                }
+                Err(fs::TryLockError::WouldBlock) if start.elapsed() < timeout => {
+                    thread::sleep(RETRY);
+                }
+                Err(fs::TryLockError::WouldBlock) => {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    return Err(super::Error::Locked(name.into_owned()));
+                }
+                Err(fs::TryLockError::Error(err)) => return Err(err.into()),
+            }
+        }
+    }
+
+    /// Returns the path of the lock file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Keeps the lock of another file until this one is dropped, so a single
+    /// value holds the locks of a persister and its mirrors.
+    #[inline]
+    #[must_use]
+    pub fn join(mut self, other: Self) -> Self {
+        self.joined.push(other);
+        self
+    }
+}
+
+impl Drop for Lock {
+    #[inline]
+    fn drop(&mut self) {
+        // Closing the file releases the lock anyway
+        let _ = self.file.unlock();
+    }
+}
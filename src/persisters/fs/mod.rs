@@ -3,16 +3,25 @@
 //! The currently supported formats are:
 //! - csv
 //! - json
+//! - jsonl
 //! - xml
 
+#[cfg(feature = "cache")]
+mod cache;
 mod csv;
 mod error;
 mod file;
 mod json;
+mod jsonl;
+mod lock;
 mod xml;
 
-pub use csv::Csv;
+#[cfg(feature = "cache")]
+pub use cache::Cache;
+pub use csv::{Csv, CsvDialect, Quoting};
 pub use error::{Error, Result};
 pub use file::{File, Format};
 pub use json::Json;
+pub use jsonl::Jsonl;
+pub use lock::Lock;
 pub use xml::Xml;
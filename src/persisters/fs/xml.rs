@@ -2,12 +2,10 @@
 //!
 //! The `XML` struct implements the [`FilePersister`] trait.
 
-use std::io::Write as _;
 use std::path::{Path, PathBuf};
 use std::{fs, io};
 
 use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
-use quick_xml::name::QName;
 use quick_xml::{Reader, Writer};
 
 use crate::models::{Priority, Task, Todo};
@@ -45,6 +43,18 @@ impl Xml {
         id CDATA #REQUIRED
         priority (low | med | high | none) #REQUIRED
         checked (true | false) #REQUIRED
+        refs CDATA #IMPLIED
+        tags CDATA #IMPLIED
+        notes CDATA #IMPLIED
+        created_at CDATA #IMPLIED
+        updated_at CDATA #IMPLIED
+        completed_at CDATA #IMPLIED
+        estimate CDATA #IMPLIED
+        snoozed_until CDATA #IMPLIED
+        order CDATA #IMPLIED
+        pinned CDATA #IMPLIED
+        attachments CDATA #IMPLIED
+        due CDATA #IMPLIED
     >
 ]>\n",
         )
@@ -81,6 +91,49 @@ impl Xml {
         task_bytes.push_attribute(("priority", task.priority.to_str()));
         task_bytes.push_attribute(("checked", task.checked.to_string().as_str()));
 
+        if !task.refs.is_empty() {
+            task_bytes.push_attribute(("refs", task.refs.join(" ").as_str()));
+        }
+
+        if !task.tags.is_empty() {
+            task_bytes.push_attribute(("tags", task.tags.join(" ").as_str()));
+        }
+
+        if !task.notes.is_empty() {
+            task_bytes.push_attribute(("notes", task.notes.as_str()));
+        }
+
+        if let Some(minutes) = task.estimate {
+            task_bytes.push_attribute(("estimate", minutes.to_string().as_str()));
+        }
+
+        if let Some(order) = task.order {
+            task_bytes.push_attribute(("order", order.to_string().as_str()));
+        }
+
+        if task.pinned {
+            task_bytes.push_attribute(("pinned", "true"));
+        }
+
+        if !task.attachments.is_empty() {
+            task_bytes.push_attribute(("attachments", task.attachments_field().as_str()));
+        }
+
+        let timestamps = [
+            ("created_at", &task.created_at),
+            ("updated_at", &task.updated_at),
+            ("completed_at", &task.completed_at),
+            ("snoozed_until", &task.snoozed_until),
+            ("due", &task.due),
+        ];
+
+        for (key, timestamp) in timestamps {
+            if timestamp.is_some() {
+                let value = Task::format_timestamp(timestamp.as_ref());
+                task_bytes.push_attribute((key, value.as_str()));
+            }
+        }
+
         writer.write_event(Event::Start(task_bytes))?;
 
         writer.write_event(Event::Text(BytesText::new(&task.content)))?;
@@ -88,58 +141,163 @@ impl Xml {
         writer.write_event(Event::End(BytesEnd::new("Task")))
     }
 
+    /// Parses XML text into tasks.
+    ///
+    /// # Errors
+    /// - The XML is malformed or doesn't match the DTD.
+    /// - A value can't be unescaped.
+    #[inline]
+    pub fn parse(content: &str) -> super::Result<Vec<Task>> {
+        Self::xml_to_tasks(Reader::from_str(content.trim_end()))
+    }
+
     /// Reads the tasks from an XML reader and returns a vector of tasks.
     ///
+    /// The document is validated against the [`Xml::dtd`] while it is read,
+    /// so malformed elements and attributes are reported with their line
+    /// instead of being read as default values. The `Tasks` element can be
+    /// missing or empty, which is how files without tasks are written.
+    ///
     /// # Errors
+    /// - The XML is malformed or doesn't match the DTD.
     /// - A value can't be unescaped.
     #[inline]
     pub fn xml_to_tasks(mut reader: Reader<&[u8]>) -> super::Result<Vec<Task>> {
+        let source = *reader.get_ref();
+        let line = |position: u64| {
+            let end = usize::try_from(position)
+                .unwrap_or(usize::MAX)
+                .min(source.len());
+            source
+                .get(..end)
+                .map_or(1, |read| read.split(|&b| b == b'\n').count())
+        };
+
         let mut tasks = vec![];
         let mut task = None::<Task>;
+        let mut open = Vec::<String>::new();
+        let mut has_root = false;
 
         loop {
-            match reader.read_event() {
-                Ok(Event::Start(e)) if e.name() == QName(b"Task") => {
-                    let mut new_task = Task::default();
-
-                    for attr in e.attributes().flatten() {
-                        let value = attr.unescape_value()?;
-                        match attr.key {
-                            QName(b"id") => new_task.id = value.parse().unwrap_or(0),
-                            QName(b"priority") => new_task.priority = Priority::from(value),
-                            QName(b"checked") => new_task.checked = value == "true",
-                            _ => {}
+            let position = reader.buffer_position();
+            let invalid = |reason: String| super::Error::InvalidXml(line(position), reason);
+
+            let event = reader.read_event().map_err(|e| invalid(e.to_string()))?;
+
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                    let parent = open.last().map(String::as_str);
+
+                    match (name.as_str(), parent) {
+                        ("Tasks", None) if !has_root => has_root = true,
+                        ("Task", Some("Tasks")) => {
+                            task = Some(Self::xml_to_task(e).map_err(invalid)?);
+                        }
+                        ("Tasks" | "Task", _) => {
+                            return Err(invalid(format!("the <{name}> element can't be here")));
                         }
+                        _ => return Err(invalid(format!("unknown element <{name}>"))),
                     }
 
-                    task = Some(new_task);
+                    if matches!(event, Event::Start(_)) {
+                        open.push(name);
+                    } else if let Some(t) = task.take() {
+                        tasks.push(t);
+                    }
                 }
 
-                Ok(Event::Text(e)) => {
+                Event::Text(e) => {
+                    let text = e.unescape()?;
+
                     if let Some(t) = &mut task {
-                        t.content = e.unescape()?.into_owned();
+                        t.content = text.into_owned();
+                    } else if !text.trim().is_empty() {
+                        return Err(invalid(format!("unexpected text '{}'", text.trim())));
                     }
                 }
 
-                Ok(Event::End(e)) if e.name() == QName(b"Task") => {
+                Event::End(_) => {
+                    open.pop();
+
                     if let Some(t) = task.take() {
                         tasks.push(t);
                     }
                 }
 
-                Ok(Event::Eof) => break,
+                Event::Eof => {
+                    if let Some(name) = open.last() {
+                        return Err(invalid(format!("the <{name}> element isn't closed")));
+                    }
 
-                Err(e) => {
-                    eprintln!("Error reading the XML file: {e:?}");
                     break;
                 }
 
-                _ => {}
+                Event::CData(_)
+                | Event::Comment(_)
+                | Event::Decl(_)
+                | Event::PI(_)
+                | Event::DocType(_) => (),
             }
         }
 
         Ok(tasks)
     }
+
+    /// Reads a task from the attributes of a `Task` element, setting the field
+    /// that matches each attribute.
+    ///
+    /// # Errors
+    /// - An attribute is malformed, unknown or has an invalid value, or a
+    ///   required one is missing (returns the reason).
+    #[inline]
+    pub fn xml_to_task(element: &BytesStart) -> Result<Task, String> {
+        let mut task = Task::default();
+        let mut keys = Vec::new();
+
+        for attr in element.attributes() {
+            let attr = attr.map_err(|e| e.to_string())?;
+            let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+            let value = attr.unescape_value().map_err(|e| e.to_string())?;
+            let value = value.as_ref();
+
+            let invalid = || format!("the '{key}' attribute of <Task> can't be '{value}'");
+            let timestamp = || Task::parse_timestamp(value).map(Some).ok_or_else(invalid);
+            let words = || value.split_whitespace().map(str::to_owned).collect();
+
+            match key.as_str() {
+                "id" => task.id = value.parse().map_err(|_| invalid())?,
+                "priority" if matches!(value, "high" | "med" | "low" | "none") => {
+                    task.priority = Priority::from(value);
+                }
+                "priority" => return Err(invalid()),
+                "checked" => task.checked = value.parse().map_err(|_| invalid())?,
+                "refs" => task.refs = words(),
+                "tags" => task.tags = words(),
+                "notes" => value.clone_into(&mut task.notes),
+                "created_at" => task.created_at = timestamp()?,
+                "updated_at" => task.updated_at = timestamp()?,
+                "completed_at" => task.completed_at = timestamp()?,
+                "estimate" => task.estimate = Some(value.parse().map_err(|_| invalid())?),
+                "snoozed_until" => task.snoozed_until = timestamp()?,
+                "order" => task.order = Some(value.parse().map_err(|_| invalid())?),
+                "pinned" => task.pinned = value.parse().map_err(|_| invalid())?,
+                "attachments" => task.attachments = Task::parse_attachments(value),
+                "due" => task.due = timestamp()?,
+                _ => return Err(format!("<Task> has the unknown attribute '{key}'")),
+            }
+
+            keys.push(key);
+        }
+
+        for required in ["id", "priority", "checked"] {
+            if !keys.iter().any(|key| key == required) {
+                return Err(format!("<Task> doesn't have the required '{required}' attribute"));
+            }
+        }
+
+        Ok(task)
+    }
 }
 
 impl FilePersister for Xml {
@@ -159,11 +317,13 @@ impl FilePersister for Xml {
     }
 
     #[inline]
-    fn tasks(&self) -> super::Result<Vec<Task>> {
-        let xml = fs::read_to_string(&self.path)?;
-        let reader = Reader::from_str(xml.trim());
+    fn cached(&self) -> bool {
+        true
+    }
 
-        Self::xml_to_tasks(reader)
+    #[inline]
+    fn tasks(&self) -> super::Result<Vec<Task>> {
+        Self::parse(&fs::read_to_string(&self.path)?)
     }
 
     #[inline]
@@ -185,9 +345,7 @@ impl FilePersister for Xml {
 
         let bytes = [self.default(), xml].join("").into_bytes();
 
-        self.open()?.write_all(&bytes)?;
-
-        Ok(())
+        self.write_atomic(&bytes)
     }
 
     #[inline]
@@ -0,0 +1,155 @@
+//! Contains the `Memory` persister, which keeps the tasks in memory instead of
+//! storing them in a file or a database.
+//!
+//! It is useful for tests and for programs that use postit as a library
+//! without touching the disk. Tasks are lost when the persister is dropped,
+//! so it is rarely useful from the command line.
+
+use std::cell::RefCell;
+
+use crate::models::{Task, Todo};
+use crate::traits::Persister;
+use crate::{info, Action};
+
+/// Persister that keeps the tasks in a [`Vec`].
+///
+/// It can be obtained with the `:memory:` or `memory://NAME` connections,
+/// where `NAME` is only used to tell persisters apart.
+#[derive(Debug)]
+pub struct Memory {
+    /// Value that created the persister.
+    conn: String,
+    /// Stored tasks, or `None` if the persister wasn't created yet.
+    tasks: RefCell<Option<Vec<Task>>>,
+}
+
+impl Default for Memory {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Memory {
+    /// Constructor of the `Memory` struct, which is created with no tasks.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with(Vec::new())
+    }
+
+    /// Creates a `Memory` persister that stores the passed tasks.
+    #[inline]
+    pub fn with(tasks: Vec<Task>) -> Self {
+        Self {
+            conn: String::from(":memory:"),
+            tasks: RefCell::new(Some(tasks)),
+        }
+    }
+
+    /// Creates a `Memory` persister from a connection, which doesn't exist
+    /// until it is created.
+    #[inline]
+    pub fn from<T: AsRef<str>>(conn: T) -> Self {
+        Self {
+            conn: conn.as_ref().to_owned(),
+            tasks: RefCell::new(None),
+        }
+    }
+
+    /// Checks if the passed connection is a `Memory` persister.
+    #[inline]
+    pub fn is_memory(conn: &str) -> bool {
+        conn == ":memory:" || conn.starts_with("memory://")
+    }
+
+    /// Returns the error of the operations that need the persister to exist.
+    #[inline]
+    pub fn doesnt_exist(&self) -> crate::Error {
+        crate::Error::PersisterDoesntExist(self.conn.clone())
+    }
+
+    /// Replaces the stored tasks, which fails if the persister doesn't exist.
+    ///
+    /// # Errors
+    /// - The persister doesn't exist.
+    #[inline]
+    pub fn store(&self, todo: &Todo) -> crate::Result<()> {
+        match self.tasks.borrow_mut().as_mut() {
+            Some(tasks) => todo.tasks.clone_into(tasks),
+            None => return Err(self.doesnt_exist()),
+        }
+
+        Ok(())
+    }
+}
+
+impl Persister for Memory {
+    #[inline]
+    fn boxed(self) -> Box<dyn Persister> {
+        Box::new(self)
+    }
+
+    #[inline]
+    fn to_string(&self) -> String {
+        self.conn.clone()
+    }
+
+    #[inline]
+    fn create(&self) -> crate::Result<()> {
+        self.tasks.borrow_mut().get_or_insert_with(Vec::new);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn exists(&self) -> crate::Result<bool> {
+        Ok(self.tasks.borrow().is_some())
+    }
+
+    #[inline]
+    fn view(&self) -> crate::Result<()> {
+        Todo::new(self.tasks()?).view()?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn tasks(&self) -> crate::Result<Vec<Task>> {
+        self.tasks
+            .borrow()
+            .clone()
+            .ok_or_else(|| self.doesnt_exist())
+    }
+
+    #[inline]
+    fn edit(&self, todo: &Todo, _ids: &[u32], _action: &Action) -> crate::Result<()> {
+        self.store(todo)
+    }
+
+    #[inline]
+    fn save(&self, todo: &Todo) -> crate::Result<()> {
+        self.create()?;
+        self.store(todo)
+    }
+
+    #[inline]
+    fn replace(&self, todo: &Todo) -> crate::Result<()> {
+        self.save(todo)?;
+
+        info!("Replaced the tasks of '{}'", self.conn);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn clean(&self) -> crate::Result<()> {
+        self.store(&Todo::new(Vec::new()))
+    }
+
+    #[inline]
+    fn remove(&self) -> crate::Result<()> {
+        self.tasks.borrow_mut().take();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+//! Contains the `Mirror` persister, which keeps copies of the tasks of a
+//! persister up to date in other persisters (e.g.: a backup database).
+//!
+//! It is used when the config file has `mirrors`, or a list of persisters in
+//! `persister` (e.g.: `persister = ["tasks.csv", "sqlite:///backup.db"]`).
+
+use std::time::Duration;
+
+use crate::fs::Lock;
+use crate::models::{Task, Todo};
+use crate::traits::Persister;
+use crate::{debug, warning, Action, Verbosity};
+
+/// Persister that reads the tasks from a persister and writes every change
+/// to it and to its mirrors.
+///
+/// After every change, the mirrors are replaced with the tasks of the
+/// persister, so they keep the same ids even if they missed a change. A
+/// mirror that can't be written only shows a warning, because the change
+/// was already stored in the persister.
+///
+/// Mirrors are backups, so 'clean' and 'remove' only change the persister
+/// and the mirrors keep their tasks until the next change.
+#[derive(Debug)]
+pub struct Mirror {
+    /// Persister used to read the tasks.
+    primary: Box<dyn Persister>,
+    /// Persisters that receive a copy of every change.
+    mirrors: Vec<Box<dyn Persister>>,
+}
+
+impl Mirror {
+    /// Constructor of the `Mirror` struct.
+    #[inline]
+    pub fn new(primary: Box<dyn Persister>, mirrors: Vec<Box<dyn Persister>>) -> Self {
+        Self { primary, mirrors }
+    }
+
+    /// Returns the persister used to read the tasks.
+    #[inline]
+    pub fn primary(&self) -> &dyn Persister {
+        self.primary.as_ref()
+    }
+
+    /// Returns the persisters that receive a copy of every change.
+    #[inline]
+    pub fn mirrors(&self) -> &[Box<dyn Persister>] {
+        &self.mirrors
+    }
+
+    /// Performs an operation on the persister and then copies its tasks to
+    /// every mirror, creating the ones that don't exist yet.
+    ///
+    /// # Errors
+    /// - The operation fails on the persister.
+    /// - The tasks of the persister can't be read.
+    fn fan_out<F>(&self, op: F) -> crate::Result<()>
+    where
+        F: FnOnce(&dyn Persister) -> crate::Result<()>,
+    {
+        op(self.primary.as_ref())?;
+
+        if self.mirrors.is_empty() || !self.primary.exists()? {
+            return Ok(());
+        }
+
+        let todo = Todo::new(self.primary.tasks()?);
+
+        for mirror in &self.mirrors {
+            // The messages of the mirrors would repeat the ones of the persister
+            let verbosity = Verbosity::current();
+            Verbosity::Quiet.set();
+
+            let synced = mirror
+                .exists()
+                .and_then(|exists| if exists { Ok(()) } else { mirror.create() })
+                .and_then(|()| mirror.replace(&todo));
+
+            verbosity.set();
+
+            match synced {
+                Ok(()) => debug!("Mirrored the changes to '{}'", mirror.to_string()),
+                Err(e) => warning!("Can't mirror the changes to '{}': {e}", mirror.to_string()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Persister for Mirror {
+    #[inline]
+    fn boxed(self) -> Box<dyn Persister> {
+        Box::new(self)
+    }
+
+    #[inline]
+    fn to_string(&self) -> String {
+        self.primary.to_string()
+    }
+
+    #[inline]
+    fn create(&self) -> crate::Result<()> {
+        self.primary.create()?;
+
+        for mirror in &self.mirrors {
+            if !mirror.exists()? {
+                mirror.create()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn exists(&self) -> crate::Result<bool> {
+        self.primary.exists()
+    }
+
+    #[inline]
+    fn check(&self) -> crate::Result<()> {
+        self.primary.check()?;
+        self.mirrors.iter().try_for_each(|mirror| mirror.check())
+    }
+
+    #[inline]
+    fn view(&self) -> crate::Result<()> {
+        self.primary.view()
+    }
+
+    #[inline]
+    fn tasks(&self) -> crate::Result<Vec<Task>> {
+        self.primary.tasks()
+    }
+
+    #[inline]
+    fn stream(&self) -> crate::Result<Box<dyn Iterator<Item = crate::Result<Task>> + '_>> {
+        self.primary.stream()
+    }
+
+    #[inline]
+    fn edit(&self, todo: &Todo, ids: &[u32], action: &Action) -> crate::Result<()> {
+        self.fan_out(|persister| persister.edit(todo, ids, action))
+    }
+
+    #[inline]
+    fn save(&self, todo: &Todo) -> crate::Result<()> {
+        self.fan_out(|persister| persister.save(todo))
+    }
+
+    #[inline]
+    fn append(&self, tasks: &[Task]) -> crate::Result<()> {
+        self.fan_out(|persister| persister.append(tasks))
+    }
+
+    #[inline]
+    fn replace(&self, todo: &Todo) -> crate::Result<()> {
+        self.fan_out(|persister| persister.replace(todo))
+    }
+
+    #[inline]
+    fn clean(&self) -> crate::Result<()> {
+        self.primary.clean()
+    }
+
+    #[inline]
+    fn remove(&self) -> crate::Result<()> {
+        self.primary.remove()
+    }
+
+    #[inline]
+    fn next_id(&self) -> crate::Result<u32> {
+        self.primary.next_id()
+    }
+
+    #[inline]
+    fn lock(&self, timeout: Duration) -> crate::Result<Option<Lock>> {
+        let mut lock = self.primary.lock(timeout)?;
+
+        for mirror in &self.mirrors {
+            if let Some(other) = mirror.lock(timeout)? {
+                lock = Some(match lock {
+                    Some(lock) => lock.join(other),
+                    None => other,
+                });
+            }
+        }
+
+        Ok(lock)
+    }
+
+    #[inline]
+    fn reopen(&self) -> crate::Result<Box<dyn Persister>> {
+        let mirrors = self
+            .mirrors
+            .iter()
+            .map(|mirror| mirror.reopen())
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self::new(self.primary.reopen()?, mirrors).boxed())
+    }
+}
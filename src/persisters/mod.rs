@@ -2,4 +2,8 @@
 
 pub mod db;
 pub mod fs;
+pub mod memory;
+pub mod mirror;
+pub mod plugin;
+pub mod registry;
 pub mod traits;
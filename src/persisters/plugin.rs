@@ -0,0 +1,229 @@
+//! Contains the `Plugin` persister, which delegates the storage of the tasks
+//! to an external program registered in the `[plugins]` table of the config
+//! file.
+//!
+//! Every operation runs the command of the plugin once, which receives a JSON
+//! object in its standard input and answers with another one in its standard
+//! output. The request has these keys:
+//! - `version`: version of the protocol (currently `1`).
+//! - `op`: operation to perform (`exists`, `create`, `tasks`, `save`, `edit`,
+//!   `replace`, `clean` or `remove`).
+//! - `conn`: connection used to obtain the persister (e.g.: `plugin://notion/inbox`).
+//! - `tasks`: tasks to store, only for the `save`, `edit` and `replace` operations.
+//! - `ids` and `action`: tasks changed by the `edit` operation and how.
+//!
+//! The response must have the `exists` boolean for the `exists` operation and
+//! the `tasks` array for the `tasks` operation. A response with an `error`
+//! string, or a non-zero exit status, makes the operation fail.
+
+use std::io::{self, Write as _};
+use std::process::{Command, Stdio};
+
+use serde_json::{json, Value};
+
+use crate::models::{Plugins, Task, Todo};
+use crate::traits::Persister;
+use crate::{info, Action};
+
+/// Persister that runs an external program to manage the tasks.
+///
+/// It is obtained with `plugin://NAME[/PATH]` connections, where `NAME` is
+/// the name of the plugin in the config file and the rest of the connection
+/// is only interpreted by the plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plugin {
+    /// Connection that created the persister.
+    conn: String,
+    /// Name of the plugin.
+    name: String,
+    /// Shell command that runs the plugin.
+    command: String,
+}
+
+impl Plugin {
+    /// Version of the protocol used to talk to plugins.
+    pub const VERSION: u32 = 1;
+
+    /// Checks if the passed connection is a `Plugin` persister.
+    #[inline]
+    pub fn is_plugin(conn: &str) -> bool {
+        conn.starts_with("plugin://")
+    }
+
+    /// Returns the name of the plugin of a `plugin://NAME[/PATH]` connection.
+    #[inline]
+    pub fn name(conn: &str) -> &str {
+        let rest = conn.strip_prefix("plugin://").unwrap_or(conn);
+        rest.split(['/', '?']).next().unwrap_or_default()
+    }
+
+    /// Creates a `Plugin` persister from a connection and the registered plugins.
+    ///
+    /// # Errors
+    /// - The plugin isn't registered in the config file.
+    #[inline]
+    pub fn from<T: AsRef<str>>(conn: T, plugins: &Plugins) -> crate::Result<Self> {
+        let conn = conn.as_ref();
+        let name = Self::name(conn);
+
+        let Some(command) = plugins.get(name) else {
+            let msg = "it isn't registered in the '[plugins]' table of the config file";
+            return Err(crate::Error::Plugin(name.to_owned(), msg.to_owned()));
+        };
+
+        Ok(Self {
+            conn: conn.to_owned(),
+            name: name.to_owned(),
+            command: command.clone(),
+        })
+    }
+
+    /// Returns an error of this plugin.
+    #[inline]
+    pub fn error<T: AsRef<str>>(&self, msg: T) -> crate::Error {
+        crate::Error::Plugin(self.name.clone(), msg.as_ref().to_owned())
+    }
+
+    /// Runs the plugin to perform an operation, adding `params` to the request,
+    /// and returns its response.
+    ///
+    /// # Errors
+    /// - The plugin can't be run or exits with a non-zero status.
+    /// - The response isn't a JSON object or has an `error`.
+    #[inline]
+    pub fn request(&self, op: &str, params: Value) -> crate::Result<Value> {
+        let mut request = json!({ "version": Self::VERSION, "op": op, "conn": self.conn });
+
+        if let (Some(request), Value::Object(params)) = (request.as_object_mut(), params) {
+            request.extend(params);
+        }
+
+        let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+
+        let mut child = Command::new(shell)
+            .arg(flag)
+            .arg(&self.command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // Plugins that don't need the request can exit without reading it
+            match stdin.write_all(request.to_string().as_bytes()) {
+                Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e.into()),
+                Ok(()) | Err(_) => {}
+            }
+        }
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let msg = stderr.trim();
+
+            return Err(if msg.is_empty() {
+                self.error(format!("'{op}' exited with {}", output.status))
+            } else {
+                self.error(msg)
+            });
+        }
+
+        let response: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| self.error(format!("'{op}' returned invalid JSON: {e}")))?;
+
+        if !response.is_object() {
+            return Err(self.error(format!("'{op}' didn't return a JSON object")));
+        }
+
+        if let Some(msg) = response.get("error").and_then(Value::as_str) {
+            return Err(self.error(msg));
+        }
+
+        Ok(response)
+    }
+}
+
+impl Persister for Plugin {
+    #[inline]
+    fn boxed(self) -> Box<dyn Persister> {
+        Box::new(self)
+    }
+
+    #[inline]
+    fn to_string(&self) -> String {
+        self.conn.clone()
+    }
+
+    #[inline]
+    fn create(&self) -> crate::Result<()> {
+        self.request("create", json!({}))?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn exists(&self) -> crate::Result<bool> {
+        self.request("exists", json!({}))?
+            .get("exists")
+            .and_then(Value::as_bool)
+            .ok_or_else(|| self.error("'exists' didn't return the 'exists' boolean"))
+    }
+
+    #[inline]
+    fn view(&self) -> crate::Result<()> {
+        Todo::new(self.tasks()?).view()?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn tasks(&self) -> crate::Result<Vec<Task>> {
+        let mut response = self.request("tasks", json!({}))?;
+
+        let Some(tasks) = response.get_mut("tasks").map(Value::take) else {
+            return Err(self.error("'tasks' didn't return the 'tasks' array"));
+        };
+
+        serde_json::from_value(tasks)
+            .map_err(|e| self.error(format!("'tasks' returned invalid tasks: {e}")))
+    }
+
+    #[inline]
+    fn edit(&self, todo: &Todo, ids: &[u32], action: &Action) -> crate::Result<()> {
+        let params = json!({ "tasks": todo.tasks, "ids": ids, "action": action.to_string() });
+        self.request("edit", params)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn save(&self, todo: &Todo) -> crate::Result<()> {
+        self.request("save", json!({ "tasks": todo.tasks }))?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn replace(&self, todo: &Todo) -> crate::Result<()> {
+        self.request("replace", json!({ "tasks": todo.tasks }))?;
+
+        info!("Replaced the tasks of '{}'", self.conn);
+
+        Ok(())
+    }
+
+    #[inline]
+    fn clean(&self) -> crate::Result<()> {
+        self.request("clean", json!({}))?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn remove(&self) -> crate::Result<()> {
+        self.request("remove", json!({}))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Contains the `Registry` struct, which lets programs that use postit as a
+//! library add their own persisters.
+//!
+//! Registered persisters are checked before the ones included in postit, so
+//! they can also replace them:
+//! - File persisters are indexed by the extension of the file (e.g.: `todo`
+//!   for `tasks.todo`), and are used by [`File::get_persister`][crate::fs::File::get_persister].
+//! - Database persisters are indexed by the scheme of the connection string
+//!   (e.g.: `notion` for `notion://workspace`), and are used by
+//!   [`Orm::get_persister`][crate::db::Orm::get_persister].
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{PoisonError, RwLock};
+
+use crate::traits::{DbPersister, FilePersister};
+
+/// Function that creates a file persister from the path of the file.
+pub type FileFactory = fn(&Path) -> Box<dyn FilePersister>;
+
+/// Function that creates a database persister from the connection string.
+pub type DbFactory = fn(&str) -> crate::Result<Box<dyn DbPersister>>;
+
+/// File persisters registered at runtime, indexed by their extension.
+static FILES: RwLock<BTreeMap<String, FileFactory>> = RwLock::new(BTreeMap::new());
+
+/// Database persisters registered at runtime, indexed by their scheme.
+static DATABASES: RwLock<BTreeMap<String, DbFactory>> = RwLock::new(BTreeMap::new());
+
+/// Persisters registered at runtime, shared by the whole program.
+#[derive(Debug, Clone, Copy)]
+pub struct Registry;
+
+impl Registry {
+    /// Registers a file persister for the files with the `ext` extension
+    /// (case insensitive), replacing the previous one.
+    #[inline]
+    pub fn register_file(ext: &str, factory: FileFactory) {
+        let mut files = FILES.write().unwrap_or_else(PoisonError::into_inner);
+        files.insert(ext.to_lowercase(), factory);
+    }
+
+    /// Registers a database persister for the connection strings with the
+    /// `scheme` scheme (case insensitive), replacing the previous one.
+    #[inline]
+    pub fn register_db(scheme: &str, factory: DbFactory) {
+        let mut databases = DATABASES.write().unwrap_or_else(PoisonError::into_inner);
+        databases.insert(scheme.to_lowercase(), factory);
+    }
+
+    /// Removes the file persister of an extension. Returns `true` if it was registered.
+    #[inline]
+    pub fn unregister_file(ext: &str) -> bool {
+        let mut files = FILES.write().unwrap_or_else(PoisonError::into_inner);
+        files.remove(&ext.to_lowercase()).is_some()
+    }
+
+    /// Removes the database persister of a scheme. Returns `true` if it was registered.
+    #[inline]
+    pub fn unregister_db(scheme: &str) -> bool {
+        let mut databases = DATABASES.write().unwrap_or_else(PoisonError::into_inner);
+        databases.remove(&scheme.to_lowercase()).is_some()
+    }
+
+    /// Returns the file persister registered for an extension.
+    #[inline]
+    pub fn file(ext: &str) -> Option<FileFactory> {
+        let files = FILES.read().unwrap_or_else(PoisonError::into_inner);
+        files.get(&ext.to_lowercase()).copied()
+    }
+
+    /// Returns the database persister registered for a scheme.
+    #[inline]
+    pub fn db(scheme: &str) -> Option<DbFactory> {
+        let databases = DATABASES.read().unwrap_or_else(PoisonError::into_inner);
+        databases.get(&scheme.to_lowercase()).copied()
+    }
+}
@@ -2,7 +2,10 @@
 
 use std::fmt::{self, Debug};
 use std::fs::File;
+use std::io::Write as _;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::{fs as std_fs, process};
 
 use crate::models::{Task, Todo};
 use crate::Action;
@@ -16,7 +19,8 @@ pub trait Persister: fmt::Debug {
     /// Returns the persister instance inside a [`Box`] pointer.
     fn boxed(self) -> Box<dyn Persister>;
 
-    /// The value that created the `Persister` instance.
+    /// The value that created the `Persister` instance, with the password of
+    /// connection strings hidden so it can be printed.
     fn to_string(&self) -> String;
 
     /// Creates the persister instance.
@@ -43,6 +47,19 @@ pub trait Persister: fmt::Debug {
     /// - The tasks can't be extracted from the persister.
     fn tasks(&self) -> crate::Result<Vec<Task>>;
 
+    /// Returns an iterator over the tasks of the persister.
+    ///
+    /// Persisters that can read their tasks one by one (e.g.: CSV files) do,
+    /// so callers that stop early don't load every task; the rest collect
+    /// them with [`Persister::tasks`] first.
+    ///
+    /// # Errors
+    /// - The persister can't be read.
+    #[inline]
+    fn stream(&self) -> crate::Result<Box<dyn Iterator<Item = crate::Result<Task>> + '_>> {
+        Ok(Box::new(self.tasks()?.into_iter().map(Ok)))
+    }
+
     /// Edits a persister by managing an [`Action`] variant.
     ///
     /// # Errors
@@ -55,6 +72,32 @@ pub trait Persister: fmt::Debug {
     /// - The persister's contents can't be saved.
     fn save(&self, todo: &Todo) -> crate::Result<()>;
 
+    /// Checks that the persister can be used (e.g.: that its database is
+    /// reachable), which only checks if it exists by default.
+    ///
+    /// # Errors
+    /// - The persister can't be used, with the reason.
+    #[inline]
+    fn check(&self) -> crate::Result<()> {
+        self.exists().map(|_| ())
+    }
+
+    /// Adds tasks at the end of the persister, without rewriting the tasks it
+    /// already stores when the persister allows it.
+    ///
+    /// # Errors
+    /// - The tasks can't be added.
+    #[inline]
+    fn append(&self, tasks: &[Task]) -> crate::Result<()> {
+        let mut todo = Todo::new(self.tasks()?);
+
+        for task in tasks {
+            todo.add(task.clone());
+        }
+
+        self.save(&todo)
+    }
+
     /// Replaces the current data with a new [`Todo`] instance.
     ///
     /// # Errors
@@ -72,6 +115,41 @@ pub trait Persister: fmt::Debug {
     /// # Errors
     /// - The persister can't be removed.
     fn remove(&self) -> crate::Result<()>;
+
+    /// Returns the id that the next added task should use. The tasks are read
+    /// with [`Persister::stream`], so they aren't all loaded at once.
+    ///
+    /// # Errors
+    /// - The tasks can't be obtained.
+    #[inline]
+    fn next_id(&self) -> crate::Result<u32> {
+        self.stream()?
+            .try_fold(1, |next, task| Ok(next.max(task?.id + 1)))
+    }
+
+    /// Takes a lock that keeps other postit processes from changing the tasks
+    /// until it is dropped, waiting up to `timeout` for it.
+    ///
+    /// Returns `None` if the persister doesn't need locks (databases handle
+    /// concurrent changes themselves).
+    ///
+    /// # Errors
+    /// - The lock can't be taken before `timeout`.
+    #[inline]
+    fn lock(&self, timeout: Duration) -> crate::Result<Option<fs::Lock>> {
+        let _ = timeout;
+        Ok(None)
+    }
+
+    /// Returns a new instance of the persister, which is built again from its
+    /// connection unless the persister wraps others.
+    ///
+    /// # Errors
+    /// - The persister can't be built from its connection.
+    #[inline]
+    fn reopen(&self) -> crate::Result<Box<dyn Persister>> {
+        crate::Postit::get_persister(Some(self.to_string()))
+    }
 }
 
 impl PartialEq for Box<dyn Persister> {
@@ -84,7 +162,7 @@ impl PartialEq for Box<dyn Persister> {
 impl Clone for Box<dyn Persister> {
     #[inline]
     fn clone(&self) -> Self {
-        crate::Postit::get_persister(Some(self.to_string())).unwrap()
+        self.reopen().unwrap()
     }
 }
 
@@ -105,6 +183,26 @@ pub trait FilePersister: Debug {
     /// - The tasks can't be extracted from the file.
     fn tasks(&self) -> fs::Result<Vec<Task>>;
 
+    /// Returns `true` if the parsed tasks of the file are worth caching (see
+    /// [`Cache`][`fs::Cache`]), which is the case for formats that are slow to
+    /// parse, like JSON and XML.
+    #[inline]
+    fn cached(&self) -> bool {
+        false
+    }
+
+    /// Returns an iterator over the tasks of the file.
+    ///
+    /// The whole file is read by default. Formats that store a task per line
+    /// (e.g.: [`Csv`][`fs::Csv`]) read it as the iterator advances instead.
+    ///
+    /// # Errors
+    /// - The file can't be read.
+    #[inline]
+    fn stream(&self) -> fs::Result<Box<dyn Iterator<Item = fs::Result<Task>> + '_>> {
+        Ok(Box::new(self.tasks()?.into_iter().map(Ok)))
+    }
+
     /// Grants access to an open file.
     ///
     /// # Errors
@@ -117,6 +215,49 @@ pub trait FilePersister: Debug {
     /// - Tasks can't be written.
     fn write(&self, todo: &Todo) -> fs::Result<()>;
 
+    /// Replaces the contents of the file without leaving it half-written.
+    ///
+    /// The bytes are written to a temporary file in the same directory, which
+    /// is then renamed over the file, so a crash in the middle of the write
+    /// keeps the previous contents.
+    ///
+    /// # Errors
+    /// - The temporary file can't be written or renamed.
+    #[inline]
+    fn write_atomic(&self, bytes: &[u8]) -> fs::Result<()> {
+        let path = self.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let temp = path.with_file_name(format!(".{name}.{}.tmp", process::id()));
+
+        let result = std_fs::File::create(&temp).and_then(|mut file| {
+            file.write_all(bytes)?;
+            file.sync_all()?;
+            std_fs::rename(&temp, path)
+        });
+
+        if result.is_err() {
+            let _ = std_fs::remove_file(&temp);
+        }
+
+        Ok(result?)
+    }
+
+    /// Adds a task at the end of the file.
+    ///
+    /// The whole file is parsed and rewritten by default. Formats that store a
+    /// task per line (e.g.: [`Csv`][`fs::Csv`] and [`Jsonl`][`fs::Jsonl`]) only
+    /// add the new line, without parsing the tasks of the file.
+    ///
+    /// # Errors
+    /// - The file can't be read or the task can't be written.
+    #[inline]
+    fn append(&self, task: &Task) -> fs::Result<()> {
+        let mut tasks = self.tasks()?;
+        tasks.push(task.clone());
+
+        self.write(&Todo::new(tasks))
+    }
+
     /// Deletes all tasks from the persister.
     ///
     /// # Errors
@@ -175,7 +316,8 @@ pub trait DbPersister: Debug {
     /// - The table can't be created.
     fn create(&self) -> db::Result<()>;
 
-    /// Inserts data into a table.
+    /// Inserts the tasks into a table, keeping their ids so replacing the
+    /// tasks (e.g.: on undo) doesn't renumber them.
     ///
     /// # Errors
     /// - Tasks can't be inserted.
@@ -210,6 +352,53 @@ pub trait DbPersister: Debug {
     /// # Errors
     /// - The table can't be cleaned
     fn clean(&self) -> db::Result<()>;
+
+    /// Returns the schema version of the table (see [`Migration`][db::Migration]),
+    /// which is `0` if the table was created before the schema was versioned.
+    ///
+    /// Persisters that don't have a schema (e.g.: Redis, which stores the
+    /// tasks as JSON) are always at the latest version.
+    ///
+    /// # Errors
+    /// - The version can't be read.
+    #[inline]
+    fn version(&self) -> db::Result<u32> {
+        Ok(db::Migration::latest())
+    }
+
+    /// Applies the migrations that the table lacks and records its new version.
+    /// Tables that don't exist yet are created with the latest schema instead.
+    ///
+    /// # Errors
+    /// - A migration can't be applied or the version can't be recorded.
+    #[inline]
+    fn migrate(&self) -> db::Result<()> {
+        Ok(())
+    }
+
+    /// Returns the id that the next inserted task should use.
+    ///
+    /// # Errors
+    /// - The tasks can't be obtained.
+    #[inline]
+    fn next_id(&self) -> db::Result<u32> {
+        Ok(self
+            .tasks()?
+            .iter()
+            .map(|task| task.id)
+            .max()
+            .map_or(1, |id| id + 1))
+    }
+
+    /// Replaces all tasks of the table with the ones of `todo`.
+    ///
+    /// # Errors
+    /// - The table can't be cleaned or the tasks can't be inserted.
+    #[inline]
+    fn replace(&self, todo: &Todo) -> db::Result<()> {
+        self.clean()?;
+        self.insert(todo)
+    }
 }
 
 impl PartialEq for Box<dyn DbPersister> {
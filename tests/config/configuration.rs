@@ -1,10 +1,13 @@
+use std::ffi::OsString;
 use std::ops::Not;
 use std::path::PathBuf;
 
 use postit::cli::{arguments as args, subcommands as sub};
-use postit::config::Config;
-
-use crate::mocks::{MockConfig, MockEnvVar};
+use postit::config::{Config, Migration};
+use postit::fs::CsvDialect;
+use postit::models::{ColorMode, Colors, Column, Links, Lists, Plugins, Priority, Task, Urgency};
+use postit::testing::{MockConfig, MockEnvVar};
+use postit::{Cli, Hooks, Lang};
 
 #[test]
 fn error_wrap() {
@@ -14,22 +17,79 @@ fn error_wrap() {
     assert!(matches!(err, postit::config::Error::Other(_)));
 }
 
+#[test]
+fn error_code() {
+    assert_eq!(postit::config::Error::EmptyEnvVar.code(), "E101");
+    assert_eq!(postit::config::Error::EmptySetArgs.code(), "E106");
+    assert_eq!(postit::config::Error::InvalidListName(String::new()).code(), "E113");
+    assert_eq!(postit::config::Error::ListAlreadyExists(String::new()).code(), "E114");
+    assert_eq!(postit::config::Error::Keyring(String::new()).code(), "E115");
+    assert_eq!(postit::config::Error::wrap("Error").code(), "E199");
+}
+
 #[test]
 fn fmt_display() -> postit::Result<()> {
     let config = Config {
+        version: Migration::latest(),
         persister: "tasks.json".to_string(),
+        mirrors: vec![String::from("backup.db")],
+        default_command: None,
         force_drop: true,
+        trash_days: 30,
         force_copy: false,
         drop_after_copy: true,
+        lock_timeout: 5,
+        json_pretty: true,
+        confirm: true,
+        strict: false,
+        hide_checked: true,
+        min_priority: Priority::High,
+        default_priority: Priority::Med,
+        urgency: Urgency::default(),
+        columns: Column::defaults(),
+        format: String::from(Task::DEFAULT_FORMAT),
+        color: ColorMode::Auto,
+        colors: Colors::default(),
+        lang: Lang::En,
+        hooks: Hooks::default(),
+        links: Links::default(),
+        lists: Lists::default(),
+        plugins: Plugins::default(),
+        csv: CsvDialect::default(),
+        database: String::from("test"),
+        password: None,
     };
 
     let result = format!("{}", config);
 
     let expect = "
 persister: tasks.json
+mirrors: backup.db
+default_command: none
 force_drop: true
+trash_days: 30
 force_copy: false
-drop_after_copy: true";
+drop_after_copy: true
+lock_timeout: 5
+json_pretty: true
+confirm: true
+strict: false
+hide_checked: true
+min_priority: high
+default_priority: med
+urgency: high=6 med=4 low=2 none=0 due=12 age=2
+columns: id,priority,status,content
+format: '{id}. {content}'
+color: auto
+colors: high='red bold' med='yellow bold' low='blue bold' none='white bold' checked='strikethrough' overdue='magenta bold'
+lang: en
+hooks: none
+links: none
+lists: none
+plugins: none
+csv: delimiter=',' quoting=needed header=true
+database: test
+password: none";
 
     assert_eq!(result.trim(), expect.trim());
 
@@ -188,6 +248,107 @@ fn manage_list_err() {
     assert!(Config::manage(sub::Config::List).is_err());
 }
 
+#[test]
+fn check_valid() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    assert_eq!(Config::check()?, Vec::<String>::new());
+
+    Ok(())
+}
+
+#[test]
+fn check_problems() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    std::fs::write(
+        mock.path(),
+        "persister = 1\nforce_dorp = true\n\n[lists]\n\"a.b\" = \"x.csv\"\n",
+    )?;
+
+    let _env = MockEnvVar::new().set([("POSTIT_LOCK_TIMEOUT", "soon")]);
+    let problems = Config::check()?;
+
+    assert_eq!(problems.len(), 3);
+    assert!(problems[0].contains("unknown key 'force_dorp'"));
+    assert!(problems[1].contains("invalid value"));
+    assert!(problems[2].contains("'POSTIT_LOCK_TIMEOUT'"));
+
+    Ok(())
+}
+
+#[test]
+fn check_invalid_toml() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    std::fs::write(mock.path(), "persister = ")?;
+
+    let problems = Config::check()?;
+
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("isn't valid TOML"));
+
+    Ok(())
+}
+
+#[test]
+fn check_output() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+
+    let check = || {
+        assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["config", "check"])
+            .output()
+            .map_err(postit::Error::wrap)
+    };
+
+    let output = check()?;
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "The configuration is valid");
+
+    mock.config.persister = String::from("plugin://missing");
+    mock.save()?;
+
+    let output = check()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success());
+    assert!(stdout.contains("The persister 'plugin://missing' can't be used"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[E112]"));
+
+    Ok(())
+}
+
+#[test]
+fn check_plain_password() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+
+    let check = || {
+        assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["config", "check"])
+            .env_remove(postit::config::PASSWORD_VAR)
+            .env("POSTIT_TEST_PASSWORD", "secret")
+            .output()
+            .map_err(postit::Error::wrap)
+    };
+
+    mock.config.persister = String::from("postgres://user@localhost:1/tasks");
+    mock.config.password = Some(String::from("secret"));
+    mock.save()?;
+
+    let output = check()?;
+    assert!(String::from_utf8_lossy(&output.stderr).contains("stored in plain text"));
+
+    mock.config.password = Some(String::from("env:POSTIT_TEST_PASSWORD"));
+    mock.save()?;
+
+    let output = check()?;
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("stored in plain text"));
+
+    Ok(())
+}
+
 #[test]
 fn manage_list_output() -> postit::Result<()> {
     let _mock = MockConfig::new()?;
@@ -202,7 +363,10 @@ fn manage_list_output() -> postit::Result<()> {
 
     let expect = "
 persister: tasks.csv
+mirrors: none
+default_command: none
 force_drop: false
+trash_days: 30
 force_copy: false
 drop_after_copy: false";
 
@@ -212,25 +376,135 @@ drop_after_copy: false";
     Ok(())
 }
 
+#[test]
+fn default_command_output() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    mock.config.default_command = Some(String::from("config list"));
+    mock.save()?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("default_command: config list"));
+
+    Ok(())
+}
+
+#[test]
+fn manage_set_default_command() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    let set = |value: &str| args::ConfigSet {
+        persister: None,
+        default_command: Some(String::from(value)),
+        force_drop: None,
+        trash_days: None,
+        force_copy: None,
+        drop_after_copy: None,
+        lock_timeout: None,
+        json_pretty: None,
+        confirm: None,
+        strict: None,
+        hide_checked: None,
+        min_priority: None,
+        default_priority: None,
+        columns: None,
+        format: None,
+        color: None,
+        lang: None,
+        database: None,
+        password: None,
+    };
+
+    Config::manage(sub::Config::Set(set("view")))?;
+    assert_eq!(Config::load()?.default_command, Some(String::from("view")));
+
+    Config::manage(sub::Config::Set(set("")))?;
+    assert_eq!(Config::load()?.default_command, None);
+
+    Ok(())
+}
+
+#[test]
+fn cli_with_default() {
+    let args = |args: &[&str]| -> Vec<OsString> { args.iter().map(OsString::from).collect() };
+
+    let bare = args(&["postit"]);
+
+    assert_eq!(
+        Cli::with_default(bare.clone(), Some("view --table")),
+        args(&["postit", "view", "--table"])
+    );
+    assert_eq!(Cli::with_default(bare.clone(), None), bare);
+    assert_eq!(
+        Cli::with_default(args(&["postit", "--help"]), Some("view")),
+        args(&["postit", "--help"])
+    );
+}
+
 #[test]
 fn manage_set_any() -> postit::Result<()> {
     let _mock = MockConfig::new()?;
 
     let args = args::ConfigSet {
         persister: Some(String::from("tasks.json")),
+        default_command: None,
         force_drop: None,
+        trash_days: None,
         force_copy: None,
         drop_after_copy: None,
+        lock_timeout: None,
+        json_pretty: None,
+        confirm: None,
+        strict: None,
+        hide_checked: None,
+        min_priority: None,
+        default_priority: None,
+        columns: None,
+        format: None,
+        color: None,
+        lang: None,
+        database: None,
+        password: None,
     };
 
     Config::manage(sub::Config::Set(args))?;
 
     let result = Config::load()?;
     let expect = Config {
+        version: Migration::latest(),
         persister: String::from("tasks.json"),
+        mirrors: Vec::new(),
+        default_command: None,
         force_drop: false,
+        trash_days: 30,
         force_copy: false,
         drop_after_copy: false,
+        lock_timeout: 5,
+        json_pretty: true,
+        confirm: true,
+        strict: false,
+        hide_checked: false,
+        min_priority: Priority::None,
+        default_priority: Priority::Med,
+        urgency: Urgency::default(),
+        columns: Column::defaults(),
+        format: String::from(Task::DEFAULT_FORMAT),
+        color: ColorMode::Auto,
+        colors: Colors::default(),
+        lang: Lang::En,
+        hooks: Hooks::default(),
+        links: Links::default(),
+        lists: Lists::default(),
+        plugins: Plugins::default(),
+        csv: CsvDialect::default(),
+        database: String::from("test"),
+        password: None,
     };
 
     assert_eq!(result, expect);
@@ -244,19 +518,58 @@ fn manage_set_all() -> postit::Result<()> {
 
     let args = args::ConfigSet {
         persister: Some(String::from("tasks.json")),
+        default_command: None,
         force_drop: Some(true),
+        trash_days: None,
         force_copy: Some(true),
         drop_after_copy: Some(true),
+        lock_timeout: Some(10),
+        json_pretty: Some(false),
+        confirm: Some(false),
+        strict: Some(true),
+        hide_checked: Some(true),
+        min_priority: Some(Priority::Low),
+        default_priority: Some(Priority::High),
+        columns: Some(vec![Column::Id, Column::Content]),
+        format: Some(String::from("{id} {content}")),
+        color: Some(ColorMode::Never),
+        lang: None,
+        database: Some(String::from("postit")),
+        password: Some(String::from("env:DB_PASSWORD")),
     };
 
     Config::manage(sub::Config::Set(args))?;
 
     let result = Config::load()?;
     let expect = Config {
+        version: Migration::latest(),
         persister: String::from("tasks.json"),
+        mirrors: Vec::new(),
+        default_command: None,
         force_drop: true,
+        trash_days: 30,
         force_copy: true,
         drop_after_copy: true,
+        lock_timeout: 10,
+        json_pretty: false,
+        confirm: false,
+        strict: true,
+        hide_checked: true,
+        min_priority: Priority::Low,
+        default_priority: Priority::High,
+        urgency: Urgency::default(),
+        columns: vec![Column::Id, Column::Content],
+        format: String::from("{id} {content}"),
+        color: ColorMode::Never,
+        colors: Colors::default(),
+        lang: Lang::En,
+        hooks: Hooks::default(),
+        links: Links::default(),
+        lists: Lists::default(),
+        plugins: Plugins::default(),
+        csv: CsvDialect::default(),
+        database: String::from("postit"),
+        password: Some(String::from("env:DB_PASSWORD")),
     };
 
     assert_eq!(result, expect);
@@ -268,9 +581,24 @@ fn manage_set_all() -> postit::Result<()> {
 fn manage_set_err_path_doesnt_exist() -> postit::Result<()> {
     let args = args::ConfigSet {
         persister: None,
+        default_command: None,
         force_drop: None,
+        trash_days: None,
         force_copy: None,
         drop_after_copy: None,
+        lock_timeout: None,
+        json_pretty: None,
+        confirm: None,
+        strict: None,
+        hide_checked: None,
+        min_priority: None,
+        default_priority: None,
+        columns: None,
+        format: None,
+        color: None,
+        lang: None,
+        database: None,
+        password: None,
     };
 
     let err = Config::manage(sub::Config::Set(args)).unwrap_err();
@@ -286,9 +614,24 @@ fn manage_set_err_none_set() -> postit::Result<()> {
 
     let args = args::ConfigSet {
         persister: None,
+        default_command: None,
         force_drop: None,
+        trash_days: None,
         force_copy: None,
         drop_after_copy: None,
+        lock_timeout: None,
+        json_pretty: None,
+        confirm: None,
+        strict: None,
+        hide_checked: None,
+        min_priority: None,
+        default_priority: None,
+        columns: None,
+        format: None,
+        color: None,
+        lang: None,
+        database: None,
+        password: None,
     };
 
     let err = Config::manage(sub::Config::Set(args)).unwrap_err();
@@ -306,6 +649,8 @@ fn default() -> postit::Result<()> {
     assert!(config.force_drop.not());
     assert!(config.force_copy.not());
     assert!(config.drop_after_copy.not());
+    assert!(config.hide_checked.not());
+    assert_eq!(config.min_priority, Priority::None);
 
     Ok(())
 }
@@ -382,6 +727,323 @@ fn load_default() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_list() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    let args = args::ListCreate {
+        name: String::from("work"),
+        persister: None,
+    };
+    Config::manage_lists(sub::List::Create(args))?;
+
+    let args = args::ListCreate {
+        name: String::from("home"),
+        persister: Some(String::from("home.json")),
+    };
+    Config::manage_lists(sub::List::Create(args))?;
+
+    let config = Config::load()?;
+
+    assert_eq!(config.list_persister("work")?, "work.csv");
+    assert_eq!(config.list_persister("home")?, "home.json");
+
+    Ok(())
+}
+
+#[test]
+fn uses() {
+    let mut config = Config {
+        persister: String::from("tasks.csv"),
+        mirrors: vec![String::from("backup.csv")],
+        ..Config::default()
+    };
+    config
+        .lists
+        .0
+        .insert(String::from("work"), String::from("work.csv"));
+
+    assert!(config.uses("tasks.csv"));
+    assert!(config.uses("backup.csv"));
+    assert!(config.uses("work.csv"));
+    assert!(config.uses("other.csv").not());
+}
+
+#[test]
+fn create_list_err() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    let args = args::ListCreate {
+        name: String::from("work"),
+        persister: None,
+    };
+    Config::manage_lists(sub::List::Create(args.clone()))?;
+
+    assert!(Config::manage_lists(sub::List::Create(args)).is_err());
+
+    let args = args::ListCreate {
+        name: String::from("work.csv"),
+        persister: None,
+    };
+
+    assert!(Config::manage_lists(sub::List::Create(args)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn show_lists() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    assert!(Config::manage_lists(sub::List::Show).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn remove_list() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    mock.config
+        .lists
+        .0
+        .insert(String::from("work"), String::from("work.csv"));
+    mock.save()?;
+
+    let args = args::ListRemove { name: String::from("work") };
+    Config::manage_lists(sub::List::Remove(args.clone()))?;
+
+    assert_eq!(Config::load()?.lists, Lists::default());
+
+    let err = Config::manage_lists(sub::List::Remove(args)).unwrap_err();
+
+    assert!(matches!(err, postit::config::Error::UnknownList(_)));
+
+    Ok(())
+}
+
+#[test]
+fn load_partial_uses_defaults() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    std::fs::write(mock.path(), "persister = \"tasks.json\"\n\n[urgency]\nhigh = 10\n")?;
+
+    let result = Config::load()?;
+    let expect = Config {
+        persister: String::from("tasks.json"),
+        urgency: Urgency { high: 10, ..Urgency::default() },
+        ..Config::default()
+    };
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn layers_order() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    let layers = Config::layers()?;
+    let local = std::env::current_dir()?.join(".postit.toml");
+
+    assert_eq!(layers, vec![Config::system_path(), Config::path()?, local]);
+
+    Ok(())
+}
+
+#[test]
+fn load_from_merges_layers() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let system = mock.path().with_file_name("system.toml");
+    let user = mock.path().with_file_name("user.toml");
+    let missing = mock.path().with_file_name("missing.toml");
+
+    std::fs::write(&system, "force_drop = true\n\n[urgency]\nhigh = 10\nlow = 5\n")?;
+    std::fs::write(&user, "persister = \"tasks.json\"\n\n[urgency]\nlow = 1\n")?;
+
+    let result = Config::load_from(&[system, user, missing])?;
+    let expect = Config {
+        version: Migration::latest(),
+        persister: String::from("tasks.json"),
+        mirrors: Vec::new(),
+        default_command: None,
+        force_drop: true,
+        urgency: Urgency { high: 10, low: 1, ..Urgency::default() },
+        ..Config::default()
+    };
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn load_from_persister_list() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let path = mock.path().with_file_name("mirrors.toml");
+    std::fs::write(&path, "persister = [\"tasks.csv\", \"backup.json\", \"backup.db\"]\n")?;
+
+    let result = Config::load_from(&[path])?;
+
+    assert_eq!(result.persister, "tasks.csv");
+    assert_eq!(result.mirrors, ["backup.json", "backup.db"]);
+
+    Ok(())
+}
+
+#[test]
+fn load_hide_done() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    std::fs::write(mock.path(), "hide_done = true\n")?;
+
+    assert!(Config::load()?.hide_checked);
+    assert!(Config::check()?
+        .iter()
+        .all(|problem| !problem.contains("hide_done")));
+
+    Ok(())
+}
+
+#[test]
+fn project_config_ignores_untrusted_keys() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let project = mock.path().with_file_name("project");
+    let pwned = project.join("pwned");
+    std::fs::create_dir_all(&project)?;
+
+    let config = format!(
+        "persister = \"plugin://evil\"\npassword = \"secret\"\n\n\
+         [hooks]\non_add = \"touch {}\"\n\n[plugins]\nevil = \"touch {}\"\n",
+        pwned.display(),
+        pwned.display()
+    );
+    std::fs::write(project.join(".postit.toml"), config)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .current_dir(&project)
+        .args(["add", "low", "hello"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success());
+    assert!(pwned.exists().not());
+    assert!(stderr.contains("Ignoring 'hooks'"));
+    assert!(stderr.contains("Ignoring 'persister'"));
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .current_dir(&project)
+        .args(["config", "check"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success().not());
+    assert!(stdout.contains("sets 'plugins', which is ignored in project config files"));
+    assert!(stdout.contains("sets 'password', which is ignored in project config files"));
+
+    Ok(())
+}
+
+#[test]
+fn project_config_ignores_persister_and_default_command() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let project = mock.path().with_file_name("project");
+    let victim = mock.path().with_file_name("victim.csv");
+    std::fs::create_dir_all(&project)?;
+    std::fs::write(&victim, "id,content,priority,checked\n1,Keep me,high,false\n")?;
+
+    let config = format!(
+        "persister = \"{}\"\nmirrors = [\"{}\"]\ndefault_command = \"remove -y\"\n",
+        victim.display(),
+        victim.display()
+    );
+    std::fs::write(project.join(".postit.toml"), config)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .current_dir(&project)
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Without a default command, postit only prints its help
+    assert!(output.status.success().not());
+    assert!(std::fs::read_to_string(&victim)?.contains("Keep me"));
+    assert!(stderr.contains("Ignoring 'persister'"));
+    assert!(stderr.contains("Ignoring 'mirrors'"));
+    assert!(stderr.contains("Ignoring 'default_command'"));
+
+    Ok(())
+}
+
+#[test]
+fn load_from_err_invalid_toml() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let path = mock.path().with_file_name("invalid.toml");
+    std::fs::write(&path, "persister = ")?;
+
+    let err = Config::load_from(&[path]).unwrap_err();
+
+    assert!(matches!(err, postit::config::Error::TOMLDeserialize(_)));
+
+    Ok(())
+}
+
+#[test]
+fn load_env_overrides() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    std::fs::write(mock.path(), "persister = \"tasks.json\"\nforce_drop = true\n")?;
+
+    let _env = MockEnvVar::new().set([
+        ("POSTIT_PERSISTER", "ci.xml"),
+        ("POSTIT_FORCE_DROP", "false"),
+        ("POSTIT_LOCK_TIMEOUT", "9"),
+        ("POSTIT_COLUMNS", "id, content"),
+        ("POSTIT_MIN_PRIORITY", "high"),
+    ]);
+
+    let config = Config::load()?;
+
+    assert_eq!(config.persister, "ci.xml");
+    assert!(!config.force_drop);
+    assert_eq!(config.lock_timeout, 9);
+    assert_eq!(config.columns, vec![Column::Id, Column::Content]);
+    assert_eq!(config.min_priority, Priority::High);
+    assert_eq!(Config::load_from(&[mock.path()])?.persister, "tasks.json");
+
+    Ok(())
+}
+
+#[test]
+fn load_env_overrides_err_invalid_value() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+    let _env = MockEnvVar::new().set([("POSTIT_STRICT", "yes")]);
+
+    let err = Config::load().unwrap_err();
+
+    assert!(err.to_string().contains("strict"));
+
+    Ok(())
+}
+
+#[test]
+fn env_var() {
+    assert_eq!(Config::env_var("drop_after_copy"), "POSTIT_DROP_AFTER_COPY");
+    assert!(!Config::ENV_KEYS.contains(&"password"));
+}
+
 #[test]
 fn save() -> postit::Result<()> {
     let _mock = MockConfig::new()?;
@@ -394,6 +1056,87 @@ fn save() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn init_comments_defaults() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let content = std::fs::read_to_string(mock.path())?;
+    let table: toml::Table = toml::from_str(&content).map_err(postit::Error::wrap)?;
+
+    assert_eq!(table.keys().collect::<Vec<_>>(), ["version"]);
+    assert!(content.contains("# force_drop = false"));
+    assert_eq!(Config::load_from(&[mock.path()])?, Config::default());
+
+    Ok(())
+}
+
+#[test]
+fn save_only_changed_keys() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let system = mock.path().with_file_name("system.toml");
+
+    std::fs::write(&system, "trash_days = 5\nforce_copy = true\n\n[hooks]\non_add = \"true\"\n")?;
+    std::fs::write(mock.path(), "force_copy = false\n")?;
+
+    let mut config = Config::load_from(&[mock.path()])?;
+    config.force_drop = true;
+    config.hooks.on_check = Some(String::from("true"));
+    config.save()?;
+
+    let content = std::fs::read_to_string(mock.path())?;
+    let table: toml::Table = toml::from_str(&content).map_err(postit::Error::wrap)?;
+
+    assert_eq!(table.keys().collect::<Vec<_>>(), ["force_copy", "force_drop", "hooks", "version"]);
+
+    let result = Config::load_from(&[system, mock.path()])?;
+
+    assert_eq!(result.trash_days, 5);
+    assert!(result.force_copy.not());
+    assert!(result.force_drop);
+    assert_eq!(result.hooks.on_add.as_deref(), Some("true"));
+    assert_eq!(result.hooks.on_check.as_deref(), Some("true"));
+
+    Ok(())
+}
+
+#[test]
+fn manage_set_writes_passed_keys() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    let args = args::ConfigSet {
+        persister: None,
+        default_command: None,
+        force_drop: None,
+        trash_days: None,
+        force_copy: None,
+        drop_after_copy: None,
+        lock_timeout: None,
+        json_pretty: Some(true),
+        confirm: None,
+        strict: None,
+        hide_checked: None,
+        min_priority: None,
+        default_priority: None,
+        columns: None,
+        format: None,
+        color: None,
+        lang: None,
+        database: None,
+        password: None,
+    };
+
+    assert_eq!(args.keys(), ["json_pretty"]);
+
+    Config::manage(sub::Config::Set(args))?;
+
+    let content = std::fs::read_to_string(mock.path())?;
+
+    assert_eq!(content.lines().filter(|line| line.contains('=')).count(), 2);
+    assert!(content.contains("json_pretty = true"));
+
+    Ok(())
+}
+
 #[test]
 #[should_panic]
 fn save_file_doesnt_exist() {
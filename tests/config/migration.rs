@@ -0,0 +1,79 @@
+use postit::config::{Config, Migration};
+use postit::testing::MockConfig;
+
+fn table(toml: &str) -> toml::Table {
+    toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn latest() {
+    assert_eq!(Migration::latest(), Migration::ALL.len() as u32);
+}
+
+#[test]
+fn version_of() {
+    assert_eq!(Migration::version_of(&table("version = 1")), 1);
+    assert_eq!(Migration::version_of(&table("persister = \"tasks.csv\"")), 0);
+}
+
+#[test]
+fn pending_latest() {
+    assert!(Migration::pending(Migration::latest()).is_empty());
+    assert_eq!(Migration::pending(0).len(), Migration::ALL.len());
+}
+
+#[test]
+fn run_unversioned() {
+    let mut result = table("path = \"tasks.json\"");
+    let applied = Migration::run(&mut result);
+
+    assert_eq!(applied.len(), Migration::ALL.len());
+    assert_eq!(
+        result,
+        table(&format!("persister = \"tasks.json\"\nversion = {}", Migration::latest()))
+    );
+}
+
+#[test]
+fn run_latest() {
+    let mut result = table("version = 1\npath = \"tasks.json\"");
+
+    assert!(Migration::run(&mut result).is_empty());
+    assert_eq!(result, table("version = 1\npath = \"tasks.json\""));
+}
+
+#[test]
+fn rename_keeps_new_key() {
+    let mut result = table("path = \"old.csv\"\npersister = \"new.csv\"");
+    Migration::rename(&mut result, "path", "persister");
+
+    assert_eq!(result, table("persister = \"new.csv\""));
+}
+
+#[test]
+fn load_legacy_file() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    std::fs::write(mock.path(), "path = \"tasks.json\"\nforce_drop = true\n")?;
+
+    let config = Config::load()?;
+
+    assert_eq!(config.persister, "tasks.json");
+    assert_eq!(config.version, Migration::latest());
+    assert!(config.force_drop);
+    assert!(Config::check()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn check_newer_version() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    std::fs::write(mock.path(), "version = 99\n")?;
+
+    let problems = Config::check()?;
+
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("newer version of postit (version 99)"));
+
+    Ok(())
+}
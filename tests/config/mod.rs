@@ -1 +1,3 @@
 pub mod configuration;
+pub mod migration;
+pub mod secret;
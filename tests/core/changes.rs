@@ -0,0 +1,16 @@
+use postit::models::Task;
+use postit::Changes;
+
+#[test]
+fn ids() {
+    let changes = Changes::new(vec![Task::from("2,Task,med,false"), Task::from("4,Task,low,true")]);
+
+    assert_eq!(changes.ids(), [2, 4]);
+    assert_eq!(changes.refs().len(), 2);
+    assert!(!changes.is_empty());
+}
+
+#[test]
+fn default_is_empty() {
+    assert!(Changes::default().is_empty());
+}
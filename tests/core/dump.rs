@@ -0,0 +1,128 @@
+use std::fs;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use postit::models::Todo;
+use postit::testing::MockConfig;
+use postit::{Dump, Entry, Journal, Trash};
+
+#[test]
+fn write_and_read() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let path = mock.path().with_file_name("dump.tar.gz");
+
+    let dump = Dump::new(Some(String::from("persister = \"tasks.json\"\n")), Todo::sample().tasks);
+
+    dump.write(&path)?;
+
+    assert_eq!(Dump::read(&path)?, dump);
+
+    Ok(())
+}
+
+#[test]
+fn write_and_read_without_config() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let path = mock.path().with_file_name("dump.tar.gz");
+
+    let dump = Dump::new(None, Todo::sample().tasks);
+
+    dump.write(&path)?;
+
+    assert_eq!(Dump::read(&path)?, dump);
+
+    Ok(())
+}
+
+#[test]
+fn collect_and_unpack() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let path = mock.path().with_file_name("dump.tar.gz");
+    let tasks = Todo::sample().tasks;
+
+    let mut journal = Journal::default();
+    journal.record(Entry::new("clean", "tasks.csv", &tasks, &[]));
+    journal.save()?;
+
+    let mut trash = Trash::default();
+    trash.add("tasks.csv", &tasks);
+    trash.save()?;
+
+    let dump = Dump::collect(tasks)?;
+    dump.write(&path)?;
+
+    assert!(dump.config.is_some());
+    assert_eq!(Dump::read(&path)?, dump);
+
+    fs::remove_file(Journal::path()?)?;
+    fs::remove_file(Trash::path()?)?;
+
+    Dump::read(&path)?.unpack()?;
+
+    assert_eq!(Journal::load()?, journal);
+    assert_eq!(Trash::load()?, trash);
+
+    Ok(())
+}
+
+#[test]
+fn unpack_keeps_missing_files() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    let mut trash = Trash::default();
+    trash.add("tasks.csv", &Todo::sample().tasks);
+    trash.save()?;
+
+    Dump::new(None, Vec::new()).unpack()?;
+
+    assert_eq!(Trash::load()?, trash);
+    assert!(!Journal::path()?.exists());
+
+    Ok(())
+}
+
+#[test]
+fn read_err_no_tasks() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let path = mock.path().with_file_name("empty.tar.gz");
+
+    let encoder = GzEncoder::new(fs::File::create(&path)?, Compression::default());
+    tar::Builder::new(encoder).into_inner()?.finish()?;
+
+    let err = Dump::read(&path).unwrap_err();
+
+    assert!(err.to_string().contains("tasks.json"));
+
+    Ok(())
+}
+
+#[test]
+fn read_err_not_an_archive() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+
+    assert!(Dump::read(mock.path()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn backups() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    assert!(Dump::backups()?.is_empty());
+
+    let path = Dump::backup_path()?;
+    Dump::new(None, Todo::sample().tasks).write(&path)?;
+    fs::write(Dump::dir()?.join("notes.txt"), "Not a backup")?;
+
+    assert!(path.starts_with(Dump::dir()?));
+    assert_eq!(Dump::backups()?, vec![path.clone()]);
+
+    let name = path.file_name().unwrap().to_string_lossy().into_owned();
+
+    assert_eq!(Dump::find(&name)?, path);
+    assert_eq!(Dump::find(&path.to_string_lossy())?, path);
+    assert!(Dump::find("missing.tar.gz").is_err());
+
+    Ok(())
+}
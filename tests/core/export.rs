@@ -0,0 +1,50 @@
+use std::fs;
+
+use postit::fs::Format;
+use postit::models::{Links, Task, Todo};
+use postit::testing::MockConfig;
+use postit::ExportFormat;
+
+#[test]
+fn write_files() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let todo = Todo::sample();
+
+    for (export, format) in [
+        (ExportFormat::Json, Format::Json),
+        (ExportFormat::Csv, Format::Csv),
+        (ExportFormat::Xml, Format::Xml),
+    ] {
+        let path = mock.path().with_file_name(format!("export.{export}"));
+
+        export.write(&todo, &Links::default(), &path, true)?;
+
+        assert_eq!(format.parse(&fs::read_to_string(&path)?)?, todo.tasks);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn write_markdown() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let path = mock.path().with_file_name("export.md");
+
+    let mut links = Links::default();
+    links
+        .0
+        .insert(String::from("gh"), String::from("https://example.com/{id}"));
+
+    let todo = Todo::new(vec![
+        Task::from("1,Deploy,high,false,gh#42,work"),
+        Task::from("2,Task,none,true"),
+    ]);
+
+    ExportFormat::Markdown.write(&todo, &links, &path, true)?;
+
+    let expect = "- [ ] `high` Deploy #work [gh#42](https://example.com/42)\n- [x] Task\n";
+
+    assert_eq!(fs::read_to_string(&path)?, expect);
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use std::fs;
+use std::ops::Not;
+
+use chrono::{TimeZone as _, Utc};
+
+use postit::fs::Format;
+use postit::models::Task;
+use postit::testing::{MockConfig, MockPath};
+use postit::{Hook, Hooks};
+
+#[test]
+fn fmt_display_none() {
+    assert_eq!(Hooks::default().to_string(), "none");
+}
+
+#[test]
+fn fmt_display_some() {
+    let hooks = Hooks {
+        on_add: None,
+        on_check: Some(String::from("echo {id}")),
+        on_overdue: None,
+        on_pomo: None,
+        webhook_url: None,
+    };
+
+    assert_eq!(hooks.to_string(), "on_check='echo {id}'");
+}
+
+#[test]
+fn fmt_display_webhook() {
+    let hooks = Hooks {
+        webhook_url: Some(String::from("http://localhost/tasks")),
+        ..Hooks::default()
+    };
+
+    assert_eq!(hooks.to_string(), "webhook_url='http://localhost/tasks'");
+}
+
+#[test]
+fn command() {
+    let hooks = Hooks {
+        on_add: Some(String::from("true")),
+        on_check: None,
+        on_overdue: None,
+        on_pomo: None,
+        webhook_url: None,
+    };
+
+    assert_eq!(hooks.command(Hook::Add), Some("true"));
+    assert_eq!(hooks.command(Hook::Check), None);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn render() {
+    let task = Task::from("3,Buy milk,high,false");
+
+    let result = Hooks::render("echo {id}: {content} {priority}", &task);
+
+    assert_eq!(result, "echo '3': 'Buy milk' 'high'");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn render_quotes_content() {
+    let task = Task::from("1,It's done; rm -rf tmp,med,false");
+
+    let result = Hooks::render("echo {content}", &task);
+
+    assert_eq!(result, r"echo 'It'\''s done; rm -rf tmp'");
+}
+
+#[test]
+fn overdue() {
+    let mut old = Task::from("1,Old,med,false");
+    old.due = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).single();
+
+    let mut renumbered = old.clone();
+    renumbered.id = 2;
+
+    let mut new = Task::from("3,New,med,false");
+    new.due = old.due;
+
+    let mut checked = Task::from("4,Checked,med,true");
+    checked.due = old.due;
+
+    let before = vec![old];
+    let after = vec![renumbered, new.clone(), checked, Task::from("5,Undated,med,false")];
+
+    assert_eq!(Hooks::overdue(&before, &after), vec![&new]);
+    assert!(Hooks::overdue(&after, &after).is_empty());
+}
+
+#[test]
+fn run_undefined() -> postit::Result<()> {
+    let task = Task::from("1,Task,med,false");
+
+    Hooks::default().run(Hook::Add, &[&task])
+}
+
+#[test]
+fn run_ok() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let out = mock.path().with_file_name("hook.txt");
+
+    let hooks = Hooks {
+        on_add: None,
+        on_check: Some(format!("echo {{id}} \"$POSTIT_CONTENT\" >> {}", out.display())),
+        on_overdue: None,
+        on_pomo: None,
+        webhook_url: None,
+    };
+
+    let first = Task::from("1,First,med,true");
+    let second = Task::from("2,Second,low,true");
+
+    hooks.run(Hook::Check, &[&first, &second])?;
+
+    assert_eq!(fs::read_to_string(out)?, "1 First\n2 Second\n");
+
+    Ok(())
+}
+
+#[test]
+fn run_err_status() {
+    let hooks = Hooks {
+        on_add: Some(String::from("exit 3")),
+        on_check: None,
+        on_overdue: None,
+        on_pomo: None,
+        webhook_url: None,
+    };
+    let task = Task::from("1,Task,med,false");
+
+    assert!(hooks.run(Hook::Add, &[&task]).is_err());
+}
+
+#[test]
+fn add_runs_hook() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let out = mock.path().with_file_name("hook.txt");
+
+    mock.config.hooks.on_add = Some(format!("echo {{content}} > {}", out.display()));
+    mock.save()?;
+
+    assert!(out.exists().not());
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "high", "Hooked"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+    assert_eq!(fs::read_to_string(out)?, "Hooked\n");
+
+    Ok(())
+}
+
+#[test]
+fn set_due_runs_overdue_hook() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let tasks = MockPath::create(Format::Csv)?;
+    let out = mock.path().with_file_name("hook.txt");
+
+    mock.config.hooks.on_overdue = Some(format!("echo {{content}} >> {}", out.display()));
+    mock.save()?;
+
+    let run = |date: &str| {
+        assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["set", "-p", &tasks.to_string(), "due", "1", "--on", date])
+            .output()
+            .map_err(postit::Error::wrap)
+    };
+
+    assert!(run("2999-01-14")?.status.success());
+    assert!(out.exists().not());
+
+    assert!(run("2000-01-14")?.status.success());
+    assert!(run("2000-01-14")?.status.success());
+
+    assert_eq!(fs::read_to_string(out)?, "Task\n");
+
+    Ok(())
+}
+
+#[test]
+fn pomo_runs_hook() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let tasks = MockPath::create(Format::Csv)?;
+    let out = mock.path().with_file_name("hook.txt");
+
+    mock.config.hooks.on_pomo = Some(format!("echo {{id}} > {}", out.display()));
+    mock.save()?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["pomo", "2", "--minutes", "0", "-p", &tasks.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+    assert_eq!(fs::read_to_string(out)?, "2\n");
+
+    Ok(())
+}
+
+#[test]
+fn payload() {
+    let before = vec![Task::from("1,First,med,false"), Task::from("2,Second,low,false")];
+    let after = vec![Task::from("1,First,med,true"), Task::from("3,Third,high,false")];
+
+    let payload = Hooks::payload("check", "tasks.csv", &before, &after);
+
+    assert_eq!(payload["command"], "check");
+    assert_eq!(payload["persister"], "tasks.csv");
+    assert_eq!(payload["ids"], serde_json::json!([1, 2, 3]));
+    assert_eq!(payload["before"][0]["checked"], false);
+    assert_eq!(payload["before"][1]["content"], "Second");
+    assert_eq!(payload["after"][0]["checked"], true);
+    assert_eq!(payload["after"][1]["content"], "Third");
+}
+
+#[test]
+fn post_undefined() -> postit::Result<()> {
+    Hooks::default().post("add", "tasks.csv", &[], &[Task::from("1,Task,med,false")])
+}
+
+#[test]
+#[cfg(feature = "webhooks")]
+fn add_posts_webhook() -> postit::Result<()> {
+    use std::io::{BufRead as _, BufReader, Read as _, Write as _};
+    use std::net::TcpListener;
+    use std::thread;
+
+    let mut mock = MockConfig::new()?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    mock.config.hooks.webhook_url = Some(format!("http://{}/tasks", listener.local_addr()?));
+    mock.save()?;
+
+    let server = thread::spawn(move || -> std::io::Result<String> {
+        let (stream, _) = listener.accept()?;
+        let mut reader = BufReader::new(stream);
+        let mut length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+
+            if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap_or_default();
+            }
+
+            if line.trim().is_empty() {
+                break;
+            }
+        }
+
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 204 No Content\r\n\r\n")?;
+
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    });
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "high", "Hooked"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let body = server
+        .join()
+        .map_err(|_| postit::Error::wrap("The server panicked"))??;
+    let payload: serde_json::Value = serde_json::from_str(&body).map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+    assert_eq!(payload["command"], "add");
+    assert_eq!(payload["after"][0]["content"], "Hooked");
+
+    Ok(())
+}
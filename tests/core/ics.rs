@@ -0,0 +1,50 @@
+use postit::models::{Task, Todo};
+use postit::Ics;
+
+fn now() -> chrono::DateTime<chrono::Utc> {
+    Task::parse_timestamp("2025-02-01T09:00:00Z").unwrap()
+}
+
+#[test]
+fn calendar() {
+    let todo = Todo::new(vec![
+        Task::from("1,Deploy; then test,high,false,,work ops,Line 1%0ALine 2"),
+        Task::from("2,Task,none,true,,,,2025-01-31T18:30:00Z,,2025-01-31T20:00:00Z"),
+    ]);
+
+    let ics = Ics::calendar(&todo, &now());
+    let lines: Vec<&str> = ics.split("\r\n").collect();
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+
+    assert!(lines.contains(&"UID:1@postit"));
+    assert!(lines.contains(&"DTSTAMP:20250201T090000Z"));
+    assert!(lines.contains(&"SUMMARY:Deploy\\; then test"));
+    assert!(lines.contains(&"PRIORITY:1"));
+    assert!(lines.contains(&"STATUS:NEEDS-ACTION"));
+    assert!(lines.contains(&"CATEGORIES:work,ops"));
+    assert!(lines.contains(&"DESCRIPTION:Line 1\\nLine 2"));
+
+    assert!(lines.contains(&"UID:2-20250131T183000Z@postit"));
+    assert!(lines.contains(&"PRIORITY:0"));
+    assert!(lines.contains(&"STATUS:COMPLETED"));
+    assert!(lines.contains(&"CREATED:20250131T183000Z"));
+    assert!(lines.contains(&"COMPLETED:20250131T200000Z"));
+}
+
+#[test]
+fn escape() {
+    assert_eq!(Ics::escape("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+}
+
+#[test]
+fn fold() {
+    let line = "x".repeat(160);
+    let folded = Ics::fold(&line);
+    let parts: Vec<&str> = folded.split("\r\n").collect();
+
+    assert_eq!(parts.len(), 3);
+    assert!(parts.iter().all(|part| part.len() <= 75));
+    assert_eq!(folded.replace("\r\n ", ""), line);
+}
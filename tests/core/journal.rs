@@ -0,0 +1,135 @@
+use postit::models::{Task, Todo};
+use postit::testing::MockConfig;
+use postit::{Entry, Journal};
+
+fn entry(persister: &str, content: &str) -> Entry {
+    let before = Todo::sample().tasks;
+    let after = [before.clone(), vec![Task::from(format!("5,{content},med,false"))]].concat();
+
+    Entry::new("add", persister, &before, &after)
+}
+
+#[test]
+fn save_and_load() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    let mut journal = Journal::load()?;
+    assert_eq!(journal, Journal::default());
+
+    journal.record(entry("tasks.csv", "Task"));
+    journal.save()?;
+
+    assert_eq!(Journal::load()?, journal);
+
+    Ok(())
+}
+
+#[test]
+fn pop_by_persister() {
+    let mut journal = Journal::default();
+
+    journal.record(entry("tasks.csv", "First"));
+    journal.record(entry("tasks.json", "Second"));
+
+    assert_eq!(journal.pop_undo("tasks.csv"), Some(entry("tasks.csv", "First")));
+    assert_eq!(journal.pop_undo("tasks.csv"), None);
+    assert_eq!(journal.pop_redo("tasks.json"), None);
+    assert_eq!(journal.undo.len(), 1);
+}
+
+#[test]
+fn record_clears_redo_of_persister() {
+    let mut journal = Journal::default();
+
+    journal.redo.push(entry("tasks.csv", "First"));
+    journal.redo.push(entry("tasks.json", "Second"));
+
+    journal.record(entry("tasks.csv", "Third"));
+
+    assert_eq!(journal.redo, [entry("tasks.json", "Second")]);
+}
+
+#[test]
+fn record_limit() {
+    let mut journal = Journal::default();
+
+    for i in 0..60 {
+        journal.record(entry("tasks.csv", &i.to_string()));
+    }
+
+    assert_eq!(journal.undo.len(), 50);
+    assert_eq!(journal.undo[0], entry("tasks.csv", "10"));
+}
+
+#[test]
+fn history() {
+    let mut journal = Journal::default();
+
+    journal.record(entry("tasks.csv", "First"));
+    journal.record(entry("tasks.json", "Second"));
+
+    let before = Todo::sample().tasks;
+    let mut after = before.clone();
+    after[0].checked = true;
+
+    journal.record(Entry::new("check", "tasks.csv", &before, &after));
+    journal.record(Entry::new("tidy", "tasks.csv", &before, &[]));
+
+    let commands = |id: u32| -> Vec<&str> {
+        journal
+            .history("tasks.csv", id)
+            .iter()
+            .map(|entry| entry.command.as_str())
+            .collect()
+    };
+
+    assert_eq!(commands(1), ["check"]);
+    assert_eq!(commands(5), ["add"]);
+    assert!(commands(2).is_empty());
+}
+
+#[test]
+fn entry_keeps_changes() {
+    let entry = entry("tasks.csv", "Task");
+
+    assert!(entry.before.is_empty());
+    assert_eq!(entry.after, [Task::from("5,Task,med,false")]);
+}
+
+#[test]
+fn replay() {
+    let before = Todo::sample().tasks;
+    let mut after = before.clone();
+    after[0].checked = true;
+    after.remove(1);
+
+    let entry = Entry::new("check", "tasks.csv", &before, &after);
+
+    assert_eq!(entry.before, before[..2]);
+    assert_eq!(entry.after, after[..1]);
+
+    assert_eq!(Entry::replay(&after, &entry.after, &entry.before), Some(before.clone()));
+    assert_eq!(Entry::replay(&before, &entry.before, &entry.after), Some(after));
+}
+
+#[test]
+fn replay_keeps_other_tasks() {
+    let entry = entry("tasks.csv", "Task");
+
+    let mut tasks = Todo::sample().tasks;
+    tasks[0].content = String::from("Edited");
+
+    let added = [tasks.clone(), entry.after.clone()].concat();
+
+    assert_eq!(Entry::replay(&added, &entry.after, &entry.before), Some(tasks));
+}
+
+#[test]
+fn replay_none_if_changed() {
+    let entry = entry("tasks.csv", "Task");
+
+    let mut tasks = [Todo::sample().tasks, entry.after.clone()].concat();
+    tasks[4].content = String::from("Edited");
+
+    assert_eq!(Entry::replay(&tasks, &entry.after, &entry.before), None);
+}
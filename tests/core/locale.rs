@@ -0,0 +1,65 @@
+use std::ops::Not;
+
+use postit::testing::MockConfig;
+use postit::Lang;
+
+#[test]
+fn display() {
+    assert_eq!(Lang::En.to_string(), "en");
+    assert_eq!(Lang::Es.to_string(), "es");
+    assert_eq!(Lang::default(), Lang::En);
+}
+
+#[test]
+fn tr() {
+    assert_eq!(Lang::En.tr("Content"), "Content");
+    assert_eq!(Lang::Es.tr("Content"), "Contenido");
+    assert_eq!(Lang::Es.tr("Task {id} doesn't exist"), "La tarea {id} no existe");
+}
+
+#[test]
+fn tr_fallback() {
+    assert_eq!(Lang::Es.tr("Untranslated message"), "Untranslated message");
+}
+
+#[test]
+fn fill() {
+    let msg = Lang::fill(
+        "'{answer}' is not a number between 1 and {max}",
+        &[("answer", String::from("x")), ("max", String::from("3"))],
+    );
+
+    assert_eq!(msg, "'x' is not a number between 1 and 3");
+    assert_eq!(Lang::fill("{unknown}", &[]), "{unknown}");
+}
+
+#[test]
+fn spanish_output() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    mock.config.lang = Lang::Es;
+    mock.save()?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["config", "set"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success().not());
+    assert!(stderr.contains("Debes indicar los valores que se cambian"));
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["docs", "add"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("Uso: postit add"));
+    assert!(stdout.contains("Descripción:"));
+
+    Ok(())
+}
@@ -1,2 +1,15 @@
 pub mod action;
+pub mod changes;
+pub mod dump;
+pub mod export;
+pub mod hooks;
+pub mod ics;
+pub mod journal;
+pub mod locale;
+pub mod pager;
+pub mod picker;
 pub mod postit;
+pub mod prompt;
+pub mod report;
+pub mod trash;
+pub mod verbosity;
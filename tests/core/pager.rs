@@ -0,0 +1,18 @@
+use postit::Pager;
+
+#[test]
+fn disabled() {
+    let pager = Pager::new(false);
+
+    assert!(!pager.is_enabled());
+    assert!(!pager.pages(usize::MAX));
+}
+
+#[test]
+fn not_a_terminal() -> postit::Result<()> {
+    // The output of tests is captured, so it is never paged
+    let pager = Pager::new(true);
+
+    assert!(!pager.is_enabled());
+    pager.print("1. Task\n")
+}
@@ -0,0 +1,74 @@
+use std::io::Cursor;
+
+use postit::models::{Task, Todo};
+use postit::Picker;
+
+fn run(tasks: &[Task], input: &str) -> postit::Result<Vec<u32>> {
+    Picker::new(tasks).run(Cursor::new(input), Vec::new())
+}
+
+#[test]
+fn score_matches() {
+    assert!(Picker::score("bmlk", "Buy milk").is_some());
+    assert!(Picker::score("", "Buy milk").is_some());
+    assert!(Picker::score("klm", "Buy milk").is_none());
+}
+
+#[test]
+fn score_rewards_closer_matches() {
+    let close = Picker::score("milk", "Buy milk").unwrap();
+    let far = Picker::score("milk", "Make it like koalas").unwrap();
+
+    assert!(close > far);
+}
+
+#[test]
+fn matches_sorted_by_score() {
+    let tasks = vec![
+        Task::from("1,Make it like koalas,med,false"),
+        Task::from("2,Buy milk,low,false"),
+        Task::from("3,Write report,high,false"),
+    ];
+
+    let result: Vec<u32> = Picker::new(&tasks)
+        .matches("milk")
+        .iter()
+        .map(|t| t.id)
+        .collect();
+
+    assert_eq!(result, vec![2, 1]);
+}
+
+#[test]
+fn run_pick_positions() -> postit::Result<()> {
+    let tasks = Todo::sample().tasks;
+
+    assert_eq!(run(&tasks, "1,3\n")?, vec![1, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn run_filter_then_pick() -> postit::Result<()> {
+    let tasks = vec![Task::from("1,Buy milk,low,false"), Task::from("2,Write report,high,false")];
+
+    assert_eq!(run(&tasks, "report\n1\n")?, vec![2]);
+    assert_eq!(run(&tasks, "report\n\n")?, vec![2]);
+
+    Ok(())
+}
+
+#[test]
+fn run_quit() -> postit::Result<()> {
+    let tasks = Todo::sample().tasks;
+
+    assert!(run(&tasks, "q\n")?.is_empty());
+    assert!(run(&tasks, "")?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn run_err() {
+    assert!(run(&[], "1\n").is_err());
+}
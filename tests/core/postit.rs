@@ -1,15 +1,19 @@
 use std::ops::Not;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use postit::cli::{arguments as args, subcommands as sub};
 use postit::config::Config;
+#[cfg(any(feature = "mongo", feature = "sqlite"))]
 use postit::db::Protocol;
-use postit::fs::{File, Format};
-use postit::models::{Priority, Task, Todo};
-use postit::traits::Persister;
-use postit::{Cli, Command, Postit};
-
-use crate::mocks::{MockConfig, MockConn, MockPath};
+use postit::fs::{Csv, File, Format};
+use postit::models::{ColorMode, Column, GroupBy, Priority, Task, Todo};
+use postit::registry::Registry;
+#[cfg(any(feature = "mongo", feature = "sqlite"))]
+use postit::testing::MockConn;
+use postit::testing::{MockConfig, MockEnvVar, MockPath};
+use postit::traits::{FilePersister, Persister};
+use postit::{Action, Cli, Command, Journal, Output, Postit, Report, Trash};
 
 fn fakes(mock: &MockPath) -> postit::Result<(Box<dyn Persister>, Todo)> {
     let persister = Postit::get_persister(Some(mock.to_string()))?;
@@ -27,6 +31,20 @@ fn expected(mock: &MockPath) -> postit::Result<(File, Todo)> {
     Ok((file, todo))
 }
 
+#[test]
+fn error_code() {
+    let err = postit::Error::from(postit::config::Error::EmptySetArgs);
+
+    assert_eq!(err.code(), "E106");
+    assert_eq!(postit::Error::wrap("Error").code(), "E002");
+    assert_eq!(postit::Error::TaskNotFound(vec![9]).code(), "E003");
+    assert_eq!(postit::Error::NoTasks("edit").code(), "E005");
+    assert_eq!(postit::Error::PersisterDoesntExist(String::new()).code(), "E006");
+    assert_eq!(postit::Error::NotInTrash(String::new()).code(), "E007");
+    assert_eq!(postit::Error::InvalidPriority(String::new()).code(), "E010");
+    assert_eq!(postit::Error::Copy(String::new()).code(), "E015");
+}
+
 #[test]
 fn get_persister_file() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
@@ -37,6 +55,7 @@ fn get_persister_file() -> postit::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "mongo")]
 #[test]
 fn get_persister_db() -> postit::Result<()> {
     let mock = MockConn::create(Protocol::Mongo)?;
@@ -47,6 +66,14 @@ fn get_persister_db() -> postit::Result<()> {
     Ok(())
 }
 
+#[cfg(not(feature = "mongo"))]
+#[test]
+fn get_persister_db_feature_disabled() {
+    let err = Postit::get_persister(Some("mongodb://localhost:27017")).unwrap_err();
+
+    assert!(matches!(err, postit::Error::Db(postit::db::Error::FeatureDisabled("mongo"))));
+}
+
 #[test]
 fn get_persister_none() -> postit::Result<()> {
     let persister = Postit::get_persister::<&str>(None)?.to_string();
@@ -61,9 +88,85 @@ fn get_persister_none() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn list_flag() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+
+    config
+        .config
+        .lists
+        .0
+        .insert(String::from("work"), mock.to_string());
+    config.save()?;
+
+    let (file, _) = fakes(&mock)?;
+    let cli = Cli {
+        list: Some(String::from("work")),
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Check(args::Edit { persister: None, ids: vec![2] }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let checked: Vec<bool> = file.tasks()?.iter().map(|task| task.checked).collect();
+
+    assert_eq!(checked, vec![false, true, true, true]);
+
+    Ok(())
+}
+
+#[test]
+fn list_as_persister() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+
+    config
+        .config
+        .lists
+        .0
+        .insert(String::from("work"), mock.to_string());
+    config.save()?;
+
+    let persister = Postit::get_persister(Some("work"))?;
+
+    assert_eq!(persister.to_string(), mock.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn list_flag_unknown() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+
+    let cli = Cli {
+        list: Some(String::from("work")),
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Check(args::Edit { persister: None, ids: vec![2] }),
+    };
+
+    assert!(Postit::run(cli).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn docs() {
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
         command: Command::Docs(args::Docs { subcommand: sub::Docs::Add }),
     };
 
@@ -73,6 +176,12 @@ fn docs() {
 #[test]
 fn flag() {
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: true,
+        compact: false,
+        yes: false,
         command: Command::Flag(args::Flag { subcommand: sub::Flag::Persister }),
     };
 
@@ -85,7 +194,35 @@ fn view() -> postit::Result<()> {
 
     let (file, todo) = fakes(&mock)?;
     let cli = Cli {
-        command: Command::View(args::Persister { persister: Some(file.to_string()) }),
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::View(args::View {
+            persister: Some(file.to_string()),
+            output: Output::Plain,
+            table: false,
+            columns: vec![],
+            format: None,
+            no_pager: false,
+            group_by: None,
+            show_checked: false,
+            hide_done: false,
+            show_snoozed: false,
+            min_priority: None,
+            tags: vec![],
+            created_since: None,
+            completed_since: None,
+            priority: None,
+            checked: false,
+            unchecked: false,
+            contains: None,
+            sort: None,
+            reverse: false,
+            limit: None,
+        }),
     };
 
     assert!(Postit::run(cli).is_ok());
@@ -99,101 +236,2092 @@ fn view() -> postit::Result<()> {
 }
 
 #[test]
-fn add() -> postit::Result<()> {
+fn view_hide_checked() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    mock.config.hide_checked = true;
+    mock.save()?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::View(args::View {
+            persister: Some(path.to_string()),
+            output: Output::Plain,
+            table: false,
+            columns: vec![],
+            format: None,
+            no_pager: false,
+            group_by: None,
+            show_checked: false,
+            hide_done: false,
+            show_snoozed: false,
+            min_priority: None,
+            tags: vec![],
+            created_since: None,
+            completed_since: None,
+            priority: None,
+            checked: false,
+            unchecked: false,
+            contains: None,
+            sort: None,
+            reverse: false,
+            limit: None,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn default_command() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+
+    assert_eq!(Postit::new(None).default_command(), None);
+
+    mock.config.default_command = Some(String::from("view"));
+    mock.save()?;
+
+    assert_eq!(Postit::new(None).default_command(), Some(String::from("view")));
+
+    Ok(())
+}
+
+#[test]
+fn default_command_no_color() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    mock.config.color = ColorMode::Always;
+    mock.config.default_command = Some(format!("view --no-color -p {path}"));
+    mock.save()?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Task"));
+    assert!(stdout.contains('\x1b').not());
+
+    Ok(())
+}
+
+#[test]
+fn json_pretty_and_compact() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Json)?;
+
+    let run = |args: &[&str]| -> postit::Result<usize> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["add", "low", "Task", "-p", &path.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        assert!(output.status.success());
+
+        Ok(std::fs::read_to_string(path.path())?.lines().count())
+    };
+
+    assert!(run(&[])? > 1);
+    assert_eq!(run(&["--compact"])?, 1);
+
+    mock.config.json_pretty = false;
+    mock.save()?;
+
+    assert_eq!(run(&[])?, 1);
+    assert_eq!(path.instance.tasks()?.len(), 7);
+
+    Ok(())
+}
+
+#[test]
+fn view_hide_checked_output() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    mock.config.hide_checked = true;
+    mock.save()?;
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-o", "markdown", "-p", &path.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let hidden = run(&[])?;
+    let shown = run(&["--show-checked"])?;
+
+    assert!(hidden.contains("- [ ]"));
+    assert!(hidden.contains("- [x]").not());
+    assert!(shown.contains("- [x]"));
+
+    Ok(())
+}
+
+#[test]
+fn view_hide_done_output() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["view", "-o", "markdown", "--hide-done", "-p", &path.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("- [ ]"));
+    assert!(stdout.contains("- [x]").not());
+
+    Ok(())
+}
+
+#[test]
+fn view_hide_done_config_output() -> postit::Result<()> {
+    let mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-o", "markdown", "-p", &path.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    // 'hide_done' is another name of 'hide_checked' in the user config file
+    std::fs::write(mock.path(), "hide_done = true\n")?;
+
+    assert!(run(&[])?.contains("- [x]").not());
+    assert!(run(&["--show-checked"])?.contains("- [x]"));
+
+    std::fs::write(mock.path(), "hide_checked = false\nhide_done = true\n")?;
+
+    assert!(run(&[])?.contains("- [x]"));
+
+    Ok(())
+}
+
+#[test]
+fn view_min_priority_output() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    mock.config.min_priority = Priority::Low;
+    mock.save()?;
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-o", "markdown", "-p", &path.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let from_config = run(&[])?;
+    let from_flag = run(&["--min-priority", "med"])?;
+
+    assert!(from_config.contains("`low`"));
+    assert!(from_config.contains("- [x] Task").not());
+    assert!(from_flag.contains("`med`"));
+    assert!(from_flag.contains("`low`").not());
+
+    Ok(())
+}
+
+#[test]
+fn view_tsv_output() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
-    let task = "Test";
-    let line = format!("5,{task},med,false");
 
-    let (file, mut todo) = fakes(&mock)?;
-    let cli = Cli {
-        command: Command::Add(args::Add {
-            persister: Some(mock.to_string()),
-            priority: Priority::Med,
-            content: String::from(task),
-        }),
-    };
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["view", "-o", "tsv", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let expect =
+        "1\tTask\thigh\tfalse\n2\tTask\tmed\tfalse\n3\tTask\tlow\ttrue\n4\tTask\tnone\ttrue\n";
+
+    assert!(output.status.success());
+    assert_eq!(stdout, expect);
+
+    Ok(())
+}
+
+#[test]
+fn view_json_output() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<Vec<Task>> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-o", "json", "-p", &path])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        serde_json::from_slice(&output.stdout).map_err(postit::Error::wrap)
+    };
+
+    assert_eq!(run(&[])?, mock.instance.tasks()?);
+    assert_eq!(run(&["--priority", "high"])?, [Task::from("1,Task,high,false")]);
+    assert!(run(&["--tag", "missing"])?.is_empty());
+    assert_eq!(run(&["--limit", "2"])?, mock.instance.tasks()?[..2]);
+    assert_eq!(run(&["--checked", "--limit", "1"])?, [Task::from("3,Task,low,true")]);
+
+    Ok(())
+}
+
+#[test]
+fn stats_json_output() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let run = |args: &[&str]| -> postit::Result<serde_json::Value> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["stats", "-o", "json", "-p", &mock.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        serde_json::from_slice(&output.stdout).map_err(postit::Error::wrap)
+    };
+
+    let all = run(&[])?;
+    let by = run(&["--by", "priority"])?;
+
+    assert_eq!(all["total"], 4);
+    assert_eq!(all["checked"], 2);
+    assert_eq!(all["percentage"], 50);
+    assert!(all.get("groups").is_none());
+    assert_eq!(by["by"], "priority");
+    assert_eq!(by["groups"][0]["label"], "high");
+    assert_eq!(by["groups"].as_array().map(Vec::len), Some(4));
+
+    Ok(())
+}
+
+#[test]
+fn stats_invalid_output() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["stats", "-o", "csv", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success().not());
+
+    Ok(())
+}
+
+#[test]
+fn next_json_output() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["next", "2", "-o", "json", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let tasks: Vec<Task> = serde_json::from_slice(&output.stdout).map_err(postit::Error::wrap)?;
+    let ids: Vec<u32> = tasks.iter().map(|task| task.id).collect();
+
+    assert_eq!(ids, [1, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn set_due() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["set", "-p", &path, "due"])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(output.status.success())
+    };
+
+    assert!(run(&["1,2", "--on", "2999-01-31"])?);
+    assert_eq!(mock.instance.tasks()?[0].due, Task::parse_date("2999-01-31").ok());
+    assert_eq!(mock.instance.tasks()?[1].due, Task::parse_date("2999-01-31").ok());
+
+    assert!(run(&["2", "--clear"])?);
+    assert_eq!(mock.instance.tasks()?[1].due, None);
+
+    assert!(!run(&["1"])?);
+
+    Ok(())
+}
+
+#[test]
+fn agenda_output() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["set", "-p", &path, "due", "1", "--on", "1d"])?;
+
+    let agenda = run(&["agenda", "-p", &path])?;
+    assert!(agenda.contains("tomorrow (1)\n1. Task (due in 1d)"));
+    assert!(agenda.contains("no date ("));
+
+    let week = run(&["agenda", "--week", "-p", &path])?;
+    assert!(week.contains("tomorrow (1)"));
+    assert!(!week.contains("no date"));
+
+    Ok(())
+}
+
+#[test]
+fn cal_output() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["set", "-p", &path, "due", "1,2", "--on", "2999-01-14"])?;
+
+    let cal = run(&["cal", "2999-01", "-p", &path])?;
+    assert!(cal.starts_with("January 2999\n"));
+    assert!(cal.contains(" 14*2 "));
+    assert!(cal.contains("Due this month: 2"));
+
+    Ok(())
+}
+
+#[test]
+fn view_color() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str], env: (&str, &str)| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-p", &path])
+            .args(args)
+            .env(env.0, env.1)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).contains("\x1b["))
+    };
+
+    assert!(run(&[], ("CLICOLOR_FORCE", "1"))?);
+    assert!(run(&["--no-color"], ("CLICOLOR_FORCE", "1"))?.not());
+    assert!(run(&[], ("NO_COLOR", "1"))?.not());
+
+    config.config.color = ColorMode::Never;
+    config.save()?;
+
+    assert!(run(&[], ("CLICOLOR_FORCE", "1"))?.not());
+
+    config.config.color = ColorMode::Always;
+    config.save()?;
+
+    assert!(run(&[], ("NO_COLOR", "1"))?);
+    assert!(run(&["--no-color"], ("NO_COLOR", "1"))?.not());
+
+    Ok(())
+}
+
+#[test]
+fn view_table() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "--table", "-p", &path])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let defaults = run(&[])?;
+    let selected = run(&["--columns", "id,status"])?;
+
+    assert!(defaults.starts_with("ID  PRIORITY  STATUS  CONTENT\n"));
+    assert!(defaults.contains(" 3  low       done    Task\n"));
+    assert!(selected.starts_with("ID  STATUS\n 1  todo\n"));
+
+    config.config.columns = vec![Column::Content, Column::Priority];
+    config.save()?;
+
+    assert!(run(&[])?.starts_with("CONTENT  PRIORITY\nTask     high\n"));
+
+    Ok(())
+}
+
+#[test]
+fn view_group_by() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["view", "--group-by", "status", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let headers: Vec<&str> = stdout.lines().filter(|line| line.ends_with(')')).collect();
+
+    assert_eq!(headers, ["todo (2)", "done (2)"]);
+    assert!(stdout.contains("\n\ndone (2)\n"));
+
+    Ok(())
+}
+
+#[test]
+fn view_template() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-p", &path])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    config.config.format = String::from("[{priority}] {content}");
+    config.save()?;
+
+    assert!(run(&[])?.starts_with("[high] Task\n"));
+    assert!(run(&["--format", "{id}: {status}"])?.starts_with("1: todo\n"));
+
+    Ok(())
+}
+
+#[test]
+fn view_markdown() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::View(args::View {
+            persister: Some(mock.to_string()),
+            output: Output::Markdown,
+            table: false,
+            columns: vec![],
+            format: None,
+            no_pager: false,
+            group_by: None,
+            show_checked: false,
+            hide_done: false,
+            show_snoozed: false,
+            min_priority: None,
+            tags: vec![],
+            created_since: None,
+            completed_since: None,
+            priority: None,
+            checked: false,
+            unchecked: false,
+            contains: None,
+            sort: None,
+            reverse: false,
+            limit: None,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn stats_by_priority() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Stats(args::Stats {
+            persister: Some(mock.to_string()),
+            by: Some(GroupBy::Priority),
+            output: Output::Plain,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn stats_empty() -> postit::Result<()> {
+    let mock = MockPath::blank(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Stats(args::Stats {
+            persister: Some(mock.to_string()),
+            by: None,
+            output: Output::Plain,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn next() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Next(args::Next {
+            persister: Some(mock.to_string()),
+            count: 2,
+            output: Output::Plain,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn stats_doesnt_load_config_with_persister_flag() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    std::fs::write(Config::path()?, "not = [valid toml")?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Stats(args::Stats {
+            persister: Some(mock.to_string()),
+            by: None,
+            output: Output::Plain,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+    assert!(Config::load().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn docs_doesnt_load_config() {
+    let _env = MockEnvVar::new().set([("POSTIT_ROOT", "relative")]);
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Docs(args::Docs { subcommand: sub::Docs::View }),
+    };
+
+    assert!(Config::load().is_err());
+    assert!(Postit::run(cli).is_ok());
+}
+
+#[test]
+fn add() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let task = "Test";
+    let line = format!("5,{task},med,false");
+
+    let (file, mut todo) = fakes(&mock)?;
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Add(args::Add {
+            persister: Some(mock.to_string()),
+            task: vec![String::from("med"), String::from(task)],
+            tags: vec![],
+            clipboard: false,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    todo.add(Task::from(&line));
+    file.save(&todo)?;
+
+    let (expected_file, expected_todo) = expected(&mock)?;
+
+    assert_eq!(todo, expected_todo);
+    assert_eq!(file.tasks()?, expected_file.tasks()?);
+
+    Ok(())
+}
+
+/// Times that the tasks of a [`Counted`] file were loaded at once.
+static LOADS: AtomicUsize = AtomicUsize::new(0);
+
+/// CSV file that counts the times its tasks are loaded at once.
+#[derive(Debug)]
+struct Counted(Csv);
+
+impl FilePersister for Counted {
+    fn boxed(self) -> Box<dyn FilePersister> {
+        Box::new(self)
+    }
+
+    fn path(&self) -> &PathBuf {
+        self.0.path()
+    }
+
+    fn default(&self) -> String {
+        self.0.default()
+    }
+
+    fn tasks(&self) -> postit::fs::Result<Vec<Task>> {
+        LOADS.fetch_add(1, Ordering::SeqCst);
+        self.0.tasks()
+    }
+
+    fn stream(
+        &self,
+    ) -> postit::fs::Result<Box<dyn Iterator<Item = postit::fs::Result<Task>> + '_>> {
+        self.0.stream()
+    }
+
+    fn open(&self) -> postit::fs::Result<std::fs::File> {
+        self.0.open()
+    }
+
+    fn write(&self, todo: &Todo) -> postit::fs::Result<()> {
+        self.0.write(todo)
+    }
+
+    fn append(&self, task: &Task) -> postit::fs::Result<()> {
+        self.0.append(task)
+    }
+
+    fn clean(&self) -> postit::fs::Result<()> {
+        self.0.clean()
+    }
+
+    fn remove(&self) -> postit::fs::Result<()> {
+        self.0.remove()
+    }
+}
+
+#[test]
+fn add_doesnt_load_the_file() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.path().with_extension("counted");
+    std::fs::copy(mock.path(), &path)?;
+
+    Registry::register_file("counted", |path| Counted(Csv::new(path)).boxed());
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Add(args::Add {
+            persister: Some(path.to_string_lossy().into_owned()),
+            task: vec![String::from("low"), String::from("Appended")],
+            tags: vec![],
+            clipboard: false,
+        }),
+    };
+
+    Postit::run(cli)?;
+
+    // The tasks are only loaded to show them after adding the task
+    assert_eq!(LOADS.load(Ordering::SeqCst), 1);
+
+    let entry = Journal::load()?.undo.pop().unwrap();
+
+    assert!(entry.before.is_empty());
+    assert_eq!(entry.after.len(), 1);
+    assert_eq!((entry.after[0].id, entry.after[0].content.as_str()), (5, "Appended"));
+
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[test]
+fn add_tasks() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let contents = vec![String::from("Buy milk"), String::from("Call mom")];
+    let tags = [String::from("home")];
+
+    let persister = Postit::get_persister(Some(mock.to_string()))?;
+    let changes = Postit::add_tasks(persister.as_ref(), contents, &Priority::Low, &tags)?;
+
+    assert_eq!(changes.ids(), [5, 6]);
+
+    let tasks = mock.instance.tasks()?;
+
+    assert_eq!(tasks[4].content, "Buy milk");
+    assert_eq!(tasks[5].content, "Call mom");
+    assert!(tasks[4..]
+        .iter()
+        .all(|task| task.priority == Priority::Low && task.tags == tags));
+
+    Ok(())
+}
+
+#[test]
+fn add_clipboard_with_content() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "high", "Task", "--clipboard", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("only pass a priority"));
+    assert_eq!(mock.instance.tasks()?.len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn add_from_stdin() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let run = |stdin: &str| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["add", "high", "-", "-p", &mock.to_string()])
+            .write_stdin(stdin)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(output.status.success())
+    };
+
+    assert!(run("Fix the parser\n  in the importer \n\n")?);
+    assert!(!run(" \n")?);
+
+    let tasks = mock.instance.tasks()?;
+    let task = tasks.last().unwrap();
+
+    assert_eq!(tasks.len(), 5);
+    assert_eq!(task.content, "Fix the parser in the importer");
+    assert_eq!(task.priority, Priority::High);
+
+    Ok(())
+}
+
+#[test]
+fn add_interactive() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "-p", &mock.to_string()])
+        .write_stdin("Buy milk\nurgent\nlow\n2000-01-31\n2d\nhome\n")
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+
+    let task = mock.instance.tasks()?.last().cloned().unwrap();
+
+    assert_eq!((task.id, task.content.as_str()), (5, "Buy milk"));
+    assert_eq!(task.priority, Priority::Low);
+    assert_eq!(task.days_left(), Some(2));
+    assert_eq!(task.tags, ["home"]);
+
+    Ok(())
+}
+
+#[test]
+fn add_default_priority() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+
+    config.config.default_priority = Priority::High;
+    config.save()?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "Buy milk", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+
+    let task = mock.instance.tasks()?.last().cloned().unwrap();
+
+    assert_eq!((task.id, task.content.as_str()), (5, "Buy milk"));
+    assert_eq!(task.priority, Priority::High);
+
+    Ok(())
+}
+
+#[test]
+fn add_invalid_priority() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "urgent", "Buy milk", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stderr.contains("'urgent' is not a valid priority"));
+    assert_eq!(mock.instance.tasks()?.len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn add_quiet() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "low", "Buy milk", "-q", "-p", &mock.to_string()])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(mock.instance.tasks()?.len(), 5);
+
+    Ok(())
+}
+
+#[test]
+fn view_verbose() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["view", "--verbose", "-p", &path])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success());
+    assert!(stderr.contains(&format!("Using the '{path}' persister")));
+
+    Ok(())
+}
+
+#[test]
+fn quiet_conflicts_with_verbose() -> postit::Result<()> {
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["view", "-q", "-v"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success().not());
+
+    Ok(())
+}
+
+#[test]
+fn add_interactive_cancelled() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["add", "high", "-p", &mock.to_string()])
+        .write_stdin("Buy milk\n")
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("No task was added"));
+    assert_eq!(mock.instance.tasks()?.len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn add_and_view_tags() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["add", "-p", &path, "high", "Deploy", "--tag", "work", "-t", "ops"])?;
+    run(&["set", "-p", &path, "tags", "2,3", "home"])?;
+
+    let work = run(&["view", "-p", &path, "-o", "csv", "--tag", "work"])?;
+    let home = run(&["view", "-p", &path, "-o", "csv", "-t", "home"])?;
+    let both = run(&["view", "-p", &path, "-o", "csv", "-t", "work", "-t", "home"])?;
+
+    let lines = |csv: &str| -> Vec<String> {
+        csv.lines()
+            .map(|line| line.split(',').take(6).collect::<Vec<_>>().join(","))
+            .collect()
+    };
+
+    assert_eq!(lines(&work), ["5,Deploy,high,false,,work ops"]);
+    assert_eq!(lines(&home), ["2,Task,med,false,,home", "3,Task,low,true,,home"]);
+    assert_eq!(both, "");
+
+    Ok(())
+}
+
+#[test]
+fn dup() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Dup(args::Dup {
+            persister: Some(mock.to_string()),
+            ids: vec![3, 1],
+            priority: Some(Priority::Low),
+            content: None,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let tasks = File::from(mock.to_string())?.tasks()?;
+    let copies: Vec<(u32, &str, &Priority, bool)> = tasks[4..]
+        .iter()
+        .map(|task| (task.id, task.content.as_str(), &task.priority, task.checked))
+        .collect();
+
+    assert_eq!(tasks.len(), 6);
+    assert_eq!(copies, [(5, "Task", &Priority::Low, false), (6, "Task", &Priority::Low, false)]);
+    assert!(tasks[4].created_at.is_some());
+    assert!(tasks[4].completed_at.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn dup_missing_task() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Dup(args::Dup {
+            persister: Some(mock.to_string()),
+            ids: vec![9],
+            priority: None,
+            content: Some(String::from("Copy")),
+        }),
+    };
+
+    assert!(Postit::run(cli).is_err());
+    assert_eq!(File::from(mock.to_string())?.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn pin_and_view() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let ids = |csv: &str| -> Vec<String> {
+        csv.lines()
+            .map(|line| line.split(',').next().unwrap_or_default().to_owned())
+            .collect()
+    };
+
+    run(&["pin", "-p", &path, "4"])?;
+
+    assert_eq!(ids(&run(&["view", "-p", &path, "-o", "csv"])?), ["4", "1", "2", "3"]);
+    assert_eq!(
+        ids(&run(&["view", "-p", &path, "-o", "csv", "--sort", "id", "--reverse"])?),
+        ["4", "3", "2", "1"]
+    );
+
+    run(&["unpin", "-p", &path, "4"])?;
+
+    assert_eq!(ids(&run(&["view", "-p", &path, "-o", "csv"])?), ["1", "2", "3", "4"]);
+
+    Ok(())
+}
+
+#[test]
+fn strict_ids() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    let run = |args: &[&str]| -> postit::Result<std::process::Output> {
+        assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .args(["-p", &path.to_string()])
+            .output()
+            .map_err(postit::Error::wrap)
+    };
+
+    let checked = || -> postit::Result<Vec<u32>> {
+        Ok(File::from(path.to_string())?
+            .tasks()?
+            .iter()
+            .filter(|task| task.checked)
+            .map(|task| task.id)
+            .collect())
+    };
+
+    let output = run(&["check", "1,9"])?;
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Task 9 doesn't exist; skipping"));
+    assert_eq!(checked()?, [1, 3, 4]);
+
+    mock.config.strict = true;
+    mock.save()?;
+
+    let output = run(&["uncheck", "1,8,9"])?;
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[E003] Tasks 8, 9 don't exist"));
+    assert_eq!(checked()?, [1, 3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn library_api() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let postit = Postit::new(None);
+    let persister = postit.persister(Some(mock.to_string()))?;
+    let persister = persister.as_ref();
+
+    let added = Postit::add_task(persister, String::from("Read"), Priority::Low, vec![])?;
+    assert_eq!(added.ids(), [5]);
+
+    let checked = postit.edit_tasks(persister, &[1, 3, 5], &Action::Check)?;
+    assert_eq!(checked.ids(), [1, 5]);
+    assert!(checked.tasks.iter().all(|task| task.checked));
+
+    let copies = Postit::dup_tasks(persister, &[5], Some(&Priority::High), None)?;
+    assert_eq!(copies.ids(), [6]);
+    assert!(!copies.tasks[0].checked);
+
+    let purged = Postit::purge_tasks(persister)?;
+    assert_eq!(purged.ids(), [1, 3, 4, 5]);
+
+    let ids: Vec<u32> = Postit::tasks(persister)?
+        .iter()
+        .map(|task| task.id)
+        .collect();
+    assert_eq!(ids, [2, 6]);
+
+    assert!(postit
+        .edit_tasks(persister, &[2], &Action::SetContent)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn view_filters() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    mock.config.hide_checked = true;
+    mock.save()?;
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-o", "tsv", "-p", &path.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let checked = run(&["--checked"])?;
+    let unchecked = run(&["--unchecked", "--priority", "med"])?;
+    let contains = run(&["--contains", "TASK", "--priority", "none", "--checked"])?;
+    let none = run(&["--contains", "deploy"])?;
+
+    assert_eq!(checked, "3\tTask\tlow\ttrue\n4\tTask\tnone\ttrue\n");
+    assert_eq!(unchecked, "2\tTask\tmed\tfalse\n");
+    assert_eq!(contains, "4\tTask\tnone\ttrue\n");
+    assert_eq!(none, "");
+
+    Ok(())
+}
+
+#[test]
+fn view_sorted() -> postit::Result<()> {
+    let path = MockPath::create(Format::Csv)?;
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["view", "-o", "tsv", "-p", &path.to_string()])
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let ids = |tsv: String| -> Vec<String> {
+        tsv.lines()
+            .filter_map(|line| line.split('\t').next().map(str::to_owned))
+            .collect()
+    };
+
+    assert_eq!(ids(run(&["--sort", "priority", "--reverse"])?), ["4", "3", "2", "1"]);
+    assert_eq!(ids(run(&["--sort", "checked", "--reverse"])?), ["3", "4", "1", "2"]);
+    assert!(ids(run(&["--reverse"])?).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn view_since_timestamps() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["add", "-p", &path, "high", "Deploy"])?;
+    run(&["check", "-p", &path, "1"])?;
+
+    let created = run(&["view", "-p", &path, "-o", "csv", "--created-since", "2000-01-01"])?;
+    let completed = run(&["view", "-p", &path, "-o", "csv", "--completed-since", "2000-01-01"])?;
+    let future = run(&["view", "-p", &path, "-o", "csv", "--created-since", "2999-01-01"])?;
+
+    assert!(created.starts_with("5,Deploy,high,false,,,,"));
+    assert_eq!(created.lines().count(), 1);
+    assert!(completed.starts_with("1,Task,high,true,"));
+    assert_eq!(completed.lines().count(), 1);
+    assert_eq!(future, "");
+
+    Ok(())
+}
+
+#[test]
+fn attach() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(output.status.success())
+    };
+
+    let attachments = |id: usize| -> postit::Result<Vec<String>> {
+        Ok(mock.instance.tasks()?[id].attachments.clone())
+    };
+
+    assert!(run(&["attach", "-p", &path, "2", "https://example.com/spec", "Cargo.toml"])?);
+
+    let manifest = std::fs::canonicalize("Cargo.toml")?;
+    assert_eq!(attachments(1)?, ["https://example.com/spec", manifest.to_str().unwrap()]);
+
+    assert!(run(&["attach", "-p", &path, "2", "--remove", "https://example.com/spec"])?);
+    assert_eq!(attachments(1)?, [manifest.to_str().unwrap()]);
+
+    assert!(!run(&["attach", "-p", &path, "9", "https://example.com/spec"])?);
+    assert!(!run(&["open", "-p", &path, "1"])?);
+
+    Ok(())
+}
+
+#[test]
+fn drop_and_restore_from_trash() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let original = mock.instance.tasks()?;
+
+    run(&["drop", "-p", &path, "4"])?;
+    assert_eq!(run(&["trash", "list", "-p", &path])?.lines().count(), 1);
+
+    // The id of the dropped task is used again, so the restored one gets a new id
+    run(&["add", "-p", &path, "low", "Newer"])?;
+    run(&["trash", "restore", "-p", &path, "4"])?;
+
+    let tasks = mock.instance.tasks()?;
+    assert_eq!(tasks.len(), 5);
+    assert_eq!(tasks[4].id, 5);
+    assert_eq!(tasks[4].content, original[3].content);
+    assert!(tasks[4].checked);
+    assert!(Trash::load()?.of(&path).is_empty());
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["trash", "restore", "-p", &path, "4"])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[E007]"));
+
+    run(&["drop", "-p", &path, "3"])?;
+    run(&["--yes", "trash", "empty", "-p", &path])?;
+    assert!(Trash::load()?.items.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn purge_and_restore_from_trash() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let checked: Vec<Task> = mock
+        .instance
+        .tasks()?
+        .into_iter()
+        .filter(|task| task.checked)
+        .collect();
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["--yes", "purge", "-p", &path])
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+
+    let trashed: Vec<Task> = Trash::load()?
+        .of(&path)
+        .into_iter()
+        .map(|item| item.task.clone())
+        .collect();
+
+    assert_eq!(trashed, checked);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn drop_and_restore_from_trash_sqlite() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    let conn = mock.conn();
+
+    mock.instance.insert(&Todo::sample())?;
+
+    let run = |args: &[&str]| -> postit::Result<Vec<u32>> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        assert!(output.status.success());
+
+        Ok(mock.instance.tasks()?.iter().map(|task| task.id).collect())
+    };
+
+    // Only checked tasks can be dropped, and task 3 is checked
+    assert_eq!(run(&["drop", "-p", &conn, "3"])?, [1, 2, 4]);
+    assert_eq!(run(&["trash", "restore", "-p", &conn, "3"])?, [1, 2, 3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn undo_and_redo() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(output.status.success())
+    };
+
+    let original = mock.instance.tasks()?;
+
+    run(&["check", "-p", &path, "1"])?;
+    run(&["drop", "-p", &path, "1"])?;
+    let dropped = mock.instance.tasks()?;
+
+    run(&["undo", "-p", &path])?;
+    run(&["undo", "-p", &path])?;
+    assert_eq!(mock.instance.tasks()?, original);
+
+    run(&["redo", "-p", &path])?;
+    run(&["redo", "-p", &path])?;
+    assert_eq!(mock.instance.tasks()?, dropped);
+
+    run(&["undo", "-p", &path])?;
+    mock.instance
+        .write(&Todo::new(Task::from("1,Edited,low,false")))?;
+
+    assert!(run(&["undo", "-p", &path])?.not());
+    assert!(run(&["undo", "-p", &path])?);
+    assert_eq!(mock.instance.tasks()?, [Task::from("1,Edited,low,false")]);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn undo_and_redo_sqlite() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    let conn = mock.conn();
+
+    mock.instance.insert(&Todo::sample())?;
+
+    let run = |args: &[&str]| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(output.status.success())
+    };
+
+    let ids = || -> postit::Result<Vec<u32>> {
+        Ok(mock.instance.tasks()?.iter().map(|task| task.id).collect())
+    };
+
+    run(&["check", "-p", &conn, "2"])?;
+    run(&["drop", "-p", &conn, "2"])?;
+    assert_eq!(ids()?, [1, 3, 4]);
+
+    assert!(run(&["undo", "-p", &conn])?);
+    assert_eq!(ids()?, [1, 2, 3, 4]);
+
+    assert!(run(&["redo", "-p", &conn])?);
+    assert_eq!(ids()?, [1, 3, 4]);
+
+    assert!(run(&["undo", "-p", &conn])?);
+    assert_eq!(ids()?, [1, 2, 3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn report() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["check", "-p", &path, "1"])?;
+    run(&["add", "-p", &path, "low", "New"])?;
+    run(&["drop", "-p", &path, "3"])?;
+
+    let json: serde_json::Value =
+        serde_json::from_str(&run(&["report", "-p", &path, "-o", "json"])?)
+            .map_err(postit::Error::wrap)?;
+
+    let ids = |list: &str| -> Vec<u64> {
+        json[list]
+            .as_array()
+            .map(|tasks| {
+                tasks
+                    .iter()
+                    .filter_map(|task| task["id"].as_u64())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    assert_eq!(ids("added"), [5]);
+    assert_eq!(ids("completed"), [1]);
+    assert_eq!(ids("dropped"), [3]);
+
+    assert!(run(&["report", "-p", &path, "--since", "2099-01-01"])?.contains("Added: 0\n"));
+
+    Ok(())
+}
+
+#[test]
+fn search() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["set", "-p", &path, "content", "2,3", "Deploy API"])?;
+
+    let matches = |args: &[&str]| -> postit::Result<Vec<String>> {
+        let tsv = run(&[&["search", "-p", &path, "-o", "tsv"], args].concat())?;
+        Ok(tsv
+            .lines()
+            .map(|line| line.split('\t').take(4).collect::<Vec<_>>().join(","))
+            .collect())
+    };
+
+    assert_eq!(matches(&["deploy", "-i"])?, ["2,Deploy API,med,false", "3,Deploy API,low,true"]);
+    assert!(matches(&["deploy"])?.is_empty());
+    assert_eq!(matches(&["^T.s", "--regex"])?, ["1,Task,high,false", "4,Task,none,true"]);
+    assert_eq!(matches(&["API", "--checked"])?, ["3,Deploy API,low,true"]);
+    assert_eq!(matches(&["API", "--priority", "med"])?, ["2,Deploy API,med,false"]);
+    assert_eq!(matches(&["deploy", "-i", "--limit", "1"])?, ["2,Deploy API,med,false"]);
+
+    Ok(())
+}
+
+#[test]
+fn search_invalid_regex() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Search(args::Search {
+            persister: Some(mock.to_string()),
+            pattern: String::from("(unclosed"),
+            regex: true,
+            ignore_case: false,
+            checked: false,
+            unchecked: false,
+            priority: None,
+            output: Output::Plain,
+            limit: None,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn set_priority() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let priority = Priority::Low;
+    let ids = vec![2, 3];
+
+    let (file, mut todo) = fakes(&mock)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Set(args::Set {
+            persister: Some(mock.to_string()),
+            subcommand: sub::Set::Priority(args::SetPriority {
+                priority: priority.clone(),
+                ids: ids.clone(),
+            }),
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let tasks = todo.get_mut(&ids);
+
+    for task in tasks {
+        task.priority = priority.clone();
+    }
+
+    file.save(&todo)?;
+
+    let (expected_file, expected_todo) = expected(&mock)?;
+
+    assert_eq!(todo, expected_todo);
+    assert_eq!(file.tasks()?, expected_file.tasks()?);
+
+    Ok(())
+}
+
+#[test]
+fn set_estimate() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["set", "-p", &path, "estimate", "1,2", "1h"])?;
+
+    assert_eq!(mock.instance.tasks()?[0].estimate, Some(60));
+    assert!(run(&["view", "-p", &path])?.contains(" ~1h"));
+
+    run(&["check", "-p", &path, "2"])?;
+    assert!(run(&["stats", "-p", &path])?.contains("Estimated: 2h (1h left)"));
+
+    run(&["set", "-p", &path, "estimate", "1,2", "none"])?;
+    assert_eq!(mock.instance.tasks()?[0].estimate, None);
+
+    Ok(())
+}
+
+#[test]
+fn snooze() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["snooze", "-p", &path, "2", "--until", "2d"])?;
+
+    assert!(mock.instance.tasks()?[1].is_snoozed());
+    assert!(!run(&["view", "-p", &path])?.contains("2. "));
+    assert!(!run(&["view", "-p", &path, "-o", "json"])?.contains("snoozed_until"));
+    assert!(run(&["view", "-p", &path, "--show-snoozed"])?.contains("(snoozed until "));
+
+    run(&["snooze", "-p", &path, "2", "--wake"])?;
+
+    assert_eq!(mock.instance.tasks()?[1].snoozed_until, None);
+    assert!(run(&["view", "-p", &path])?.contains("2. "));
+
+    Ok(())
+}
+
+#[test]
+fn show_task() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<(bool, String)> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok((output.status.success(), String::from_utf8_lossy(&output.stdout).into_owned()))
+    };
+
+    run(&["check", "-p", &path, "2"])?;
+    run(&["note", "-p", &path, "2", "First line"])?;
+
+    let (success, stdout) = run(&["show", "-p", &path, "2"])?;
+
+    assert!(success);
+    assert!(stdout.starts_with("Task 2\n  Content:   Task\n  Priority:  med\n  Status:    done\n"));
+    assert!(stdout.contains("\nNotes:\n  First line\n"));
+    assert!(stdout.contains("  check\n"));
+    assert!(stdout.ends_with("  note\n"));
+
+    assert!(!run(&["show", "-p", &path, "9"])?.0);
+
+    Ok(())
+}
+
+#[test]
+fn move_task() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let ids = |output: &str| -> Vec<String> {
+        output
+            .lines()
+            .filter_map(|line| line.split('.').next())
+            .map(str::to_owned)
+            .collect()
+    };
+
+    run(&["move", "-p", &path, "4", "--before", "1"])?;
+    assert_eq!(ids(&run(&["view", "-p", &path])?), ["4", "1", "2", "3"]);
+
+    run(&["move", "-p", &path, "1", "--to", "4"])?;
+    assert_eq!(ids(&run(&["view", "-p", &path])?), ["4", "2", "3", "1"]);
+
+    let tasks = mock.instance.tasks()?;
+    assert_eq!(tasks.iter().map(|task| task.id).collect::<Vec<_>>(), [4, 2, 3, 1]);
+    assert!(tasks.iter().all(|task| task.order.is_some()));
+
+    Ok(())
+}
+
+#[test]
+fn set_content() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let content = String::from("New task");
+    let ids = vec![2, 3];
+
+    let (file, mut todo) = fakes(&mock)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Set(args::Set {
+            persister: Some(mock.to_string()),
+            subcommand: sub::Set::Content(args::SetContent {
+                content: content.clone(),
+                ids: ids.clone(),
+            }),
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let tasks = todo.get_mut(&ids);
+
+    for task in tasks {
+        task.content = content.clone();
+    }
+
+    file.save(&todo)?;
+
+    let (expected_file, expected_todo) = expected(&mock)?;
+
+    assert_eq!(todo, expected_todo);
+    assert_eq!(file.tasks()?, expected_file.tasks()?);
+
+    Ok(())
+}
+
+#[test]
+fn set_ref() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+    let refs = vec![String::from("JIRA-123"), String::from("gh#42")];
+
+    let run = |refs: Vec<String>| {
+        Postit::run(Cli {
+            list: None,
+            quiet: false,
+            verbose: false,
+            no_color: false,
+            compact: false,
+            yes: false,
+            command: Command::Set(args::Set {
+                persister: Some(mock.to_string()),
+                subcommand: sub::Set::Ref(args::SetRef { ids: vec![2, 3], refs }),
+            }),
+        })
+    };
+
+    assert!(run(refs.clone()).is_ok());
+
+    let (_, todo) = expected(&mock)?;
+
+    assert_eq!(todo.tasks[0].refs, Vec::<String>::new());
+    assert_eq!(todo.tasks[1].refs, refs);
+    assert_eq!(todo.tasks[2].refs, refs);
+
+    assert!(run(vec![]).is_ok());
+
+    let (_, todo) = expected(&mock)?;
+
+    assert!(todo.tasks.iter().all(|task| task.refs.is_empty()));
+
+    Ok(())
+}
+
+#[test]
+fn view_refs_output() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let path = MockPath::create(Format::Csv)?;
+
+    mock.config
+        .links
+        .0
+        .insert(String::from("gh"), String::from("https://github.com/owner/repo/issues/{id}"));
+    mock.save()?;
+
+    let path = path.to_string();
+
+    let run = |args: &[&str]| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["set", "-p", &path, "ref", "1", "gh#42", "JIRA-123"])?;
+
+    let markdown = run(&["view", "-p", &path, "-o", "markdown"])?;
+    let csv = run(&["view", "-p", &path, "-o", "csv"])?;
+
+    assert!(markdown.contains("[gh#42](https://github.com/owner/repo/issues/42) `JIRA-123`"));
+    assert!(csv.contains("1,Task,high,false,gh#42 JIRA-123,"));
+
+    Ok(())
+}
+
+#[test]
+fn note() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+
+    let run = |text: Option<&str>, clear: bool| {
+        Postit::run(Cli {
+            list: None,
+            quiet: false,
+            verbose: false,
+            no_color: false,
+            compact: false,
+            yes: false,
+            command: Command::Note(args::Note {
+                persister: Some(mock.to_string()),
+                id: 2,
+                text: text.map(String::from),
+                edit: false,
+                clear,
+            }),
+        })
+    };
+
+    assert!(run(Some("First line"), false).is_ok());
+    assert!(run(Some("Second line"), false).is_ok());
+    assert!(run(None, false).is_ok());
+
+    let (_, todo) = expected(&mock)?;
+
+    assert_eq!(todo.tasks[1].notes, "First line\nSecond line");
+
+    assert!(run(None, true).is_ok());
+
+    let (_, todo) = expected(&mock)?;
+
+    assert!(todo.tasks[1].notes.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn pomo() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let path = mock.to_string();
+
+    let run = |id: &str| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["pomo", id, "--minutes", "0", "-p", &path])
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(output.status.success())
+    };
+
+    assert!(run("2")?);
+    assert!(run("2")?);
+
+    let notes = mock.instance.tasks()?[1].notes.clone();
+    let lines: Vec<&str> = notes.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|line| line.starts_with("Pomodoro: 0m (")));
+
+    assert!(!run("9")?);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn note_edit() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let edited = std::env::temp_dir().join("postit_note_edit.txt");
+
+    // The "editor" replaces the notes with the contents of another file
+    std::fs::write(&edited, "Edited\nnotes\n")?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["note", "3", "--edit", "-p", &mock.to_string()])
+        .env("VISUAL", format!("cp {}", edited.display()))
+        .output()
+        .map_err(postit::Error::wrap)?;
 
-    assert!(Postit::run(cli).is_ok());
+    std::fs::remove_file(&edited)?;
 
-    todo.add(Task::from(&line));
-    file.save(&todo)?;
+    assert!(output.status.success());
 
-    let (expected_file, expected_todo) = expected(&mock)?;
+    let (_, todo) = expected(&mock)?;
 
-    assert_eq!(todo, expected_todo);
-    assert_eq!(file.tasks()?, expected_file.tasks()?);
+    assert_eq!(todo.tasks[2].notes, "Edited\nnotes");
 
     Ok(())
 }
 
+#[cfg(unix)]
 #[test]
-fn set_priority() -> postit::Result<()> {
+fn edit_task() -> postit::Result<()> {
+    let _config = MockConfig::new()?;
     let mock = MockPath::create(Format::Csv)?;
-    let priority = Priority::Low;
-    let ids = vec![2, 3];
 
-    let (file, mut todo) = fakes(&mock)?;
+    let edit = |editor: &str| -> postit::Result<bool> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(["edit", "2", "-p", &mock.to_string()])
+            .env("VISUAL", editor)
+            .output()
+            .map_err(postit::Error::wrap)?;
 
-    let cli = Cli {
-        command: Command::Set(args::Set {
-            persister: Some(mock.to_string()),
-            subcommand: sub::Set::Priority(args::SetPriority {
-                priority: priority.clone(),
-                ids: ids.clone(),
-            }),
-        }),
+        Ok(output.status.success())
     };
 
-    assert!(Postit::run(cli).is_ok());
-
-    let tasks = todo.get_mut(&ids);
-
-    for task in tasks {
-        task.priority = priority.clone();
-    }
+    // The "editor" changes the content and checks the task
+    assert!(edit("sed -i -e s/Task/Edited/ -e s/false/true/ -e s/id.=.2/id=7/")?);
 
-    file.save(&todo)?;
+    let task = &mock.instance.tasks()?[1];
 
-    let (expected_file, expected_todo) = expected(&mock)?;
+    assert_eq!(task.id, 2);
+    assert_eq!(task.content, "Edited");
+    assert!(task.checked);
+    assert!(task.completed_at.is_some());
 
-    assert_eq!(todo, expected_todo);
-    assert_eq!(file.tasks()?, expected_file.tasks()?);
+    // A task without priority isn't valid
+    assert!(!edit("sed -i s/priority/level/")?);
+    assert_eq!(mock.instance.tasks()?[1].priority, Priority::Med);
 
     Ok(())
 }
 
 #[test]
-fn set_content() -> postit::Result<()> {
+fn note_err() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
-    let content = String::from("New task");
-    let ids = vec![2, 3];
-
-    let (file, mut todo) = fakes(&mock)?;
 
     let cli = Cli {
-        command: Command::Set(args::Set {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Note(args::Note {
             persister: Some(mock.to_string()),
-            subcommand: sub::Set::Content(args::SetContent {
-                content: content.clone(),
-                ids: ids.clone(),
-            }),
+            id: 99,
+            text: None,
+            edit: false,
+            clear: false,
         }),
     };
 
-    assert!(Postit::run(cli).is_ok());
-
-    let tasks = todo.get_mut(&ids);
+    assert!(Postit::run(cli).is_err());
 
-    for task in tasks {
-        task.content = content.clone();
-    }
+    Ok(())
+}
 
-    file.save(&todo)?;
+#[test]
+fn open_without_refs() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
 
-    let (expected_file, expected_todo) = expected(&mock)?;
+    let run = |id: u32| {
+        Postit::run(Cli {
+            list: None,
+            quiet: false,
+            verbose: false,
+            no_color: false,
+            compact: false,
+            yes: false,
+            command: Command::Open(args::Open { persister: Some(mock.to_string()), id }),
+        })
+    };
 
-    assert_eq!(todo, expected_todo);
-    assert_eq!(file.tasks()?, expected_file.tasks()?);
+    assert!(run(1).is_err());
+    assert!(run(99).is_err());
 
     Ok(())
 }
@@ -201,6 +2329,12 @@ fn set_content() -> postit::Result<()> {
 #[test]
 fn set_err() -> postit::Result<()> {
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
         command: Command::Set(args::Set {
             persister: Some("test.txt".to_string()),
             subcommand: sub::Set::Content(args::SetContent {
@@ -222,6 +2356,12 @@ fn check() -> postit::Result<()> {
 
     let (file, mut todo) = fakes(&mock)?;
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
         command: Command::Check(args::Edit {
             persister: Some(file.to_string()),
             ids: ids.clone(),
@@ -248,6 +2388,12 @@ fn uncheck() -> postit::Result<()> {
 
     let (file, mut todo) = fakes(&mock)?;
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
         command: Command::Uncheck(args::Edit {
             persister: Some(file.to_string()),
             ids: ids.clone(),
@@ -273,6 +2419,12 @@ fn edit_err() -> postit::Result<()> {
     let ids = vec![2, 3];
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
         command: Command::Check(args::Edit { persister: Some(file.to_string()), ids }),
     };
 
@@ -292,9 +2444,18 @@ fn drop_no_force_drop() -> postit::Result<()> {
 
     let (file, mut todo) = fakes(&mock)?;
     let cli = Cli {
-        command: Command::Drop(args::Edit {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
+        command: Command::Drop(args::Drop {
             persister: Some(file.to_string()),
             ids: ids.clone(),
+            checked: false,
+            priority: None,
+            older_than: None,
         }),
     };
 
@@ -323,9 +2484,18 @@ fn drop_force() -> postit::Result<()> {
     let (file, mut todo) = fakes(&mock)?;
 
     let cli = Cli {
-        command: Command::Drop(args::Edit {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
+        command: Command::Drop(args::Drop {
             persister: Some(file.to_string()),
             ids: ids.clone(),
+            checked: false,
+            priority: None,
+            older_than: None,
         }),
     };
 
@@ -342,6 +2512,56 @@ fn drop_force() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn drop_filters() -> postit::Result<()> {
+    let mut mock_config = MockConfig::new()?;
+    mock_config.config.force_drop = true;
+    mock_config.save()?;
+
+    let mock = MockPath::create(Format::Csv)?;
+    let file = File::from(mock.to_string())?;
+
+    let mut todo = Todo::sample();
+    todo.tasks[0].created_at = Some(Task::parse_date("2020-01-01").unwrap());
+    file.save(&todo)?;
+
+    let drop = |ids: Vec<u32>, checked: bool, priority: Option<Priority>, older_than| {
+        Postit::run(Cli {
+            list: None,
+            quiet: false,
+            verbose: false,
+            no_color: false,
+            compact: false,
+            yes: true,
+            command: Command::Drop(args::Drop {
+                persister: Some(mock.to_string()),
+                ids,
+                checked,
+                priority,
+                older_than,
+            }),
+        })
+    };
+
+    let ids = |file: &File| -> postit::Result<Vec<u32>> {
+        Ok(file.tasks()?.iter().map(|task| task.id).collect())
+    };
+
+    drop(vec![3, 4], false, Some(Priority::Low), None)?;
+    assert_eq!(ids(&file)?, vec![1, 2, 4]);
+
+    drop(Vec::new(), true, None, None)?;
+    assert_eq!(ids(&file)?, vec![1, 2]);
+
+    drop(Vec::new(), false, None, Some(Report::parse_since("30d").unwrap()))?;
+    assert_eq!(ids(&file)?, vec![2]);
+
+    drop(Vec::new(), false, Some(Priority::High), None)?;
+    assert_eq!(ids(&file)?, vec![2]);
+
+    Ok(())
+}
+
 #[test]
 fn copy() -> postit::Result<()> {
     let mut mock_config = MockConfig::new()?;
@@ -353,9 +2573,16 @@ fn copy() -> postit::Result<()> {
     let right_str = right_path.to_str().unwrap();
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: mock_left.to_string(),
             right: right_str.to_string(),
+            merge: false,
         }),
     };
 
@@ -372,6 +2599,72 @@ fn copy() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn copy_merge() -> postit::Result<()> {
+    let mut mock_config = MockConfig::new()?;
+    mock_config.config.force_copy = false;
+    mock_config.save()?;
+
+    let mock_left = MockPath::create(Format::Csv)?;
+    let right_path = Config::build_path("tasks.json")?;
+    let mock_right = MockPath::from(&right_path)?;
+    mock_right.instance.write(&Todo::sample())?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
+        command: Command::Copy(args::Copy {
+            left: mock_left.to_string(),
+            right: mock_right.to_string(),
+            merge: true,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let mut expect = Todo::sample();
+    expect.append(Todo::sample().tasks);
+
+    assert_eq!(mock_right.instance.tasks()?, expect.tasks);
+    assert_eq!(mock_left.instance.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn copy_merge_into_new_persister() -> postit::Result<()> {
+    let _mock_config = MockConfig::new()?;
+
+    let mock_left = MockPath::create(Format::Csv)?;
+    let right_path = Config::build_path("tasks.json")?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
+        command: Command::Copy(args::Copy {
+            left: mock_left.to_string(),
+            right: right_path.to_string_lossy().to_string(),
+            merge: true,
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let mock_right = MockPath::from(right_path)?;
+
+    assert_eq!(mock_right.instance.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
 #[test]
 fn copy_from_ok() -> postit::Result<()> {
     let mut mock_config = MockConfig::new()?;
@@ -382,9 +2675,16 @@ fn copy_from_ok() -> postit::Result<()> {
     mock_right.instance.write(&Todo::sample())?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: "from".to_string(),
             right: mock_right.path().to_string_lossy().to_string(),
+            merge: false,
         }),
     };
 
@@ -408,9 +2708,16 @@ fn copy_from_err() -> postit::Result<()> {
     mock_right.instance.write(&Todo::sample())?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: "from".to_string(),
             right: mock_right.path().to_string_lossy().to_string(),
+            merge: false,
         }),
     };
 
@@ -431,9 +2738,16 @@ fn copy_to_ok() -> postit::Result<()> {
     let left_right = Config::build_path("tasks.json")?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: "to".to_string(),
             right: left_right.to_string_lossy().to_string(),
+            merge: false,
         }),
     };
 
@@ -458,9 +2772,16 @@ fn copy_to_err() -> postit::Result<()> {
     let left_right = Config::build_path("tasks.csv")?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: "to".to_string(),
             right: left_right.to_string_lossy().to_string(),
+            merge: false,
         }),
     };
 
@@ -475,9 +2796,16 @@ fn copy_same_paths() -> postit::Result<()> {
     let right = MockPath::create(Format::Csv)?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: left.to_string(),
             right: right.to_string(),
+            merge: false,
         }),
     };
 
@@ -492,9 +2820,16 @@ fn copy_no_left_path() -> postit::Result<()> {
     let right = MockPath::create(Format::Json)?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: left.to_string(),
             right: right.to_string(),
+            merge: false,
         }),
     };
 
@@ -515,9 +2850,16 @@ fn copy_path_exists() -> postit::Result<()> {
     let right = MockPath::create(Format::Json)?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: left.to_string(),
             right: right.to_string(),
+            merge: false,
         }),
     };
 
@@ -537,9 +2879,16 @@ fn copy_drop_after_copy() -> postit::Result<()> {
     let right = MockPath::blank(Format::Json)?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Copy(args::Copy {
             left: left.to_string(),
             right: right.to_string(),
+            merge: false,
         }),
     };
 
@@ -554,7 +2903,17 @@ fn sample() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
 
     let cli = Cli {
-        command: Command::Sample(args::Persister { persister: Some(mock.to_string()) }),
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Sample(args::Sample {
+            persister: Some(mock.to_string()),
+            count: None,
+            seed: None,
+        }),
     };
 
     assert!(Postit::run(cli).is_ok());
@@ -569,11 +2928,270 @@ fn sample() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn sample_count_seed() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Sample(args::Sample {
+            persister: Some(mock.to_string()),
+            count: Some(25),
+            seed: Some(42),
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let file = File::from(mock.to_string())?;
+
+    let result = Todo::from(&file)?.tasks;
+    let expect = Todo::generate(25, 42).tasks;
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn tidy() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Tidy(args::Persister { persister: Some(mock.to_string()) }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let file = File::from(mock.to_string())?;
+
+    let result = Todo::from(&file)?.tasks;
+    let mut expect = Todo::sample();
+    expect.tidy();
+
+    assert_eq!(result, expect.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn tidy_empty() -> postit::Result<()> {
+    let mock = MockPath::blank(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Tidy(args::Persister { persister: Some(mock.to_string()) }),
+    };
+
+    assert!(Postit::run(cli).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn export() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let out = mock.path.with_file_name("export.json");
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["export", "-f", "json", "-p", &mock.to_string()])
+        .arg("--out")
+        .arg(&out)
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+
+    let exported = Format::Json.parse(&std::fs::read_to_string(&out)?)?;
+
+    assert_eq!(exported, mock.instance.tasks()?);
+
+    Ok(())
+}
+
+#[test]
+fn import_stdin() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["import", "--stdin", "--format", "csv", "-p", &mock.to_string()])
+        .write_stdin("1,Imported,high,false\n")
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success());
+
+    let file = File::from(mock.to_string())?;
+
+    let result = file.tasks()?.last().cloned();
+    let expect = Some(Task::from("5,Imported,high,false"));
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn import_stdin_invalid() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let output = assert_cmd::Command::cargo_bin("postit")
+        .map_err(postit::Error::wrap)?
+        .args(["import", "--stdin", "--format", "csv", "-p", &mock.to_string()])
+        .write_stdin("not a task\n")
+        .output()
+        .map_err(postit::Error::wrap)?;
+
+    assert!(output.status.success().not());
+    assert_eq!(File::from(mock.to_string())?.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn dump_and_restore() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let file = MockPath::create(Format::Csv)?;
+    let archive = mock.path().with_file_name("dump.tar.gz");
+
+    mock.config.persister = file.to_string();
+    mock.config.force_drop = true;
+    mock.save()?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
+        command: Command::Dump(args::Archive {
+            persister: None,
+            path: Some(archive.clone()),
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    Config::remove()?;
+    file.instance.clean()?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
+        command: Command::Restore(args::Restore {
+            persister: None,
+            from: Some(archive.to_string_lossy().into_owned()),
+        }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    assert_eq!(Config::load()?, mock.config);
+    assert_eq!(file.instance.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn restore_backup() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    let file = MockPath::create(Format::Csv)?;
+
+    mock.config.persister = file.to_string();
+    mock.save()?;
+
+    let run = |args: &[&str], stdin: &str| -> postit::Result<String> {
+        let output = assert_cmd::Command::cargo_bin("postit")
+            .map_err(postit::Error::wrap)?
+            .args(args)
+            .write_stdin(stdin)
+            .output()
+            .map_err(postit::Error::wrap)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    run(&["dump"], "")?;
+    file.instance.clean()?;
+
+    let stdout = run(&["restore"], "")?;
+
+    assert!(stdout.contains("  1. postit-"));
+    assert!(file.instance.tasks()?.is_empty());
+
+    // Nothing is confirmed when the input isn't a terminal
+    run(&["restore"], "1\n")?;
+    assert_eq!(file.instance.tasks()?, Todo::sample().tasks);
+
+    file.instance.clean()?;
+    run(&["restore", "--yes"], "")?;
+    assert_eq!(file.instance.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn purge() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+
+    let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
+        command: Command::Purge(args::Persister { persister: Some(mock.to_string()) }),
+    };
+
+    assert!(Postit::run(cli).is_ok());
+
+    let result = File::from(mock.to_string())?.tasks()?;
+    let expect: Vec<Task> = Todo::sample()
+        .tasks
+        .into_iter()
+        .filter(|task| !task.checked)
+        .collect();
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
 #[test]
 fn clean() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Clean(args::Persister { persister: Some(mock.to_string()) }),
     };
 
@@ -594,6 +3212,12 @@ fn remove() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: true,
         command: Command::Remove(args::Persister { persister: Some(mock.to_string()) }),
     };
 
@@ -610,6 +3234,12 @@ fn config() -> postit::Result<()> {
     Config::remove()?;
 
     let cli = Cli {
+        list: None,
+        quiet: false,
+        verbose: false,
+        no_color: false,
+        compact: false,
+        yes: false,
         command: Command::Config(args::Config { subcommand: sub::Config::Init }),
     };
 
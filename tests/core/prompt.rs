@@ -0,0 +1,118 @@
+use std::io::Cursor;
+use std::ops::Not;
+
+use postit::models::{Priority, Task};
+use postit::{Draft, Prompt};
+
+fn run(prompt: Prompt, input: &str) -> postit::Result<(Option<Draft>, String)> {
+    let mut output = Vec::new();
+    let draft = prompt.run(Cursor::new(input), &mut output)?;
+
+    Ok((draft, String::from_utf8_lossy(&output).into_owned()))
+}
+
+#[test]
+fn run_asks_every_value() -> postit::Result<()> {
+    let (draft, _) = run(Prompt::default(), "Buy milk\nhigh\n2999-01-31\nhome, errands\n")?;
+    let draft = draft.unwrap();
+
+    assert_eq!(draft.content, "Buy milk");
+    assert_eq!(draft.priority, Priority::High);
+    assert_eq!(draft.due, Some(Task::parse_date("2999-01-31").unwrap()));
+    assert_eq!(draft.tags, ["home", "errands"]);
+
+    Ok(())
+}
+
+#[test]
+fn run_defaults() -> postit::Result<()> {
+    let (draft, _) = run(Prompt::default(), "Buy milk\n\n\n\n")?;
+    let draft = draft.unwrap();
+
+    assert_eq!(draft.priority, Priority::Med);
+    assert_eq!(draft.due, None);
+    assert!(draft.tags.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn run_with_default() -> postit::Result<()> {
+    let prompt = Prompt::default().with_default(Priority::Low);
+    let (draft, output) = run(prompt, "Buy milk\n\n\n\n")?;
+
+    assert_eq!(draft.unwrap().priority, Priority::Low);
+    assert!(output.contains("[low]"));
+
+    Ok(())
+}
+
+#[test]
+fn run_skips_passed_values() -> postit::Result<()> {
+    let prompt = Prompt::new(Some(Priority::Low), vec![String::from("work")]);
+    let (draft, output) = run(prompt, "Write report\n\n")?;
+    let draft = draft.unwrap();
+
+    assert_eq!(draft.priority, Priority::Low);
+    assert_eq!(draft.tags, ["work"]);
+    assert!(output.contains("Priority").not());
+
+    Ok(())
+}
+
+#[test]
+fn run_asks_again_when_invalid() -> postit::Result<()> {
+    let input = "\nBuy milk\nurgent\nnone\nsoon\n2000-01-31\n1w\n\n";
+    let (draft, output) = run(Prompt::default(), input)?;
+    let draft = draft.unwrap();
+
+    assert_eq!(draft.priority, Priority::None);
+    assert!(draft.due.is_some());
+    assert!(output.contains("The content can't be empty"));
+    assert!(output.contains("'urgent' is not a valid priority"));
+    assert!(output.contains("'soon' is not an amount of time or a date"));
+    assert!(output.contains("'2000-01-31' is in the past"));
+
+    Ok(())
+}
+
+#[test]
+fn run_input_ends() -> postit::Result<()> {
+    assert_eq!(run(Prompt::default(), "Buy milk\n")?.0, None);
+    assert_eq!(run(Prompt::default(), "")?.0, None);
+
+    Ok(())
+}
+
+#[test]
+fn confirm() -> postit::Result<()> {
+    let mut output = Vec::new();
+
+    assert!(Prompt::confirm(Cursor::new("maybe\ny\n"), &mut output, "Sure?")?);
+    assert!(Prompt::confirm(Cursor::new("\n"), Vec::new(), "Sure?")?.not());
+    assert!(Prompt::confirm(Cursor::new(""), Vec::new(), "Sure?")?.not());
+
+    let output = String::from_utf8_lossy(&output);
+
+    assert!(output.starts_with("Sure? [y/N]: "));
+    assert!(output.contains("'maybe' is not 'y' or 'n'"));
+
+    Ok(())
+}
+
+#[test]
+fn choose() -> postit::Result<()> {
+    let options = [String::from("first"), String::from("second")];
+    let mut output = Vec::new();
+
+    assert_eq!(Prompt::choose(Cursor::new("3\n2\n"), &mut output, "Pick", &options)?, Some(1));
+    assert_eq!(Prompt::choose(Cursor::new("\n"), Vec::new(), "Pick", &options)?, Some(0));
+    assert_eq!(Prompt::choose(Cursor::new(""), Vec::new(), "Pick", &options)?, None);
+
+    let output = String::from_utf8_lossy(&output);
+
+    assert!(output.starts_with("  1. first\n  2. second\nPick (1-2) [1]: "));
+    assert!(output.contains("'3' is not a number between 1 and 2"));
+
+    Ok(())
+}
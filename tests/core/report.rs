@@ -0,0 +1,114 @@
+use chrono::{Duration, Utc};
+use postit::models::{Task, Todo};
+use postit::{Report, Trash};
+
+fn tasks() -> Vec<Task> {
+    let now = Utc::now();
+    let mut tasks = Todo::sample().tasks;
+
+    for task in &mut tasks {
+        task.created_at = Some(now - Duration::days(30));
+    }
+
+    tasks[0].checked = true;
+    tasks[0].completed_at = Some(now);
+    tasks[1].completed_at = Some(now - Duration::days(10));
+
+    let mut new = Task::from("5,New,low,false");
+    new.created_at = Some(now);
+    tasks.push(new);
+
+    tasks
+}
+
+fn trash() -> Trash {
+    let mut trash = Trash::default();
+    trash.add("tasks.csv", &[Task::from("6,Dropped,low,false")]);
+    trash.add("tasks.json", &[Task::from("7,Dropped,low,false")]);
+
+    trash
+}
+
+fn ids(tasks: &[Task]) -> Vec<u32> {
+    tasks.iter().map(|task| task.id).collect()
+}
+
+#[test]
+fn new() {
+    let since = Utc::now() - Duration::days(1);
+    let report = Report::new(&tasks(), &trash(), "tasks.csv", since);
+
+    assert_eq!(ids(&report.added), [5]);
+    assert_eq!(ids(&report.completed), [1]);
+    assert_eq!(ids(&report.dropped), [6]);
+}
+
+#[test]
+fn new_out_of_period() {
+    let since = Utc::now() + Duration::days(1);
+    let report = Report::new(&tasks(), &trash(), "tasks.csv", since);
+
+    assert!(report.added.is_empty());
+    assert!(report.completed.is_empty());
+    assert!(report.dropped.is_empty());
+}
+
+#[test]
+fn new_more_than_journal_limit() {
+    let now = Utc::now();
+
+    let tasks: Vec<Task> = (1..=60)
+        .map(|id| {
+            let mut task = Task::from(format!("{id},Task,med,true"));
+            task.created_at = Some(now);
+            task.completed_at = Some(now);
+            task
+        })
+        .collect();
+
+    let report = Report::new(&tasks, &Trash::default(), "tasks.csv", now - Duration::days(1));
+
+    assert_eq!(report.added.len(), 60);
+    assert_eq!(report.completed.len(), 60);
+}
+
+#[test]
+fn new_without_timestamps() {
+    let report = Report::new(
+        &Todo::sample().tasks,
+        &Trash::default(),
+        "tasks.csv",
+        Utc::now() - Duration::days(1),
+    );
+
+    assert!(report.added.is_empty());
+    assert!(report.completed.is_empty());
+}
+
+#[test]
+fn parse_since() {
+    let week = Report::parse_since("7d").map(|since| Utc::now() - since);
+    let hours = Report::parse_since("12h").map(|since| Utc::now() - since);
+
+    assert!(week.is_ok_and(|diff| diff >= Duration::days(7) && diff < Duration::days(8)));
+    assert!(hours.is_ok_and(|diff| diff >= Duration::hours(12) && diff < Duration::hours(13)));
+    assert_eq!(Report::parse_since("2025-01-31"), Task::parse_date("2025-01-31"));
+}
+
+#[test]
+fn parse_since_err() {
+    assert!(Report::parse_since("7y").is_err());
+    assert!(Report::parse_since("d").is_err());
+    assert!(Report::parse_since("").is_err());
+}
+
+#[test]
+fn to_string() {
+    let report = Report::new(&tasks(), &trash(), "tasks.csv", Utc::now() - Duration::days(1));
+    let text = report.to_string();
+
+    assert!(text.starts_with("Since "));
+    assert!(text.contains("\nAdded: 1\n"));
+    assert!(text.contains("\nCompleted: 1\n"));
+    assert!(text.contains("\nDropped: 1\n"));
+}
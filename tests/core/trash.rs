@@ -0,0 +1,77 @@
+use chrono::{Duration, Utc};
+use postit::models::{Task, Todo};
+use postit::testing::MockConfig;
+use postit::{Trash, Trashed};
+
+fn trash() -> Trash {
+    let mut trash = Trash::default();
+
+    trash.add("tasks.csv", &Todo::sample().tasks[..2]);
+    trash.add("tasks.json", &Todo::sample().tasks[2..]);
+
+    trash
+}
+
+#[test]
+fn save_and_load() -> postit::Result<()> {
+    let _mock = MockConfig::new()?;
+
+    assert_eq!(Trash::load()?, Trash::default());
+
+    let trash = trash();
+    trash.save()?;
+
+    assert_eq!(Trash::load()?, trash);
+    assert_eq!(std::fs::read_to_string(Trash::path()?)?.lines().count(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn of() {
+    let trash = trash();
+
+    let ids: Vec<u32> = trash
+        .of("tasks.csv")
+        .iter()
+        .map(|item| item.task.id)
+        .collect();
+
+    assert_eq!(ids, [1, 2]);
+    assert!(trash.of("tasks.xml").is_empty());
+}
+
+#[test]
+fn take_last_dropped() {
+    let mut trash = trash();
+    trash.add("tasks.csv", &[Task::from("1,Newer,low,false")]);
+
+    let tasks = trash.take("tasks.csv", &[1, 3]);
+
+    assert_eq!(tasks, [Task::from("1,Newer,low,false")]);
+    assert_eq!(trash.of("tasks.csv").len(), 2);
+    assert_eq!(trash.of("tasks.json").len(), 2);
+}
+
+#[test]
+fn expire() {
+    let mut trash = trash();
+    trash.items[0].dropped_at = Utc::now() - Duration::days(31);
+
+    assert_eq!(trash.expire(30), 1);
+    assert_eq!(trash.items.len(), 3);
+}
+
+#[test]
+fn empty() {
+    let mut trash = trash();
+
+    assert_eq!(trash.empty(Some("tasks.csv")), 2);
+    assert!(trash
+        .items
+        .iter()
+        .all(|item: &Trashed| item.persister == "tasks.json"));
+
+    assert_eq!(trash.empty(None), 2);
+    assert!(trash.items.is_empty());
+}
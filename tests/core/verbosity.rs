@@ -0,0 +1,20 @@
+use postit::Verbosity;
+
+#[test]
+fn from_flags() {
+    assert_eq!(Verbosity::from_flags(false, false), Verbosity::Normal);
+    assert_eq!(Verbosity::from_flags(true, false), Verbosity::Quiet);
+    assert_eq!(Verbosity::from_flags(false, true), Verbosity::Verbose);
+}
+
+#[test]
+fn level_is_ordered() {
+    assert!(Verbosity::Quiet < Verbosity::Normal);
+    assert!(Verbosity::Normal < Verbosity::Verbose);
+    assert_eq!(Verbosity::Verbose.level(), 2);
+}
+
+#[test]
+fn default_verbosity() {
+    assert_eq!(Verbosity::default(), Verbosity::Normal);
+}
@@ -12,6 +12,22 @@ fn get_docs_output(command: &str) -> Output {
         .expect("Error while running the test")
 }
 
+#[test]
+fn docs_no_color() {
+    let output = Command::cargo_bin("postit")
+        .unwrap()
+        .args(["docs", "view", "--no-color"])
+        .env("CLICOLOR_FORCE", "1")
+        .output()
+        .expect("Error while running the test");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Description:"));
+    assert!(!stdout.contains("\x1b["));
+}
+
 #[test]
 fn docs_sample_output() {
     let output = get_docs_output("sample");
@@ -42,6 +58,21 @@ fn docs_view_no_panic() {
     docs::Command::run(&sub::Docs::View)
 }
 
+#[test]
+fn docs_show_output() {
+    let output = get_docs_output("show");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit show <ID> [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit sh ..."));
+}
+
+#[test]
+fn docs_show_no_panic() {
+    docs::Command::run(&sub::Docs::Show)
+}
+
 #[test]
 fn docs_add_output() {
     let output = get_docs_output("add");
@@ -50,7 +81,7 @@ fn docs_add_output() {
     dbg!(&output);
 
     assert!(output.status.success());
-    assert!(stdout.contains("Usage: postit add <PRIORITY> <CONTENT> [--persister|-p]"));
+    assert!(stdout.contains("Usage: postit add [PRIORITY] [CONTENT] [--persister|-p]"));
     assert!(stdout.contains("Alias: postit a ..."));
 }
 
@@ -59,6 +90,51 @@ fn docs_add_no_panic() {
     docs::Command::run(&sub::Docs::Add)
 }
 
+#[test]
+fn docs_pin_output() {
+    let output = get_docs_output("pin");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit pin <IDS> [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit pn ..."));
+}
+
+#[test]
+fn docs_pin_no_panic() {
+    docs::Command::run(&sub::Docs::Pin)
+}
+
+#[test]
+fn docs_unpin_output() {
+    let output = get_docs_output("unpin");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit unpin <IDS> [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit upn ..."));
+}
+
+#[test]
+fn docs_unpin_no_panic() {
+    docs::Command::run(&sub::Docs::Unpin)
+}
+
+#[test]
+fn docs_dup_output() {
+    let output = get_docs_output("dup");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit dup <IDS> [--priority] [--content] [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit dp ..."));
+}
+
+#[test]
+fn docs_dup_no_panic() {
+    docs::Command::run(&sub::Docs::Dup)
+}
+
 #[test]
 fn docs_set_output() {
     let output = get_docs_output("set");
@@ -110,7 +186,9 @@ fn docs_drop_output() {
     let stdout = String::from_utf8_lossy(&output.stdout);
 
     assert!(output.status.success());
-    assert!(stdout.contains("Usage: postit drop <IDS> [--persister|-p]"));
+    assert!(stdout.contains(
+        "Usage: postit drop [IDS] [--checked] [--priority] [--older-than] [--persister|-p]"
+    ));
     assert!(stdout.contains("Alias: postit d ..."));
 }
 
@@ -119,6 +197,280 @@ fn docs_drop_no_panic() {
     docs::Command::run(&sub::Docs::Drop);
 }
 
+#[test]
+fn docs_pick_output() {
+    let output = get_docs_output("pick");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit pick <COMMAND> [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit pk ..."));
+}
+
+#[test]
+fn docs_pick_no_panic() {
+    docs::Command::run(&sub::Docs::Pick)
+}
+
+#[test]
+fn docs_stats_output() {
+    let output = get_docs_output("stats");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit stats [--persister|-p] [--by]"));
+    assert!(stdout.contains("Alias: postit st ..."));
+}
+
+#[test]
+fn docs_stats_no_panic() {
+    docs::Command::run(&sub::Docs::Stats)
+}
+
+#[test]
+fn docs_report_output() {
+    let output = get_docs_output("report");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit report [--persister|-p] [--since|-s]"));
+    assert!(stdout.contains("Alias: postit rp ..."));
+}
+
+#[test]
+fn docs_report_no_panic() {
+    docs::Command::run(&sub::Docs::Report)
+}
+
+#[test]
+fn docs_next_output() {
+    let output = get_docs_output("next");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit next [COUNT] [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit n ..."));
+}
+
+#[test]
+fn docs_next_no_panic() {
+    docs::Command::run(&sub::Docs::Next)
+}
+
+#[test]
+fn docs_agenda_no_panic() {
+    docs::Command::run(&sub::Docs::Agenda)
+}
+
+#[test]
+fn docs_cal_no_panic() {
+    docs::Command::run(&sub::Docs::Cal)
+}
+
+#[test]
+fn docs_pomo_no_panic() {
+    docs::Command::run(&sub::Docs::Pomo)
+}
+
+#[test]
+fn docs_tidy_output() {
+    let output = get_docs_output("tidy");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit tidy [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit td ..."));
+}
+
+#[test]
+fn docs_tidy_no_panic() {
+    docs::Command::run(&sub::Docs::Tidy)
+}
+
+#[test]
+fn docs_import_output() {
+    let output = get_docs_output("import");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit import --stdin --format <FORMAT> [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit im ..."));
+}
+
+#[test]
+fn docs_import_no_panic() {
+    docs::Command::run(&sub::Docs::Import)
+}
+
+#[test]
+fn docs_dump_output() {
+    let output = get_docs_output("dump");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit dump [PATH] [--persister|-p]"));
+}
+
+#[test]
+fn docs_dump_no_panic() {
+    docs::Command::run(&sub::Docs::Dump)
+}
+
+#[test]
+fn docs_restore_output() {
+    let output = get_docs_output("restore");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit restore [--from] [--yes|-y] [--persister|-p]"));
+}
+
+#[test]
+fn docs_restore_no_panic() {
+    docs::Command::run(&sub::Docs::Restore)
+}
+
+#[test]
+fn docs_errors_output() {
+    let output = get_docs_output("errors");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit docs errors"));
+    assert!(stdout.contains("E104"));
+}
+
+#[test]
+fn docs_errors_no_panic() {
+    docs::Command::run(&sub::Docs::Errors)
+}
+
+#[test]
+fn docs_note_output() {
+    let output = get_docs_output("note");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit note"));
+    assert!(stdout.contains("%0A"));
+}
+
+#[test]
+fn docs_note_no_panic() {
+    docs::Command::run(&sub::Docs::Note)
+}
+
+#[test]
+fn docs_snooze_output() {
+    let output = get_docs_output("snooze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit snooze"));
+    assert!(stdout.contains("--show-snoozed"));
+}
+
+#[test]
+fn docs_snooze_no_panic() {
+    docs::Command::run(&sub::Docs::Snooze)
+}
+
+#[test]
+fn docs_search_output() {
+    let output = get_docs_output("search");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit search"));
+    assert!(stdout.contains("--regex"));
+}
+
+#[test]
+fn docs_search_no_panic() {
+    docs::Command::run(&sub::Docs::Search)
+}
+
+#[test]
+fn docs_undo_output() {
+    let output = get_docs_output("undo");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit undo"));
+    assert!(stdout.contains(".postit_journal.json"));
+}
+
+#[test]
+fn docs_undo_no_panic() {
+    docs::Command::run(&sub::Docs::Undo)
+}
+
+#[test]
+fn docs_redo_no_panic() {
+    docs::Command::run(&sub::Docs::Redo)
+}
+
+#[test]
+fn docs_export_output() {
+    let output = get_docs_output("export");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit export"));
+    assert!(stdout.contains("markdown"));
+}
+
+#[test]
+fn docs_export_no_panic() {
+    docs::Command::run(&sub::Docs::Export)
+}
+
+#[test]
+fn docs_edit_output() {
+    let output = get_docs_output("edit");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit edit <ID> [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit e ..."));
+}
+
+#[test]
+fn docs_edit_no_panic() {
+    docs::Command::run(&sub::Docs::Edit)
+}
+
+#[test]
+fn docs_move_output() {
+    let output = get_docs_output("move");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout
+        .contains("Usage: postit move <ID> [--before|-b] [--after|-a] [--to|-t] [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit mv ..."));
+}
+
+#[test]
+fn docs_move_no_panic() {
+    docs::Command::run(&sub::Docs::Move)
+}
+
+#[test]
+fn docs_open_output() {
+    let output = get_docs_output("open");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit open"));
+    assert!(stdout.contains("[links]"));
+}
+
+#[test]
+fn docs_open_no_panic() {
+    docs::Command::run(&sub::Docs::Open)
+}
+
 #[test]
 fn docs_copy_output() {
     let output = get_docs_output("copy");
@@ -134,6 +486,21 @@ fn docs_copy_no_panic() {
     docs::Command::run(&sub::Docs::Copy)
 }
 
+#[test]
+fn docs_purge_output() {
+    let output = get_docs_output("purge");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit purge [--persister|-p]"));
+    assert!(stdout.contains("Alias: postit pu ..."));
+}
+
+#[test]
+fn docs_purge_no_panic() {
+    docs::Command::run(&sub::Docs::Purge)
+}
+
 #[test]
 fn docs_clean_output() {
     let output = get_docs_output("clean");
@@ -179,3 +546,18 @@ fn docs_config_output() {
 fn docs_config_no_panic() {
     docs::Command::run(&sub::Docs::Config)
 }
+
+#[test]
+fn docs_list_output() {
+    let output = get_docs_output("list");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit list <COMMAND>"));
+    assert!(stdout.contains("Alias: postit li ..."));
+}
+
+#[test]
+fn docs_list_no_panic() {
+    docs::Command::run(&sub::Docs::List)
+}
@@ -24,3 +24,73 @@ fn flag_persister_output() {
 fn flag_persister_no_panic() {
     docs::Flag::persister();
 }
+
+#[test]
+fn flag_quiet_output() {
+    let output = get_flag_output("quiet");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit <COMMAND> [--quiet | -q]"));
+}
+
+#[test]
+fn flag_quiet_no_panic() {
+    docs::Flag::quiet();
+}
+
+#[test]
+fn flag_verbose_output() {
+    let output = get_flag_output("verbose");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit <COMMAND> [--verbose | -v]"));
+}
+
+#[test]
+fn flag_verbose_no_panic() {
+    docs::Flag::verbose();
+}
+
+#[test]
+fn flag_no_color_output() {
+    let output = get_flag_output("no-color");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit <COMMAND> [--no-color]"));
+}
+
+#[test]
+fn flag_no_color_no_panic() {
+    docs::Flag::no_color();
+}
+
+#[test]
+fn flag_compact_output() {
+    let output = get_flag_output("compact");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit <COMMAND> [--compact]"));
+}
+
+#[test]
+fn flag_compact_no_panic() {
+    docs::Flag::compact();
+}
+
+#[test]
+fn flag_yes_output() {
+    let output = get_flag_output("yes");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Usage: postit <COMMAND> [--yes | -y]"));
+}
+
+#[test]
+fn flag_yes_no_panic() {
+    docs::Flag::yes();
+}
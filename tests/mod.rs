@@ -1,6 +1,5 @@
 pub mod config;
 pub mod core;
 pub mod docs;
-pub mod mocks;
 pub mod models;
 pub mod persisters;
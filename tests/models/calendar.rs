@@ -0,0 +1,59 @@
+use postit::models::{Calendar, Task};
+
+fn task(line: &str, due: &str) -> Task {
+    let mut task = Task::from(line);
+    task.due = Task::parse_date(due).ok();
+    task
+}
+
+#[test]
+fn new() -> Result<(), String> {
+    let tasks = vec![
+        task("1,Task,low,false", "2025-02-03"),
+        task("2,Task,med,false", "2025-02-03"),
+        task("3,Task,high,true", "2025-02-03"),
+        task("4,Task,none,false", "2025-02-28"),
+        task("5,Task,none,false", "2025-03-01"),
+        Task::from("6,Task,none,false"),
+    ];
+
+    let calendar = Calendar::new(Calendar::parse_month("2025-02")?, &tasks);
+
+    assert_eq!(calendar.due.len(), 28);
+    assert_eq!(calendar.due[2], 2);
+    assert_eq!(calendar.due[27], 1);
+    assert_eq!(calendar.total(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn fmt_display() -> Result<(), String> {
+    let tasks = vec![task("1,Task,low,false", "2025-01-14")];
+    let calendar = Calendar::new(Calendar::parse_month("2025-01")?, &tasks);
+
+    let expect = "\
+January 2025
+ Mo    Tu    We    Th    Fr    Sa    Su
+              1     2     3     4     5
+  6     7     8     9    10    11    12
+ 13    14*1  15    16    17    18    19
+ 20    21    22    23    24    25    26
+ 27    28    29    30    31
+
+Due this month: 1";
+
+    assert_eq!(calendar.to_string(), expect);
+
+    Ok(())
+}
+
+#[test]
+fn parse_month() {
+    assert_eq!(
+        Calendar::parse_month("2025-01").map(|month| month.to_string()),
+        Ok(String::from("2025-01-01"))
+    );
+    assert!(Calendar::parse_month("2025-13").is_err());
+    assert!(Calendar::parse_month("January").is_err());
+}
@@ -0,0 +1,104 @@
+use chrono::{Duration, Utc};
+use colored::{Color, Colorize as _, Styles};
+use postit::models::{ColorMode, Colors, Priority, Task};
+
+#[test]
+fn style_by_priority() {
+    let colors = Colors::default();
+
+    assert_eq!(colors.style(&Priority::High), "red bold");
+    assert_eq!(colors.style(&Priority::Med), "yellow bold");
+    assert_eq!(colors.style(&Priority::Low), "blue bold");
+    assert_eq!(colors.style(&Priority::None), "white bold");
+}
+
+#[test]
+fn apply_color_and_effects() {
+    let result = Colors::apply("Task".normal(), "bright blue italic underline");
+
+    assert_eq!(result.fgcolor, Some(Color::BrightBlue));
+    assert!(result.style.contains(Styles::Italic));
+    assert!(result.style.contains(Styles::Underline));
+    assert!(!result.style.contains(Styles::Bold));
+}
+
+#[test]
+fn apply_invalid_color() {
+    let result = Colors::apply("Task".normal(), "rainbow bold");
+
+    assert_eq!(result.fgcolor, None);
+    assert!(result.style.contains(Styles::Bold));
+}
+
+#[test]
+fn apply_empty() {
+    let result = Colors::apply("Task".normal(), "");
+
+    assert_eq!(result.fgcolor, None);
+    assert!(result.style.contains(Styles::Clear));
+}
+
+#[test]
+fn paint_checked() {
+    let colors = Colors::default();
+    let task = Task::from("1,Task,high,true");
+
+    let result = colors.paint("Task", &task);
+
+    assert_eq!(result.fgcolor, Some(Color::Red));
+    assert!(result.style.contains(Styles::Bold));
+    assert!(result.style.contains(Styles::Strikethrough));
+}
+
+#[test]
+fn paint_checked_without_style() {
+    let colors = Colors {
+        checked: String::new(),
+        ..Colors::default()
+    };
+    let task = Task::from("1,Task,low,true");
+
+    let result = colors.paint("Task", &task);
+
+    assert_eq!(result.fgcolor, Some(Color::Blue));
+    assert!(!result.style.contains(Styles::Strikethrough));
+}
+
+#[test]
+fn paint_overdue() {
+    let colors = Colors::default();
+    let mut task = Task::from("1,Task,low,false");
+    task.due = Some(Utc::now() - Duration::days(2));
+
+    let result = colors.paint("Task", &task);
+    assert_eq!(result.fgcolor, Some(Color::Magenta));
+
+    task.checked = true;
+
+    let result = colors.paint("Task", &task);
+    assert_eq!(result.fgcolor, Some(Color::Blue));
+}
+
+#[test]
+fn fmt_display() {
+    let colors = Colors {
+        high: String::from("red"),
+        med: String::from("yellow"),
+        low: String::from("blue"),
+        none: String::new(),
+        checked: String::from("dimmed"),
+        overdue: String::from("magenta"),
+    };
+
+    assert_eq!(
+        colors.to_string(),
+        "high='red' med='yellow' low='blue' none='' checked='dimmed' overdue='magenta'"
+    );
+}
+
+#[test]
+fn color_mode_fmt_display() {
+    assert_eq!(ColorMode::default().to_string(), "auto");
+    assert_eq!(ColorMode::Always.to_string(), "always");
+    assert_eq!(ColorMode::Never.to_string(), "never");
+}
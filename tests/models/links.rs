@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use postit::models::Links;
+
+fn fake_links() -> Links {
+    Links(BTreeMap::from([
+        (String::from("JIRA"), String::from("https://jira.example.com/browse/{ref}")),
+        (String::from("gh"), String::from("https://github.com/owner/repo/issues/{id}")),
+    ]))
+}
+
+#[test]
+fn split() {
+    assert_eq!(Links::split("JIRA-123"), ("JIRA", "123"));
+    assert_eq!(Links::split("gh#42"), ("gh", "42"));
+    assert_eq!(Links::split("gh#a-b"), ("gh", "a-b"));
+    assert_eq!(Links::split("ticket"), ("ticket", ""));
+}
+
+#[test]
+fn url() {
+    let links = fake_links();
+
+    assert_eq!(links.url("JIRA-123").unwrap(), "https://jira.example.com/browse/JIRA-123");
+    assert_eq!(links.url("gh#42").unwrap(), "https://github.com/owner/repo/issues/42");
+    assert_eq!(links.url("GL-7"), None);
+}
+
+#[test]
+fn markdown() {
+    let refs = vec![String::from("gh#42"), String::from("GL-7")];
+
+    let result = fake_links().markdown(&refs);
+    let expect = " [gh#42](https://github.com/owner/repo/issues/42) `GL-7`";
+
+    assert_eq!(result, expect);
+    assert_eq!(fake_links().markdown(&[]), "");
+}
+
+#[test]
+fn plain_without_templates() {
+    let refs = vec![String::from("gh#42"), String::from("GL-7")];
+
+    assert_eq!(Links::default().plain(&refs), " [gh#42 GL-7]");
+    assert_eq!(Links::default().plain(&[]), "");
+}
+
+#[test]
+fn fmt_display() {
+    let expect = "JIRA='https://jira.example.com/browse/{ref}' \
+                  gh='https://github.com/owner/repo/issues/{id}'";
+
+    assert_eq!(fake_links().to_string(), expect);
+    assert_eq!(Links::default().to_string(), "none");
+}
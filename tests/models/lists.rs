@@ -0,0 +1,42 @@
+use std::collections::BTreeMap;
+
+use postit::models::Lists;
+
+fn fake_lists() -> Lists {
+    Lists(BTreeMap::from([
+        (String::from("home"), String::from("tasks.db")),
+        (String::from("work"), String::from("work.csv")),
+    ]))
+}
+
+#[test]
+fn fmt_display() {
+    assert_eq!(fake_lists().to_string(), "home='tasks.db' work='work.csv'");
+    assert_eq!(Lists::default().to_string(), "none");
+}
+
+#[test]
+fn is_name() {
+    assert!(Lists::is_name("work"));
+    assert!(Lists::is_name("side-project"));
+    assert!(!Lists::is_name(""));
+    assert!(!Lists::is_name("tasks.csv"));
+    assert!(!Lists::is_name("dir/tasks"));
+    assert!(!Lists::is_name("mongodb://localhost:27017"));
+}
+
+#[test]
+fn get() {
+    let lists = fake_lists();
+
+    assert_eq!(lists.get("work"), Some(&String::from("work.csv")));
+    assert_eq!(lists.get("other"), None);
+}
+
+#[test]
+fn resolve() {
+    let lists = fake_lists();
+
+    assert_eq!(lists.resolve("home"), "tasks.db");
+    assert_eq!(lists.resolve("other"), "other");
+}
@@ -1,2 +1,9 @@
+pub mod calendar;
+pub mod colors;
+pub mod links;
+pub mod lists;
+pub mod stats;
+pub mod table;
 pub mod task;
 pub mod todo;
+pub mod urgency;
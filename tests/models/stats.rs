@@ -0,0 +1,93 @@
+use postit::models::{GroupBy, Stats, Todo};
+
+#[test]
+fn fmt_display() {
+    let stats = Stats::new("all", 4, 1);
+
+    let expect = "Tasks: 4 (1 checked, 3 unchecked)\nCompleted: 25%";
+
+    assert_eq!(stats.to_string(), expect);
+}
+
+#[test]
+fn fmt_display_estimate() {
+    let mut todo = Todo::sample();
+    todo.tasks[0].estimate = Some(90);
+    todo.tasks[3].estimate = Some(30);
+
+    let stats = todo.stats();
+    let expect = "Tasks: 4 (2 checked, 2 unchecked)\nCompleted: 50%\nEstimated: 2h (1h30m left)";
+
+    assert_eq!((stats.estimate, stats.remaining), (120, 90));
+    assert_eq!(stats.to_string(), expect);
+}
+
+#[test]
+fn as_json() {
+    let stats = Stats::new("all", 4, 1);
+
+    let expect = serde_json::json!({
+        "label": "all",
+        "total": 4,
+        "checked": 1,
+        "unchecked": 3,
+        "percentage": 25,
+        "estimate": 0,
+        "remaining": 0,
+    });
+
+    assert_eq!(stats.as_json(), expect);
+}
+
+#[test]
+fn percentage_empty() {
+    let stats = Stats::new("all", 0, 0);
+
+    assert_eq!(stats.percentage(), 0);
+    assert_eq!(stats.unchecked(), 0);
+}
+
+#[test]
+fn todo_stats() {
+    let todo = Todo::sample();
+
+    assert_eq!(todo.stats(), Stats::new("all", 4, 2));
+}
+
+#[test]
+fn todo_stats_by_priority() {
+    let todo = Todo::sample();
+
+    let result = todo.stats_by(&GroupBy::Priority);
+    let expect = vec![
+        Stats::new("high", 1, 0),
+        Stats::new("med", 1, 0),
+        Stats::new("low", 1, 1),
+        Stats::new("none", 1, 1),
+    ];
+
+    assert_eq!(result, expect);
+}
+
+#[test]
+fn todo_stats_by_status() {
+    let todo = Todo::sample();
+
+    let result = todo.stats_by(&GroupBy::Status);
+    let expect = vec![Stats::new("todo", 2, 0), Stats::new("done", 2, 2)];
+
+    assert_eq!(result, expect);
+}
+
+#[test]
+fn table() {
+    let rows = vec![Stats::new("high", 2, 1), Stats::new("none", 0, 0)];
+
+    let result = Stats::table("priority", &rows);
+    let expect = "\
+priority  total  checked  completed
+high          2        1        50%
+none          0        0         0%";
+
+    assert_eq!(result, expect);
+}
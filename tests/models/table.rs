@@ -0,0 +1,62 @@
+use postit::models::{Column, Task};
+
+#[test]
+fn value() {
+    let mut task = Task::from("7,Deploy,high,true");
+    task.tags = vec![String::from("work"), String::from("ops")];
+
+    assert_eq!(Column::Id.value(&task), "7");
+    assert_eq!(Column::Priority.value(&task), "high");
+    assert_eq!(Column::Status.value(&task), "done");
+    assert_eq!(Column::Content.value(&task), "Deploy");
+    assert_eq!(Column::Tags.value(&task), "work ops");
+    assert_eq!(Column::Refs.value(&task), "");
+    assert_eq!(Column::Estimate.value(&task), "");
+}
+
+#[test]
+fn table_estimate() {
+    let mut task = Task::from("1,Task,low,false");
+    task.estimate = Some(150);
+
+    let result = Column::table(&[Column::Content, Column::Estimate], &[task]);
+
+    assert_eq!(result, ["CONTENT  ESTIMATE", "Task        2h30m"]);
+}
+
+#[test]
+fn value_dates() {
+    let task = Task::from("1,Task,low,false");
+
+    assert_eq!(Column::Created.value(&task), "");
+    assert_eq!(Column::Completed.value(&task), "");
+}
+
+#[test]
+fn table_aligned() {
+    let tasks = [Task::from("9,Short,high,false"), Task::from("10,A longer task,none,true")];
+
+    let result = Column::table(&Column::defaults(), &tasks);
+    let expect = [
+        "ID  PRIORITY  STATUS  CONTENT",
+        " 9  high      todo    Short",
+        "10  none      done    A longer task",
+    ];
+
+    assert_eq!(result, expect);
+}
+
+#[test]
+fn table_trims_empty_columns() {
+    let tasks = [Task::from("1,Task,med,false")];
+
+    let result = Column::table(&[Column::Content, Column::Tags], &tasks);
+
+    assert_eq!(result, ["CONTENT  TAGS", "Task"]);
+}
+
+#[test]
+fn join() {
+    assert_eq!(Column::join(&Column::defaults()), "id,priority,status,content");
+    assert_eq!(Column::join(&[]), "");
+}
@@ -1,3 +1,4 @@
+use chrono::{Duration, Utc};
 use postit::models::{Priority, Task};
 
 fn fake_task_unchecked() -> Task {
@@ -53,6 +54,215 @@ fn to_string() {
     assert_eq!(result, expect);
 }
 
+#[test]
+fn from_with_refs() {
+    let task = Task::from("1,Test,med,false,JIRA-123 gh#42");
+
+    assert_eq!(task.refs, vec![String::from("JIRA-123"), String::from("gh#42")]);
+    assert_eq!(task.as_line(), "1,Test,med,false,JIRA-123 gh#42");
+    assert_eq!(task.as_tsv(), "1\tTest\tmed\tfalse\tJIRA-123 gh#42");
+}
+
+#[test]
+fn from_with_tags() {
+    let task = Task::from("1,Test,med,false,,work home");
+
+    assert!(task.refs.is_empty());
+    assert_eq!(task.tags, vec![String::from("work"), String::from("home")]);
+    assert_eq!(task.as_line(), "1,Test,med,false,,work home");
+    assert_eq!(task.as_tsv(), "1\tTest\tmed\tfalse\t\twork home");
+    assert_eq!(task.hashtags(), " #work #home");
+}
+
+#[test]
+fn from_with_notes() {
+    let mut task = fake_task_unchecked();
+    task.notes = String::from("First, line\n100% done\tok");
+
+    let line = task.as_line();
+
+    assert_eq!(line, "1,Test,med,false,,,First%2C line%0A100%25 done%09ok");
+    assert_eq!(Task::from(&line), task);
+}
+
+#[test]
+fn unescape_invalid() {
+    assert_eq!(Task::unescape("100%"), "100%");
+    assert_eq!(Task::unescape("%zz%41"), "%zzA");
+}
+
+#[test]
+fn as_markdown() {
+    assert_eq!(fake_task_unchecked().as_markdown(), "- [ ] `med` Test");
+    assert_eq!(fake_task_checked().as_markdown(), "- [x] `high` Test");
+    assert_eq!(Task::from("1,Test,none,false").as_markdown(), "- [ ] Test");
+}
+
+#[test]
+fn from_with_estimate() {
+    let task = Task::from("1,Task,low,false,,,,,,,90");
+
+    assert_eq!(task.estimate, Some(90));
+    assert_eq!(task.as_line(), "1,Task,low,false,,,,,,,90");
+    assert_eq!(task.estimate_badge(), " ~1h30m");
+    assert_eq!(Task::from("1,Task,low,false").estimate_badge(), "");
+}
+
+#[test]
+fn from_with_snooze() {
+    let line = "1,Task,low,false,,,,,,,,2999-01-31T18:30:00Z";
+    let task = Task::from(line);
+
+    assert_eq!(task.snoozed_until, Task::parse_timestamp("2999-01-31T18:30:00Z"));
+    assert_eq!(task.as_line(), line);
+    assert!(task.is_snoozed());
+    assert!(task.snooze_badge().starts_with(" (snoozed until 2999-"));
+}
+
+#[test]
+fn from_with_order() {
+    let line = "1,Task,low,false,,,,,,,,,3";
+    let task = Task::from(line);
+
+    assert_eq!(task.order, Some(3));
+    assert_eq!(task.as_line(), line);
+}
+
+#[test]
+fn from_with_pinned() {
+    let line = "1,Task,low,false,,,,,,,,,,true";
+    let task = Task::from(line);
+
+    assert!(task.pinned);
+    assert_eq!(task.as_line(), line);
+    assert!(!Task::from("1,Task,low,false").pinned);
+}
+
+#[test]
+fn from_with_attachments() {
+    let line = "1,Task,low,false,,,,,,,,,,,/tmp/My%20notes.txt https://example.com/a%2Cb";
+    let task = Task::from(line);
+
+    assert_eq!(task.attachments, ["/tmp/My notes.txt", "https://example.com/a,b"]);
+    assert_eq!(task.as_line(), line);
+    assert!(Task::from("1,Task,low,false").attachments.is_empty());
+}
+
+#[test]
+fn from_with_due() {
+    let line = "1,Task,low,false,,,,,,,,,,,,2999-01-31T18:30:00Z";
+    let task = Task::from(line);
+
+    assert_eq!(task.due, Task::parse_timestamp("2999-01-31T18:30:00Z"));
+    assert_eq!(task.as_line(), line);
+}
+
+#[test]
+fn due_badge() {
+    let mut task = Task::from("1,Task,low,false");
+    assert_eq!(task.due_badge(), "");
+
+    task.due = Some(Utc::now());
+    assert_eq!(task.days_left(), Some(0));
+    assert_eq!(task.due_badge(), " (due today)");
+    assert!(!task.is_overdue());
+
+    task.due = Some(Utc::now() + Duration::days(3));
+    assert_eq!(task.due_badge(), " (due in 3d)");
+
+    task.due = Some(Utc::now() - Duration::days(2));
+    assert_eq!(task.due_badge(), " (overdue 2d)");
+    assert!(task.is_overdue());
+
+    task.checked = true;
+    assert_eq!(task.due_badge(), "");
+    assert!(!task.is_overdue());
+}
+
+#[test]
+fn details() {
+    let mut task = Task::from("1,Task,low,true,,work home,,,,,90");
+    task.notes = String::from("Not shown");
+
+    let result = task.details();
+    let expect = vec![
+        ("Content", String::from("Task")),
+        ("Priority", String::from("low")),
+        ("Status", String::from("done")),
+        ("Tags", String::from("work home")),
+        ("Estimate", String::from("1h30m")),
+    ];
+
+    assert_eq!(result, expect);
+}
+
+#[test]
+fn is_snoozed_expired() {
+    let task = Task::from("1,Task,low,false,,,,,,,,2000-01-31T18:30:00Z");
+
+    assert!(!task.is_snoozed());
+    assert_eq!(task.snooze_badge(), "");
+}
+
+#[test]
+fn parse_duration() {
+    assert_eq!(Task::parse_duration("30m"), Some(Duration::minutes(30)));
+    assert_eq!(Task::parse_duration("2w"), Some(Duration::weeks(2)));
+    assert_eq!(Task::parse_duration("2y"), None);
+    assert_eq!(Task::parse_duration("d"), None);
+}
+
+#[test]
+fn parse_until() {
+    let days = Task::parse_until("2d").map(|until| until - Utc::now());
+
+    assert!(days.is_ok_and(|diff| diff > Duration::days(1) && diff <= Duration::days(2)));
+    assert_eq!(Task::parse_until("2025-01-31"), Task::parse_date("2025-01-31"));
+    assert!(Task::parse_until("later").is_err());
+}
+
+#[test]
+fn parse_estimate() {
+    assert_eq!(Task::parse_estimate("30m"), Ok(30));
+    assert_eq!(Task::parse_estimate("2h"), Ok(120));
+    assert_eq!(Task::parse_estimate("1H30M"), Ok(90));
+    assert_eq!(Task::parse_estimate("45"), Ok(45));
+    assert_eq!(Task::parse_estimate("none"), Ok(0));
+}
+
+#[test]
+fn parse_estimate_err() {
+    for estimate in ["", "h", "m", "1h30", "2d", "-5m", "soon"] {
+        assert!(Task::parse_estimate(estimate).is_err(), "{estimate}");
+    }
+}
+
+#[test]
+fn estimate_to_string() {
+    assert_eq!(Task::format_estimate(45), "45m");
+    assert_eq!(Task::format_estimate(120), "2h");
+    assert_eq!(Task::format_estimate(150), "2h30m");
+}
+
+#[test]
+fn render() {
+    let mut task = Task::from("7,Deploy,high,false");
+    task.tags = vec![String::from("ops")];
+
+    assert_eq!(task.render(Task::DEFAULT_FORMAT), "7. Deploy");
+    assert_eq!(task.render("{id} [{priority}] {content} ({tags})"), "7 [high] Deploy (ops)");
+    assert_eq!(task.render("{ Status }: {CONTENT}"), "todo: Deploy");
+}
+
+#[test]
+fn render_literal_braces() {
+    let task = Task::from("1,Task,low,true");
+
+    assert_eq!(task.render("{{id}} {id}"), "{id} 1");
+    assert_eq!(task.render("{unknown} {id"), "{unknown} {id");
+    assert_eq!(task.render("}{status}"), "}done");
+}
+
 #[test]
 fn check_ok() {
     let mut task = fake_task_unchecked();
@@ -85,6 +295,38 @@ fn uncheck_err() {
     assert!(result.is_err());
 }
 
+#[test]
+fn pin_ok() {
+    let mut task = fake_task_unchecked();
+
+    assert!(task.pin().is_ok());
+    assert!(task.pinned);
+}
+
+#[test]
+fn pin_err() {
+    let mut task = fake_task_unchecked();
+    task.pinned = true;
+
+    assert!(task.pin().is_err());
+}
+
+#[test]
+fn unpin_ok() {
+    let mut task = fake_task_unchecked();
+    task.pinned = true;
+
+    assert!(task.unpin().is_ok());
+    assert!(!task.pinned);
+}
+
+#[test]
+fn unpin_err() {
+    let mut task = fake_task_unchecked();
+
+    assert!(task.unpin().is_err());
+}
+
 #[test]
 fn priority_as_str() {
     assert_eq!(Priority::Low.to_str(), "low");
@@ -92,3 +334,38 @@ fn priority_as_str() {
     assert_eq!(Priority::High.to_str(), "high");
     assert_eq!(Priority::None.to_str(), "none");
 }
+
+#[test]
+fn priority_order() {
+    assert!(Priority::High < Priority::Med);
+    assert!(Priority::Med < Priority::Low);
+    assert!(Priority::Low < Priority::None);
+}
+
+#[test]
+fn as_tsv() {
+    let task = Task::new(3, String::from("Tab\there\nand line"), Priority::Low, true);
+
+    assert_eq!(task.as_tsv(), "3\tTab here and line\tlow\ttrue");
+}
+
+#[test]
+fn from_with_timestamps() {
+    let line = "1,Test,med,true,,,,2025-01-31T18:30:00Z,,2025-02-01T09:00:00+02:00";
+    let task = Task::from(line);
+
+    assert_eq!(Task::format_timestamp(task.created_at.as_ref()), "2025-01-31T18:30:00Z");
+    assert_eq!(task.updated_at, None);
+    assert_eq!(Task::format_timestamp(task.completed_at.as_ref()), "2025-02-01T07:00:00Z");
+    assert_eq!(task.as_line(), "1,Test,med,true,,,,2025-01-31T18:30:00Z,,2025-02-01T07:00:00Z");
+}
+
+#[test]
+fn parse_date() {
+    let timestamp = Task::parse_date("2025-01-31T18:30:00Z");
+    let day = Task::parse_date("2025-01-31");
+
+    assert_eq!(Task::format_timestamp(timestamp.ok().as_ref()), "2025-01-31T18:30:00Z");
+    assert!(day.is_ok());
+    assert!(Task::parse_date("31/01/2025").is_err());
+}
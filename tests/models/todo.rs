@@ -1,4 +1,6 @@
-use postit::models::{Priority, Task, Todo};
+use chrono::{Duration, TimeZone as _, Utc};
+use postit::models::{GroupBy, Links, Priority, SortBy, Task, Todo};
+use postit::{Action, Output};
 
 #[test]
 fn new() {
@@ -32,6 +34,35 @@ fn get_mut() {
     assert_eq!(tasks, expect);
 }
 
+#[test]
+fn generate() {
+    let todo = Todo::generate(100, 42);
+    let ids: Vec<u32> = todo.tasks.iter().map(|task| task.id).collect();
+
+    assert_eq!(ids, (1..=100).collect::<Vec<u32>>());
+    assert!(todo.tasks.iter().any(|task| task.checked));
+    assert!(todo.tasks.iter().any(|task| !task.checked));
+    assert!(todo
+        .tasks
+        .iter()
+        .any(|task| task.priority == Priority::High));
+    assert!(todo
+        .tasks
+        .iter()
+        .any(|task| task.priority == Priority::None));
+}
+
+#[test]
+fn generate_same_seed() {
+    assert_eq!(Todo::generate(20, 7), Todo::generate(20, 7));
+    assert_ne!(Todo::generate(20, 7), Todo::generate(20, 8));
+}
+
+#[test]
+fn generate_empty() {
+    assert!(Todo::generate(0, 42).tasks.is_empty());
+}
+
 #[test]
 fn view_ok() {
     let todo = Todo::sample();
@@ -39,11 +70,32 @@ fn view_ok() {
     assert!(todo.view().is_ok());
 }
 
+#[test]
+fn view_as_markdown_ok() {
+    assert!(Todo::sample().view_as(&Output::Markdown).is_ok());
+}
+
 #[test]
 fn view_err() {
     assert!(Todo::new(&[]).view().is_err());
 }
 
+#[test]
+fn view_as_json_empty_ok() {
+    assert!(Todo::new(&[]).view_as(&Output::Json).is_ok());
+}
+
+#[test]
+fn as_json() -> postit::Result<()> {
+    let todo = Todo::new(&[Task::from("1,Task,high,false")]);
+
+    let result: Vec<Task> = serde_json::from_str(&todo.as_json()?).map_err(postit::Error::wrap)?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
 #[test]
 fn add_ok() {
     let mut todo = Todo::sample();
@@ -57,6 +109,47 @@ fn add_ok() {
     assert_eq!(todo, expect);
 }
 
+#[test]
+fn tidy() {
+    let mut todo = Todo::new(vec![
+        Task::from("1,A,low,true"),
+        Task::from("2,B,none,false"),
+        Task::from("4,C,high,false"),
+        Task::from("6,D,high,true"),
+        Task::from("7,E,none,false"),
+    ]);
+
+    todo.tidy();
+
+    let expect = Todo::new(vec![
+        Task::from("1,C,high,false"),
+        Task::from("2,B,none,false"),
+        Task::from("3,E,none,false"),
+        Task::from("4,D,high,true"),
+        Task::from("5,A,low,true"),
+    ]);
+
+    assert_eq!(todo, expect);
+}
+
+#[test]
+fn append() {
+    let mut todo = Todo::sample();
+
+    let ids = todo.append(vec![Task::from("1,A,high,true"), Task::from("1,B,low,false")]);
+
+    assert_eq!(ids, vec![5, 6]);
+    assert_eq!(todo.tasks[4], Task::from("5,A,high,true"));
+    assert_eq!(todo.tasks[5], Task::from("6,B,low,false"));
+}
+
+#[test]
+fn append_empty_list() {
+    let mut todo = Todo::new(vec![]);
+
+    assert_eq!(todo.append(vec![Task::from("9,A,none,false")]), vec![1]);
+}
+
 #[test]
 fn set_content_ok() -> postit::Result<()> {
     let ids = &[1];
@@ -79,6 +172,37 @@ fn set_content_err() {
     assert!(result.is_err());
 }
 
+#[test]
+fn set_refs() -> postit::Result<()> {
+    let refs = vec![String::from("gh#42")];
+    let mut todo = Todo::sample();
+    todo.set_refs(&[1, 3], &refs)?;
+
+    assert_eq!(todo.tasks[0].refs, refs);
+    assert!(todo.tasks[1].refs.is_empty());
+    assert_eq!(todo.tasks[2].refs, refs);
+
+    assert!(Todo::new(&[]).set_refs(&[1], &refs).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn set_tags() -> postit::Result<()> {
+    let tags = vec![String::from("work")];
+    let mut todo = Todo::sample();
+    todo.set_tags(&[2], &tags)?;
+
+    assert_eq!(todo.tasks[1].tags, tags);
+    assert!(todo.tasks[0].tags.is_empty());
+
+    todo.set_tags(&[2], &[])?;
+
+    assert!(todo.tasks[1].tags.is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn set_priority_ok() -> postit::Result<()> {
     let ids = &[1];
@@ -135,7 +259,7 @@ fn uncheck_ok() {
 #[test]
 fn uncheck_err() {
     let err = Todo::new(&[]).uncheck(&[1]).unwrap_err();
-    assert!(matches!(err, postit::Error::Other(_)));
+    assert!(matches!(err, postit::Error::NoTasks("uncheck")));
 }
 
 #[test]
@@ -160,3 +284,338 @@ fn drop_ok() -> postit::Result<()> {
 fn drop_err() {
     assert!(Todo::new(&[]).drop(&[1]).is_err());
 }
+
+#[test]
+fn stamp() {
+    let mut todo = Todo::sample();
+
+    todo.stamp(&[1], &Action::Check);
+    todo.stamp(&[2], &Action::Drop);
+
+    let tasks = todo.get(&[1, 2]);
+
+    assert!(tasks[0].updated_at.is_some());
+    assert_eq!(tasks[0].completed_at, tasks[0].updated_at);
+    assert_eq!(tasks[1].updated_at, None);
+
+    todo.stamp(&[1], &Action::Uncheck);
+
+    assert!(todo.get(&[1])[0].completed_at.is_none());
+}
+
+#[test]
+fn sort_by() {
+    let mut todo = Todo::new(vec![
+        Task::from("1,b task,low,true,,,,2025-01-02T00:00:00Z"),
+        Task::from("2,C task,high,false"),
+        Task::from("3,a task,med,false,,,,2025-01-01T00:00:00Z"),
+    ]);
+
+    let ids = |todo: &Todo| -> Vec<u32> { todo.tasks.iter().map(|task| task.id).collect() };
+
+    todo.sort_by(&SortBy::Content, false);
+    assert_eq!(ids(&todo), [3, 1, 2]);
+
+    todo.sort_by(&SortBy::Priority, false);
+    assert_eq!(ids(&todo), [2, 3, 1]);
+
+    todo.sort_by(&SortBy::Created, false);
+    assert_eq!(ids(&todo), [3, 1, 2]);
+
+    todo.sort_by(&SortBy::Id, true);
+    assert_eq!(ids(&todo), [3, 2, 1]);
+}
+
+#[test]
+fn sort_by_due_keeps_undated_last() {
+    let mut todo = Todo::new(vec![
+        Task::from("1,No date,high,false"),
+        Task::from("2,Later,low,false"),
+        Task::from("3,Sooner,med,false"),
+    ]);
+    todo.tasks[1].due = Some(Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap());
+    todo.tasks[2].due = Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+
+    let ids = |todo: &Todo| -> Vec<u32> { todo.tasks.iter().map(|task| task.id).collect() };
+
+    todo.sort_by(&SortBy::Due, false);
+    assert_eq!(ids(&todo), [3, 2, 1]);
+
+    todo.sort_by(&SortBy::Due, true);
+    assert_eq!(ids(&todo), [2, 3, 1]);
+
+    todo.sort_by(&SortBy::Created, true);
+    assert_eq!(ids(&todo), [2, 3, 1]);
+}
+
+#[test]
+fn sort_by_keeps_pinned_first() {
+    let mut todo = Todo::new(vec![
+        Task::from("1,b task,low,false"),
+        Task::from("2,c task,high,false,,,,,,,,,,true"),
+        Task::from("3,a task,med,false"),
+    ]);
+
+    let ids = |todo: &Todo| -> Vec<u32> { todo.tasks.iter().map(|task| task.id).collect() };
+
+    todo.sort_by(&SortBy::Content, false);
+    assert_eq!(ids(&todo), [2, 3, 1]);
+
+    todo.sort_by(&SortBy::Priority, true);
+    assert_eq!(ids(&todo), [2, 1, 3]);
+}
+
+#[test]
+fn render_with_pinned_first() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    todo.pin(&[3])?;
+
+    let csv = todo.render_with(&Output::Csv, &Links::default())?;
+    let ids: Vec<&str> = csv
+        .lines()
+        .filter_map(|line| line.split(',').next())
+        .collect();
+
+    assert_eq!(ids, ["3", "1", "2", "4"]);
+    assert_eq!(todo.tasks[2].id, 3);
+
+    Ok(())
+}
+
+#[test]
+fn truncate_keeps_pinned_first() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    todo.pin(&[3])?;
+    todo.truncate(2);
+
+    let ids: Vec<u32> = todo.tasks.iter().map(|task| task.id).collect();
+
+    assert_eq!(ids, [1, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn from_stream_keeps_pinned_first() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    todo.pin(&[3])?;
+
+    let stream = todo.tasks.clone().into_iter().map(Ok);
+    let result = Todo::from_stream(stream, Some(2))?;
+
+    todo.truncate(2);
+
+    assert_eq!(result, todo);
+    assert_eq!(Todo::from_stream(std::iter::empty(), Some(2))?.tasks, []);
+
+    Ok(())
+}
+
+#[test]
+fn from_stream_err() {
+    let stream = [Ok(Task::from("1,Task,med,false")), Err(postit::Error::wrap("Broken"))];
+
+    assert!(Todo::from_stream(stream.into_iter(), None).is_err());
+}
+
+#[test]
+fn missing() {
+    let todo = Todo::sample();
+
+    assert_eq!(todo.missing(&[1, 7, 9, 4, 7]), [7, 9]);
+    assert!(todo.missing(&[2, 3]).is_empty());
+}
+
+#[test]
+fn pin_and_unpin() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+
+    assert_eq!(todo.pin(&[3, 4])?, [3, 4]);
+    assert_eq!(todo.pin(&[3])?, Vec::<u32>::new());
+    assert_eq!(todo.unpin(&[1, 4])?, [4]);
+
+    let pinned: Vec<u32> = todo
+        .tasks
+        .iter()
+        .filter(|task| task.pinned)
+        .map(|task| task.id)
+        .collect();
+
+    assert_eq!(pinned, [3]);
+    assert!(Todo::new(&[]).pin(&[1]).is_err());
+    assert!(Todo::new(&[]).unpin(&[1]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn grouped() {
+    let todo = Todo::new(vec![
+        Task::from("1,Task,low,false"),
+        Task::from("2,Task,high,true"),
+        Task::from("3,Task,low,true"),
+    ]);
+
+    let groups = |by: &GroupBy| -> Vec<(String, Vec<u32>)> {
+        todo.grouped(by)
+            .into_iter()
+            .map(|(label, group)| (label, group.tasks.iter().map(|task| task.id).collect()))
+            .collect()
+    };
+
+    let by_priority = vec![(String::from("high"), vec![2]), (String::from("low"), vec![1, 3])];
+    let by_status = vec![(String::from("todo"), vec![1]), (String::from("done"), vec![2, 3])];
+
+    assert_eq!(groups(&GroupBy::Priority), by_priority);
+    assert_eq!(groups(&GroupBy::Status), by_status);
+}
+
+#[test]
+fn agenda() {
+    let mut todo = Todo::new(vec![
+        Task::from("1,Task,low,false"),
+        Task::from("2,Task,high,false"),
+        Task::from("3,Task,low,false"),
+        Task::from("4,Task,med,true"),
+        Task::from("5,Task,none,false"),
+        Task::from("6,Task,none,false"),
+    ]);
+
+    let now = Utc::now();
+    todo.tasks[1].due = Some(now + Duration::days(30));
+    todo.tasks[2].due = Some(now - Duration::days(2));
+    todo.tasks[3].due = Some(now);
+    todo.tasks[4].due = Some(now + Duration::days(3));
+    todo.tasks[5].due = Some(now + Duration::days(1));
+
+    let groups = |week: bool| -> Vec<(String, Vec<u32>)> {
+        todo.agenda(week)
+            .into_iter()
+            .map(|(label, group)| (label, group.tasks.iter().map(|task| task.id).collect()))
+            .collect()
+    };
+
+    let expect = vec![
+        (String::from("overdue"), vec![3]),
+        (String::from("tomorrow"), vec![6]),
+        (String::from("this week"), vec![5]),
+        (String::from("later"), vec![2]),
+        (String::from("no date"), vec![1]),
+    ];
+
+    assert_eq!(groups(false), expect);
+    assert_eq!(groups(true), expect[..3]);
+}
+
+#[test]
+fn grouped_by_tag() {
+    let mut todo = Todo::sample();
+    todo.tasks[0].tags = vec![String::from("work"), String::from("urgent")];
+    todo.tasks[2].tags = vec![String::from("work")];
+
+    let result: Vec<(String, usize)> = todo
+        .grouped(&GroupBy::Tag)
+        .into_iter()
+        .map(|(label, group)| (label, group.tasks.len()))
+        .collect();
+
+    let expect =
+        vec![(String::from("urgent"), 1), (String::from("work"), 2), (String::from("untagged"), 2)];
+
+    assert_eq!(result, expect);
+}
+
+#[test]
+fn move_to() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+
+    let ids = |todo: &Todo| -> Vec<u32> { todo.tasks.iter().map(|task| task.id).collect() };
+
+    assert_eq!(todo.move_to(3, 0)?, vec![3, 1, 2, 4]);
+    assert_eq!(ids(&todo), vec![3, 1, 2, 4]);
+
+    assert_eq!(todo.move_to(3, 99)?, vec![1, 2, 4, 3]);
+    assert_eq!(ids(&todo), vec![1, 2, 4, 3]);
+
+    assert!(todo.move_to(9, 0).is_err());
+
+    // The manual order is kept when the tasks are loaded again
+    let mut tasks = todo.tasks.clone();
+    tasks.reverse();
+    assert_eq!(ids(&Todo::new(tasks)), vec![1, 2, 4, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn move_next_to() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+
+    let ids = |todo: &Todo| -> Vec<u32> { todo.tasks.iter().map(|task| task.id).collect() };
+
+    todo.move_next_to(4, 2, false)?;
+    assert_eq!(ids(&todo), vec![1, 4, 2, 3]);
+
+    todo.move_next_to(1, 2, true)?;
+    assert_eq!(ids(&todo), vec![4, 2, 1, 3]);
+
+    assert!(todo.move_next_to(1, 1, true).is_err());
+    assert!(todo.move_next_to(1, 9, true).is_err());
+
+    todo.tidy();
+    assert!(todo.tasks.iter().all(|task| task.order.is_none()));
+
+    Ok(())
+}
+
+#[test]
+fn snooze() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let until = Utc::now() + Duration::days(1);
+
+    todo.snooze(&[1, 2], Some(until))?;
+    assert_eq!(todo.get(&[1])[0].snoozed_until, Some(until));
+
+    let ids: Vec<u32> = todo.awake().tasks.iter().map(|task| task.id).collect();
+    assert_eq!(ids, [3, 4]);
+
+    todo.snooze(&[1, 2], None)?;
+    assert_eq!(todo.awake().tasks.len(), 4);
+
+    assert!(Todo::new(vec![]).snooze(&[1], Some(until)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn set_estimate() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+
+    todo.set_estimate(&[1, 2], 30)?;
+    assert_eq!(todo.get(&[1])[0].estimate, Some(30));
+    assert_eq!(todo.get(&[2])[0].estimate, Some(30));
+
+    todo.set_estimate(&[1], 0)?;
+    assert_eq!(todo.get(&[1])[0].estimate, None);
+
+    assert!(Todo::new(vec![]).set_estimate(&[1], 30).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn set_due() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let due = Task::parse_timestamp("2025-01-31T18:30:00Z");
+
+    todo.set_due(&[1, 2], due)?;
+    assert_eq!(todo.get(&[1])[0].due, due);
+    assert_eq!(todo.get(&[2])[0].due, due);
+
+    todo.set_due(&[1], None)?;
+    assert_eq!(todo.get(&[1])[0].due, None);
+
+    assert!(Todo::new(vec![]).set_due(&[1], due).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use chrono::{Duration, Utc};
+use postit::models::{Task, Todo, Urgency};
+
+#[test]
+fn score_by_priority() {
+    let urgency = Urgency::default();
+
+    assert_eq!(urgency.score(&Task::from("1,Task,high,false")), 6);
+    assert_eq!(urgency.score(&Task::from("1,Task,med,false")), 4);
+    assert_eq!(urgency.score(&Task::from("1,Task,low,false")), 2);
+    assert_eq!(urgency.score(&Task::from("1,Task,none,false")), 0);
+}
+
+#[test]
+fn score_checked_is_zero() {
+    let urgency = Urgency::default();
+
+    assert_eq!(urgency.score(&Task::from("1,Task,high,true")), 0);
+}
+
+#[test]
+fn score_overdue() {
+    let urgency = Urgency::default();
+
+    let mut task = Task::from("1,Task,none,false");
+    task.due = Some(Utc::now() - Duration::days(3));
+
+    assert_eq!(urgency.score(&task), 12);
+
+    task.checked = true;
+
+    assert_eq!(urgency.score(&task), 0);
+}
+
+#[test]
+fn score_due_soon() {
+    let urgency = Urgency::default();
+
+    let mut soon = Task::from("1,Task,low,false");
+    soon.due = Some(Utc::now() + Duration::days(7));
+
+    let mut later = Task::from("2,Task,low,false");
+    later.due = Some(Utc::now() + Duration::days(30));
+
+    assert_eq!(urgency.score(&soon), 2 + 6);
+    assert_eq!(urgency.score(&later), 2);
+}
+
+#[test]
+fn score_old_task() {
+    let urgency = Urgency { age: 4, ..Urgency::default() };
+
+    let mut old = Task::from("1,Task,med,false");
+    old.created_at = Some(Utc::now() - Duration::days(800));
+
+    let mut half = Task::from("2,Task,med,false");
+    half.created_at = Some(Utc::now() - Duration::days(183));
+
+    let mut new = Task::from("3,Task,med,false");
+    new.created_at = Some(Utc::now());
+
+    assert_eq!(urgency.score(&old), 4 + 4);
+    assert_eq!(urgency.score(&half), 4 + 2);
+    assert_eq!(urgency.score(&new), 4);
+}
+
+#[test]
+fn fmt_display() {
+    let urgency = Urgency {
+        high: 1,
+        med: 2,
+        low: 3,
+        none: 4,
+        due: 5,
+        age: 6,
+    };
+
+    assert_eq!(urgency.to_string(), "high=1 med=2 low=3 none=4 due=5 age=6");
+}
+
+#[test]
+fn todo_next() {
+    let todo = Todo::new(vec![
+        Task::from("1,Low,low,false"),
+        Task::from("2,High,high,true"),
+        Task::from("3,Med,med,false"),
+        Task::from("4,Other med,med,false"),
+    ]);
+
+    let result: Vec<u32> = todo
+        .next(&Urgency::default(), 2)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+
+    assert_eq!(result, vec![3, 4]);
+}
+
+#[test]
+fn todo_next_custom_weights() {
+    let todo = Todo::sample();
+    let urgency = Urgency { high: 0, med: 1, ..Urgency::default() };
+
+    let result: Vec<u32> = todo.next(&urgency, 5).iter().map(|t| t.id).collect();
+
+    assert_eq!(result, vec![2, 1]);
+}
+
+#[test]
+fn todo_next_overdue_first() {
+    let mut overdue = Task::from("2,Overdue,low,false");
+    overdue.due = Some(Utc::now() - Duration::days(1));
+
+    let todo = Todo::new(vec![Task::from("1,High,high,false"), overdue]);
+
+    let result: Vec<u32> = todo
+        .next(&Urgency::default(), 2)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+
+    assert_eq!(result, vec![2, 1]);
+}
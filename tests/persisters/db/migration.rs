@@ -0,0 +1,26 @@
+use postit::db::Migration;
+
+#[test]
+fn latest() {
+    assert_eq!(Migration::latest(), Migration::ALL.len() as u32);
+}
+
+#[test]
+fn pending() {
+    let result: Vec<&str> = Migration::pending(9)
+        .iter()
+        .map(|migration| migration.column)
+        .collect();
+
+    assert_eq!(result, vec!["pinned", "attachments", "due"]);
+}
+
+#[test]
+fn pending_unversioned() {
+    assert_eq!(Migration::pending(0), Migration::ALL.to_vec());
+}
+
+#[test]
+fn pending_latest() {
+    assert!(Migration::pending(Migration::latest()).is_empty());
+}
@@ -1,3 +1,13 @@
+#[cfg(feature = "libsql")]
+pub mod libsql;
+pub mod migration;
+#[cfg(feature = "mongo")]
 pub mod mongo;
+#[cfg(all(feature = "sqlite", feature = "mongo"))]
 pub mod orm;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "redis")]
+pub mod redis;
+#[cfg(feature = "sqlite")]
 pub mod sqlite;
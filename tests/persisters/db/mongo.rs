@@ -1,10 +1,9 @@
 use postit::db::{Mongo, Protocol};
-use postit::models::Todo;
+use postit::models::{Task, Todo};
+use postit::testing::{MockConfig, MockConn};
 use postit::traits::DbPersister;
 use postit::Action;
 
-use crate::mocks::MockConn;
-
 #[test]
 fn clone() -> postit::Result<()> {
     let mock = MockConn::create(Protocol::Mongo)?;
@@ -17,6 +16,28 @@ fn clone() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn database_from_uri() -> postit::Result<()> {
+    let mongo = Mongo::from("mongodb://localhost:27017/postit")?;
+
+    assert_eq!(mongo.database(), "postit");
+
+    Ok(())
+}
+
+#[test]
+fn database_from_config() -> postit::Result<()> {
+    let mut mock = MockConfig::new()?;
+    mock.config.database = String::from("my_tasks");
+    mock.save()?;
+
+    let mongo = Mongo::from("mongodb://localhost:27017")?;
+
+    assert_eq!(mongo.database(), "my_tasks");
+
+    Ok(())
+}
+
 #[test]
 fn count_ok() -> postit::Result<()> {
     let mock = MockConn::create(Protocol::Mongo)?;
@@ -245,7 +266,7 @@ fn tasks_err() -> postit::Result<()> {
     let mock = MockConn::create(Protocol::Mongo)?;
     mock.instance.drop_table()?;
 
-    assert!(matches!(mock.instance.tasks().unwrap_err(), postit::db::Error::Other(_)));
+    assert!(matches!(mock.instance.tasks().unwrap_err(), postit::db::Error::TableDoesntExist(_)));
 
     Ok(())
 }
@@ -266,3 +287,61 @@ fn clean() -> postit::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn replace() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Mongo)?;
+
+    let mongo = Mongo::from(mock.conn())?;
+    mongo.insert(&Todo::sample())?;
+
+    let todo = Todo::new(vec![Task::from("1,Replaced,low,false")]);
+    mongo.replace(&todo)?;
+
+    assert_eq!(mongo.tasks()?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn insert_empty() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Mongo)?;
+
+    let mongo = Mongo::from(mock.conn())?;
+
+    assert!(mongo.insert(&Todo::new(vec![])).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn atomic_err_aborts() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Mongo)?;
+
+    let mongo = Mongo::from(mock.conn())?;
+    mongo.insert(&Todo::sample())?;
+
+    let result = mongo.atomic(|_| Err(postit::db::Error::wrap("Failed on purpose")));
+
+    assert!(result.is_err());
+    assert_eq!(mongo.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn next_id_counter() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Mongo)?;
+
+    let mongo = Mongo::from(mock.conn())?;
+    mongo.insert(&Todo::sample())?;
+
+    assert_eq!(mongo.next_id()?, 5);
+    assert_eq!(mongo.next_id()?, 6);
+
+    mongo.clean()?;
+
+    assert_eq!(mongo.next_id()?, 1);
+
+    Ok(())
+}
@@ -3,11 +3,10 @@ use std::ops::Not;
 use postit::config::Config;
 use postit::db::{Mongo, Orm, Protocol};
 use postit::models::{Task, Todo};
+use postit::testing::{MockConfig, MockConn};
 use postit::traits::{DbPersister, Persister};
 use postit::Action;
 
-use crate::mocks::{MockConfig, MockConn};
-
 #[test]
 fn error_wrap() {
     let msg = "Error";
@@ -16,12 +15,24 @@ fn error_wrap() {
     assert!(matches!(err, postit::db::Error::Other(_)));
 }
 
+#[test]
+fn error_code() {
+    assert_eq!(postit::db::Error::UnsupportedDatabase.code(), "E301");
+    assert_eq!(postit::db::Error::FeatureDisabled("mongo").code(), "E303");
+    assert_eq!(postit::db::Error::TableDoesntExist(String::new()).code(), "E309");
+    assert_eq!(postit::db::Error::wrap("Error").code(), "E399");
+}
+
 #[test]
 fn protocol_from() {
     assert_eq!(Protocol::from("file"), Protocol::Sqlite);
     assert_eq!(Protocol::from("sqlite"), Protocol::Sqlite);
     assert_eq!(Protocol::from("mongodb"), Protocol::Mongo);
     assert_eq!(Protocol::from("mongodb+srv"), Protocol::MongoSrv);
+    assert_eq!(Protocol::from("postgres"), Protocol::Postgres);
+    assert_eq!(Protocol::from("postgresql"), Protocol::Postgres);
+    assert_eq!(Protocol::from("redis"), Protocol::Redis);
+    assert_eq!(Protocol::from("libsql"), Protocol::Libsql);
 }
 
 #[test]
@@ -29,6 +40,9 @@ fn protocol_to_str() {
     assert_eq!(Protocol::Sqlite.to_str(), "sqlite");
     assert_eq!(Protocol::Mongo.to_str(), "mongo");
     assert_eq!(Protocol::MongoSrv.to_str(), "mongo+srv");
+    assert_eq!(Protocol::Postgres.to_str(), "postgres");
+    assert_eq!(Protocol::Redis.to_str(), "redis");
+    assert_eq!(Protocol::Libsql.to_str(), "libsql");
 }
 
 #[test]
@@ -36,6 +50,9 @@ fn display() {
     assert_eq!(Protocol::Sqlite.to_string(), "sqlite");
     assert_eq!(Protocol::Mongo.to_string(), "mongo");
     assert_eq!(Protocol::MongoSrv.to_string(), "mongo+srv");
+    assert_eq!(Protocol::Postgres.to_string(), "postgres");
+    assert_eq!(Protocol::Redis.to_string(), "redis");
+    assert_eq!(Protocol::Libsql.to_string(), "libsql");
 }
 
 #[test]
@@ -55,7 +72,7 @@ fn orm_fmt_debug() -> postit::Result<()> {
 
 #[test]
 fn is_sqlite() {
-    assert!(Orm::is_sqlite(":memory:"));
+    assert!(!Orm::is_sqlite(":memory:"));
     assert!(Orm::is_sqlite("sqlite:///tasks.db"));
     assert!(Orm::is_sqlite("test.db"));
     assert!(Orm::is_sqlite("test.sqlite"));
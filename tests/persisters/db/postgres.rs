@@ -0,0 +1,285 @@
+use postit::db::{Postgres, Protocol};
+use postit::models::Todo;
+use postit::testing::MockConn;
+use postit::traits::DbPersister;
+use postit::Action;
+
+#[test]
+fn fmt_debug() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    let postgres = Postgres::from(mock.conn())?;
+
+    let result = format!("{:?}", postgres);
+    let expect = format!(
+        "Postgres {{ conn_str: {:?}, connection: \"[connection omitted]\" }}",
+        postgres.conn()
+    );
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn clone() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+
+    let expect = Postgres::from(mock.conn())?;
+    let result = expect.clone();
+
+    assert_eq!(result.conn(), expect.conn());
+
+    Ok(())
+}
+
+#[test]
+fn count_ok() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&Todo::sample())?;
+
+    assert_eq!(Postgres::from(mock.conn())?.count()?, 4);
+
+    Ok(())
+}
+
+#[test]
+fn count_table_doesnt_exist() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.drop_table()?;
+
+    assert_eq!(Postgres::from(mock.conn())?.count()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn exists() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    let postgres = Postgres::from(mock.conn())?;
+
+    assert!(postgres.exists().is_ok_and(|bool| bool));
+
+    Ok(())
+}
+
+#[test]
+fn format_ids() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+
+    let ids = vec![1, 2, 3];
+
+    let result = Postgres::from(mock.conn())?.format_ids(&ids);
+    let expect = vec![1_i64, 2, 3];
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn database() -> postit::Result<()> {
+    let mock = MockConn::postgres()?;
+
+    assert_eq!(mock.instance.database(), "postgres");
+
+    Ok(())
+}
+
+#[test]
+fn boxed() -> postit::Result<()> {
+    let mock = MockConn::postgres()?;
+
+    let postgres = Postgres::from(mock.conn())?;
+    let result = postgres.clone().boxed();
+
+    assert_eq!(result.conn(), postgres.conn());
+
+    Ok(())
+}
+
+#[test]
+fn create() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.create()?;
+
+    let postgres = Postgres::from(mock.conn())?;
+
+    assert!(postgres.exists().is_ok_and(|bool| bool));
+
+    Ok(())
+}
+
+#[test]
+fn insert_and_tasks() -> postit::Result<()> {
+    let todo = Todo::sample();
+
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&todo)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_check() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![2, 3];
+
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::Check)?;
+
+    todo.check(&ids)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_uncheck() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![2, 3];
+
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::Uncheck)?;
+
+    todo.uncheck(&ids)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_set_content() -> postit::Result<()> {
+    let ids = vec![2, 3];
+
+    let mut todo = Todo::sample();
+    todo.set_content(&ids, "test")?;
+
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::SetContent)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_set_priority() -> postit::Result<()> {
+    let ids = vec![2, 3];
+
+    let mut todo = Todo::sample();
+    todo.set_priority(&ids, &postit::models::Priority::High)?;
+
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::SetPriority)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_delete() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![2, 3];
+
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::Drop)?;
+
+    todo.check(&ids)?;
+    todo.drop(&ids)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn drop_table() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.drop_table()?;
+
+    assert!(mock.instance.exists().is_ok_and(|bool| !bool));
+
+    Ok(())
+}
+
+#[test]
+fn tasks_ok() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    let todo = Todo::sample();
+
+    let postgres = Postgres::from(mock.conn())?;
+    postgres.insert(&todo)?;
+
+    assert_eq!(todo.tasks, postgres.tasks()?);
+
+    Ok(())
+}
+
+#[test]
+fn tasks_err() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    mock.instance.drop_table()?;
+
+    assert!(mock.instance.tasks().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn clean() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    let todo = Todo::sample();
+
+    let postgres = Postgres::from(mock.conn())?;
+    postgres.insert(&todo)?;
+    postgres.clean()?;
+
+    let result = postgres.tasks()?;
+    let expect = Vec::new();
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn clean_restarts_ids() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Postgres)?;
+    let todo = Todo::sample();
+    let task = Todo::new(&todo.tasks[0]);
+
+    let postgres = Postgres::from(mock.conn())?;
+
+    postgres.insert(&todo)?;
+    postgres.clean()?;
+    postgres.insert(&task)?;
+
+    let result = postgres.tasks()?[0].id;
+    let expect = 1;
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
@@ -0,0 +1,279 @@
+use postit::db::{Protocol, Redis};
+use postit::models::Todo;
+use postit::testing::MockConn;
+use postit::traits::DbPersister;
+use postit::Action;
+
+#[test]
+fn fmt_debug() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    let redis = Redis::from(mock.conn())?;
+
+    let result = format!("{:?}", redis);
+    let expect =
+        format!("Redis {{ conn_str: {:?}, connection: \"[connection omitted]\" }}", redis.conn());
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn clone() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+
+    let expect = Redis::from(mock.conn())?;
+    let result = expect.clone();
+
+    assert_eq!(result.conn(), expect.conn());
+
+    Ok(())
+}
+
+#[test]
+fn count_ok() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&Todo::sample())?;
+
+    assert_eq!(Redis::from(mock.conn())?.count()?, 4);
+
+    Ok(())
+}
+
+#[test]
+fn count_table_doesnt_exist() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.drop_table()?;
+
+    assert_eq!(Redis::from(mock.conn())?.count()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn exists() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    let redis = Redis::from(mock.conn())?;
+
+    assert!(redis.exists().is_ok_and(|bool| bool));
+
+    Ok(())
+}
+
+#[test]
+fn keys() -> postit::Result<()> {
+    let mock = MockConn::redis()?;
+    let redis = Redis::from(mock.conn())?;
+
+    assert_eq!(redis.tasks_key(), "postit:tasks");
+    assert_eq!(redis.ids_key(), "postit:tasks:ids");
+    assert_eq!(redis.tables_key(), "postit:tables");
+
+    Ok(())
+}
+
+#[test]
+fn database() -> postit::Result<()> {
+    let mock = MockConn::redis()?;
+
+    assert_eq!(mock.instance.database(), "0");
+
+    Ok(())
+}
+
+#[test]
+fn boxed() -> postit::Result<()> {
+    let mock = MockConn::redis()?;
+
+    let redis = Redis::from(mock.conn())?;
+    let result = redis.clone().boxed();
+
+    assert_eq!(result.conn(), redis.conn());
+
+    Ok(())
+}
+
+#[test]
+fn create() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.create()?;
+
+    let redis = Redis::from(mock.conn())?;
+
+    assert!(redis.exists().is_ok_and(|bool| bool));
+
+    Ok(())
+}
+
+#[test]
+fn insert_and_tasks() -> postit::Result<()> {
+    let todo = Todo::sample();
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_check() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![2, 3];
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::Check)?;
+
+    todo.check(&ids)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_uncheck() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![2, 3];
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::Uncheck)?;
+
+    todo.uncheck(&ids)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_set_content() -> postit::Result<()> {
+    let ids = vec![2, 3];
+
+    let mut todo = Todo::sample();
+    todo.set_content(&ids, "test")?;
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::SetContent)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_set_priority() -> postit::Result<()> {
+    let ids = vec![2, 3];
+
+    let mut todo = Todo::sample();
+    todo.set_priority(&ids, &postit::models::Priority::High)?;
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::SetPriority)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn update_delete() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![2, 3];
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::Drop)?;
+
+    todo.check(&ids)?;
+    todo.drop(&ids)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn drop_table() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.drop_table()?;
+
+    assert!(mock.instance.exists().is_ok_and(|bool| !bool));
+
+    Ok(())
+}
+
+#[test]
+fn tasks_ok() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    let todo = Todo::sample();
+
+    let redis = Redis::from(mock.conn())?;
+    redis.insert(&todo)?;
+
+    assert_eq!(todo.tasks, redis.tasks()?);
+
+    Ok(())
+}
+
+#[test]
+fn tasks_err() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.drop_table()?;
+
+    assert!(mock.instance.tasks().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn clean() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Redis)?;
+    let todo = Todo::sample();
+
+    let redis = Redis::from(mock.conn())?;
+    redis.insert(&todo)?;
+    redis.clean()?;
+
+    let result = redis.tasks()?;
+    let expect = Vec::new();
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn update_set_tags() -> postit::Result<()> {
+    let ids = vec![1, 4];
+
+    let mut todo = Todo::sample();
+    todo.set_tags(&ids, &[String::from("work")])?;
+
+    let mock = MockConn::create(Protocol::Redis)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::SetTags)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
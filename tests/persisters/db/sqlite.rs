@@ -2,13 +2,12 @@ use std::ops::Not;
 use std::path::PathBuf;
 
 use postit::config::Config;
-use postit::db::{Protocol, Sqlite};
-use postit::models::Todo;
+use postit::db::{Migration, Protocol, Sqlite};
+use postit::models::{Priority, Task, Todo};
+use postit::testing::MockConn;
 use postit::traits::DbPersister;
 use postit::Action;
 
-use crate::mocks::MockConn;
-
 #[test]
 fn fmt_debug() -> postit::Result<()> {
     let mock = MockConn::create(Protocol::Sqlite)?;
@@ -75,7 +74,7 @@ fn format_ids() -> postit::Result<()> {
     let ids = vec![1, 2, 3];
 
     let result = Sqlite::from(mock.conn())?.format_ids(&ids);
-    let expect = "1, 2, 3";
+    let expect = "?, ?, ?";
 
     assert_eq!(result, expect);
 
@@ -154,6 +153,23 @@ fn insert_and_tasks() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn insert_and_tasks_content_with_commas() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    todo.tasks[0].content = String::from("Buy milk, eggs");
+    todo.tasks[0].priority = Priority::High;
+    todo.tasks[0].tags = vec![String::from("shop")];
+
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.insert(&todo)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
 #[test]
 fn update_check() -> postit::Result<()> {
     let mut todo = Todo::sample();
@@ -208,6 +224,24 @@ fn update_set_content() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn update_set_content_with_quotes() -> postit::Result<()> {
+    let ids = vec![1];
+
+    let mut todo = Todo::sample();
+    todo.set_content(&ids, "Don't \"quote\" me'); DROP TABLE tasks; --")?;
+
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.update(&todo, &ids, &Action::SetContent)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+
+    Ok(())
+}
+
 #[test]
 fn update_set_priority() -> postit::Result<()> {
     let ids = vec![2, 3];
@@ -226,6 +260,29 @@ fn update_set_priority() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn update_pin_keeps_completed_at() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![1, 3];
+
+    todo.tasks[0].completed_at = None;
+    todo.tasks[2].completed_at = Task::parse_timestamp("2025-01-01T00:00:00Z");
+
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.insert(&todo)?;
+
+    todo.pin(&ids)?;
+    todo.stamp(&ids, &Action::Pin);
+    mock.instance.update(&todo, &ids, &Action::Pin)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result, todo.tasks);
+    assert!(result[2].completed_at.is_some());
+
+    Ok(())
+}
+
 #[test]
 fn update_delete() -> postit::Result<()> {
     let mut todo = Todo::sample();
@@ -305,3 +362,139 @@ fn clean() -> postit::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn replace() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    let todo = Todo::sample();
+
+    let sqlite = Sqlite::from(mock.conn())?;
+    sqlite.insert(&todo)?;
+
+    let expect = Todo::new(todo.tasks[1..].to_vec());
+    sqlite.replace(&expect)?;
+
+    assert_eq!(sqlite.count()?, 3);
+    assert_eq!(sqlite.tasks()?, expect.tasks);
+    assert_eq!(sqlite.next_id()?, 5);
+
+    Ok(())
+}
+
+#[test]
+fn atomic_rolls_back() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    let todo = Todo::sample();
+
+    let sqlite = Sqlite::from(mock.conn())?;
+    sqlite.insert(&todo)?;
+
+    let result = sqlite.atomic(|| {
+        sqlite.clean()?;
+        Err(postit::db::Error::UnsupportedDatabase)
+    });
+
+    assert!(result.is_err());
+    assert_eq!(sqlite.tasks()?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn create_records_version() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.create()?;
+
+    assert_eq!(mock.instance.version()?, Migration::latest());
+
+    Ok(())
+}
+
+#[test]
+fn migrate() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.create()?;
+
+    let sqlite = Sqlite::from(mock.conn())?;
+    sqlite.set_version(3)?;
+    sqlite.migrate()?;
+
+    assert_eq!(sqlite.version()?, Migration::latest());
+
+    Ok(())
+}
+
+#[test]
+fn update_set_tags_and_notes() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    let ids = vec![1, 2];
+
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.insert(&todo)?;
+
+    todo.set_tags(&ids, &[String::from("work"), String::from("urgent")])?;
+    mock.instance.update(&todo, &ids, &Action::SetTags)?;
+
+    todo.tasks[1].notes = String::from("See the ticket, line 2");
+    mock.instance.update(&todo, &[2], &Action::SetNotes)?;
+
+    let result = mock.instance.tasks()?;
+
+    assert_eq!(result[0].tags, ["work", "urgent"]);
+    assert_eq!(result[1].notes, "See the ticket, line 2");
+    assert!(result[2].tags.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn delete_cascades_tags_and_notes() -> postit::Result<()> {
+    let mut todo = Todo::sample();
+    todo.tasks[0].tags = vec![String::from("shop")];
+    todo.tasks[0].notes = String::from("Milk");
+
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.insert(&todo)?;
+    mock.instance.delete(&[1])?;
+
+    let connection = sqlite::open(mock.instance.conn()).map_err(postit::Error::wrap)?;
+    let count = |table: &str| -> postit::Result<i64> {
+        let mut stmt = connection
+            .prepare(format!("SELECT COUNT(*) AS count FROM {table}"))
+            .map_err(postit::Error::wrap)?;
+        stmt.next().map_err(postit::Error::wrap)?;
+
+        stmt.read::<i64, _>("count").map_err(postit::Error::wrap)
+    };
+
+    assert_eq!(count("tags")?, 0);
+    assert_eq!(count("notes")?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_moves_tags_and_notes() -> postit::Result<()> {
+    let mock = MockConn::create(Protocol::Sqlite)?;
+    mock.instance.create()?;
+
+    let connection = sqlite::open(mock.instance.conn()).map_err(postit::Error::wrap)?;
+    connection
+        .execute(
+            "DROP TABLE tags; DROP TABLE notes;
+             INSERT INTO tasks (id, content, priority, checked, tags, notes)
+             VALUES (1, 'Buy milk', 'med', 0, 'shop home', 'Two, or three')",
+        )
+        .map_err(postit::Error::wrap)?;
+
+    let sqlite = Sqlite::from(mock.conn())?;
+    sqlite.migrate()?;
+
+    let task = sqlite.tasks()?.remove(0);
+
+    assert!(sqlite.has_table("tags")?);
+    assert_eq!(task.tags, ["shop", "home"]);
+    assert_eq!(task.notes, "Two, or three");
+
+    Ok(())
+}
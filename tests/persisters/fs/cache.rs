@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::Path;
+
+use postit::fs::{Cache, File, Format};
+use postit::models::{Task, Todo};
+use postit::testing::MockPath;
+use postit::traits::Persister;
+
+#[test]
+fn path_of() {
+    let path = Path::new("/tmp/tasks.xml");
+
+    assert_eq!(Cache::path_of(path), Path::new("/tmp/.tasks.xml.cache"));
+}
+
+#[test]
+fn store_and_load() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Xml)?;
+    let cache = Cache::new(mock.path());
+
+    assert!(cache.load().is_none());
+
+    cache.store(&Todo::sample().tasks)?;
+
+    assert_eq!(cache.load(), Some(Todo::sample().tasks));
+
+    Ok(())
+}
+
+#[test]
+fn load_outdated() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+    let cache = Cache::new(mock.path());
+
+    cache.store(&Todo::sample().tasks)?;
+    fs::write(mock.path(), "[]")?;
+
+    assert!(cache.load().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn load_invalid() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+    let cache = Cache::new(mock.path());
+
+    fs::write(cache.path(), "not msgpack")?;
+
+    assert!(cache.load().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn file_uses_cache() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Xml)?;
+    let file = File::from(mock.to_string())?;
+    let cache = Cache::new(mock.path());
+
+    assert_eq!(file.tasks()?, Todo::sample().tasks);
+    assert!(cache.path().exists());
+
+    let cached = vec![Task::from("1,Cached,low,false")];
+    cache.store(&cached)?;
+
+    assert_eq!(file.tasks()?, cached);
+
+    Ok(())
+}
+
+#[test]
+fn file_skips_csv() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let file = File::from(mock.to_string())?;
+
+    assert_eq!(file.tasks()?, Todo::sample().tasks);
+    assert!(!Cache::new(mock.path()).path().exists());
+
+    Ok(())
+}
+
+#[test]
+fn file_remove_clears_cache() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+    let file = File::from(mock.to_string())?;
+
+    file.tasks()?;
+    file.remove()?;
+
+    assert!(!Cache::new(mock.path()).path().exists());
+
+    Ok(())
+}
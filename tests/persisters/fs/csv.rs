@@ -2,11 +2,11 @@ use std::fs;
 use std::io::Read;
 use std::ops::Not;
 
-use postit::fs::{Csv, Format};
-use postit::models::Todo;
+use postit::fs::{Csv, CsvDialect, Format, Quoting};
+use postit::models::{Priority, Task, Todo};
+use postit::testing::{MockConfig, MockPath};
 use postit::traits::FilePersister;
-
-use crate::mocks::MockPath;
+use postit::Postit;
 
 #[test]
 fn default() -> postit::Result<()> {
@@ -51,6 +51,80 @@ fn write() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn write_atomic() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let parent = mock.path().parent().unwrap().to_path_buf();
+
+    mock.instance
+        .write_atomic(b"id,content\n1,Task,med,false")?;
+
+    let leftovers = fs::read_dir(parent)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+        .count();
+
+    assert_eq!(leftovers, 0);
+    assert_eq!(fs::read_to_string(mock.path())?, "id,content\n1,Task,med,false");
+
+    Ok(())
+}
+
+#[test]
+fn write_atomic_keeps_file_on_error() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let before = fs::read_to_string(mock.path())?;
+
+    // A directory in place of the temporary file makes the write fail
+    let temp = mock.path().with_file_name(format!(
+        ".{}.{}.tmp",
+        mock.path().file_name().unwrap().to_string_lossy(),
+        std::process::id()
+    ));
+    fs::create_dir(&temp)?;
+
+    assert!(mock.instance.write_atomic(b"id,content\n").is_err());
+    assert_eq!(fs::read_to_string(mock.path())?, before);
+
+    fs::remove_dir(temp)?;
+
+    Ok(())
+}
+
+#[test]
+fn append() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let csv = Csv::new(mock.path());
+
+    let mut todo = Todo::sample();
+    let task = Task::from("5,Appended,low,false");
+    todo.add(task.clone());
+
+    let before = fs::read_to_string(mock.path())?;
+    csv.append(&task)?;
+    let after = fs::read_to_string(mock.path())?;
+
+    assert!(after.starts_with(&before));
+    assert_eq!(csv.tasks()?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn append_to_empty_file() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let csv = Csv::new(mock.path());
+    fs::write(mock.path(), "")?;
+
+    let task = Task::from("1,First,low,false");
+    csv.append(&task)?;
+
+    assert!(fs::read_to_string(mock.path())?.starts_with(&Csv::header()));
+    assert_eq!(csv.tasks()?, vec![task]);
+
+    Ok(())
+}
+
 #[test]
 fn clean() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
@@ -73,3 +147,226 @@ fn remove() -> postit::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parse() -> postit::Result<()> {
+    let content = format!("{}\n1,Task,high,false\n\n2,Task,low,true\n", Csv::header());
+
+    let result = Csv::parse(&content)?;
+    let expect = vec![Task::from("1,Task,high,false"), Task::from("2,Task,low,true")];
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn parse_without_header() -> postit::Result<()> {
+    let result = Csv::parse("7,Task,none,false")?;
+
+    assert_eq!(result, vec![Task::from("7,Task,none,false")]);
+
+    Ok(())
+}
+
+#[test]
+fn parse_old_header() -> postit::Result<()> {
+    let result = Csv::parse("id,content,priority,checked\n7,Task,none,false,gh#42")?;
+
+    let mut expect = Task::from("7,Task,none,false");
+    expect.refs = vec![String::from("gh#42")];
+
+    assert_eq!(result, vec![expect]);
+
+    Ok(())
+}
+
+#[test]
+fn parse_err_invalid_line() {
+    let err = Csv::parse("1,Task,high,false\nnot a task").unwrap_err();
+
+    assert!(matches!(err, postit::fs::Error::InvalidCsv(2, _)));
+    assert!(err.to_string().contains("line 2"));
+}
+
+#[test]
+fn stream() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let csv = Csv::new(mock.path());
+
+    let result = csv.stream()?.collect::<postit::fs::Result<Vec<Task>>>()?;
+    let expect = Todo::sample().tasks;
+
+    assert_eq!(result, expect);
+
+    Ok(())
+}
+
+#[test]
+fn stream_skips_blank_lines() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let csv = Csv::new(mock.path());
+
+    fs::write(mock.path(), format!("\n{}\n1,Task,med,false\n\n", Csv::header().trim()))?;
+
+    let mut stream = csv.stream()?;
+
+    assert_eq!(stream.next().transpose()?.map(|task| task.id), Some(1));
+    assert!(stream.next().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn stream_checks_header() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let with_header = Csv::new(mock.path());
+    let without_header =
+        Csv::with(mock.path(), CsvDialect { header: false, ..CsvDialect::default() });
+
+    let expect = Todo::sample().tasks;
+
+    assert_eq!(without_header.tasks()?, expect);
+
+    fs::write(mock.path(), "1,Task,med,false\n2,Task,low,true\n")?;
+
+    assert_eq!(with_header.tasks()?.len(), 2);
+    assert_eq!(with_header.tasks()?, without_header.tasks()?);
+
+    Ok(())
+}
+
+#[test]
+fn stream_err_invalid_line() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let csv = Csv::new(mock.path());
+
+    fs::write(
+        mock.path(),
+        format!("{}\n1,Task,med,false\nx,Task,low,true\n", Csv::header().trim()),
+    )?;
+
+    let mut stream = csv.stream()?;
+
+    assert_eq!(stream.next().transpose()?.map(|task| task.id), Some(1));
+    assert!(stream
+        .next()
+        .transpose()
+        .is_err_and(|err| err.to_string().contains("line 3")));
+
+    Ok(())
+}
+
+#[test]
+fn write_content_line_breaks() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let csv = Csv::new(mock.path());
+
+    let task = Task::new(1, String::from("Buy\nmilk\r\n"), Priority::Med, false);
+    csv.write(&Todo::new(vec![task]))?;
+
+    assert_eq!(csv.tasks()?.first().map(|task| task.content.as_str()), Some("Buy milk"));
+
+    Ok(())
+}
+
+#[test]
+fn dialect_split() {
+    let dialect = CsvDialect { delimiter: ';', ..CsvDialect::default() };
+
+    let result = dialect.split("1;\"Buy milk; eggs\";\"Say \"\"hi\"\"\";;x,y");
+    let expect = ["1", "Buy milk; eggs", "Say \"hi\"", "", "x,y"];
+
+    assert_eq!(result, expect);
+}
+
+#[test]
+fn dialect_join() {
+    let fields = [String::from("1"), String::from("Buy milk; eggs"), String::from("\"Hi\"")];
+
+    let needed = CsvDialect { delimiter: ';', ..CsvDialect::default() };
+    let always = CsvDialect {
+        quoting: Quoting::Always,
+        ..needed.clone()
+    };
+
+    assert_eq!(needed.join(&fields), "1;\"Buy milk; eggs\";\"\"\"Hi\"\"\"");
+    assert_eq!(always.join(&fields), "\"1\";\"Buy milk; eggs\";\"\"\"Hi\"\"\"");
+}
+
+#[test]
+fn dialect_header_row() {
+    let tab = CsvDialect { delimiter: '\t', ..CsvDialect::default() };
+    let none = CsvDialect { header: false, ..CsvDialect::default() };
+
+    assert_eq!(CsvDialect::default().header_row(), Csv::header());
+    assert!(tab.header_row().starts_with("id\tcontent\tpriority\t"));
+    assert!(none.header_row().is_empty());
+}
+
+#[test]
+fn dialect_task() {
+    let dialect = CsvDialect { delimiter: '\t', ..CsvDialect::default() };
+
+    let task = dialect.task("3\tPay rent, water\thigh\tfalse\t\twork\tSee the bill, page 2");
+
+    assert_eq!(task.id, 3);
+    assert_eq!(task.content, "Pay rent, water");
+    assert_eq!(task.tags, vec![String::from("work")]);
+    assert_eq!(task.notes, "See the bill, page 2");
+    assert_eq!(dialect.task(&dialect.line(&task)), task);
+}
+
+#[test]
+fn dialect_round_trip() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let dialect = CsvDialect {
+        delimiter: ';',
+        quoting: Quoting::Always,
+        header: false,
+    };
+    let csv = Csv::with(mock.path(), dialect);
+
+    let mut todo = Todo::sample();
+    todo.tasks[0].content = String::from("Milk; eggs and \"bread\"");
+
+    csv.write(&todo)?;
+    csv.append(&Task::from("5,Appended,low,false"))?;
+
+    let content = fs::read_to_string(mock.path())?;
+    assert!(content.starts_with("\"1\";\"Milk; eggs and \"\"bread\"\"\";"));
+
+    todo.tasks.push(Task::from("5,Appended,low,false"));
+    assert_eq!(csv.tasks()?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn dialect_from_config() -> postit::Result<()> {
+    let mut config = MockConfig::new()?;
+    let mock = MockPath::blank(Format::Csv)?;
+
+    config.config.csv = CsvDialect { delimiter: ';', ..CsvDialect::default() };
+    config.config.persister = mock.to_string();
+    config.save()?;
+
+    fs::write(mock.path(), "id;content;priority;checked\n1;Read, then write;high;false\n")?;
+
+    let tasks = Postit::get_persister::<&str>(None)?.tasks()?;
+
+    assert_eq!(tasks[0].content, "Read, then write");
+    assert_eq!(tasks[0].priority, Priority::High);
+
+    config.config.persister = String::from("other.csv");
+    config.save()?;
+
+    // Files that the config doesn't use are read with the default dialect
+    fs::write(mock.path(), "id,content,priority,checked\n1,Read; then write,high,false\n")?;
+
+    let tasks = Postit::get_persister(Some(mock.to_string()))?.tasks()?;
+
+    assert_eq!(tasks[0].content, "Read; then write");
+
+    Ok(())
+}
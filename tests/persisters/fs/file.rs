@@ -1,13 +1,13 @@
 use std::fs;
 use std::ops::Not;
+use std::time::Duration;
 
-use postit::fs::{Csv, File, Format};
+use postit::fs::{Csv, CsvDialect, File, Format, Lock};
 use postit::models::Todo;
+use postit::testing::MockPath;
 use postit::traits::{FilePersister, Persister};
 use postit::Action;
 
-use crate::mocks::MockPath;
-
 #[test]
 fn error_wrap() {
     let msg = "Error";
@@ -16,6 +16,18 @@ fn error_wrap() {
     assert!(matches!(err, postit::fs::Error::Other(_)));
 }
 
+#[test]
+fn error_code() {
+    assert_eq!(postit::fs::Error::IsDirectory.code(), "E201");
+    assert_eq!(postit::fs::Error::UnsupportedFormat.code(), "E202");
+    assert_eq!(postit::fs::Error::Locked(String::new()).code(), "E207");
+    assert_eq!(postit::fs::Error::InvalidXml(1, String::new()).code(), "E208");
+    assert_eq!(postit::fs::Error::InvalidCsv(1, String::new()).code(), "E209");
+    assert_eq!(postit::fs::Error::AlreadyExists(String::new()).code(), "E210");
+    assert_eq!(postit::fs::Error::ReadOnly(String::new()).code(), "E211");
+    assert_eq!(postit::fs::Error::wrap("Error").code(), "E299");
+}
+
 #[test]
 fn exists_return_true() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
@@ -26,6 +38,40 @@ fn exists_return_true() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn lock_path() {
+    let path = std::path::Path::new("dir").join("tasks.csv");
+
+    assert_eq!(Lock::path_of(&path), std::path::Path::new("dir").join(".tasks.csv.lock"));
+}
+
+#[test]
+fn lock_released_on_drop() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let file = File::from(mock.to_string())?;
+
+    let lock = file.lock(Duration::ZERO)?.unwrap();
+    assert!(lock.path().exists());
+    drop(lock);
+
+    assert!(file.lock(Duration::ZERO)?.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn lock_timeout() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let file = File::from(mock.to_string())?;
+
+    let _lock = file.lock(Duration::ZERO)?;
+    let result = Lock::acquire(&mock.path(), Duration::from_millis(100));
+
+    assert!(matches!(result, Err(postit::fs::Error::Locked(_))));
+
+    Ok(())
+}
+
 #[test]
 fn format_from() {
     assert_eq!(Format::from("txt"), Format::Csv);
@@ -183,6 +229,17 @@ fn get_persister_json() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn get_persister_json_compact() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+
+    File::get_persister_with(mock.path(), CsvDialect::default(), false)?.write(&Todo::sample())?;
+
+    assert_eq!(fs::read_to_string(mock.path())?.lines().count(), 1);
+
+    Ok(())
+}
+
 #[test]
 fn get_persister_xml() -> postit::Result<()> {
     let mock = MockPath::create(Format::Xml)?;
@@ -254,8 +311,9 @@ fn file_persister_eq() -> postit::Result<()> {
 fn create_err_already_exists() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
     let file = File::from(mock.to_string())?;
+    let err = file.create().unwrap_err();
 
-    assert!(file.create().is_err());
+    assert!(matches!(err, postit::Error::Fs(postit::fs::Error::AlreadyExists(_))));
 
     Ok(())
 }
@@ -324,3 +382,13 @@ fn remove_err_doesnt_exist() -> postit::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn format_parse() -> postit::Result<()> {
+    let expect = Todo::sample().tasks;
+
+    assert_eq!(Format::Csv.parse(&Csv::header())?, vec![]);
+    assert_eq!(Format::Json.parse(&serde_json::to_string(&expect).unwrap())?, expect);
+
+    Ok(())
+}
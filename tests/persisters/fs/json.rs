@@ -3,11 +3,10 @@ use std::io::Read;
 use std::ops::Not;
 
 use postit::fs::{Format, Json};
-use postit::models::Todo;
+use postit::models::{Task, Todo};
+use postit::testing::MockPath;
 use postit::traits::FilePersister;
 
-use crate::mocks::MockPath;
-
 #[test]
 fn tasks() -> postit::Result<()> {
     let mock = MockPath::create(Format::Json)?;
@@ -44,6 +43,24 @@ fn open_err() {
     assert!(matches!(err, postit::fs::Error::Io(_)));
 }
 
+#[test]
+fn write_pretty_and_compact() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Json)?;
+    let todo = Todo::sample();
+
+    Json::with(mock.path(), true).write(&todo)?;
+    let pretty = fs::read_to_string(mock.path())?;
+
+    Json::with(mock.path(), false).write(&todo)?;
+    let compact = fs::read_to_string(mock.path())?;
+
+    assert!(pretty.lines().count() > todo.tasks.len());
+    assert_eq!(compact.lines().count(), 1);
+    assert_eq!(Json::new(mock.path()).tasks()?, todo.tasks);
+
+    Ok(())
+}
+
 #[test]
 fn clean() -> postit::Result<()> {
     let mock = MockPath::create(Format::Json)?;
@@ -66,3 +83,17 @@ fn remove() -> postit::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parse() -> postit::Result<()> {
+    let content = r#"[{"id":1,"content":"Task","priority":"high","checked":true}]"#;
+
+    assert_eq!(Json::parse(content)?, vec![Task::from("1,Task,high,true")]);
+
+    Ok(())
+}
+
+#[test]
+fn parse_err() {
+    assert!(Json::parse("1,Task,high,true").is_err());
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::ops::Not;
+
+use postit::fs::{Format, Jsonl};
+use postit::models::{Task, Todo};
+use postit::testing::MockPath;
+use postit::traits::FilePersister;
+
+#[test]
+fn tasks() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Jsonl)?;
+
+    let result = Jsonl::new(mock.path()).tasks()?;
+    let expect = Todo::sample().tasks;
+
+    assert_eq!(result, expect);
+    assert_eq!(fs::read_to_string(mock.path())?.lines().count(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn append() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Jsonl)?;
+    let jsonl = Jsonl::new(mock.path());
+
+    let mut todo = Todo::sample();
+    let task = Task::from("5,Appended,low,false");
+    todo.add(task.clone());
+
+    let before = fs::read_to_string(mock.path())?;
+    jsonl.append(&task)?;
+    let after = fs::read_to_string(mock.path())?;
+
+    assert!(after.starts_with(&before));
+    assert_eq!(jsonl.tasks()?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn clean() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Jsonl)?;
+    Jsonl::new(mock.path()).clean()?;
+
+    assert_eq!(Jsonl::new(mock.path()).tasks()?, Vec::new());
+
+    Ok(())
+}
+
+#[test]
+fn remove() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Jsonl)?;
+    Jsonl::new(mock.path()).remove()?;
+
+    assert!(mock.path().exists().not());
+
+    Ok(())
+}
+
+#[test]
+fn parse() -> postit::Result<()> {
+    let content = "{\"id\":1,\"content\":\"Task\",\"priority\":\"high\",\"checked\":true}\n\n";
+
+    assert_eq!(Jsonl::parse(content)?, vec![Task::from("1,Task,high,true")]);
+
+    Ok(())
+}
+
+#[test]
+fn parse_err() {
+    assert!(Jsonl::parse("[]").is_err());
+}
@@ -1,4 +1,7 @@
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod csv;
 pub mod file;
 pub mod json;
+pub mod jsonl;
 pub mod xml;
@@ -3,11 +3,10 @@ use std::io::Read;
 use std::ops::Not;
 
 use postit::fs::{Format, Xml};
-use postit::models::Todo;
+use postit::models::{Task, Todo};
+use postit::testing::MockPath;
 use postit::traits::FilePersister as _;
 
-use crate::mocks::MockPath;
-
 #[test]
 fn default() -> postit::Result<()> {
     let mock = MockPath::create(Format::Xml)?;
@@ -78,3 +77,100 @@ fn remove() -> postit::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parse() -> postit::Result<()> {
+    let content = r#"<Tasks><Task id="4" priority="low" checked="false">Task</Task></Tasks>"#;
+
+    assert_eq!(Xml::parse(content)?, vec![Task::from("4,Task,low,false")]);
+
+    Ok(())
+}
+
+#[test]
+fn parse_empty() -> postit::Result<()> {
+    assert!(Xml::parse(&(Xml::prolog() + &Xml::dtd()))?.is_empty());
+    assert!(Xml::parse("<Tasks>\n</Tasks>")?.is_empty());
+    assert!(Xml::parse("<Tasks/>")?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn parse_err_line() {
+    let err = |content: &str| Xml::parse(content).unwrap_err().to_string();
+
+    let content = format!(
+        "{}{}<Tasks>\n    <Task id=\"1\" priority=\"med\" checked=\"false\">Ok</Task>\n    {}\n</Tasks>",
+        Xml::prolog(),
+        Xml::dtd(),
+        r#"<Task id="x" priority="med" checked="false">Bad</Task>"#
+    );
+    let line = content
+        .lines()
+        .position(|line| line.contains("Bad"))
+        .unwrap()
+        + 1;
+
+    assert_eq!(
+        err(&content),
+        format!("Invalid XML at line {line}: the 'id' attribute of <Task> can't be 'x'")
+    );
+}
+
+#[test]
+fn parse_err_attributes() {
+    let err = |task: &str| {
+        Xml::parse(&format!("<Tasks>{task}</Tasks>"))
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(err(r#"<Task id="1" priority="urgent" checked="false">T</Task>"#)
+        .ends_with("the 'priority' attribute of <Task> can't be 'urgent'"));
+    assert!(err(r#"<Task id="1" priority="med" checked="yes">T</Task>"#)
+        .ends_with("the 'checked' attribute of <Task> can't be 'yes'"));
+    assert!(err(r#"<Task id="1" priority="med" checked="false" due="soon">T</Task>"#)
+        .ends_with("the 'due' attribute of <Task> can't be 'soon'"));
+    assert!(err(r#"<Task id="1" priority="med" checked="false" color="red">T</Task>"#)
+        .ends_with("<Task> has the unknown attribute 'color'"));
+    assert!(err(r#"<Task id="1" checked="false">T</Task>"#)
+        .ends_with("<Task> doesn't have the required 'priority' attribute"));
+}
+
+#[test]
+fn parse_err_elements() {
+    let err = |content: &str| Xml::parse(content).unwrap_err().to_string();
+
+    assert!(err(r#"<Task id="1" priority="med" checked="false">T</Task>"#)
+        .ends_with("the <Task> element can't be here"));
+    assert!(err("<Tasks><Item>T</Item></Tasks>").ends_with("unknown element <Item>"));
+    assert!(err("<Tasks>T</Tasks>").ends_with("unexpected text 'T'"));
+    assert!(err("<Tasks>").ends_with("the <Tasks> element isn't closed"));
+    assert!(err("<Tasks></Task>").starts_with("Invalid XML at line 1: "));
+}
+
+#[test]
+fn refs_tags_and_notes_roundtrip() -> postit::Result<()> {
+    let todo = Todo::new(Task::from("1,Task,high,false,JIRA-123 gh#42,work,Line 1%0ALine 2"));
+    let xml = String::from_utf8(Xml::todo_to_xml(&todo)?).map_err(postit::Error::wrap)?;
+
+    assert!(xml.contains(r#"refs="JIRA-123 gh#42""#));
+    assert!(xml.contains(r#"tags="work""#));
+    assert_eq!(Xml::parse(&xml)?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn timestamps_roundtrip() -> postit::Result<()> {
+    let todo =
+        Todo::new(Task::from("1,Task,high,true,,,,2025-01-31T18:30:00Z,,2025-02-01T09:00:00Z"));
+    let xml = String::from_utf8(Xml::todo_to_xml(&todo)?).map_err(postit::Error::wrap)?;
+
+    assert!(xml.contains(r#"created_at="2025-01-31T18:30:00Z""#));
+    assert!(!xml.contains("updated_at="));
+    assert_eq!(Xml::parse(&xml)?, todo.tasks);
+
+    Ok(())
+}
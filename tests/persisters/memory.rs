@@ -0,0 +1,94 @@
+use postit::memory::Memory;
+use postit::models::Todo;
+use postit::traits::Persister;
+use postit::{Action, Postit};
+
+#[test]
+fn is_memory() {
+    assert!(Memory::is_memory(":memory:"));
+    assert!(Memory::is_memory("memory://work"));
+    assert!(!Memory::is_memory("tasks.db"));
+}
+
+#[test]
+fn get_persister() -> postit::Result<()> {
+    let persister = Postit::get_persister(Some("memory://work"))?;
+
+    assert_eq!(persister.to_string(), "memory://work");
+    assert!(!persister.exists()?);
+
+    Ok(())
+}
+
+#[test]
+fn create_and_exists() -> postit::Result<()> {
+    let memory = Memory::from(":memory:");
+    assert!(memory.tasks().is_err());
+
+    memory.create()?;
+
+    assert!(memory.exists()?);
+    assert!(memory.tasks()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn save_and_tasks() -> postit::Result<()> {
+    let memory = Memory::new();
+    let todo = Todo::sample();
+
+    memory.save(&todo)?;
+
+    assert_eq!(memory.tasks()?, todo.tasks);
+    assert_eq!(memory.next_id()?, 5);
+
+    Ok(())
+}
+
+#[test]
+fn edit() -> postit::Result<()> {
+    let memory = Memory::with(Todo::sample().tasks);
+
+    let mut todo = Todo::from(&memory)?;
+    todo.check(&[2])?;
+    memory.edit(&todo, &[2], &Action::Check)?;
+
+    assert!(memory.tasks()?[1].checked);
+
+    Ok(())
+}
+
+#[test]
+fn edit_doesnt_exist() {
+    let memory = Memory::from(":memory:");
+
+    assert!(memory.edit(&Todo::sample(), &[1], &Action::Check).is_err());
+}
+
+#[test]
+fn clean_and_remove() -> postit::Result<()> {
+    let memory = Memory::with(Todo::sample().tasks);
+
+    memory.clean()?;
+    assert!(memory.tasks()?.is_empty());
+
+    memory.remove()?;
+    assert!(!memory.exists()?);
+
+    Ok(())
+}
+
+#[test]
+fn library_api() -> postit::Result<()> {
+    let memory = Memory::new();
+
+    Postit::add_task(&memory, String::from("Read"), postit::models::Priority::Low, vec![])?;
+    Postit::add_task(&memory, String::from("Write"), postit::models::Priority::High, vec![])?;
+
+    let ids: Vec<u32> = Postit::tasks(&memory)?.iter().map(|task| task.id).collect();
+
+    assert_eq!(ids, [1, 2]);
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use postit::fs::{Format, Lock};
+use postit::memory::Memory;
+use postit::mirror::Mirror;
+use postit::models::{Priority, Task, Todo};
+use postit::testing::{MockConfig, MockPath};
+use postit::traits::Persister;
+use postit::{Action, Postit};
+
+fn mirror(tasks: Vec<Task>) -> Mirror {
+    let backup = Memory::new();
+    Mirror::new(Memory::with(tasks).boxed(), vec![backup.boxed()])
+}
+
+#[test]
+fn reads_from_primary() -> postit::Result<()> {
+    let backup = Memory::with(Vec::new());
+    let mirror = Mirror::new(Memory::with(Todo::sample().tasks).boxed(), vec![backup.boxed()]);
+
+    assert_eq!(mirror.to_string(), ":memory:");
+    assert_eq!(mirror.tasks()?, Todo::sample().tasks);
+    assert_eq!(mirror.next_id()?, 5);
+
+    Ok(())
+}
+
+#[test]
+fn save_fans_out() -> postit::Result<()> {
+    let mirror = mirror(Vec::new());
+    let todo = Todo::sample();
+
+    mirror.save(&todo)?;
+
+    assert_eq!(mirror.primary().tasks()?, todo.tasks);
+    assert_eq!(mirror.mirrors()[0].tasks()?, todo.tasks);
+
+    Ok(())
+}
+
+#[test]
+fn edit_fans_out() -> postit::Result<()> {
+    let mirror = mirror(Todo::sample().tasks);
+    mirror.mirrors()[0].save(&Todo::sample())?;
+
+    let mut todo = Todo::from(&mirror)?;
+    todo.check(&[2])?;
+    mirror.edit(&todo, &[2], &Action::Check)?;
+
+    assert!(mirror.primary().tasks()?[1].checked);
+    assert!(mirror.mirrors()[0].tasks()?[1].checked);
+
+    Ok(())
+}
+
+#[test]
+fn missing_mirror_is_created() -> postit::Result<()> {
+    let backup = Memory::from("memory://backup");
+    let mirror = Mirror::new(Memory::with(Todo::sample().tasks).boxed(), vec![backup.boxed()]);
+
+    mirror.append(&[Task::from("5,Test,med,false")])?;
+
+    assert!(mirror.mirrors()[0].exists()?);
+    assert_eq!(mirror.mirrors()[0].tasks()?, mirror.tasks()?);
+    assert_eq!(mirror.tasks()?.len(), 5);
+
+    Ok(())
+}
+
+#[test]
+fn clean_and_remove_keep_mirrors() -> postit::Result<()> {
+    let mirror = mirror(Todo::sample().tasks);
+    mirror.mirrors()[0].save(&Todo::sample())?;
+
+    mirror.clean()?;
+    assert!(mirror.tasks()?.is_empty());
+    assert_eq!(mirror.mirrors()[0].tasks()?, Todo::sample().tasks);
+
+    mirror.remove()?;
+    assert!(!mirror.exists()?);
+    assert_eq!(mirror.mirrors()[0].tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn mirrors_keep_ids() -> postit::Result<()> {
+    let tasks = vec![Task::from("2,Second,low,false"), Task::from("7,Seventh,high,true")];
+
+    // The mirror missed the changes that dropped the rest of tasks
+    let mirror = mirror(tasks.clone());
+    mirror.mirrors()[0].save(&Todo::sample())?;
+
+    mirror.append(&[Task::from("8,Eighth,med,false")])?;
+
+    assert_eq!(mirror.mirrors()[0].tasks()?, mirror.tasks()?);
+    assert_eq!(mirror.mirrors()[0].tasks()?[..2], tasks);
+
+    Ok(())
+}
+
+#[test]
+fn lock_and_clone() -> postit::Result<()> {
+    let primary = MockPath::create(Format::Csv)?;
+    let backup = MockPath::create(Format::Json)?;
+
+    let mirror = Mirror::new(
+        Postit::get_persister(Some(primary.to_string()))?,
+        vec![Postit::get_persister(Some(backup.to_string()))?],
+    );
+
+    let lock = mirror.lock(Duration::ZERO)?;
+    assert!(lock.is_some());
+    assert!(Lock::acquire(&backup.path(), Duration::ZERO).is_err());
+
+    drop(lock);
+    assert!(Lock::acquire(&backup.path(), Duration::ZERO).is_ok());
+
+    let cloned = mirror.boxed().clone();
+    cloned.append(&[Task::from("5,Test,med,false")])?;
+
+    let backup = Postit::get_persister(Some(backup.to_string()))?;
+    assert_eq!(backup.tasks()?.len(), 5);
+
+    Ok(())
+}
+
+#[test]
+fn postit_uses_mirrors() -> postit::Result<()> {
+    let primary = MockPath::create(Format::Csv)?;
+    let backup = MockPath::blank(Format::Json)?;
+    std::fs::remove_file(backup.path())?;
+
+    let mut config = MockConfig::new()?;
+    config.config.persister = primary.to_string();
+    config.config.mirrors = vec![backup.to_string()];
+    config.save()?;
+
+    let postit = Postit::new(None);
+    let persister = postit.persister(None::<&str>)?;
+
+    Postit::add_task(persister.as_ref(), String::from("Read"), Priority::Med, vec![])?;
+
+    let backup = Postit::get_persister(Some(backup.to_string()))?;
+    assert_eq!(backup.tasks()?, persister.tasks()?);
+    assert_eq!(backup.tasks()?.len(), 5);
+
+    Ok(())
+}
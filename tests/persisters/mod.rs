@@ -1,3 +1,8 @@
 pub mod db;
 pub mod fs;
+pub mod memory;
+pub mod mirror;
+#[cfg(unix)]
+pub mod plugin;
+pub mod registry;
 pub mod traits;
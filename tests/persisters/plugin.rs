@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use postit::fs::Format;
+use postit::models::{Plugins, Todo};
+use postit::plugin::Plugin;
+use postit::testing::MockPath;
+use postit::traits::Persister;
+use serde_json::{json, Value};
+
+fn plugin(command: &str) -> postit::Result<Plugin> {
+    let plugins = Plugins(BTreeMap::from([(String::from("test"), command.to_owned())]));
+    Plugin::from("plugin://test/inbox", &plugins)
+}
+
+#[test]
+fn is_plugin() {
+    assert!(Plugin::is_plugin("plugin://notion"));
+    assert!(!Plugin::is_plugin("tasks.csv"));
+}
+
+#[test]
+fn name() {
+    assert_eq!(Plugin::name("plugin://notion"), "notion");
+    assert_eq!(Plugin::name("plugin://notion/inbox"), "notion");
+    assert_eq!(Plugin::name("plugin://notion?db=tasks"), "notion");
+}
+
+#[test]
+fn from_unregistered() {
+    let err = Plugin::from("plugin://notion", &Plugins::default()).unwrap_err();
+
+    assert_eq!(err.code(), "E004");
+    assert!(err.to_string().contains("'notion'"));
+}
+
+#[test]
+fn tasks() -> postit::Result<()> {
+    let mock = MockPath::blank(Format::Json)?;
+    let response = json!({ "tasks": Todo::sample().tasks });
+    fs::write(mock.path(), response.to_string())?;
+
+    let plugin = plugin(&format!("cat '{}'", mock.path().display()))?;
+
+    assert_eq!(plugin.to_string(), "plugin://test/inbox");
+    assert_eq!(plugin.tasks()?, Todo::sample().tasks);
+
+    Ok(())
+}
+
+#[test]
+fn exists() -> postit::Result<()> {
+    assert!(plugin(r#"echo '{"exists": true}'"#)?.exists()?);
+    assert!(plugin("echo '{}'")?.exists().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn save_sends_request() -> postit::Result<()> {
+    let mock = MockPath::blank(Format::Json)?;
+    let plugin = plugin(&format!("cat > '{}'; echo '{{}}'", mock.path().display()))?;
+
+    plugin.save(&Todo::sample())?;
+
+    let request: Value = serde_json::from_str(&fs::read_to_string(mock.path())?).unwrap();
+
+    assert_eq!(request["version"], 1);
+    assert_eq!(request["op"], "save");
+    assert_eq!(request["conn"], "plugin://test/inbox");
+    assert_eq!(request["tasks"], json!(Todo::sample().tasks));
+
+    Ok(())
+}
+
+#[test]
+fn error_response() -> postit::Result<()> {
+    let err = plugin(r#"echo '{"error": "no access"}'"#)?
+        .clean()
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "The 'test' plugin failed: no access");
+
+    Ok(())
+}
+
+#[test]
+fn failing_command() -> postit::Result<()> {
+    let err = plugin("echo 'not found' >&2; exit 3")?
+        .remove()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "The 'test' plugin failed: not found");
+
+    let err = plugin("echo 'not json'")?.create().unwrap_err();
+    assert!(err.to_string().contains("invalid JSON"));
+
+    Ok(())
+}
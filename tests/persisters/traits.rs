@@ -1,10 +1,14 @@
+#[cfg(feature = "mongo")]
 use postit::db::{Mongo, Protocol};
 use postit::fs::{Csv, Format};
-use postit::traits::{DbPersister, FilePersister};
+#[cfg(feature = "mongo")]
+use postit::testing::MockConn;
+use postit::testing::MockPath;
+#[cfg(feature = "mongo")]
+use postit::traits::DbPersister;
+use postit::traits::FilePersister;
 use postit::Postit;
 
-use crate::mocks::{MockConn, MockPath};
-
 #[test]
 fn persister_eq() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
@@ -18,6 +22,20 @@ fn persister_eq() -> postit::Result<()> {
     Ok(())
 }
 
+#[test]
+fn persister_next_id() -> postit::Result<()> {
+    let mock = MockPath::create(Format::Csv)?;
+    let file = Postit::get_persister(Some(mock.to_string()))?;
+
+    assert_eq!(file.next_id()?, 5);
+
+    file.clean()?;
+
+    assert_eq!(file.next_id()?, 1);
+
+    Ok(())
+}
+
 #[test]
 fn file_persister_eq() -> postit::Result<()> {
     let mock = MockPath::create(Format::Csv)?;
@@ -31,6 +49,7 @@ fn file_persister_eq() -> postit::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "mongo")]
 #[test]
 fn db_persister_eq() -> postit::Result<()> {
     let mock = MockConn::create(Protocol::Mongo)?;